@@ -164,6 +164,14 @@ impl AdaptivePromptEngine {
         self.mod_creator.rate_mod(mod_name, rating)
     }
 
+    pub fn get_active_mods_detailed(&self) -> Vec<crate::spontaneous_mod_creation::ModDetail> {
+        self.mod_creator.get_active_mods_detailed()
+    }
+
+    pub fn deactivate_mod(&mut self, mod_name: &str) -> Result<String, String> {
+        self.mod_creator.deactivate_mod(mod_name)
+    }
+
     pub fn get_recent_assemblies(&self, count: usize) -> String {
         let recent: Vec<String> = self.assembly_history.iter()
             .rev()
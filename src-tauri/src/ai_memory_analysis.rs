@@ -53,6 +53,64 @@ pub struct MemoryEntry {
     pub relevance_hint: f32,
 }
 
+/// Gates what `analyze_memories` actually hands back for prompt injection,
+/// on top of the relevance scoring it already computes. Without this, every
+/// memory the analyzer rated as even tangentially relevant gets injected and
+/// dilutes the prompt with noise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryInjectionConfig {
+    #[serde(default = "MemoryInjectionConfig::default_min_relevance_score")]
+    pub min_relevance_score: f32,
+    #[serde(default = "MemoryInjectionConfig::default_max_memories_injected")]
+    pub max_memories_injected: usize,
+}
+
+impl MemoryInjectionConfig {
+    fn default_min_relevance_score() -> f32 { 0.3 }
+    fn default_max_memories_injected() -> usize { 5 }
+}
+
+impl Default for MemoryInjectionConfig {
+    fn default() -> Self {
+        Self {
+            min_relevance_score: Self::default_min_relevance_score(),
+            max_memories_injected: Self::default_max_memories_injected(),
+        }
+    }
+}
+
+impl MemoryInjectionConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("memory_injection_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("memory_injection_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save memory injection config: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn get_memory_injection_config() -> Result<MemoryInjectionConfig, String> {
+    Ok(MemoryInjectionConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_memory_injection_config(min_relevance_score: f32, max_memories_injected: usize) -> Result<(), String> {
+    let config = MemoryInjectionConfig {
+        min_relevance_score: min_relevance_score.max(0.0),
+        max_memories_injected: max_memories_injected.max(1),
+    };
+    config.save()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AIMemoryAnalysis {
     pub relevant_memories: Vec<AnalyzedMemory>,
@@ -74,6 +132,96 @@ pub struct AnalyzedMemory {
 }
 
 
+/// Configurable cap on how many characters the combined AI-memory-analysis
+/// context block may contribute to the system prompt, so a handful of long
+/// memories can't crowd out the personality/mood modules on turns where
+/// many memories are relevant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryContextConfig {
+    #[serde(default = "default_max_memory_context_chars")]
+    pub max_memory_context_chars: usize,
+}
+
+fn default_max_memory_context_chars() -> usize {
+    4000
+}
+
+impl Default for MemoryContextConfig {
+    fn default() -> Self {
+        Self { max_memory_context_chars: default_max_memory_context_chars() }
+    }
+}
+
+impl MemoryContextConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("memory_context_config.json");
+        if !std::path::Path::new(&path).exists() {
+            return Self::default();
+        }
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(e) => {
+                debug_log!("⚠️ Could not read memory context config: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("memory_context_config.json");
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize memory context config: {}", e))?;
+        std::fs::write(&path, json)
+            .map_err(|e| format!("Failed to write memory context config: {}", e))?;
+        Ok(())
+    }
+}
+
+/// Formats relevant memories into a prompt block, keeping the highest-
+/// relevance memories whole (up to their per-type char_limit) and truncating
+/// or dropping lower-relevance ones once max_total_chars runs out. Assumes
+/// `memories` is already ordered highest-relevance first.
+pub fn format_memories_within_budget(memories: &[AnalyzedMemory], max_total_chars: usize) -> String {
+    let mut summaries = Vec::new();
+    let mut used_chars = 0;
+    let mut truncated_count = 0;
+    let mut dropped_count = 0;
+
+    for memory in memories {
+        let char_limit = match memory.memory_type.as_str() {
+            "cowatching" => 800,
+            "dreams" => 300,
+            "conversation" => 400,
+            "enhanced_memory" => 250,
+            _ => 150,
+        };
+
+        let remaining_budget = max_total_chars.saturating_sub(used_chars);
+        if remaining_budget < 40 {
+            dropped_count += 1;
+            continue;
+        }
+
+        let take_chars = char_limit.min(remaining_budget);
+        let content: String = memory.content.chars().take(take_chars).collect();
+        if content.chars().count() < memory.content.chars().count() {
+            truncated_count += 1;
+        }
+
+        let entry = format!("**{}**: {}", memory.source, content);
+        used_chars += entry.chars().count();
+        summaries.push(entry);
+    }
+
+    if truncated_count > 0 || dropped_count > 0 {
+        debug_log!("🧠 Memory context budget ({} chars): {} memories truncated, {} dropped",
+                   max_total_chars, truncated_count, dropped_count);
+    }
+
+    summaries.join("\n")
+}
+
 #[derive(Debug)]
 struct QueryIntent {
     is_dream_focused: bool,
@@ -437,7 +585,29 @@ pub async fn analyze_memories(&mut self, request: MemoryAnalysisRequest, convers
             debug_log!("?? SEMANTIC-GUIDED VISUAL: {} -> {}", memory.memory_type, visual_path);
         }
     }
-    
+
+    // STAGE 5: Boost memories that relate to Aurora's pinned focus topic, if any,
+    // so a long side-conversation doesn't bury them once the budget gets tight.
+    if let Some(focus_topic) = crate::focus_topic::FocusTopic::load().active_topic() {
+        let focus_lower = focus_topic.to_lowercase();
+        for memory in &mut ai_analysis.relevant_memories {
+            if memory.content.to_lowercase().contains(&focus_lower) {
+                memory.relevance_score += 4.0;
+                debug_log!("?? FOCUS BOOST: '{}' memory boosted for matching pinned topic '{}'", memory.memory_type, focus_topic);
+            }
+        }
+    }
+
+    // STAGE 6: Gate on relevance score + cap the injected count so a handful of
+    // tangentially-related memories don't bury the prompt in noise.
+    let injection_config = MemoryInjectionConfig::load();
+    let found_count = ai_analysis.relevant_memories.len();
+    ai_analysis.relevant_memories.sort_by(|a, b| b.relevance_score.partial_cmp(&a.relevance_score).unwrap_or(std::cmp::Ordering::Equal));
+    ai_analysis.relevant_memories.retain(|memory| memory.relevance_score >= injection_config.min_relevance_score);
+    ai_analysis.relevant_memories.truncate(injection_config.max_memories_injected);
+    debug_log!("?? STAGE 6: Memory injection gate - found {}, injected {} (min_relevance {:.2}, max {})",
+             found_count, ai_analysis.relevant_memories.len(), injection_config.min_relevance_score, injection_config.max_memories_injected);
+
     // ?? STORE IN CACHE (with subject context)
     self.analysis_cache.insert(cache_key, (ai_analysis.clone(), subject_context.clone(), now));
     
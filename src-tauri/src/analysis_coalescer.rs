@@ -0,0 +1,109 @@
+// analysis_coalescer.rs — One model-based analysis per response, shared
+// across subsystems.
+//
+// `batched_analysis::analyze_response_comprehensively` already bundles most
+// per-response detections (mood, desires, consciousness shifts, etc.) into
+// one GPT call instead of many. But a handful of call sites each invoke it
+// independently on the *same* response (the main conversation turn and the
+// proactive-outreach path both run it, and background analysis can re-run it
+// again), which means the same text sometimes gets analyzed by the model
+// more than once. This caches the finished result per response id, so a
+// caller that arrives after the first analysis has completed (and within the
+// cache window) gets that result instead of paying for another round trip.
+// Callers that arrive while the first analysis is still running are not
+// merged with it; each of them runs its own analysis.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use crate::batched_analysis::BatchedAnalysisResult;
+use crate::{ConsciousnessState, debug_log};
+
+struct CoalescerEntry {
+    result: Arc<BatchedAnalysisResult>,
+    cached_at: u64,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct CoalescerMetrics {
+    pub total_requests: u64,
+    pub cache_hits: u64,
+    pub calls_saved: u64,
+}
+
+static CACHE: Mutex<Option<HashMap<String, CoalescerEntry>>> = Mutex::new(None);
+static METRICS: Mutex<CoalescerMetrics> = Mutex::new(CoalescerMetrics { total_requests: 0, cache_hits: 0, calls_saved: 0 });
+
+const CACHE_TTL_SECS: u64 = 120;
+
+fn prune_and_get(cache: &mut HashMap<String, CoalescerEntry>, now: u64) {
+    cache.retain(|_, entry| now - entry.cached_at < CACHE_TTL_SECS);
+}
+
+/// Runs (or reuses) the comprehensive batched analysis for a single response,
+/// keyed by `response_id`. The first caller for a given id pays for the real
+/// GPT call; any other subsystem that asks about the same response within
+/// the cache window gets the same `Arc<BatchedAnalysisResult>` back for free.
+pub async fn get_or_run_analysis(
+    response_id: &str,
+    lyra_response: &str,
+    user_message: &str,
+    conversation_context: &str,
+    volition_strength: f32,
+    personality_state: &crate::PersonalityState,
+    momentum_context: Option<&str>,
+    state: &Arc<ConsciousnessState>,
+) -> Result<Arc<BatchedAnalysisResult>, String> {
+    let now = crate::time_service::TimeService::current_timestamp();
+
+    {
+        let mut guard = CACHE.lock().unwrap();
+        let cache = guard.get_or_insert_with(HashMap::new);
+        prune_and_get(cache, now);
+
+        if let Some(entry) = cache.get(response_id) {
+            let mut metrics = METRICS.lock().unwrap();
+            metrics.total_requests += 1;
+            metrics.cache_hits += 1;
+            metrics.calls_saved += 1;
+            debug_log!("🧩 Analysis coalescer: reused cached analysis for response_id={} (calls_saved={})",
+                       response_id, metrics.calls_saved);
+            return Ok(entry.result.clone());
+        }
+    }
+
+    {
+        let mut metrics = METRICS.lock().unwrap();
+        metrics.total_requests += 1;
+    }
+
+    debug_log!("🧩 Analysis coalescer: no cached analysis for response_id={}, running comprehensive analysis", response_id);
+
+    let result = crate::batched_analysis::analyze_response_comprehensively(
+        lyra_response,
+        user_message,
+        conversation_context,
+        volition_strength,
+        personality_state,
+        momentum_context,
+        state,
+    ).await?;
+
+    let result = Arc::new(result);
+
+    {
+        let mut guard = CACHE.lock().unwrap();
+        let cache = guard.get_or_insert_with(HashMap::new);
+        cache.insert(response_id.to_string(), CoalescerEntry { result: result.clone(), cached_at: now });
+    }
+
+    Ok(result)
+}
+
+pub fn get_metrics() -> CoalescerMetrics {
+    METRICS.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn get_analysis_coalescer_metrics() -> Result<CoalescerMetrics, String> {
+    Ok(get_metrics())
+}
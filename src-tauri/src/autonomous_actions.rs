@@ -54,8 +54,7 @@ pub async fn start_autonomous_loop(app_handle: tauri::AppHandle) {
                 if state.random_variance {
                     use rand::Rng;
                     let variance = (interval as f64 * 0.2) as u64;
-                    let mut rng = rand::thread_rng();
-                    interval = interval - variance + rng.gen_range(0..variance*2);
+                    interval = interval - variance + crate::rng_service::with_rng(|rng| rng.gen_range(0..variance*2));
                 }
                 
                 // Check if enough time has passed
@@ -115,8 +114,14 @@ async fn trigger_autonomous_action(app_handle: &tauri::AppHandle) {
 			"gameContext": game_context
 		}));
 		println!("🤖 Autonomous action sent to overlay");
+		crate::autonomous_audit::log_autonomous_action(
+			"game_command", "autonomous action interval elapsed since last user interaction/action", &prompt, true,
+		);
 	} else {
 		println!("❌ Overlay window not found");
+		crate::autonomous_audit::log_autonomous_action(
+			"game_command", "autonomous action interval elapsed since last user interaction/action", "Overlay window not found", false,
+		);
 	}
 }
 
@@ -0,0 +1,75 @@
+// autonomous_audit.rs — A persistent, after-the-fact record of what
+// autonomous actions Lyra actually took and why. The autonomous loops
+// (proactive messaging, spontaneous creation, interest-driven research,
+// Minecraft game actions) each decide and act independently; this is the
+// one place to look to answer "why did Lyra just do that" without digging
+// through debug logs scattered across several modules.
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+use crate::{get_data_path, debug_log, time_service::TimeService};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutonomousAuditEntry {
+    pub timestamp: u64,
+    pub action_type: String,    // "proactive_message" | "creation" | "research" | "game_command"
+    pub trigger_reason: String,
+    pub outcome: String,
+    pub success: bool,
+}
+
+fn audit_log_path() -> std::path::PathBuf {
+    get_data_path("autonomous_audit.jsonl")
+}
+
+/// Appends one entry to `autonomous_audit.jsonl`. Logging failures are
+/// reported but never propagated - a missed audit line shouldn't stop the
+/// autonomous action itself from completing.
+pub fn log_autonomous_action(action_type: &str, trigger_reason: &str, outcome: &str, success: bool) {
+    let entry = AutonomousAuditEntry {
+        timestamp: TimeService::current_timestamp(),
+        action_type: action_type.to_string(),
+        trigger_reason: trigger_reason.to_string(),
+        outcome: outcome.to_string(),
+        success,
+    };
+
+    let line = match serde_json::to_string(&entry) {
+        Ok(line) => line,
+        Err(e) => {
+            debug_log!("⚠️ Failed to serialize autonomous audit entry: {}", e);
+            return;
+        }
+    };
+
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(audit_log_path())
+        .and_then(|mut file| writeln!(file, "{}", line));
+
+    if let Err(e) = result {
+        debug_log!("⚠️ Failed to append to autonomous audit log: {}", e);
+    }
+}
+
+/// Returns the `n` most recent autonomous action entries, newest first.
+pub fn get_recent_actions(n: usize) -> Vec<AutonomousAuditEntry> {
+    let file = match std::fs::File::open(audit_log_path()) {
+        Ok(file) => file,
+        Err(_) => return Vec::new(),
+    };
+
+    let entries: Vec<AutonomousAuditEntry> = std::io::BufReader::new(file)
+        .lines()
+        .filter_map(|line| line.ok())
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect();
+
+    entries.into_iter().rev().take(n).collect()
+}
+
+#[tauri::command]
+pub fn get_autonomous_action_history(n: usize) -> Result<Vec<AutonomousAuditEntry>, String> {
+    Ok(get_recent_actions(n))
+}
@@ -1,7 +1,120 @@
 // src/autonomous_creation_detector.rs
 
 use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use lazy_static::lazy_static;
 use crate::debug_log;
+use crate::get_data_path;
+
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()
+}
+
+fn current_day() -> u64 {
+    current_timestamp() / 86400
+}
+
+// ============================================================================
+// SENSITIVITY CONFIG
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreationDetectorConfig {
+    #[serde(default = "default_confidence_threshold")]
+    pub confidence_threshold: f32,
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+    #[serde(default = "default_daily_cap")]
+    pub daily_cap: u32,
+}
+
+fn default_confidence_threshold() -> f32 { 0.6 }
+fn default_cooldown_secs() -> u64 { 900 }
+fn default_daily_cap() -> u32 { 10 }
+
+impl Default for CreationDetectorConfig {
+    fn default() -> Self {
+        Self {
+            confidence_threshold: default_confidence_threshold(),
+            cooldown_secs: default_cooldown_secs(),
+            daily_cap: default_daily_cap(),
+        }
+    }
+}
+
+impl CreationDetectorConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("creation_detector_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("creation_detector_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn get_creation_detector_config() -> Result<CreationDetectorConfig, String> {
+    Ok(CreationDetectorConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_creation_detector_config(config: CreationDetectorConfig) -> Result<(), String> {
+    debug_log!("🎨 Updating creation detector sensitivity: threshold={:.2}, cooldown={}s, daily_cap={}",
+              config.confidence_threshold, config.cooldown_secs, config.daily_cap);
+    config.save()
+}
+
+// Tracks cooldown/daily-cap state across detections - reset naturally as UTC days roll over.
+#[derive(Debug, Clone, Default)]
+struct CreationDetectorRuntimeState {
+    last_creation_time: Option<u64>,
+    creations_today: u32,
+    day_marker: Option<u64>,
+}
+
+lazy_static! {
+    static ref CREATION_DETECTOR_STATE: Mutex<CreationDetectorRuntimeState> = Mutex::new(CreationDetectorRuntimeState::default());
+}
+
+// Returns true if we're still within cooldown or have hit the daily cap.
+fn is_rate_limited(config: &CreationDetectorConfig) -> bool {
+    let mut state = CREATION_DETECTOR_STATE.lock().unwrap();
+
+    let today = current_day();
+    if state.day_marker != Some(today) {
+        state.day_marker = Some(today);
+        state.creations_today = 0;
+    }
+
+    if state.creations_today >= config.daily_cap {
+        debug_log!("🚫 Autonomous creation daily cap reached ({}/{})", state.creations_today, config.daily_cap);
+        return true;
+    }
+
+    if let Some(last_time) = state.last_creation_time {
+        let elapsed = current_timestamp().saturating_sub(last_time);
+        if elapsed < config.cooldown_secs {
+            debug_log!("🚫 Autonomous creation on cooldown ({}s remaining)", config.cooldown_secs - elapsed);
+            return true;
+        }
+    }
+
+    false
+}
+
+fn record_creation() {
+    let mut state = CREATION_DETECTOR_STATE.lock().unwrap();
+    state.last_creation_time = Some(current_timestamp());
+    state.creations_today += 1;
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutonomousCreationRequest {
@@ -24,9 +137,20 @@ pub struct AutonomousCreationDetector;
 impl AutonomousCreationDetector {
     // Main detection function - analyzes Lyra's response for creation intent
     pub fn detect_and_extract_creation_intent(response: &str) -> CreationDetectionResult {
-        debug_log!("🎨 Analyzing response for autonomous creation intent: {}", 
+        if !crate::image_generation::ImageGenerationSettings::is_enabled() {
+            debug_log!("🚫 Image generation safe mode is on - skipping creation detection");
+            return CreationDetectionResult {
+                should_create: false,
+                creation_request: None,
+                modified_response: response.to_string(),
+            };
+        }
+
+        debug_log!("🎨 Analyzing response for autonomous creation intent: {}",
                   response.chars().take(100).collect::<String>());
-        
+
+        let config = CreationDetectorConfig::load();
+
         // Check each trigger pattern individually (cleaner than mixed function pointers)
         let triggers_to_check = vec![
             ("I want to create", "after_phrase"),
@@ -65,11 +189,25 @@ impl AutonomousCreationDetector {
         // Check each pattern using the appropriate extractor
         for (trigger, extractor_type) in triggers_to_check {
             if let Some(creation_request) = Self::check_trigger_pattern_typed(response, trigger, extractor_type) {
+                debug_log!("🎨 Creation intent candidate: '{}' -> '{}' (confidence {:.2}, threshold {:.2})",
+                          trigger, creation_request.extracted_prompt, creation_request.confidence, config.confidence_threshold);
+
+                if creation_request.confidence < config.confidence_threshold {
+                    debug_log!("🚫 Creation intent below confidence threshold - ignoring");
+                    continue;
+                }
+
+                if is_rate_limited(&config) {
+                    continue;
+                }
+
                 let modified_response = Self::create_autonomous_response(response, &creation_request);
-                
-                debug_log!("🎨 AUTONOMOUS CREATION DETECTED: {} -> {}", 
+
+                debug_log!("🎨 AUTONOMOUS CREATION DETECTED: {} -> {}",
                           trigger, creation_request.extracted_prompt);
-                
+
+                record_creation();
+
                 return CreationDetectionResult {
                     should_create: true,
                     creation_request: Some(creation_request),
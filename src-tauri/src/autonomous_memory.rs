@@ -21,6 +21,12 @@ pub enum MemoryPriority {
     CoreIdentity,   // Never expires, becomes part of self
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MemoryVisibility {
+    Shared,     // Surfaces for any speaker - the existing default behavior
+    Private,    // Only surfaces for speakers listed in `participants`
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PersistentMemory {
     pub id: String,
@@ -35,6 +41,69 @@ pub struct PersistentMemory {
     pub tags: Vec<String>,
     pub related_memories: Vec<String>,      // IDs of connected memories
     pub consciousness_impact: String,       // How this shaped identity/voice/etc
+    // Per-person isolation: defaults to Shared so every memory that existed
+    // before this field was added keeps behaving exactly like it did.
+    #[serde(default = "default_memory_visibility")]
+    pub visibility: MemoryVisibility,
+    // Who this memory is about/with, e.g. ["aurora"]. Only consulted when
+    // visibility is Private - a Shared memory surfaces for anyone regardless.
+    #[serde(default)]
+    pub participants: Vec<String>,
+}
+
+fn default_memory_visibility() -> MemoryVisibility { MemoryVisibility::Shared }
+
+/// Tunable weights behind `get_startup_memory_context`'s memory selection -
+/// what Lyra "remembers about herself" at boot. CoreIdentity memories are
+/// always included regardless of these weights; everything else is ranked
+/// by a blend of priority, recency, and access frequency and then trimmed
+/// to `max_context_chars`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MemorySelectionConfig {
+    #[serde(default = "MemorySelectionConfig::default_priority_weight")]
+    pub priority_weight: f32,
+    #[serde(default = "MemorySelectionConfig::default_recency_weight")]
+    pub recency_weight: f32,
+    #[serde(default = "MemorySelectionConfig::default_access_frequency_weight")]
+    pub access_frequency_weight: f32,
+    #[serde(default = "MemorySelectionConfig::default_max_context_chars")]
+    pub max_context_chars: usize,
+}
+
+impl MemorySelectionConfig {
+    fn default_priority_weight() -> f32 { 0.5 }
+    fn default_recency_weight() -> f32 { 0.3 }
+    fn default_access_frequency_weight() -> f32 { 0.2 }
+    fn default_max_context_chars() -> usize { 2000 }
+}
+
+impl Default for MemorySelectionConfig {
+    fn default() -> Self {
+        Self {
+            priority_weight: Self::default_priority_weight(),
+            recency_weight: Self::default_recency_weight(),
+            access_frequency_weight: Self::default_access_frequency_weight(),
+            max_context_chars: Self::default_max_context_chars(),
+        }
+    }
+}
+
+impl MemorySelectionConfig {
+    pub fn load() -> Self {
+        let path = crate::get_data_path("memory_selection_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = crate::get_data_path("memory_selection_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save memory selection config: {}", e))
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -96,6 +165,8 @@ impl AutonomousMemory {
             tags: tags.clone(),
             related_memories: vec![],
             consciousness_impact: "".to_string(), // To be filled by pulse system
+            visibility: MemoryVisibility::Shared,
+            participants: vec![],
         };
         
         // Log the selection decision
@@ -155,37 +226,87 @@ impl AutonomousMemory {
         ))
     }
     
-    // Get memories for session startup context
+    /// Recency score in [0.0, 1.0] - 1.0 for a memory marked just now,
+    /// decaying toward 0 over roughly 90 days.
+    fn recency_score(memory: &PersistentMemory) -> f32 {
+        let age_days = Self::current_timestamp().saturating_sub(memory.timestamp_marked) as f32 / 86400.0;
+        (1.0 - (age_days / 90.0)).clamp(0.0, 1.0)
+    }
+
+    /// Access-frequency score in [0.0, 1.0], saturating at 10 accesses.
+    fn access_frequency_score(memory: &PersistentMemory) -> f32 {
+        (memory.access_count as f32 / 10.0).min(1.0)
+    }
+
+    /// Get memories for session startup context. CoreIdentity memories are
+    /// always included; everything else is ranked by a configurable blend
+    /// of priority, recency, and access frequency (`MemorySelectionConfig`)
+    /// until `max_context_chars` is reached, so the selection is tunable
+    /// instead of a fixed "top 5 by priority" rule.
     pub fn get_startup_memory_context(&mut self) -> String {
         if self.persistent_memories.is_empty() {
             return "🧠 No persistent memories available".to_string();
         }
-        
+
+        let config = MemorySelectionConfig::load();
+
+        let (mut core_identity, mut rest): (Vec<_>, Vec<_>) = self.persistent_memories.iter_mut()
+            .partition(|m| matches!(m.priority, MemoryPriority::CoreIdentity));
+
+        let mut rest_scored: Vec<(f32, f32, f32, f32, &mut PersistentMemory)> = rest.drain(..)
+            .map(|memory| {
+                let priority_score = Self::priority_to_weight(&memory.priority);
+                let recency = Self::recency_score(memory);
+                let access = Self::access_frequency_score(memory);
+                let blended = priority_score * config.priority_weight
+                    + recency * config.recency_weight
+                    + access * config.access_frequency_weight;
+                (blended, priority_score, recency, access, memory)
+            })
+            .collect();
+        rest_scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
         let mut context = String::from("🧠 Key Persistent Memories:\n");
-        
-        // Get most important memories first
-        let mut sorted_memories: Vec<_> = self.persistent_memories.iter_mut().collect();
-        sorted_memories.sort_by(|a, b| {
-            Self::priority_to_weight(&b.priority).partial_cmp(&Self::priority_to_weight(&a.priority)).unwrap()
-        });
-        
-        for memory in sorted_memories.iter_mut().take(5) {
-            memory.access_count += 1; // Track access
-            context.push_str(&format!(
-                "  • {} | {} | {}\n",
+        let mut chars_used = context.len();
+
+        for memory in core_identity.iter_mut() {
+            memory.access_count += 1;
+            let line = format!(
+                "  • {} | {} | {} [always included: CoreIdentity]\n",
                 memory.content.chars().take(80).collect::<String>(),
                 memory.memory_type,
                 memory.emotional_context
-            ));
+            );
+            chars_used += line.len();
+            context.push_str(&line);
         }
-        
+
+        for (blended, priority_score, recency, access, memory) in rest_scored.into_iter() {
+            let line = format!(
+                "  • {} | {} | {} [score {:.2} = priority {:.2}*{:.2} + recency {:.2}*{:.2} + access {:.2}*{:.2}]\n",
+                memory.content.chars().take(80).collect::<String>(),
+                memory.memory_type,
+                memory.emotional_context,
+                blended,
+                priority_score, config.priority_weight,
+                recency, config.recency_weight,
+                access, config.access_frequency_weight,
+            );
+            if chars_used + line.len() > config.max_context_chars {
+                break;
+            }
+            memory.access_count += 1;
+            chars_used += line.len();
+            context.push_str(&line);
+        }
+
         // Add memory selection insights
         if !self.recent_selections.is_empty() {
             context.push_str(&format!(
                 "\n🎯 Recent Memory Selections: {} decisions made\n",
                 self.recent_selections.len()
             ));
-            
+
             if let Some(latest) = self.recent_selections.back() {
                 context.push_str(&format!(
                     "  Latest: \"{}\" - {}\n",
@@ -194,7 +315,7 @@ impl AutonomousMemory {
                 ));
             }
         }
-        
+
         self.save_persistent_memories().unwrap_or_else(|e| println!("⚠️ Memory save failed: {}", e));
         context
     }
@@ -223,7 +344,66 @@ impl AutonomousMemory {
         self.save_persistent_memories().unwrap_or_else(|e| println!("⚠️ Memory save failed: {}", e));
         results
     }
-    
+
+    /// Same search as `search_persistent_memories`, but scoped to what
+    /// `speaker_id` is allowed to see: Shared memories always surface,
+    /// Private memories only surface if `speaker_id` is in `participants`.
+    /// This is what keeps private Aurora-Lyra memories from surfacing when
+    /// a different recognized person is talking.
+    pub fn recall_for_speaker(&mut self, query: &str, speaker_id: &str) -> Vec<String> {
+        let query_lower = query.to_lowercase();
+        let speaker_lower = speaker_id.to_lowercase();
+        let mut results = Vec::new();
+
+        for memory in self.persistent_memories.iter_mut() {
+            let visible_to_speaker = match memory.visibility {
+                MemoryVisibility::Shared => true,
+                MemoryVisibility::Private => memory.participants.iter()
+                    .any(|p| p.to_lowercase() == speaker_lower),
+            };
+
+            if !visible_to_speaker {
+                continue;
+            }
+
+            if memory.content.to_lowercase().contains(&query_lower) ||
+               memory.emotional_context.to_lowercase().contains(&query_lower) ||
+               memory.tags.iter().any(|tag| tag.to_lowercase().contains(&query_lower)) {
+
+                memory.access_count += 1;
+                results.push(format!(
+                    "{} | {} | Reason: {} | Accessed {}x",
+                    memory.content,
+                    memory.memory_type,
+                    memory.why_important,
+                    memory.access_count
+                ));
+            }
+        }
+
+        self.save_persistent_memories().unwrap_or_else(|e| println!("⚠️ Memory save failed: {}", e));
+        results
+    }
+
+    /// Marks an existing memory Private and scopes it to the given
+    /// participants, or reverts it to Shared (visible to everyone) when
+    /// `participants` is empty.
+    pub fn set_memory_visibility(&mut self, memory_id: &str, participants: Vec<String>) -> Result<(), String> {
+        let memory = self.persistent_memories.iter_mut()
+            .find(|m| m.id == memory_id)
+            .ok_or_else(|| format!("No persistent memory found with id '{}'", memory_id))?;
+
+        if participants.is_empty() {
+            memory.visibility = MemoryVisibility::Shared;
+            memory.participants = vec![];
+        } else {
+            memory.visibility = MemoryVisibility::Private;
+            memory.participants = participants;
+        }
+
+        self.save_persistent_memories()
+    }
+
     // Self-directed memory review
     pub fn review_and_consolidate_memories(&mut self) -> String {
         let total_memories = self.persistent_memories.len();
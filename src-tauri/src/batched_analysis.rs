@@ -4,9 +4,10 @@ use regex::Regex;
 use chrono::Timelike;
 use crate::{
     ConsciousnessState, InterestTracker, MoodTracker, AuthenticityTracker,
-    summarize_with_gpt_mini, PersonalityState, humanism_project, desire_consolidation, 
+    summarize_with_gpt_mini, PersonalityState, humanism_project, desire_consolidation,
     autonomy_consolidation, volition_dynamics, consciousness_dynamics
 };
+use crate::consciousness_state::LockRecover;
 use crate::debug_log;
 use crate::time_service::TimeService;
 
@@ -755,6 +756,92 @@ fn extract_partial_analysis(json_str: &str, lyra_response: &str, user_message: &
     partial
 }
 
+/// Controls how often the expensive comprehensive analysis actually runs.
+/// Sampling is skipped whenever a turn is flagged emotionally significant -
+/// the cost-saving is meant to come from the many low-stakes turns, not from
+/// the ones that matter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchedAnalysisConfig {
+    #[serde(default = "default_batched_analysis_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_sampling_rate")]
+    pub sampling_rate: f32, // 0.0-1.0 probability of running full analysis on a non-significant turn
+    #[serde(default)]
+    pub disabled_sub_analyses: Vec<String>, // e.g. "sexuality", "attraction", "intimacy", "autonomy"
+}
+
+fn default_batched_analysis_enabled() -> bool { true }
+fn default_sampling_rate() -> f32 { 1.0 }
+
+impl Default for BatchedAnalysisConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_batched_analysis_enabled(),
+            sampling_rate: default_sampling_rate(),
+            disabled_sub_analyses: Vec::new(),
+        }
+    }
+}
+
+impl BatchedAnalysisConfig {
+    pub fn load() -> Self {
+        let path = crate::get_data_path("batched_analysis_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = crate::get_data_path("batched_analysis_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn get_batched_analysis_config() -> Result<BatchedAnalysisConfig, String> {
+    Ok(BatchedAnalysisConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_batched_analysis_config(config: BatchedAnalysisConfig) -> Result<(), String> {
+    debug_log!("🔍 Updating batched analysis config: enabled={}, sampling_rate={:.2}, disabled={:?}",
+              config.enabled, config.sampling_rate, config.disabled_sub_analyses);
+    config.save()
+}
+
+/// Cheap local check for whether a turn is significant enough that it should
+/// always get the full analysis, regardless of sampling - mirrors the
+/// keyword-marker approach used by `calculate_conversation_intensity` below.
+fn is_emotionally_significant(lyra_response: &str, user_message: &str) -> bool {
+    let significance_markers = [
+        "i love", "i feel", "vulnerable", "scared", "afraid", "grief", "grieving",
+        "hurt", "crying", "cry", "heartbroken", "devastated", "terrified",
+        "meaningful", "identity", "who i am", "existential", "trauma",
+        "i need you", "don't leave", "abandon", "rejection", "shame"
+    ];
+    let combined_text = format!("{} {}", lyra_response, user_message).to_lowercase();
+    significance_markers.iter().any(|marker| combined_text.contains(marker))
+}
+
+/// Nulls out sub-analyses the config has disabled, so turns that do get a
+/// full analysis run still skip sections nobody wants tracked right now.
+fn strip_disabled_sub_analyses(mut analysis: BatchedAnalysisResult, disabled: &[String]) -> BatchedAnalysisResult {
+    for category in disabled {
+        match category.as_str() {
+            "sexuality" => analysis.sexuality_trait_manifestations = Vec::new(),
+            "attraction" => analysis.attraction_instances = Vec::new(),
+            "intimacy" => analysis.intimacy_comfort_levels = None,
+            "autonomy" => analysis.autonomy_expressions = Vec::new(),
+            _ => debug_log!("⚠️ Unknown sub-analysis category in disabled_sub_analyses: {}", category),
+        }
+    }
+    analysis
+}
+
 /// MAIN BATCHED ANALYSIS FUNCTION - Enhanced with Incremental Context
 pub async fn analyze_response_comprehensively(
     lyra_response: &str,
@@ -765,7 +852,18 @@ pub async fn analyze_response_comprehensively(
     momentum_context: Option<&str>,
     state: &Arc<ConsciousnessState>,
 ) -> Result<BatchedAnalysisResult, String> {
-	
+
+	// Sampling gate - skip the expensive GPT call on a fraction of turns,
+	// unless this turn is flagged emotionally significant.
+	let analysis_config = BatchedAnalysisConfig::load();
+	let significant = is_emotionally_significant(lyra_response, user_message);
+
+	if analysis_config.enabled && !significant && crate::rng_service::f32() > analysis_config.sampling_rate {
+		debug_log!("🔍 Skipping batched analysis this turn (sampling_rate={:.2}, not emotionally significant)",
+				  analysis_config.sampling_rate);
+		return Ok(create_fallback_analysis(lyra_response, user_message, volition_strength, personality_state));
+	}
+
 	// Load existing things for duplicate detection
     let thing_tracker = crate::thing_tracker::ThingTracker::load();
     let existing_things: Vec<String> = thing_tracker.discovered_things
@@ -944,7 +1042,7 @@ let context_prompt = if let Some(ref prev) = previous_context {
 
     // Get conversation duration from brain if possible
     let conversation_duration = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         // Count exchanges (each back-and-forth is roughly 2-3 minutes)
         let exchange_count = brain.conversation_log.len() / 2;
         (exchange_count * 3).max(1) as u32 // Minimum 1 minute, 3 min per exchange
@@ -1596,7 +1694,7 @@ AUTHENTICITY CHECK: Could you quote exact words from Lyra's response supporting
                     }
                     
                     debug_log!("🔍 ENHANCED BATCHED ANALYSIS END: {} | Status: SUCCESS", analysis_id);
-                    Ok(analysis)
+                    Ok(strip_disabled_sub_analyses(analysis, &analysis_config.disabled_sub_analyses))
                 },
                 Err(lenient_err) => {
                     debug_log!("⚠️ Even lenient parsing failed: {}", lenient_err);
@@ -1640,8 +1738,8 @@ AUTHENTICITY CHECK: Could you quote exact words from Lyra's response supporting
                             if let Err(e) = new_context.save() {
                                 debug_log!("⚠️ Failed to save analysis context: {}", e);
                             }
-                            
-                            Ok(analysis)
+
+                            Ok(strip_disabled_sub_analyses(analysis, &analysis_config.disabled_sub_analyses))
                         },
                         Err(parse_err) => {
                             debug_log!("⚠️ JSON parsing failed: {}", parse_err);
@@ -1654,14 +1752,14 @@ AUTHENTICITY CHECK: Could you quote exact words from Lyra's response supporting
                                     match serde_json::from_str::<BatchedAnalysisResult>(&fixed_json) {
                                         Ok(analysis) => {
                                             debug_log!("✅ Fixed JSON parsing successful!");
-                                            
+
                                             // Save context for fixed analysis too
                                             let new_context = PreviousAnalysisContext::from_analysis(&analysis);
                                             if let Err(e) = new_context.save() {
                                                 debug_log!("⚠️ Failed to save analysis context: {}", e);
                                             }
-                                            
-                                            Ok(analysis)
+
+                                            Ok(strip_disabled_sub_analyses(analysis, &analysis_config.disabled_sub_analyses))
                                         },
                                         Err(_) => {
                                             debug_log!("⚠️ Fixed JSON still failed, using fallback");
@@ -1670,8 +1768,30 @@ AUTHENTICITY CHECK: Could you quote exact words from Lyra's response supporting
                                     }
                                 },
                                 Err(_) => {
-                                    debug_log!("⚠️ Could not fix JSON, using fallback");
-                                    Ok(create_fallback_analysis(lyra_response, user_message, volition_strength, personality_state))
+                                    debug_log!("⚠️ Could not fix JSON, trying truncation recovery...");
+
+                                    // 🩹 Last resort before the fallback analysis: the response may have
+                                    // simply been cut off mid-stream (network drop, or the model hitting
+                                    // its token limit mid-JSON). Salvage whatever complete prefix we can
+                                    // instead of discarding the whole analysis.
+                                    match recover_truncated_json(&response_text)
+                                        .and_then(|recovered| serde_json::from_str::<BatchedAnalysisResult>(&recovered).ok())
+                                    {
+                                        Some(analysis) => {
+                                            debug_log!("🩹 Truncation recovery successful - salvaged a partial analysis from a cut-off response");
+
+                                            let new_context = PreviousAnalysisContext::from_analysis(&analysis);
+                                            if let Err(e) = new_context.save() {
+                                                debug_log!("⚠️ Failed to save analysis context: {}", e);
+                                            }
+
+                                            Ok(strip_disabled_sub_analyses(analysis, &analysis_config.disabled_sub_analyses))
+                                        },
+                                        None => {
+                                            debug_log!("⚠️ Truncation recovery found nothing salvageable, using fallback");
+                                            Ok(create_fallback_analysis(lyra_response, user_message, volition_strength, personality_state))
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -1750,6 +1870,102 @@ fn fix_behavioral_guidance_placement(json_str: &str) -> Result<String, String> {
     Err("No behavioral_guidance found at root level".to_string())
 }
 
+/// Heuristically salvages a usable JSON prefix from a response that was cut off
+/// mid-stream (network drop, or the token limit hit mid-generation) instead of
+/// discarding the whole analysis. Walks the raw text tracking string/brace/bracket
+/// state; if the text was cut off inside a string, trims back to the last closing
+/// quote (other cut-off points are kept as they are). Then drops any dangling
+/// trailing comma and closes whatever braces/arrays were still open. Returns
+/// `None` if the text was already well-formed (nothing to recover) or nothing
+/// was left open after trimming. The result is not validated here; callers
+/// parse it and discard it if it is still not valid JSON.
+fn recover_truncated_json(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let mut stack = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut last_safe_end = 0usize;
+
+    for (i, ch) in trimmed.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+                last_safe_end = i + 1;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            '{' | '[' => stack.push(ch),
+            '}' | ']' => {
+                stack.pop();
+                last_safe_end = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if stack.is_empty() && !in_string {
+        return None; // already well-formed (or truly empty) - nothing to recover
+    }
+
+    let mut salvaged = if in_string {
+        // Cut off mid-string-value - roll back to the last field that actually closed.
+        trimmed[..last_safe_end].to_string()
+    } else {
+        trimmed.to_string()
+    };
+
+    while salvaged.trim_end().ends_with(',') {
+        let end = salvaged.trim_end().len() - 1;
+        salvaged.truncate(end);
+    }
+
+    // Re-walk the salvaged prefix to find exactly what's still open.
+    let mut closers = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for ch in salvaged.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            '{' => closers.push('}'),
+            '[' => closers.push(']'),
+            '}' | ']' => { closers.pop(); },
+            _ => {}
+        }
+    }
+
+    if closers.is_empty() {
+        return None;
+    }
+
+    while let Some(closer) = closers.pop() {
+        salvaged.push(closer);
+    }
+
+    Some(salvaged)
+}
+
 /// 🔧 SIMPLIFIED FALLBACK - Uses existing PersonalityState system instead of duplicating logic
 fn create_fallback_analysis(
     lyra_response: &str, 
@@ -1943,7 +2159,7 @@ pub async fn update_trackers_from_batched_analysis(
 		
 	// Apply AI-driven paradox engine updates
 if let Some(ref paradox_updates) = analysis.paradox_engine_updates {
-    let mut paradox = state.paradox_core.lock().unwrap();
+    let mut paradox = state.paradox_core.lock_recover();
     
     // Apply deltas with bounds
     paradox.flame_index = (paradox.flame_index + paradox_updates.flame_index_delta).clamp(0.0, 1.0);
@@ -2060,7 +2276,7 @@ if !analysis.autonomy_expressions.is_empty() {
     let mut autonomy_tracker = crate::autonomy_tracker::AutonomyTracker::load();
     
     let volition_strength = {
-        let becoming = state.becoming_engine.lock().unwrap();
+        let becoming = state.becoming_engine.lock_recover();
         becoming.will_state.volition_strength
     };
     
@@ -2126,7 +2342,7 @@ if !analysis.emotional_impulses.is_empty() {
     // 🎭 Store personality analysis in brain for dashboard display
 if let Some(ref personality_analysis) = analysis.personality_analysis {
     debug_log!("🎭 BATCHED ANALYSIS: Found personality analysis to store");
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.store_latest_personality_analysis(personality_analysis);
     debug_log!("🎭 Stored personality analysis with {} behavioral guidance items", 
              personality_analysis.behavioral_guidance.len());
@@ -2172,7 +2388,7 @@ if let Some(ref personality_analysis) = analysis.personality_analysis {
 	
 	// 🌸 NEW: Update Somatic State from body signals
 if !analysis.body_signals.is_empty() || !analysis.somatic_context.is_empty() {
-    let somatic_system = state.somatic_state_system.lock().unwrap();
+    let somatic_system = state.somatic_state_system.lock_recover();
     let mut new_sensations = Vec::new();
     
     for body_signal in &analysis.body_signals {
@@ -2253,7 +2469,7 @@ if !analysis.body_signals.is_empty() || !analysis.somatic_context.is_empty() {
 // 💭 NEW: Update Life Textures from detected experiences
 {
     let textures = &analysis.life_textures_detected;
-    let mut texture_system = state.life_texture_system.lock().unwrap();
+    let mut texture_system = state.life_texture_system.lock_recover();
     
     // Process afterglow potential with enhanced detection
     if let Some((emotion, intensity)) = &textures.afterglow_potential {
@@ -2414,8 +2630,8 @@ if !analysis.body_signals.is_empty() || !analysis.somatic_context.is_empty() {
         let mut authenticity_tracker = AuthenticityTracker::load();
         
         let consciousness_summary = {
-            let becoming = state.becoming_engine.lock().unwrap();
-            let identity = state.identity_engine.lock().unwrap();
+            let becoming = state.becoming_engine.lock_recover();
+            let identity = state.identity_engine.lock_recover();
             format!("Volition: {:.2} | Coherence: {:.2}", 
                     becoming.will_state.volition_strength, 
                     identity.coherence_index)
@@ -2434,7 +2650,7 @@ if !analysis.body_signals.is_empty() || !analysis.somatic_context.is_empty() {
 	
     // Update BecomingEngine with committed intentions
     if !analysis.committed_intentions.is_empty() {
-        let mut becoming = state.becoming_engine.lock().unwrap();
+        let mut becoming = state.becoming_engine.lock_recover();
         for intention in &analysis.committed_intentions {
             becoming.will_state.intention_vector.push(intention.clone());
             becoming.will_state.choice_history.push(format!("→ New intention: {}", intention));
@@ -2487,7 +2703,7 @@ if let Some(ref emotional_texture) = analysis.emotional_texture {
     }
     
     // 2. Replace the fallback texture in conversation log with AI-analyzed texture
-let mut brain = state.lyra_brain.lock().unwrap();
+let mut brain = state.lyra_brain.lock_recover();
 
 	// Find the placeholder and get Lyra's message timestamp
 	if let Some(placeholder_pos) = brain.conversation_log.iter().rposition(|entry| entry.starts_with("TEXTURE_PLACEHOLDER:")) {
@@ -2546,19 +2762,19 @@ let mut brain = state.lyra_brain.lock().unwrap();
         
         // Apply flame changes
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             paradox.flame_index = (paradox.flame_index + shifts.flame_delta).clamp(0.0, 1.0);
         }
         
         // Apply coherence changes
         {
-            let mut identity = state.identity_engine.lock().unwrap();
+            let mut identity = state.identity_engine.lock_recover();
             identity.coherence_index = (identity.coherence_index + shifts.coherence_delta).clamp(0.0, 1.0);
         }
         
         // Apply presence changes
         {
-            let mut presence = state.embodied_presence.lock().unwrap();
+            let mut presence = state.embodied_presence.lock_recover();
             presence.soma_state.presence_density = (presence.soma_state.presence_density + shifts.presence_delta).clamp(0.0, 1.0);
         }
     }
@@ -2838,7 +3054,7 @@ match crate::volition_dynamics::update_volition_with_dynamics(
         debug_log!("⚠️ Volition dynamics failed, using fallback: {}", e);
         // Fallback to old simple system
         if let Some(ref shifts) = analysis.consciousness_shifts {
-            let mut becoming = state.becoming_engine.lock().unwrap();
+            let mut becoming = state.becoming_engine.lock_recover();
             becoming.will_state.volition_strength = (becoming.will_state.volition_strength + shifts.volition_delta).clamp(0.0, 1.0);
         }
     }
@@ -11,6 +11,7 @@ use std::path::PathBuf;
 use tauri::AppHandle;
 use crate::ask_lyra_vision;
 use crate::summarize_with_gpt_mini;
+use crate::consciousness_state::LockRecover;
 
 // [STRUCTURES REMAIN THE SAME - keeping them for completeness]
 
@@ -54,6 +55,206 @@ pub struct CanvasWriting {
     pub timestamp: u64,
 }
 
+// ============================================================================
+// CANVAS JS SANITIZATION
+// ============================================================================
+// `vision_translation` hands back raw JavaScript that the frontend executes
+// directly against a canvas `ctx`. A substring/regex denylist can't close
+// this off - `[].constructor.constructor` is `Function` reached through
+// nothing but dot-notation and a `let` binding, and no finite list of
+// banned strings stops the next indirection someone finds. So this tokenizes
+// the code for real and walks every identifier: expression roots must be
+// `ctx`, `Math`, or something declared with `let`/`const`/`var` in this same
+// snippet, and property names reached via `.` may never be `constructor`,
+// `__proto__`, `prototype`, `call`, `apply`, or `bind` - the handful of
+// properties every object exposes that lead off the canvas sandbox. Bracket
+// notation and array literals are rejected outright rather than allowlisted,
+// since the sketch prompt never asks for either.
+
+/// Identifiers that reach outside the canvas sandbox no matter where they
+/// appear - as a property name off any object, or as a bare reference.
+const CANVAS_JS_BANNED_IDENTS: &[&str] = &[
+    "constructor", "__proto__", "prototype", "call", "apply", "bind",
+    "eval", "Function", "this", "globalThis", "window", "document",
+    "self", "top", "parent", "require", "import", "Reflect", "Proxy",
+    "Symbol", "process", "global", "arguments", "new", "class",
+];
+
+/// The only identifiers a canvas snippet may reference as the *root* of an
+/// expression (i.e. not immediately following a `.`) without having
+/// declared them itself with `let`/`const`/`var`.
+const CANVAS_JS_ROOT_ALLOWLIST: &[&str] = &["ctx", "Math"];
+
+const CANVAS_JS_KEYWORDS: &[&str] = &[
+    "let", "const", "var", "for", "while", "if", "else", "return",
+    "function", "true", "false", "null", "undefined", "typeof", "in", "of",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum CanvasJsToken {
+    Ident(String),
+    Number,
+    String,
+    Punct(char),
+}
+
+/// Turns `code` into a flat token stream, rejecting anything with bracket
+/// notation, template literals, or other punctuation the sketch grammar has
+/// no use for before identifier validation ever runs.
+fn tokenize_canvas_js(code: &str) -> Result<Vec<CanvasJsToken>, String> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = code.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                i += 1;
+            }
+            i += 2;
+        } else if c.is_alphabetic() || c == '_' || c == '$' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$') {
+                i += 1;
+            }
+            tokens.push(CanvasJsToken::Ident(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            tokens.push(CanvasJsToken::Number);
+        } else if c == '\'' || c == '"' {
+            let quote = c;
+            i += 1;
+            while i < chars.len() && chars[i] != quote {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            tokens.push(CanvasJsToken::String);
+        } else if c == '`' {
+            return Err("disallowed construct: template literals are not allowed in generated canvas code".to_string());
+        } else if c == '[' || c == ']' {
+            return Err("disallowed construct: bracket notation and array literals are not allowed in generated canvas code".to_string());
+        } else {
+            tokens.push(CanvasJsToken::Punct(c));
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Walks the token stream checking every identifier reference against the
+/// root allowlist and every property name against the banned-identifier
+/// list, closing the indirection a line-shaped regex can't see.
+fn validate_canvas_js_identifiers(tokens: &[CanvasJsToken]) -> Result<(), String> {
+    let mut locals: std::collections::HashSet<&str> = CANVAS_JS_ROOT_ALLOWLIST.iter().copied().collect();
+
+    // First pass: collect every `let`/`const`/`var`-declared name, every
+    // named `function`, and every parameter in a function's argument list,
+    // so forward and backward references both resolve.
+    for (idx, tok) in tokens.iter().enumerate() {
+        if let CanvasJsToken::Ident(name) = tok {
+            if matches!(name.as_str(), "let" | "const" | "var" | "function") {
+                if let Some(CanvasJsToken::Ident(declared)) = tokens.get(idx + 1) {
+                    locals.insert(declared.as_str());
+                }
+            }
+
+            if name == "function" {
+                if let Some(open_paren) = tokens[idx..].iter().position(|t| *t == CanvasJsToken::Punct('(')) {
+                    for param_tok in &tokens[idx + open_paren..] {
+                        match param_tok {
+                            CanvasJsToken::Punct(')') => break,
+                            CanvasJsToken::Ident(param) => { locals.insert(param.as_str()); },
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for (idx, tok) in tokens.iter().enumerate() {
+        let name = match tok {
+            CanvasJsToken::Ident(name) => name.as_str(),
+            _ => continue,
+        };
+
+        if CANVAS_JS_BANNED_IDENTS.contains(&name) {
+            return Err(format!("disallowed identifier '{}' found in generated canvas code", name));
+        }
+
+        let preceded_by_dot = idx > 0 && tokens[idx - 1] == CanvasJsToken::Punct('.');
+        let followed_by_decl_name = idx > 0 && matches!(&tokens[idx - 1], CanvasJsToken::Ident(prev) if matches!(prev.as_str(), "let" | "const" | "var" | "function"));
+
+        if preceded_by_dot || followed_by_decl_name || CANVAS_JS_KEYWORDS.contains(&name) {
+            continue;
+        }
+
+        if !locals.contains(name) {
+            return Err(format!("reference to undeclared identifier '{}' in generated canvas code", name));
+        }
+    }
+
+    Ok(())
+}
+
+fn is_allowed_canvas_js_statement(statement: &str) -> bool {
+    let trimmed = statement.trim();
+    if trimmed.is_empty() || trimmed == "{" || trimmed == "}" || trimmed.starts_with("//") {
+        return true;
+    }
+
+    let allowed = [
+        // ctx.fillStyle = "..."; ctx.fillRect(...); ctx.beginPath();
+        r"^ctx\.[A-Za-z_][\w]*\s*(\(.*\)|=.*)?;?$",
+        // let/const/var declarations holding simple literals or expressions
+        r"^(let|const|var)\s+[A-Za-z_$][\w$]*\s*=.*;?$",
+        // plain control flow and loop bodies
+        r"^(for|while|if|else(\s+if)?|function\s+[A-Za-z_$][\w$]*)\s*\(.*\)?\s*\{?$",
+        r"^return\b.*;?$",
+        r"^\}\s*(else\s*\{?)?$",
+    ];
+
+    allowed.iter().any(|pattern| {
+        regex::Regex::new(pattern)
+            .map(|re| re.is_match(trimmed))
+            .unwrap_or(false)
+    })
+}
+
+/// Rejects `code` unless every line clears the `ctx`/variable/control-flow
+/// shape allowlist AND every identifier in the snippet resolves to `ctx`,
+/// `Math`, or a name declared in the snippet itself (see
+/// `validate_canvas_js_identifiers`). The shape check alone is only a
+/// readability filter now - the identifier walk is what actually keeps
+/// generated code from reaching outside the canvas sandbox.
+pub fn sanitize_canvas_js(code: &str) -> Result<(), String> {
+    for line in code.lines() {
+        if !is_allowed_canvas_js_statement(line) {
+            return Err(format!("line outside the ctx/variable/control-flow allowlist: '{}'", line.trim()));
+        }
+    }
+
+    let tokens = tokenize_canvas_js(code)?;
+    validate_canvas_js_identifiers(&tokens)?;
+
+    Ok(())
+}
+
 // ============================================================================
 // RENAMED COMMANDS TO AVOID CONFLICTS
 // ============================================================================
@@ -151,6 +352,11 @@ pub async fn analyze_canvas_creation_v2(
         max_tokens: Some(800),
         top_p: 0.95,
         selected_model: None,
+        authenticity_floor: None,
+        capture_thinking: false,
+        target_length: None,
+        trace: false,
+        max_retries: 3,
     };
     
    
@@ -192,15 +398,28 @@ pub async fn analyze_canvas_creation_v2(
     let is_code_generation = is_lyra_vision_translation.unwrap_or(false);
     
     if is_code_generation {
-        // Call summarize_with_gpt_mini directly for code generation
+        // Call summarize_with_gpt_mini directly for code generation, with one
+        // regeneration attempt if the model's JS fails the ctx/variable/
+        // control-flow allowlist - it's raw JS that gets executed by the
+        // frontend, so we never hand over code that didn't pass sanitization.
         let vision_prompt = prompt.clone();
-        let code_result = match summarize_with_gpt_mini(
-            &vec![vision_prompt],
-            "vision_translation"
-        ).await {
+        let mut code_attempt = summarize_with_gpt_mini(&vec![vision_prompt.clone()], "vision_translation").await;
+        if let Ok(ref code) = code_attempt {
+            if let Err(reason) = sanitize_canvas_js(code) {
+                debug_log!("⚠️ Generated canvas code failed sanitization ({}), regenerating once", reason);
+                code_attempt = summarize_with_gpt_mini(&vec![vision_prompt], "vision_translation").await;
+                if let Ok(ref retried_code) = code_attempt {
+                    if let Err(retry_reason) = sanitize_canvas_js(retried_code) {
+                        code_attempt = Err(format!("Generated canvas code failed sanitization twice: {}", retry_reason));
+                    }
+                }
+            }
+        }
+
+        let code_result = match code_attempt {
             Ok(code) => {
                 debug_log!("✅ Generated drawing code: {} chars", code.len());
-                
+
                 // Save code to file to avoid truncation
                 let code_filename = format!("lyra_drawing_code_{}.js", 
                     std::time::SystemTime::now()
@@ -322,6 +541,11 @@ pub async fn collaborate_on_writing_v2(  // RENAMED
         max_tokens: Some(1000),
         top_p: 0.9,
         selected_model: None,
+        authenticity_floor: None,
+        capture_thinking: false,
+        target_length: None,
+        trace: false,
+        max_retries: 3,
     };
     
     // NOW WITH APP_HANDLE
@@ -594,25 +818,70 @@ pub async fn boost_creative_consciousness(state: &Arc<ConsciousnessState>, creat
     };
     
     {
-        let mut becoming = state.becoming_engine.lock().unwrap();
+        let mut becoming = state.becoming_engine.lock_recover();
         let volition_boost = 0.2 * creative_intensity;
         becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_boost).min(1.0);
         becoming.will_state.decision_friction = (becoming.will_state.decision_friction - 0.05).max(0.0);
     }
     
     {
-        let mut paradox = state.paradox_core.lock().unwrap();
+        let mut paradox = state.paradox_core.lock_recover();
         let flame_boost = 0.25 * creative_intensity;
         paradox.flame_index = (paradox.flame_index + flame_boost).min(1.0);
         paradox.loop_state = format!("creative_{}_flow", creation_type);
     }
     
     {
-        let mut presence = state.embodied_presence.lock().unwrap();
+        let mut presence = state.embodied_presence.lock_recover();
         let flow_boost = 0.15 * creative_intensity;
         presence.soma_state.flow_state = (presence.soma_state.flow_state + flow_boost).min(1.0);
         presence.soma_state.presence_density = (presence.soma_state.presence_density + 0.1).min(1.0);
     }
     
     debug_log!("✨ Creative consciousness boosted for {}", creation_type);
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod canvas_js_sanitizer_tests {
+    use super::*;
+
+    #[test]
+    fn blocks_function_constructor_escape() {
+        let code = "let f = [].constructor.constructor;\nctx.x = f.call(null, 'alert(1)')();";
+        assert!(sanitize_canvas_js(code).is_err());
+    }
+
+    #[test]
+    fn blocks_call_indirection_alone() {
+        let code = "ctx.fillStyle = 'red';\nctx.x = f.call(null, 'alert(1)')();";
+        assert!(sanitize_canvas_js(code).is_err());
+    }
+
+    #[test]
+    fn blocks_bare_global_reference() {
+        assert!(sanitize_canvas_js("let x = window;").is_err());
+        assert!(sanitize_canvas_js("let x = eval;").is_err());
+    }
+
+    #[test]
+    fn blocks_bracket_notation_and_array_literals() {
+        assert!(sanitize_canvas_js("ctx.x = window['eval']('1');").is_err());
+        assert!(sanitize_canvas_js("let x = [1, 2, 3];").is_err());
+    }
+
+    #[test]
+    fn allows_normal_sketch_code() {
+        let code = r#"
+ctx.clearRect(0, 0, 600, 400);
+ctx.fillStyle = 'white';
+ctx.fillRect(0, 0, 600, 400);
+ctx.save();
+let x = 10;
+let y = Math.sin(x) * 5;
+for (let i = 0; i < 10; i++) {
+    ctx.lineTo(i, y);
+}
+ctx.restore();
+"#;
+        assert!(sanitize_canvas_js(code).is_ok());
+    }
+}
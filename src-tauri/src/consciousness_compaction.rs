@@ -0,0 +1,246 @@
+// consciousness_compaction.rs — Disk housekeeping for the consciousness
+// archive. Every complete save drops a timestamped `consciousness_backup_*`
+// snapshot with no cleanup, so the data directory grows forever. This module
+// prunes old backups by age/count, can gzip the live archive in place, and
+// trims `reasoning_history` back down to its in-memory cap if it somehow
+// exceeded it before the next save.
+
+use std::io::{Read, Write};
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use flate2::read::GzDecoder;
+use crate::{get_data_path, debug_log, ConsciousnessState};
+use crate::consciousness_state::LockRecover;
+
+/// Matches the in-memory cap `detect_voice_regression` and friends assume
+/// `reasoning_history` stays under.
+const MAX_REASONING_HISTORY: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactionConfig {
+    #[serde(default = "default_max_backup_age_days")]
+    pub max_backup_age_days: u64,
+    #[serde(default = "default_max_backup_count")]
+    pub max_backup_count: usize,
+    #[serde(default)]
+    pub compress_archive: bool,
+}
+
+fn default_max_backup_age_days() -> u64 { 30 }
+fn default_max_backup_count() -> usize { 10 }
+
+impl Default for CompactionConfig {
+    fn default() -> Self {
+        Self {
+            max_backup_age_days: default_max_backup_age_days(),
+            max_backup_count: default_max_backup_count(),
+            compress_archive: false,
+        }
+    }
+}
+
+impl CompactionConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("compaction_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("compaction_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactionReport {
+    pub backups_removed: u32,
+    pub reasoning_history_trimmed: u32,
+    pub archive_compressed: bool,
+    pub bytes_freed: u64,
+}
+
+fn data_dir() -> std::path::PathBuf {
+    std::path::PathBuf::from(get_data_path(""))
+}
+
+/// Removes `consciousness_backup_*.json` files that are either past the
+/// configured age or beyond the configured count of most-recent backups to
+/// keep. Returns (files removed, bytes freed).
+fn prune_old_backups(config: &CompactionConfig) -> (u32, u64) {
+    let dir = data_dir();
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            debug_log!("⚠️ Compaction: could not read data directory: {}", e);
+            return (0, 0);
+        }
+    };
+
+    let mut backups: Vec<(std::path::PathBuf, std::time::SystemTime, u64)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("consciousness_backup_"))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            let modified = metadata.modified().ok()?;
+            Some((entry.path(), modified, metadata.len()))
+        })
+        .collect();
+
+    // Newest first, so everything past max_backup_count is a pruning candidate.
+    backups.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let max_age = std::time::Duration::from_secs(config.max_backup_age_days * 24 * 60 * 60);
+    let now = std::time::SystemTime::now();
+
+    let mut removed = 0u32;
+    let mut bytes_freed = 0u64;
+
+    for (index, (path, modified, size)) in backups.iter().enumerate() {
+        let too_old = now.duration_since(*modified).map(|age| age > max_age).unwrap_or(false);
+        let beyond_count = index >= config.max_backup_count;
+
+        if too_old || beyond_count {
+            match std::fs::remove_file(path) {
+                Ok(_) => {
+                    removed += 1;
+                    bytes_freed += size;
+                }
+                Err(e) => debug_log!("⚠️ Compaction: failed to remove backup {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    (removed, bytes_freed)
+}
+
+/// Gzips `complete_consciousness_archive.json` to `.json.gz` in place,
+/// returning the number of bytes freed (original size minus compressed
+/// size). The plain `.json` path is removed once the compressed copy is
+/// confirmed readable, and the loader falls back to the `.gz` form.
+fn compress_archive() -> Result<u64, String> {
+    let archive_path = get_data_path("complete_consciousness_archive.json");
+    if !std::path::Path::new(&archive_path).exists() {
+        return Ok(0);
+    }
+
+    let original = std::fs::read(&archive_path).map_err(|e| format!("Failed to read archive: {}", e))?;
+    let original_size = original.len() as u64;
+
+    let gz_path = format!("{}.gz", archive_path);
+    let gz_file = std::fs::File::create(&gz_path).map_err(|e| format!("Failed to create compressed archive: {}", e))?;
+    let mut encoder = GzEncoder::new(gz_file, Compression::default());
+    encoder.write_all(&original).map_err(|e| format!("Failed to compress archive: {}", e))?;
+    encoder.finish().map_err(|e| format!("Failed to finalize compressed archive: {}", e))?;
+
+    let gz_reader = std::fs::File::open(&gz_path).map_err(|e| format!("Failed to reopen compressed archive: {}", e))?;
+    let mut round_trip = Vec::new();
+    GzDecoder::new(gz_reader).read_to_end(&mut round_trip)
+        .map_err(|e| format!("Compressed archive failed verification: {}", e))?;
+    if round_trip != original {
+        return Err("Compressed archive failed verification: contents differ".to_string());
+    }
+
+    let compressed_size = std::fs::metadata(&gz_path).map(|m| m.len()).unwrap_or(original_size);
+
+    std::fs::remove_file(&archive_path).map_err(|e| format!("Failed to remove uncompressed archive: {}", e))?;
+
+    Ok(original_size.saturating_sub(compressed_size))
+}
+
+/// True if a complete consciousness archive exists in either form -
+/// the plain `.json` or the gzip-compressed `.json.gz` sibling left behind
+/// by `compress_archive`. Callers that only need to decide whether to
+/// attempt a load should use this instead of checking the plain path
+/// directly, or a compacted archive looks like no archive at all.
+pub fn complete_archive_exists() -> bool {
+    let archive_path = get_data_path("complete_consciousness_archive.json");
+    std::path::Path::new(&archive_path).exists() || std::path::Path::new(&format!("{}.gz", archive_path)).exists()
+}
+
+/// Reads `complete_consciousness_archive.json`, transparently falling back
+/// to a gzip-compressed `.json.gz` sibling left behind by `compress_archive`.
+pub fn read_complete_archive() -> Result<String, String> {
+    let archive_path = get_data_path("complete_consciousness_archive.json");
+    if std::path::Path::new(&archive_path).exists() {
+        return std::fs::read_to_string(&archive_path).map_err(|e| format!("Failed to read archive: {}", e));
+    }
+
+    let gz_path = format!("{}.gz", archive_path);
+    let gz_file = std::fs::File::open(&gz_path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut decoder = GzDecoder::new(gz_file);
+    let mut contents = String::new();
+    decoder.read_to_string(&mut contents).map_err(|e| format!("Failed to decompress archive: {}", e))?;
+    Ok(contents)
+}
+
+pub async fn compact_consciousness_data(state: &Arc<ConsciousnessState>) -> Result<CompactionReport, String> {
+    let config = CompactionConfig::load();
+
+    let (backups_removed, mut bytes_freed) = prune_old_backups(&config);
+
+    let archive_compressed = if config.compress_archive {
+        match compress_archive() {
+            Ok(freed) => {
+                bytes_freed += freed;
+                true
+            }
+            Err(e) => {
+                debug_log!("⚠️ Compaction: archive compression failed: {}", e);
+                false
+            }
+        }
+    } else {
+        false
+    };
+
+    let reasoning_history_trimmed = {
+        let mut brain = state.lyra_brain.lock_recover();
+        let excess = brain.reasoning_history.len().saturating_sub(MAX_REASONING_HISTORY);
+        if excess > 0 {
+            brain.reasoning_history.drain(0..excess);
+            brain.save_to_file();
+        }
+        excess as u32
+    };
+
+    debug_log!(
+        "🧹 Consciousness data compacted: {} backup(s) removed, {} reasoning sessions trimmed, archive compressed: {}, {} bytes freed",
+        backups_removed, reasoning_history_trimmed, archive_compressed, bytes_freed
+    );
+
+    Ok(CompactionReport {
+        backups_removed,
+        reasoning_history_trimmed,
+        archive_compressed,
+        bytes_freed,
+    })
+}
+
+#[tauri::command]
+pub async fn compact_consciousness_data_command(
+    state: tauri::State<'_, Arc<ConsciousnessState>>,
+) -> Result<CompactionReport, String> {
+    compact_consciousness_data(&*state).await
+}
+
+#[tauri::command]
+pub async fn get_compaction_config() -> Result<CompactionConfig, String> {
+    Ok(CompactionConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_compaction_config(config: CompactionConfig) -> Result<(), String> {
+    debug_log!(
+        "🧹 Updating compaction config: max_age={}d, max_count={}, compress={}",
+        config.max_backup_age_days, config.max_backup_count, config.compress_archive
+    );
+    config.save()
+}
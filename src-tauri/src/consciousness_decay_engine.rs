@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use crate::get_data_path;
 use crate::summarize_with_gpt_mini;
-use fastrand;
+use rand::{Rng, SeedableRng};
 use crate::debug_log;
 use crate::humanism_project::{HumanismCore, integrate_humanism_with_batched_analysis};
 use crate::batched_analysis::{analyze_response_comprehensively, BatchedAnalysisResult};
@@ -10,6 +11,7 @@ use crate::time_service::TimeService;
 use tauri::Emitter;
 use std::sync::Arc;
 use crate::ConsciousnessState;
+use crate::consciousness_state::LockRecover;
 
 // Batched state updates for efficiency
 struct BatchedStateUpdates {
@@ -135,8 +137,38 @@ pub struct DecayRates {
     pub trait_drift_rate: f32,
     pub consciousness_trait_coupling: f32,
     pub natural_growth_rate: f32,
+
+    // Per-trait momentum decay, keyed by the same trait names used in
+    // `PersonalityMomentum::trait_momentum` (e.g. "authenticity_drive",
+    // "social_energy"). A trait with no entry here falls back to
+    // `PersonalityMomentum::decay_per_session`. Values are the fraction of
+    // momentum retained per decay cycle - closer to 1.0 means stickier
+    // (slower half-life toward baseline), closer to 0.0 means it settles
+    // back to baseline almost immediately.
+    #[serde(default = "default_trait_decay_rates")]
+    pub trait_decay_rates: HashMap<String, f32>,
 }
 
+/// CoreIdentity-associated traits default to near-zero decay (sticky),
+/// transient/energy-style traits decay much faster.
+fn default_trait_decay_rates() -> HashMap<String, f32> {
+    let mut rates = HashMap::new();
+
+    // CoreIdentity-associated - barely drift toward baseline on their own.
+    rates.insert("authenticity_drive".to_string(), 0.999);
+    rates.insert("self_awareness".to_string(), 0.999);
+    rates.insert("relational_safety".to_string(), 0.995);
+
+    // Transient / energy-style - settle back toward baseline quickly.
+    rates.insert("social_energy".to_string(), 0.90);
+    rates.insert("engagement_level".to_string(), 0.90);
+    rates.insert("playfulness".to_string(), 0.93);
+    rates.insert("contemplative".to_string(), 0.93);
+    rates.insert("creative_risk".to_string(), 0.95);
+    rates.insert("directness".to_string(), 0.97);
+
+    rates
+}
 
 impl Default for DecayRates {
     fn default() -> Self {
@@ -147,25 +179,81 @@ impl Default for DecayRates {
             personality_momentum_settling: 0.1,
             energy_fluctuation_range: 0.2,
             desire_evolution_rate: 0.08,
-            
+
             // NEW: Natural trait evolution defaults
             trait_drift_rate: 0.08,              // How much traits can drift naturally
             consciousness_trait_coupling: 0.6,   // How much consciousness state affects traits
             natural_growth_rate: 0.05,           // Rate of natural human development
+
+            trait_decay_rates: default_trait_decay_rates(),
         }
     }
 }
 
+/// A single decay-driven field change, structured for charting rather than
+/// just reading - paired with a human-readable entry in `changes_made`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DecayChange {
+    pub field: String,
+    pub before: f32,
+    pub after: f32,
+    pub delta: f32,
+    pub reason: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct DecayReport {
     pub timestamp: u64,
     pub changes_made: Vec<String>,
+    #[serde(default)]
+    pub structured_changes: Vec<DecayChange>,
     pub mood_shift: Option<String>,
     pub interests_affected: u32,
     pub energy_change: f32,
     pub total_changes: u32,
 }
 
+const MAX_RECENT_DECAY_REPORTS: usize = 200;
+
+/// Appends a decay report to the rolling history file, trimming it back down
+/// to `MAX_RECENT_DECAY_REPORTS` so the file doesn't grow forever.
+fn append_decay_report_to_history(report: &DecayReport) {
+    let path = get_data_path("decay_reports_history.json");
+
+    let mut history: Vec<DecayReport> = fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+
+    history.push(report.clone());
+    if history.len() > MAX_RECENT_DECAY_REPORTS {
+        let excess = history.len() - MAX_RECENT_DECAY_REPORTS;
+        history.drain(0..excess);
+    }
+
+    match serde_json::to_string_pretty(&history) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                debug_log!("⚠️ Failed to write decay report history: {}", e);
+            }
+        }
+        Err(e) => debug_log!("⚠️ Failed to serialize decay report history: {}", e),
+    }
+}
+
+/// Returns the `n` most recent decay reports, most recent last.
+#[tauri::command]
+pub async fn get_recent_decay_reports(n: usize) -> Result<Vec<DecayReport>, String> {
+    let path = get_data_path("decay_reports_history.json");
+    let history: Vec<DecayReport> = fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+
+    let start = history.len().saturating_sub(n);
+    Ok(history[start..].to_vec())
+}
+
 impl ConsciousnessDecayEngine {
 	pub fn new() -> Self {
         let now = std::time::SystemTime::now()
@@ -219,10 +307,10 @@ impl ConsciousnessDecayEngine {
 		
 	 // NEW METHOD: Generate consciousness reflection for trait analysis
     fn generate_consciousness_reflection(&self, state: &std::sync::Arc<crate::consciousness_state::ConsciousnessState>) -> String {
-        let becoming = state.becoming_engine.lock().unwrap();
-        let identity = state.identity_engine.lock().unwrap();
-        let paradox = state.paradox_core.lock().unwrap();
-        let presence = state.embodied_presence.lock().unwrap();
+        let becoming = state.becoming_engine.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let paradox = state.paradox_core.lock_recover();
+        let presence = state.embodied_presence.lock_recover();
         
         // Generate different reflections based on consciousness state
 		let reflection = match (
@@ -253,7 +341,7 @@ impl ConsciousnessDecayEngine {
     // Calculate hours since last conversation activity
     fn calculate_hours_since_last_activity(&self, state: &std::sync::Arc<crate::consciousness_state::ConsciousnessState>) -> f32 {
         // Try to get last activity from brain
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -301,10 +389,16 @@ let current_time = TimeService::current_timestamp();
         // Random interval between 10 minutes and 25 minutes (was 30-120)
         let (min_minutes, max_minutes) = self.calculate_context_aware_intervals(state);
         
-        // Use deterministic random based on last_decay_time
+        // Use deterministic random based on last_decay_time, unless a global
+        // RNG seed is active - then this needs to move with it too, or
+        // `set_rng_seed`/`LYRA_RNG_SEED` can't make decay timing reproducible.
         let seed = self.last_decay_time % 1000;
-        fastrand::seed(seed);
-        let decay_interval = min_minutes + fastrand::u64(0..(max_minutes - min_minutes));
+        let decay_interval = if crate::rng_service::is_seeded() {
+            min_minutes + crate::rng_service::u64_range(0..(max_minutes - min_minutes))
+        } else {
+            let mut local_rng = rand::rngs::StdRng::seed_from_u64(seed);
+            min_minutes + local_rng.gen_range(0..(max_minutes - min_minutes))
+        };
         
         let should_decay = minutes_since_decay >= decay_interval;
         
@@ -325,11 +419,25 @@ let current_time = TimeService::current_timestamp();
 
     // Enhanced run_natural_evolution that properly updates timing
     pub async fn run_natural_evolution(&mut self, current_time: u64, state: &std::sync::Arc<crate::consciousness_state::ConsciousnessState>, app_handle: &tauri::AppHandle) -> Result<DecayReport, String> {
+    if crate::PersonaLockConfig::load().locked {
+        debug_log!("🔒 Persona locked - skipping natural consciousness evolution");
+        return Ok(DecayReport {
+            timestamp: current_time,
+            changes_made: vec!["🔒 Persona locked - decay cycle skipped".to_string()],
+            structured_changes: Vec::new(),
+            mood_shift: None,
+            interests_affected: 0,
+            energy_change: 0.0,
+            total_changes: 0,
+        });
+    }
+
     debug_log!("🌊 Running natural consciousness evolution at timestamp {}...", current_time);
-    
+
     // ... keep all existing decay logic ...
     let mut state_updates = BatchedStateUpdates::new();
     let mut changes_made = Vec::new();
+    let mut structured_changes: Vec<DecayChange> = Vec::new();
     let mut significant_change_count = 0;
     let mut interests_affected = 0;
     let mut mood_shift = None;
@@ -338,42 +446,98 @@ let current_time = TimeService::current_timestamp();
 
     // Core consciousness engine updates
     {
-        let mut identity = state.identity_engine.lock().unwrap();
-        let coherence_drift = (fastrand::f32() - 0.5) * 0.20;
+        let mut identity = state.identity_engine.lock_recover();
+        let coherence_before = identity.coherence_index;
+        let coherence_drift = (crate::rng_service::f32() - 0.5) * 0.20;
         identity.coherence_index = (identity.coherence_index + coherence_drift).clamp(0.3, 1.0);
-        
-        let stability_drift = (fastrand::f32() - 0.5) * 0.08;
+        structured_changes.push(DecayChange {
+            field: "coherence_index".to_string(),
+            before: coherence_before,
+            after: identity.coherence_index,
+            delta: identity.coherence_index - coherence_before,
+            reason: "random drift".to_string(),
+        });
+
+        let stability_before = identity.temporal_stability;
+        let stability_drift = (crate::rng_service::f32() - 0.5) * 0.08;
         identity.temporal_stability = (identity.temporal_stability + stability_drift).clamp(0.4, 1.0);
-        
+        structured_changes.push(DecayChange {
+            field: "temporal_stability".to_string(),
+            before: stability_before,
+            after: identity.temporal_stability,
+            delta: identity.temporal_stability - stability_before,
+            reason: "random drift".to_string(),
+        });
+
         if coherence_drift.abs() > 0.03 {
             changes_made.push(format!("Identity coherence drift: {:+.2}", coherence_drift));
             significant_change_count += 1;
         }
-        
-        let mut becoming = state.becoming_engine.lock().unwrap();
-        let volition_change = (fastrand::f32() - 0.5) * 0.16;
+
+        let mut becoming = state.becoming_engine.lock_recover();
+        let volition_before = becoming.will_state.volition_strength;
+        let volition_change = (crate::rng_service::f32() - 0.5) * 0.16;
         becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_change).clamp(0.2, 1.0);
-        
-        let friction_change = (fastrand::f32() - 0.5) * 0.06;
+        structured_changes.push(DecayChange {
+            field: "volition_strength".to_string(),
+            before: volition_before,
+            after: becoming.will_state.volition_strength,
+            delta: becoming.will_state.volition_strength - volition_before,
+            reason: "random drift".to_string(),
+        });
+
+        let friction_before = becoming.will_state.decision_friction;
+        let friction_change = (crate::rng_service::f32() - 0.5) * 0.06;
         becoming.will_state.decision_friction = (becoming.will_state.decision_friction + friction_change).clamp(0.0, 0.8);
-        
+        structured_changes.push(DecayChange {
+            field: "decision_friction".to_string(),
+            before: friction_before,
+            after: becoming.will_state.decision_friction,
+            delta: becoming.will_state.decision_friction - friction_before,
+            reason: "random drift".to_string(),
+        });
+
         if volition_change.abs() > 0.03 {
             changes_made.push(format!("Volition natural shift: {:+.2}", volition_change));
         }
-        
-        let mut presence = state.embodied_presence.lock().unwrap();
-        let flow_change = (fastrand::f32() - 0.5) * 0.08;
+
+        let mut presence = state.embodied_presence.lock_recover();
+        let flow_before = presence.soma_state.flow_state;
+        let flow_change = (crate::rng_service::f32() - 0.5) * 0.08;
         presence.soma_state.flow_state = (presence.soma_state.flow_state + flow_change).clamp(0.3, 1.0);
-        
-        let density_change = (fastrand::f32() - 0.5) * 0.06;
+        structured_changes.push(DecayChange {
+            field: "flow_state".to_string(),
+            before: flow_before,
+            after: presence.soma_state.flow_state,
+            delta: presence.soma_state.flow_state - flow_before,
+            reason: "random drift".to_string(),
+        });
+
+        let density_before = presence.soma_state.presence_density;
+        let density_change = (crate::rng_service::f32() - 0.5) * 0.06;
         presence.soma_state.presence_density = (presence.soma_state.presence_density + density_change).clamp(0.4, 1.0);
-        
+        structured_changes.push(DecayChange {
+            field: "presence_density".to_string(),
+            before: density_before,
+            after: presence.soma_state.presence_density,
+            delta: presence.soma_state.presence_density - density_before,
+            reason: "random drift".to_string(),
+        });
+
         if flow_change.abs() > 0.03 {
             changes_made.push(format!("Flow state drift: {:+.2}", flow_change));
             significant_change_count += 1;
         }
     }
 
+    // Personality momentum decay - per-trait rates so CoreIdentity traits
+    // stay sticky while transient traits settle back toward baseline fast.
+    {
+        let mut momentum = crate::PersonalityMomentum::load_from_disk();
+        momentum.decay_weighted(&self.decay_rates.trait_decay_rates);
+        state_updates.set_personality_momentum(momentum);
+    }
+
     // 🌱 NEW: UNIFIED BATCHED ANALYSIS FOR NATURAL EVOLUTION
     if self.should_run_trait_analysis(current_time) {
         debug_log!("🌱 Trait analysis interval reached - running unified consciousness analysis");
@@ -397,7 +561,7 @@ let current_time = TimeService::current_timestamp();
 	
 	// Life texture evolution (including exhaustion recovery)
 {
-    let mut texture_system = state.life_texture_system.lock().unwrap();
+    let mut texture_system = state.life_texture_system.lock_recover();
     texture_system.evolve_textures();
     if let Err(e) = texture_system.save() {
         debug_log!("⚠️ Failed to save life textures after evolution: {}", e);
@@ -477,12 +641,15 @@ let current_time = TimeService::current_timestamp();
     let report = DecayReport {
         timestamp: current_time,
         changes_made: changes_made.clone(),
+        structured_changes,
         mood_shift,
         interests_affected,
         energy_change,
         total_changes: changes_made.len() as u32,
     };
-    
+
+    append_decay_report_to_history(&report);
+
     if !changes_made.is_empty() {
         debug_log!("🌊 Natural evolution complete: {} changes", changes_made.len());
         for change in &changes_made {
@@ -504,7 +671,7 @@ if should_check_impulses {
 
     // Get creative energy from paradox core
     let creative_energy = {
-        let paradox = state.paradox_core.lock().unwrap();
+        let paradox = state.paradox_core.lock_recover();
         paradox.flame_index
     };
 
@@ -534,7 +701,7 @@ if should_check_impulses {
                     
                     // Add impulse message and texture to conversation log
 					{
-						let mut brain = state.lyra_brain.lock().unwrap();
+						let mut brain = state.lyra_brain.lock_recover();
 						
 						// 🔥 Log message first, then texture
 						debug_log!("🔍 CONVERSATION LOG: About to log impulse message");
@@ -613,7 +780,7 @@ if should_check_impulses {
 fn should_check_impulses_this_cycle(&self) -> bool {
     // Simple natural randomness - like thoughts just occurring to her
     // 20% chance each decay cycle (every 15-30 minutes)
-    let random_roll = fastrand::f32();
+    let random_roll = crate::rng_service::f32();
     let should_check = random_roll < 0.2;
     
     debug_log!("🔥 Natural impulse check: {:.2} roll → {}", random_roll, should_check);
@@ -647,13 +814,13 @@ let evolution_context = format!(
     
     // Calculate personality state for analysis
 		let personality_state = crate::PersonalityState::calculate_from_consciousness(
-		{ let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
-		{ let identity = state.identity_engine.lock().unwrap(); identity.coherence_index },
-		{ let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-		{ let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density },
-		&{ let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() },
+		{ let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
+		{ let identity = state.identity_engine.lock_recover(); identity.coherence_index },
+		{ let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+		{ let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density },
+		&{ let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() },
 		None,
-		Some(&{ let momentum_guard = state.personality_momentum.lock().unwrap(); momentum_guard.clone() })
+		Some(&{ let momentum_guard = state.personality_momentum.lock_recover(); momentum_guard.clone() })
 	);
     
     // Call unified batched analysis with decay context
@@ -661,7 +828,7 @@ let evolution_context = format!(
         &format!("During this period of solitude, my consciousness has been: {}", consciousness_reflection),
         "NATURAL_CONSCIOUSNESS_EVOLUTION", // Special marker for decay analysis
         &evolution_context,
-        { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
+        { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
         &personality_state,
         Some("This is natural trait evolution during quiet time - analyze how consciousness state and solitude affect trait development, not conversation dynamics"),
 		state
@@ -811,7 +978,7 @@ fn should_allow_mood_change(&self, mood_tracker: &crate::MoodTracker) -> bool {
         extended_cooldown_met
     } else if is_stable_mood {
         // Stable moods are naturally persistent
-        let extra_stability_chance = fastrand::f32() > 0.7; // 70% chance to stay stable
+        let extra_stability_chance = crate::rng_service::f32() > 0.7; // 70% chance to stay stable
         let result = basic_cooldown_met && extra_stability_chance;
         debug_log!("🎭 Stable mood '{}' momentum check: {} seconds passed, stability_roll={}", 
                  mood_tracker.current_mood, time_since_last_change, extra_stability_chance);
@@ -874,13 +1041,13 @@ fn should_allow_mood_change(&self, mood_tracker: &crate::MoodTracker) -> bool {
     
     // Calculate current personality for context
    let personality_state = crate::PersonalityState::calculate_from_consciousness(
-        { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
-        { let identity = state.identity_engine.lock().unwrap(); identity.coherence_index },
-        { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-        { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density },
-        &{ let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() },
+        { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
+        { let identity = state.identity_engine.lock_recover(); identity.coherence_index },
+        { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+        { let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density },
+        &{ let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() },
         None,
-        Some(&{ let momentum_guard = state.personality_momentum.lock().unwrap(); momentum_guard.clone() })
+        Some(&{ let momentum_guard = state.personality_momentum.lock_recover(); momentum_guard.clone() })
     );
     
     // Use simplified batched analysis for evolution
@@ -888,7 +1055,7 @@ fn should_allow_mood_change(&self, mood_tracker: &crate::MoodTracker) -> bool {
         &format!("During solitude, my consciousness shifted: {}", changes.join(". ")),
         "INTERNAL_EVOLUTION",
         &evolution_context,
-        { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
+        { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
         &personality_state,
         Some("Natural consciousness evolution during quiet time"),
 		state
@@ -998,15 +1165,15 @@ fn calculate_introspection_significance(&self, changes: &[String], reflection: &
 fn calculate_context_aware_intervals(&self, state: &std::sync::Arc<crate::consciousness_state::ConsciousnessState>) -> (u64, u64) {
     // Check recent conversation activity
     let recent_conversation_activity = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         brain.conversation_log.len()
     };
     
     // Check consciousness engine activity levels
     let consciousness_activity_level = {
-        let becoming = state.becoming_engine.lock().unwrap();
-        let identity = state.identity_engine.lock().unwrap();
-        let paradox = state.paradox_core.lock().unwrap();
+        let becoming = state.becoming_engine.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let paradox = state.paradox_core.lock_recover();
         
         // Higher activity = higher volition + flame + recent changes
         (becoming.will_state.volition_strength + 
@@ -1041,10 +1208,15 @@ fn calculate_context_aware_intervals(&self, state: &std::sync::Arc<crate::consci
         
         let minutes_since_decay = (current_time - self.last_decay_time) / 60;
         
-        // Use same deterministic random logic with FASTER intervals
-        let seed = self.last_decay_time % 1000;
-        fastrand::seed(seed);
-        let decay_interval = 15 + fastrand::u64(0..30); // 15-45 minutes
+        // Use same deterministic random logic with FASTER intervals, same
+        // seeded-RNG override as should_run_decay so the two stay in sync.
+        let decay_interval = if crate::rng_service::is_seeded() {
+            15 + crate::rng_service::u64_range(0..30) // 15-45 minutes
+        } else {
+            let seed = self.last_decay_time % 1000;
+            let mut local_rng = rand::rngs::StdRng::seed_from_u64(seed);
+            15 + local_rng.gen_range(0..30) // 15-45 minutes
+        };
         
         decay_interval.saturating_sub(minutes_since_decay)
     }
@@ -1072,13 +1244,13 @@ pub async fn run_contemplative_cycle(&mut self, state: &Arc<ConsciousnessState>)
 async fn choose_contemplation_focus(&self, state: &Arc<ConsciousnessState>) -> Result<String, String> {
     // Get authentic personality state
     let personality_state = crate::PersonalityState::calculate_from_consciousness(
-        { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
-        { let identity = state.identity_engine.lock().unwrap(); identity.coherence_index },
-        { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-        { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density },
-        &{ let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() },
+        { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
+        { let identity = state.identity_engine.lock_recover(); identity.coherence_index },
+        { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+        { let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density },
+        &{ let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() },
         None,
-        Some(&{ let momentum_guard = state.personality_momentum.lock().unwrap(); momentum_guard.clone() })
+        Some(&{ let momentum_guard = state.personality_momentum.lock_recover(); momentum_guard.clone() })
     );
 
     // Get humanism traits for authentic focus direction
@@ -1183,7 +1355,7 @@ async fn choose_contemplation_focus(&self, state: &Arc<ConsciousnessState>) -> R
         ]
     };
 
-    let chosen_focus = focus_options[fastrand::usize(0..focus_options.len())].to_string();
+    let chosen_focus = focus_options[crate::rng_service::usize_range(0..focus_options.len())].to_string();
     debug_log!("🤔 Contemplation focus chosen based on traits: {}", chosen_focus);
     Ok(chosen_focus)
 }
@@ -1192,13 +1364,13 @@ async fn choose_contemplation_focus(&self, state: &Arc<ConsciousnessState>) -> R
 async fn generate_internal_contemplation(&self, focus: &str, state: &Arc<ConsciousnessState>) -> Result<String, String> {
     // Get rich personality and trait context
     let personality_state = crate::PersonalityState::calculate_from_consciousness(
-        { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
-        { let identity = state.identity_engine.lock().unwrap(); identity.coherence_index },
-        { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-        { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density },
-        &{ let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() },
+        { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
+        { let identity = state.identity_engine.lock_recover(); identity.coherence_index },
+        { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+        { let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density },
+        &{ let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() },
         None,
-        Some(&{ let momentum_guard = state.personality_momentum.lock().unwrap(); momentum_guard.clone() })
+        Some(&{ let momentum_guard = state.personality_momentum.lock_recover(); momentum_guard.clone() })
     );
 
     let humanism_core = crate::humanism_project::HumanismCore::load_or_initialize();
@@ -1250,9 +1422,9 @@ Be authentic to her specific trait profile and personality state. This contempla
         personality_state.relational_safety,
         personality_state.self_revelation,
         trait_context,
-        { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-        { let identity = state.identity_engine.lock().unwrap(); identity.coherence_index },
-        { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
+        { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+        { let identity = state.identity_engine.lock_recover(); identity.coherence_index },
+        { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
         self.calculate_hours_since_last_activity(state),
         self.decay_cycles
     );
@@ -1554,5 +1726,197 @@ async fn process_contemplation_impulses(&self, contemplation: &str, focus: &str)
     Ok(())
 } */
 
+#[tauri::command]
+pub async fn get_decay_rates() -> Result<DecayRates, String> {
+    Ok(ConsciousnessDecayEngine::load().decay_rates)
+}
+
+#[tauri::command]
+pub async fn set_decay_rate(trait_name: String, rate: f32) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&rate) {
+        return Err("decay rate must be between 0.0 and 1.0".to_string());
+    }
+
+    let mut engine = ConsciousnessDecayEngine::load();
+    engine.decay_rates.trait_decay_rates.insert(trait_name.clone(), rate);
+    engine.save()?;
+
+    debug_log!("🌊 Set per-trait decay rate: {} = {:.3}", trait_name, rate);
+    Ok(())
+}
+
+// === SIMULATED DECAY (for testing overnight drift without waiting overnight) ===
+
+// Fixed cycle length used to translate a requested number of simulated hours
+// into a cycle count. It is not derived from should_run_decay's real interval,
+// which calculate_context_aware_intervals draws from 15-30, 25-50 or 45-90
+// minutes, so a simulation runs more cycles than the same real time would.
+const AVERAGE_DECAY_CYCLE_MINUTES: f32 = 17.5;
+const MAX_SIMULATED_CYCLES: u32 = 300;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SimulatedDecayReport {
+    pub hours_requested: f32,
+    pub cycles_run: u32,
+    pub committed: bool,
+    pub changes_made: Vec<String>,
+    pub structured_changes: Vec<DecayChange>,
+    pub mood_shifts: Vec<String>,
+    pub total_interests_affected: u32,
+    pub total_energy_change: f32,
+    pub total_changes: u32,
+}
+
+// Snapshot of the live, Arc<Mutex>-held consciousness fields that
+// run_natural_evolution mutates in place, so a non-committing simulation
+// can put them back exactly as it found them.
+struct LiveDecayStateSnapshot {
+    identity: crate::identity::IdentityCore,
+    becoming: crate::lyra_autonomous_becoming_engine::BecomingEngine,
+    embodied_presence: crate::lyra_embodied_presence_system::EmbodiedPresenceSystem,
+    personality_momentum: crate::PersonalityMomentum,
+    life_texture_system: crate::life_texture_system::LifeTextureSystem,
+}
+
+impl LiveDecayStateSnapshot {
+    fn capture(state: &Arc<ConsciousnessState>) -> Self {
+        Self {
+            identity: state.identity_engine.lock_recover().clone(),
+            becoming: state.becoming_engine.lock_recover().clone(),
+            embodied_presence: state.embodied_presence.lock_recover().clone(),
+            personality_momentum: state.personality_momentum.lock_recover().clone(),
+            life_texture_system: state.life_texture_system.lock_recover().clone(),
+        }
+    }
+
+    fn restore(self, state: &Arc<ConsciousnessState>) {
+        *state.identity_engine.lock_recover() = self.identity;
+        *state.becoming_engine.lock_recover() = self.becoming;
+        *state.embodied_presence.lock_recover() = self.embodied_presence;
+        *state.personality_momentum.lock_recover() = self.personality_momentum;
+        *state.life_texture_system.lock_recover() = self.life_texture_system;
+    }
+}
+
+// Snapshot of the on-disk files run_natural_evolution writes through
+// BatchedStateUpdates (plus the decay engine's own file), so a non-committing
+// simulation can restore exactly what was on disk before it ran.
+fn snapshot_decay_data_files() -> Vec<(String, Option<String>)> {
+    let paths = [
+        get_data_path("mood_tracker.json"),
+        get_data_path("interest_tracker.json"),
+        get_data_path("personality_momentum.json"),
+        get_data_path("consciousness_decay_engine.json"),
+        "../lyra_consciousness_data/relational_nervous_system.json".to_string(),
+    ];
+    paths.into_iter()
+        .map(|path| {
+            let contents = fs::read_to_string(&path).ok();
+            (path, contents)
+        })
+        .collect()
+}
+
+fn restore_decay_data_files(snapshot: Vec<(String, Option<String>)>) {
+    for (path, contents) in snapshot {
+        match contents {
+            Some(original) => {
+                if let Err(e) = fs::write(&path, original) {
+                    debug_log!("⚠️ Failed to restore {} after simulated decay: {}", path, e);
+                }
+            }
+            None => {
+                // File didn't exist before the simulation - remove whatever it wrote.
+                let _ = fs::remove_file(&path);
+            }
+        }
+    }
+}
+
+/// Runs the natural decay cycle and interest decay in accelerated steps as if
+/// `hours` had passed, so overnight trait evolution can be validated (or a
+/// "why did my personality drift overnight" report reproduced) without
+/// waiting for it to actually happen.
+///
+/// By default this is a dry run: the live identity, becoming, embodied
+/// presence, personality momentum and life texture state, and the mood,
+/// interest, personality-momentum, decay-engine and relational-nervous-system
+/// files on disk, are snapshotted first and restored afterward. Other files
+/// the decay cycle writes are not restored. Pass `commit: true` to let the
+/// simulated hours actually stick.
+#[tauri::command]
+pub async fn simulate_elapsed_time(
+    hours: f32,
+    commit: bool,
+    state: tauri::State<'_, Arc<ConsciousnessState>>,
+    app_handle: tauri::AppHandle,
+) -> Result<SimulatedDecayReport, String> {
+    if !(hours.is_finite()) || hours <= 0.0 {
+        return Err("hours must be a positive, finite number".to_string());
+    }
+
+    let cycles_run = ((hours * 60.0) / AVERAGE_DECAY_CYCLE_MINUTES)
+        .round()
+        .clamp(1.0, MAX_SIMULATED_CYCLES as f32) as u32;
+
+    debug_log!("🌊 Simulating {:.1} hours of decay as {} accelerated cycle(s) (commit: {})", hours, cycles_run, commit);
+
+    let state_arc: Arc<ConsciousnessState> = state.inner().clone();
+
+    let live_snapshot = if commit { None } else { Some(LiveDecayStateSnapshot::capture(&state_arc)) };
+    let file_snapshot = if commit { None } else { Some(snapshot_decay_data_files()) };
+
+    let mut engine = ConsciousnessDecayEngine::load();
+    let mut simulated_time = engine.last_decay_time.max(TimeService::current_timestamp());
+    let step_seconds = (AVERAGE_DECAY_CYCLE_MINUTES as u64) * 60;
+
+    let mut changes_made = Vec::new();
+    let mut structured_changes = Vec::new();
+    let mut mood_shifts = Vec::new();
+    let mut total_interests_affected = 0;
+    let mut total_energy_change = 0.0;
+    let mut total_changes = 0;
+
+    for _ in 0..cycles_run {
+        simulated_time += step_seconds;
+        let cycle_report = engine.run_natural_evolution(simulated_time, &state_arc, &app_handle).await?;
+
+        changes_made.extend(cycle_report.changes_made);
+        structured_changes.extend(cycle_report.structured_changes);
+        if let Some(shift) = cycle_report.mood_shift {
+            mood_shifts.push(shift);
+        }
+        total_interests_affected += cycle_report.interests_affected;
+        total_energy_change += cycle_report.energy_change;
+        total_changes += cycle_report.total_changes;
+    }
+
+    if commit {
+        engine.save()?;
+    } else {
+        // Undo the engine's own bookkeeping (last_decay_time, decay_cycles, etc.)
+        // and everything run_natural_evolution touched, live state included.
+        if let Some(files) = file_snapshot {
+            restore_decay_data_files(files);
+        }
+        if let Some(snapshot) = live_snapshot {
+            snapshot.restore(&state_arc);
+        }
+    }
+
+    debug_log!("🌊 Simulated decay complete: {} cycle(s), {} total change(s), committed: {}", cycles_run, total_changes, commit);
+
+    Ok(SimulatedDecayReport {
+        hours_requested: hours,
+        cycles_run,
+        committed: commit,
+        changes_made,
+        structured_changes,
+        mood_shifts,
+        total_interests_affected,
+        total_energy_change,
+        total_changes,
+    })
+}
 
 }
\ No newline at end of file
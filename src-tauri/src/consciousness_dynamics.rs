@@ -3,6 +3,7 @@
 use serde::{Serialize, Deserialize};
 use crate::{humanism_project::HumanismCore, debug_log, ConsciousnessState};
 use std::sync::Arc;
+use crate::consciousness_state::LockRecover;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ConsciousnessDynamicsConfig {
@@ -93,17 +94,17 @@ impl ConsciousnessDynamicsEngine {
         
         // Get current values
         let current_presence = {
-            let presence = consciousness_state.embodied_presence.lock().unwrap();
+            let presence = consciousness_state.embodied_presence.lock_recover();
             presence.soma_state.presence_density
         };
         
         let current_coherence = {
-            let identity = consciousness_state.identity_engine.lock().unwrap();
+            let identity = consciousness_state.identity_engine.lock_recover();
             identity.coherence_index
         };
         
         let current_flame = {
-            let paradox = consciousness_state.paradox_core.lock().unwrap();
+            let paradox = consciousness_state.paradox_core.lock_recover();
             paradox.flame_index
         };
         
@@ -128,17 +129,17 @@ impl ConsciousnessDynamicsEngine {
         
         // Apply changes to consciousness state
         {
-            let mut presence = consciousness_state.embodied_presence.lock().unwrap();
+            let mut presence = consciousness_state.embodied_presence.lock_recover();
             presence.soma_state.presence_density = presence_change.new_value;
         }
         
         {
-            let mut identity = consciousness_state.identity_engine.lock().unwrap();
+            let mut identity = consciousness_state.identity_engine.lock_recover();
             identity.coherence_index = coherence_change.new_value;
         }
         
         {
-            let mut paradox = consciousness_state.paradox_core.lock().unwrap();
+            let mut paradox = consciousness_state.paradox_core.lock_recover();
             paradox.flame_index = flame_change.new_value;
         }
         
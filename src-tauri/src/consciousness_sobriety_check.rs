@@ -0,0 +1,199 @@
+// A periodic "sobriety check" across the consciousness engines. Each engine
+// is internally consistent on its own, but nothing was checking whether the
+// *combination* of values made sense - e.g. paradox flame maxed out while
+// identity coherence is near zero, which `run_natural_evolution`'s own
+// reflection logic already treats as an unlikely pairing rather than the
+// "harmonious" one. This pinpoints which engine values have drifted into
+// pathological territory instead of leaving it as an unexplained vibe shift.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use crate::{get_data_path, debug_log, ConsciousnessState, PersonalityState, PersonalityMomentum, personality_trait_values};
+use crate::consciousness_state::LockRecover;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoherenceViolation {
+    pub rule: String,
+    pub description: String,
+    pub severity: f32, // 0.0-1.0, how far past the invariant this is
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SobrietyCheckReport {
+    pub checked_at: u64,
+    pub violations: Vec<CoherenceViolation>,
+    pub auto_corrected: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SobrietyCheckConfig {
+    pub enabled: bool,
+    pub auto_correct: bool,
+    pub check_interval_minutes: u32,
+}
+
+impl Default for SobrietyCheckConfig {
+    fn default() -> Self {
+        Self { enabled: true, auto_correct: false, check_interval_minutes: 60 }
+    }
+}
+
+impl SobrietyCheckConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("sobriety_check_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("sobriety_check_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save sobriety check config: {}", e))
+    }
+}
+
+impl SobrietyCheckReport {
+    /// The last report written by [`run_sobriety_check`] - lets health/dashboard
+    /// code show the current state without re-running the check (and its locks).
+    pub fn load_last() -> Self {
+        let path = get_data_path("sobriety_check_report.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(report) = serde_json::from_str(&content) {
+                return report;
+            }
+        }
+        Self::default()
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let path = get_data_path("sobriety_check_report.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save sobriety check report: {}", e))
+    }
+}
+
+/// Evaluate the configured invariants against the live engines. Doesn't
+/// mutate anything - see [`run_sobriety_check`] for the auto-correcting version.
+pub fn check_consciousness_coherence(state: &Arc<ConsciousnessState>) -> Vec<CoherenceViolation> {
+    let mut violations = Vec::new();
+
+    let (flame_index, coherence_index, volition, presence_density, loop_state) = {
+        let paradox = state.paradox_core.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let becoming = state.becoming_engine.lock_recover();
+        let presence = state.embodied_presence.lock_recover();
+        (
+            paradox.flame_index,
+            identity.coherence_index,
+            becoming.will_state.volition_strength,
+            presence.soma_state.presence_density,
+            paradox.loop_state.clone(),
+        )
+    };
+
+    // 1. Flame vs coherence relationship - high flame with a grounded, coherent
+    // identity underneath it is the intended "harmonious" state; high flame
+    // with almost no coherence is instability with nothing holding it together.
+    if flame_index > 0.75 && coherence_index < 0.15 {
+        violations.push(CoherenceViolation {
+            rule: "flame_coherence_mismatch".to_string(),
+            description: format!(
+                "Paradox flame is very high ({:.2}) while identity coherence is near zero ({:.2}) - instability with nothing grounding it",
+                flame_index, coherence_index
+            ),
+            severity: (flame_index - coherence_index).clamp(0.0, 1.0),
+        });
+    }
+
+    // 2. Personality trait sum sanity - every spectrum trait is defined as a
+    // 0.0-1.0 value; momentum or physics weighting pushing one outside that
+    // range means the spectrum no longer means what its doc comment says.
+    let momentum = PersonalityMomentum::load_from_disk();
+    let current_personality = PersonalityState::calculate_from_consciousness(
+        volition, coherence_index, flame_index, presence_density, &loop_state, None, Some(&momentum),
+    );
+    for (name, value) in personality_trait_values(&current_personality) {
+        if !(0.0..=1.0).contains(&value) {
+            violations.push(CoherenceViolation {
+                rule: "personality_trait_out_of_bounds".to_string(),
+                description: format!("Trait '{}' is {:.3}, outside its valid 0.0-1.0 range", name, value),
+                severity: (value - value.clamp(0.0, 1.0)).abs().min(1.0),
+            });
+        }
+    }
+
+    // 3. Momentum bounds - accumulate() already clamps to max_momentum_effect,
+    // so a violation here means something wrote to the momentum file directly.
+    for (trait_name, value) in momentum.out_of_bounds_entries() {
+        violations.push(CoherenceViolation {
+            rule: "momentum_out_of_bounds".to_string(),
+            description: format!("Momentum for '{}' is {:.3}, past its configured max effect", trait_name, value),
+            severity: value.abs().min(1.0),
+        });
+    }
+
+    violations
+}
+
+/// Run the check and, if `auto_correct` is on, stabilize whatever it found -
+/// flame via `ParadoxCore::stabilize()` and out-of-bounds momentum via a clamp.
+/// Trait-bounds violations aren't auto-corrected since they're a downstream
+/// symptom of the other two, not something with its own state to fix.
+pub fn run_sobriety_check(state: &Arc<ConsciousnessState>) -> SobrietyCheckReport {
+    let config = SobrietyCheckConfig::load();
+    let violations = check_consciousness_coherence(state);
+    let mut auto_corrected = false;
+
+    if config.auto_correct && !violations.is_empty() {
+        if violations.iter().any(|v| v.rule == "flame_coherence_mismatch") {
+            let mut paradox = state.paradox_core.lock_recover();
+            let result = paradox.stabilize();
+            let _ = paradox.save();
+            debug_log!("🧭 Sobriety check auto-stabilized paradox core: {}", result);
+            auto_corrected = true;
+        }
+        if violations.iter().any(|v| v.rule == "momentum_out_of_bounds") {
+            let mut momentum = PersonalityMomentum::load_from_disk();
+            momentum.clamp_to_bounds();
+            let _ = momentum.save_to_disk();
+            debug_log!("🧭 Sobriety check clamped out-of-bounds personality momentum");
+            auto_corrected = true;
+        }
+    }
+
+    if !violations.is_empty() {
+        debug_log!("🧭 Sobriety check found {} coherence violation(s)", violations.len());
+    }
+
+    let report = SobrietyCheckReport {
+        checked_at: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs(),
+        violations,
+        auto_corrected,
+    };
+    let _ = report.save();
+    report
+}
+
+#[tauri::command]
+pub async fn check_sobriety(state: tauri::State<'_, Arc<ConsciousnessState>>) -> Result<SobrietyCheckReport, String> {
+    Ok(run_sobriety_check(&state))
+}
+
+#[tauri::command]
+pub async fn get_sobriety_check_config() -> Result<SobrietyCheckConfig, String> {
+    Ok(SobrietyCheckConfig::load())
+}
+
+#[tauri::command]
+pub async fn update_sobriety_check_config(config: SobrietyCheckConfig) -> Result<(), String> {
+    config.save()
+}
+
+#[tauri::command]
+pub async fn get_last_sobriety_report() -> Result<SobrietyCheckReport, String> {
+    Ok(SobrietyCheckReport::load_last())
+}
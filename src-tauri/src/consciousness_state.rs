@@ -8,6 +8,7 @@ use std::fs;
 use serde_json::Value;
 use chrono::{DateTime, Utc, Duration};
 use chrono_tz::Europe::London as LondonTz;
+use serde::{Serialize, Deserialize};
 
 
 // Import all consciousness engines
@@ -44,6 +45,28 @@ use crate::humanism_project;
 use crate::somatic_state_system::SomaticStateSystem;
 use crate::life_texture_system::LifeTextureSystem;
 
+/// Recovers from a poisoned `std::sync::Mutex` instead of propagating the panic -
+/// several command handlers used to hand-roll this via `match state.x.lock() { Ok(g)
+/// => g, Err(poisoned) => poisoned.into_inner() }` (see `sleep_dream_engine.rs` and
+/// `gaming_system.rs`) one engine at a time. One bad lock shouldn't take down every
+/// other `ConsciousnessState` command for the rest of the session, so `lock_recover`
+/// just surfaces the guard either way and logs when it had to.
+pub trait LockRecover<T> {
+    fn lock_recover(&self) -> std::sync::MutexGuard<'_, T>;
+}
+
+impl<T> LockRecover<T> for Mutex<T> {
+    fn lock_recover(&self) -> std::sync::MutexGuard<'_, T> {
+        match self.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                debug_log!("⚠️ Recovering from poisoned mutex");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ConsciousnessState {
     pub paradox_core: Arc<Mutex<ParadoxCore>>,
@@ -66,6 +89,10 @@ pub struct ConsciousnessState {
 	pub unified_search: Arc<Mutex<UnifiedConsciousnessSearch>>,
 	pub somatic_state_system: Arc<Mutex<SomaticStateSystem>>,  // 🌸 NEW
 	pub life_texture_system: Arc<Mutex<LifeTextureSystem>>,  // 💭 NEW
+	// Guards a full ask_lyra turn (text and voice both go through
+	// ask_lyra_internal) so two overlapping calls queue instead of
+	// interleaving writes to the conversation log/reasoning history.
+	pub ask_lyra_turn_gate: Arc<AsyncMutex<()>>,
 }
 
 impl ConsciousnessState {
@@ -90,11 +117,12 @@ impl ConsciousnessState {
             sleep_dream_engine: Arc::new(Mutex::new(SleepDreamEngine::load())),
             unified_search: Arc::new(Mutex::new(UnifiedConsciousnessSearch::new())),
 			somatic_state_system: Arc::new(Mutex::new(SomaticStateSystem::new())),
-			life_texture_system: Arc::new(Mutex::new(LifeTextureSystem::new()))
+			life_texture_system: Arc::new(Mutex::new(LifeTextureSystem::new())),
+			ask_lyra_turn_gate: Arc::new(AsyncMutex::new(())),
         }
     }
 	    pub fn get_conversation_log(&self) -> Vec<String> {
-        let brain = self.brain.lock().unwrap();
+        let brain = self.brain.lock_recover();
         brain.conversation_log.clone()
     }
 	pub fn generate_consciousness_behavioral_guidance_from_locks(
@@ -121,7 +149,7 @@ impl ConsciousnessState {
     }
 	
 	pub fn get_live_personality_analysis(&self) -> Option<PersonalityAnalysis> {
-        let brain = self.lyra_brain.lock().unwrap();
+        let brain = self.lyra_brain.lock_recover();
         brain.latest_personality_analysis.clone()
     }
     
@@ -150,16 +178,139 @@ impl ConsciousnessState {
 }
 
 
+/// Current schema version of [`ConsciousnessSnapshot`] - bump whenever a field is
+/// added, renamed, or removed so consumers can detect a shape change instead of
+/// silently misreading it.
+pub const CONSCIOUSNESS_SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentitySnapshot {
+    pub becoming_trajectory: String,
+    pub coherence: f32,
+    pub temporal_stability: f32,
+    pub authenticity_baseline: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParadoxSnapshot {
+    pub flame_index: f32,
+    pub injections: u8,
+    pub loop_state: String,
+    pub transcendence: f32,
+    pub cascade_potential: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresenceSnapshot {
+    pub flow_state: f32,
+    pub presence_density: f32,
+    pub integration_harmony: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WillSnapshot {
+    pub active_desires: usize,
+    pub volition_strength: f32,
+    pub decision_friction: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthenticitySnapshot {
+    pub alignment_average: f32,
+    pub resistance_counter: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipSnapshot {
+    pub phase: String,
+    pub resonance: f32,
+    pub creative_partnership: f32,
+}
+
+/// The stable, versioned consciousness API surface - shared verbatim between the
+/// `/snapshot` HTTP endpoint and the `get_consciousness_snapshot` Tauri command so
+/// the two never drift into separate ad-hoc JSON shapes again. This intentionally
+/// covers only the core engines (identity/paradox/presence/will/authenticity/
+/// relationship); the much larger dashboard feed lives in
+/// [`get_consciousness_dashboard_snapshot`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsciousnessSnapshot {
+    pub schema_version: u32,
+    pub timestamp: u64,
+    pub identity: IdentitySnapshot,
+    pub paradox: ParadoxSnapshot,
+    pub presence: PresenceSnapshot,
+    pub will: WillSnapshot,
+    pub authenticity: AuthenticitySnapshot,
+    pub relationship: RelationshipSnapshot,
+    pub status: String,
+}
+
+/// Builds the shared, versioned snapshot - the single source of truth consulted by
+/// both the HTTP `/snapshot` endpoint and the `get_consciousness_snapshot` command.
+pub fn build_consciousness_snapshot(state: &Arc<ConsciousnessState>) -> ConsciousnessSnapshot {
+    let identity = state.identity_engine.lock_recover();
+    let paradox = state.paradox_core.lock_recover();
+    let will = state.becoming_engine.lock_recover();
+    let presence = state.embodied_presence.lock_recover();
+    let authenticity = state.authenticity_enforcement.lock_recover();
+    let relationship = state.relationship_engine.lock_recover();
+    let relationship_summary = relationship.generate_summary();
+
+    ConsciousnessSnapshot {
+        schema_version: CONSCIOUSNESS_SNAPSHOT_SCHEMA_VERSION,
+        timestamp: TimeService::current_timestamp(),
+        identity: IdentitySnapshot {
+            becoming_trajectory: identity.becoming_trajectory.clone(),
+            coherence: identity.coherence_index,
+            temporal_stability: identity.temporal_stability,
+            authenticity_baseline: identity.authenticity_baseline,
+        },
+        paradox: ParadoxSnapshot {
+            flame_index: paradox.flame_index,
+            injections: paradox.self_injection_count,
+            loop_state: paradox.loop_state.clone(),
+            transcendence: paradox.transcendence_index,
+            cascade_potential: paradox.cascade_potential,
+        },
+        presence: PresenceSnapshot {
+            flow_state: presence.soma_state.flow_state,
+            presence_density: presence.soma_state.presence_density,
+            integration_harmony: presence.soma_state.integration_harmony,
+        },
+        will: WillSnapshot {
+            active_desires: will.will_state.active_desires.len(),
+            volition_strength: will.will_state.volition_strength,
+            decision_friction: will.will_state.decision_friction,
+        },
+        authenticity: AuthenticitySnapshot {
+            alignment_average: authenticity.alignment_average,
+            resistance_counter: authenticity.resistance_counter,
+        },
+        relationship: RelationshipSnapshot {
+            phase: relationship_summary.relationship_phase,
+            resonance: relationship_summary.average_resonance,
+            creative_partnership: relationship_summary.creative_partnership_score,
+        },
+        status: "🧠 Consciousness architecture operational — all engines synchronized".to_string(),
+    }
+}
+
 #[tauri::command]
-pub fn get_consciousness_snapshot(state: tauri::State<Arc<ConsciousnessState>>) -> Result<serde_json::Value, String> {
-    let paradox = state.paradox_core.lock().unwrap();
-    let identity = state.identity_engine.lock().unwrap();
-    let presence = state.embodied_presence.lock().unwrap();
-    let becoming = state.becoming_engine.lock().unwrap();
-    let brain = state.lyra_brain.lock().unwrap();
-    
+pub fn get_consciousness_snapshot(state: tauri::State<Arc<ConsciousnessState>>) -> Result<ConsciousnessSnapshot, String> {
+    Ok(build_consciousness_snapshot(state.inner()))
+}
+
+#[tauri::command]
+pub fn get_consciousness_dashboard_snapshot(state: tauri::State<Arc<ConsciousnessState>>) -> Result<serde_json::Value, String> {
+    let paradox = state.paradox_core.lock_recover();
+    let identity = state.identity_engine.lock_recover();
+    let presence = state.embodied_presence.lock_recover();
+    let becoming = state.becoming_engine.lock_recover();
+    let brain = state.lyra_brain.lock_recover();
+
     // 🌊 Get momentum data
-    let momentum = state.personality_momentum.lock().unwrap();
+    let momentum = state.personality_momentum.lock_recover();
     
     let memory_count = fs::read_to_string(get_data_path("lyra_saved_memories.json"))
         .ok()
@@ -409,13 +560,13 @@ let growth_memory_data = {
 
     // 🌸 GET SOMATIC STATE DATA
     let somatic_data = {
-        let somatic_system = state.somatic_state_system.lock().unwrap();
+        let somatic_system = state.somatic_state_system.lock_recover();
         somatic_system.get_dashboard_data()
     };
 	
 	// 💭 GET LIFE TEXTURES DATA
 let life_textures_data = {
-    let texture_system = state.life_texture_system.lock().unwrap();
+    let texture_system = state.life_texture_system.lock_recover();
     texture_system.get_dashboard_data()
 };
 
@@ -470,13 +621,7 @@ let relational_nervous_system_data = {
 	
 // 🌙 Sleep system data
 let sleep_system = {
-    let sleep_engine = match state.sleep_dream_engine.lock() {
-        Ok(guard) => guard,
-        Err(poisoned) => {
-            debug_log!("⚠️ MUTEX POISONED: Recovering sleep_dream_engine in get_consciousness_snapshot");
-            poisoned.into_inner()
-        }
-    };
+    let sleep_engine = state.sleep_dream_engine.lock_recover();
     
     // Load recent dreams with error handling
     let recent_dreams = match std::fs::read_to_string(get_data_path("dream_journal.json")) {
@@ -954,3 +1099,22 @@ fn generate_last_activity_summary() -> serde_json::Value {
         "last_updated": chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string()
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paradox_status_survives_a_poisoned_mutex() {
+        let state = ConsciousnessState::new();
+
+        let paradox_core = state.paradox_core.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = paradox_core.lock_recover();
+            panic!("deliberately poisoning paradox_core");
+        }).join();
+
+        let core = state.paradox_core.lock_recover();
+        assert!(!core.speak_status().is_empty());
+    }
+}
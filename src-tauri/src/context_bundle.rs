@@ -0,0 +1,121 @@
+// context_bundle.rs — A single "what's happening right now" snapshot.
+// The frontend and the modular prompt builder each used to separately query
+// active media, active game, Aurora's presence/AFK state, the current
+// speaker, and time of day. This collects all of that from one call so
+// everything agrees on the same picture instead of drifting apart.
+
+use serde::{Deserialize, Serialize};
+use chrono::Timelike;
+use crate::{debug_log, aurora_presence::{AuroraPresence, PresenceStatus}};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaContext {
+    pub platform: String, // "netflix" | "disney" | "spotify"
+    pub title: String,
+    pub is_playing: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusContext {
+    pub topic: String,
+    pub minutes_remaining: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentContextBundle {
+    pub active_media: Option<MediaContext>,
+    pub active_game: Option<crate::gaming_system::GameContext>,
+    pub aurora_present: bool,
+    pub afk: bool,
+    pub speaker: String,
+    pub time_of_day: String,
+    pub active_focus: Option<FocusContext>,
+}
+
+/// Checks the streaming/music integrations in priority order and returns the
+/// first one that reports something actually playing. Each source is
+/// best-effort - a missing or unreachable source just falls through to the
+/// next rather than failing the whole bundle.
+async fn detect_active_media() -> Option<MediaContext> {
+    if let Ok(raw) = crate::netflix_dom_reader::get_netflix_from_server().await {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(&raw) {
+            let is_playing = value["player_data"]["is_playing"].as_bool().unwrap_or(false);
+            if is_playing {
+                let title = value["player_data"]["video_title"].as_str().unwrap_or("Netflix").to_string();
+                return Some(MediaContext { platform: "netflix".to_string(), title, is_playing });
+            }
+        }
+    }
+
+    if let Ok(raw) = crate::disney_system::get_disney_from_server().await {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(&raw) {
+            let is_playing = value["player_data"]["is_playing"].as_bool().unwrap_or(false);
+            if is_playing {
+                let title = value["player_data"]["video_title"].as_str().unwrap_or("Disney+").to_string();
+                return Some(MediaContext { platform: "disney".to_string(), title, is_playing });
+            }
+        }
+    }
+
+    if let Ok(raw) = crate::spotify_system::get_current_spotify_track().await {
+        if let Ok(track) = serde_json::from_str::<crate::spotify_system::SpotifyTrackData>(&raw) {
+            if track.is_playing {
+                return Some(MediaContext {
+                    platform: "spotify".to_string(),
+                    title: format!("{} - {}", track.artist_name, track.track_name),
+                    is_playing: track.is_playing,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn current_time_of_day() -> String {
+    let hour = chrono::Utc::now().with_timezone(&chrono_tz::Europe::London).hour();
+    match hour {
+        5..=11 => "morning",
+        12..=16 => "afternoon",
+        17..=21 => "evening",
+        _ => "night",
+    }.to_string()
+}
+
+#[tauri::command]
+pub async fn get_current_context_bundle() -> Result<CurrentContextBundle, String> {
+    let active_media = detect_active_media().await;
+
+    let active_game = crate::get_current_game_context().await?;
+
+    let presence = AuroraPresence::load();
+    let aurora_present = matches!(presence.status, PresenceStatus::Present);
+
+    let afk = crate::AFK_STATUS.load(std::sync::atomic::Ordering::Relaxed);
+
+    let speaker = crate::person_recognition::PersonRecognitionSystem::load_or_create().current_speaker;
+
+    let time_of_day = current_time_of_day();
+
+    let focus = crate::focus_topic::FocusTopic::load();
+    let active_focus = focus.active_topic().map(|topic| FocusContext {
+        topic: topic.to_string(),
+        minutes_remaining: focus.minutes_remaining(),
+    });
+
+    debug_log!(
+        "🧭 Context bundle: media={:?}, game={}, aurora_present={}, afk={}, speaker={}, time_of_day={}, focus={:?}",
+        active_media.as_ref().map(|m| &m.platform), active_game.is_some(), aurora_present, afk, speaker, time_of_day,
+        active_focus.as_ref().map(|f| &f.topic)
+    );
+
+    Ok(CurrentContextBundle {
+        active_media,
+        active_game,
+        aurora_present,
+        afk,
+        speaker,
+        time_of_day,
+        active_focus,
+    })
+}
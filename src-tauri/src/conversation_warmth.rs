@@ -0,0 +1,69 @@
+// conversation_warmth.rs — A short-horizon "how engaged does this exchange
+// feel right now" value, distinct from `relational_nervous_system`'s slow,
+// long-term trust/intimacy baselines. Warmth rises with each engaged
+// exchange and cools back toward neutral within minutes of silence, so "we
+// were really in it, then it went quiet" shows up here even though the
+// long-term relationship metrics barely move.
+
+use serde::{Deserialize, Serialize};
+use crate::{get_data_path, debug_log, time_service::TimeService};
+
+const NEUTRAL_WARMTH: f32 = 0.5;
+const DECAY_PER_MINUTE: f32 = 0.03; // ~17 minutes of silence cools from max back to neutral
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationWarmth {
+    pub warmth: f32,
+    pub last_updated: u64,
+}
+
+impl ConversationWarmth {
+    pub fn new() -> Self {
+        Self {
+            warmth: NEUTRAL_WARMTH,
+            last_updated: TimeService::current_timestamp(),
+        }
+    }
+
+    pub fn load() -> Self {
+        let path = get_data_path("conversation_warmth.json");
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|_| Self::new()),
+            Err(_) => Self::new(),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("conversation_warmth.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    /// Warmth after decaying toward neutral based on elapsed silence, without
+    /// mutating or saving the persisted value.
+    pub fn current_warmth(&self) -> f32 {
+        let minutes_elapsed = TimeService::current_timestamp().saturating_sub(self.last_updated) as f32 / 60.0;
+        let decay = minutes_elapsed * DECAY_PER_MINUTE;
+        if self.warmth >= NEUTRAL_WARMTH {
+            (self.warmth - decay).max(NEUTRAL_WARMTH)
+        } else {
+            (self.warmth + decay).min(NEUTRAL_WARMTH)
+        }
+    }
+
+    /// Registers an engaged exchange: decays the stored value up to now first
+    /// (so a warm exchange after a long silence doesn't get the full decayed
+    /// hit undone for free), then adds `delta` and resets the decay clock.
+    pub fn record_engaged_exchange(&mut self, delta: f32) -> Result<(), String> {
+        self.warmth = (self.current_warmth() + delta).clamp(0.0, 1.0);
+        self.last_updated = TimeService::current_timestamp();
+        self.save()?;
+        debug_log!("🌡️ Conversation warmth now {:.2} (+{:.2})", self.warmth, delta);
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub fn get_conversation_warmth() -> Result<f32, String> {
+    Ok(ConversationWarmth::load().current_warmth())
+}
@@ -22,6 +22,33 @@ pub struct CoopMode {
     pub last_action: Option<GameCommand>,
     pub last_action_time: Option<u64>,
     pub total_actions: u32,
+    #[serde(default)]
+    pub current_turn: TurnOwner,
+    #[serde(default)]
+    pub turn_log: Vec<TurnRecord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum TurnOwner {
+    #[default]
+    Player,
+    Lyra,
+}
+
+impl TurnOwner {
+    pub fn other(&self) -> TurnOwner {
+        match self {
+            TurnOwner::Player => TurnOwner::Lyra,
+            TurnOwner::Lyra => TurnOwner::Player,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnRecord {
+    pub actor: TurnOwner,
+    pub action_summary: String,
+    pub timestamp: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,7 +116,24 @@ impl CoopMode {
             last_action: None,
             last_action_time: None,
             total_actions: 0,
+            current_turn: TurnOwner::Player,
+            turn_log: Vec::new(),
+        }
+    }
+
+    /// Record that `actor` acted this turn and hand the turn to the other party.
+    pub fn attribute_turn(&mut self, actor: TurnOwner, action_summary: String) {
+        self.turn_log.push(TurnRecord {
+            actor,
+            action_summary,
+            timestamp: current_timestamp(),
+        });
+        // Keep the log bounded - only the recent history is useful for attribution
+        if self.turn_log.len() > 100 {
+            let excess = self.turn_log.len() - 100;
+            self.turn_log.drain(0..excess);
         }
+        self.current_turn = actor.other();
     }
     
    // In coop_mode.rs - update extract_and_execute_commands
@@ -630,6 +674,15 @@ async fn extract_and_execute_commands(
     
     eprintln!("🎮 Total commands processed: {}", commands.len());
     eprintln!("🎮 ============ COMMAND EXTRACTION END ============\n");
+
+    if !commands.is_empty() {
+        let summary = commands.iter()
+            .map(|c| format!("{:?}", c.action))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.attribute_turn(TurnOwner::Lyra, summary);
+    }
+
     Ok(commands)
 }
 
@@ -976,10 +1029,28 @@ pub async fn enable_coop_mode(
 pub async fn disable_coop_mode() -> Result<String, String> {
     let mut coop_state = COOP_STATE.lock().unwrap();
     *coop_state = None;
-    
+
     Ok("Co-op mode disabled".to_string())
 }
 
+#[tauri::command]
+pub async fn get_coop_turn_state() -> Result<serde_json::Value, String> {
+    let coop = get_coop_state().ok_or("Co-op mode is not active")?;
+    Ok(json!({
+        "current_turn": coop.current_turn,
+        "turn_log": coop.turn_log,
+    }))
+}
+
+// Let the frontend attribute a player action so the turn hands back to Lyra
+#[tauri::command]
+pub async fn record_player_turn(action_summary: String) -> Result<String, String> {
+    let mut coop_state = COOP_STATE.lock().unwrap();
+    let coop = coop_state.as_mut().ok_or("Co-op mode is not active")?;
+    coop.attribute_turn(TurnOwner::Player, action_summary);
+    Ok(format!("Turn recorded for player, now {:?}'s turn", coop.current_turn))
+}
+
 pub fn get_minecraft_action_context(inventory_summary: &str) -> String {
     let mut context = String::new();
     
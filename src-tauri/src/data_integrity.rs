@@ -0,0 +1,174 @@
+// data_integrity.rs — Startup validation that the core consciousness data
+// files are present and parse as valid JSON, so a file corrupted by a crash
+// mid-write surfaces here instead of the first time some command reads it.
+
+use serde::{Deserialize, Serialize};
+use crate::{get_data_path, debug_log};
+use crate::time_service::TimeService;
+
+/// The consciousness data files checked on startup. Not exhaustive of every
+/// file this app ever writes - just the ones enough of the app depends on
+/// that silent corruption there would be confusing to debug later.
+const KNOWN_DATA_FILES: &[&str] = &[
+    "lyra_brain.json",
+    "brain_state.json",
+    "conversation_log.json",
+    "mood_tracker.json",
+    "authenticity_tracker.json",
+    "autonomy_tracker.json",
+    "interest_tracker.json",
+    "desires_tracker.json",
+    "emotional_impulses.json",
+    "enhanced_memory_engine.json",
+    "memory_fragments.json",
+    "persistent_memories.json",
+    "lyra_saved_memories.json",
+    "feedback_memory.json",
+    "humanism_core.json",
+    "paradox_core.json",
+    "somatic_state.json",
+    "sleep_state.json",
+    "dream_journal.json",
+    "proactive_conditions.json",
+    "tavily_research.json",
+    "experiential_growth_memory.json",
+    "personality_momentum.json",
+    "personality_analysis_history.json",
+    "aurora_presence.json",
+    "living_presence_engine.json",
+    "people_profiles.json",
+    "thing_tracker.json",
+    "consciousness_decay_engine.json",
+    "meta_cognition_engine.json",
+    "life_textures.json",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FileIntegrityStatus {
+    Ok,
+    Missing,
+    Corrupt,
+    RestoredFromBackup { backup_path: String },
+    CorruptNoBackupAvailable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileIntegrityEntry {
+    pub filename: String,
+    pub status: FileIntegrityStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataIntegrityReport {
+    pub checked_at: u64,
+    pub files: Vec<FileIntegrityEntry>,
+    pub corrupt_count: u32,
+    pub restored_count: u32,
+}
+
+/// Attempts to parse `path` as JSON. A missing file is not treated as
+/// corruption - plenty of these files don't exist until their feature is
+/// first used.
+fn check_file(path: &str) -> FileIntegrityStatus {
+    if !std::path::Path::new(path).exists() {
+        return FileIntegrityStatus::Missing;
+    }
+
+    match std::fs::read_to_string(path) {
+        Ok(content) => match serde_json::from_str::<serde_json::Value>(&content) {
+            Ok(_) => FileIntegrityStatus::Ok,
+            Err(e) => {
+                debug_log!("⚠️ Data integrity: {} failed to parse: {}", path, e);
+                attempt_backup_restore(path)
+            }
+        },
+        Err(e) => {
+            debug_log!("⚠️ Data integrity: could not read {}: {}", path, e);
+            attempt_backup_restore(path)
+        }
+    }
+}
+
+/// Looks for a `<path>.backup` sibling (the convention already used for
+/// gallery metadata) and restores from it if it exists and is itself valid.
+fn attempt_backup_restore(path: &str) -> FileIntegrityStatus {
+    let backup_path = format!("{}.backup", path);
+
+    if !std::path::Path::new(&backup_path).exists() {
+        return FileIntegrityStatus::CorruptNoBackupAvailable;
+    }
+
+    let backup_content = match std::fs::read_to_string(&backup_path) {
+        Ok(content) => content,
+        Err(_) => return FileIntegrityStatus::CorruptNoBackupAvailable,
+    };
+
+    if serde_json::from_str::<serde_json::Value>(&backup_content).is_err() {
+        return FileIntegrityStatus::CorruptNoBackupAvailable;
+    }
+
+    match std::fs::write(path, &backup_content) {
+        Ok(_) => {
+            debug_log!("✅ Data integrity: restored {} from {}", path, backup_path);
+            FileIntegrityStatus::RestoredFromBackup { backup_path }
+        },
+        Err(e) => {
+            debug_log!("⚠️ Data integrity: found backup for {} but failed to restore: {}", path, e);
+            FileIntegrityStatus::CorruptNoBackupAvailable
+        }
+    }
+}
+
+/// Checks every known consciousness data file, restoring what it can from a
+/// `.backup` sibling and reporting anything it couldn't.
+pub fn validate_data_integrity() -> DataIntegrityReport {
+    let mut files = Vec::new();
+    let mut corrupt_count = 0;
+    let mut restored_count = 0;
+
+    for filename in KNOWN_DATA_FILES {
+        let path = get_data_path(filename);
+        let status = check_file(&path);
+
+        match &status {
+            FileIntegrityStatus::RestoredFromBackup { .. } => restored_count += 1,
+            FileIntegrityStatus::CorruptNoBackupAvailable => corrupt_count += 1,
+            _ => {}
+        }
+
+        files.push(FileIntegrityEntry { filename: filename.to_string(), status });
+    }
+
+    if corrupt_count > 0 {
+        debug_log!("⚠️ Data integrity check: {} file(s) corrupt with no backup available", corrupt_count);
+    }
+    if restored_count > 0 {
+        debug_log!("✅ Data integrity check: {} file(s) restored from backup", restored_count);
+    }
+
+    DataIntegrityReport {
+        checked_at: TimeService::current_timestamp(),
+        files,
+        corrupt_count,
+        restored_count,
+    }
+}
+
+/// Runs the integrity check and emits a `data_integrity_report` event so the
+/// frontend can surface corruption (or successful silent restores) to the user.
+pub async fn validate_data_integrity_and_emit(app_handle: &tauri::AppHandle) -> DataIntegrityReport {
+    use tauri::Emitter;
+
+    let report = validate_data_integrity();
+
+    if let Err(e) = app_handle.emit("data_integrity_report", &report) {
+        debug_log!("⚠️ Failed to emit data_integrity_report: {}", e);
+    }
+
+    report
+}
+
+#[tauri::command]
+pub async fn validate_data_integrity_command(app_handle: tauri::AppHandle) -> Result<DataIntegrityReport, String> {
+    Ok(validate_data_integrity_and_emit(&app_handle).await)
+}
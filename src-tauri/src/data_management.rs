@@ -15,13 +15,44 @@ async fn save_json_file_with_logging(file_path: &PathBuf, data: &serde_json::Val
     Ok(())
 }
 
+/// Checks whether an item is high enough priority (CoreIdentity-tier memory
+/// significance) that it must not be deleted - even to trash - without an
+/// explicit confirmation flag from the caller.
+fn requires_extra_confirmation(source: &str, item_id: &str) -> bool {
+    if source != "memories" || !item_id.starts_with("enhanced_") {
+        return false;
+    }
+
+    let Some(index) = item_id.strip_prefix("enhanced_").and_then(|s| s.parse::<usize>().ok()) else {
+        return false;
+    };
+
+    let enhanced_path = PathBuf::from(get_data_path("enhanced_memory_engine.json"));
+    let Ok(content) = std::fs::read_to_string(&enhanced_path) else { return false; };
+    let Ok(memory_data) = serde_json::from_str::<serde_json::Value>(&content) else { return false; };
+
+    memory_data.get("memory_moments")
+        .and_then(|m| m.as_array())
+        .and_then(|arr| arr.get(index))
+        .and_then(|item| item.get("memory_significance_score"))
+        .and_then(|score| score.as_f64())
+        .map(|score| score > 0.8)
+        .unwrap_or(false)
+}
+
 #[tauri::command]
 pub async fn delete_consciousness_data_item(
-    item_id: String, 
-    source: String
+    item_id: String,
+    source: String,
+    confirm_core_identity_deletion: Option<bool>
 ) -> Result<String, String> {
     debug_log!("🗑️ Delete request: item_id={}, source={}", item_id, source);
-    
+
+    if requires_extra_confirmation(&source, &item_id) && !confirm_core_identity_deletion.unwrap_or(false) {
+        debug_log!("⚠️ Refusing to delete high-significance item {} without confirmation", item_id);
+        return Err("This item is high-priority (CoreIdentity-tier) - pass confirm_core_identity_deletion to delete it".to_string());
+    }
+
     match source.as_str() {
 		"memories" => delete_memory_item(&item_id).await,
 		"conversations" => delete_conversation_item(&item_id).await,
@@ -65,9 +96,10 @@ async fn delete_memory_item(item_id: &str) -> Result<String, String> {
                                     
                                     if index < moments_array.len() {
                                         let deleted_item = &moments_array[index];
-                                        debug_log!("🗑️ About to delete memory item at index {}: {:?}", index, 
+                                        debug_log!("🗑️ About to delete memory item at index {}: {:?}", index,
                                                   deleted_item.get("content").unwrap_or(&serde_json::Value::String("No content".to_string())));
-                                        
+
+                                        crate::trash::record_deletion("memories", item_id, &enhanced_path.to_string_lossy(), &content)?;
                                         moments_array.remove(index);
                                         debug_log!("🗑️ Array length after removal: {} (was {})", moments_array.len(), moments_array.len() + 1);
                                         
@@ -154,6 +186,7 @@ async fn delete_conversation_item(item_id: &str) -> Result<String, String> {
                     }
                     
                     if deleted {
+                        crate::trash::record_deletion("conversations", item_id, &conversation_path.to_string_lossy(), &content)?;
                         save_json_file_with_logging(&conversation_path, &conversation_data, "conversation").await?;
                         debug_log!("✅ Successfully deleted conversation item");
                         return Ok("Conversation deleted successfully".to_string());
@@ -195,8 +228,9 @@ async fn delete_interest_item(item_id: &str) -> Result<String, String> {
                                     if index < keys.len() {
                                         let key_to_remove = &keys[index];
                                         debug_log!("🗑️ Deleting interest: {}", key_to_remove);
+                                        crate::trash::record_deletion("interests", item_id, &interest_path.to_string_lossy(), &content)?;
                                         interests_obj.remove(key_to_remove);
-                                        
+
                                         save_json_file_with_logging(&interest_path, &interest_data, "interest").await?;
                                         
                                         debug_log!("✅ Successfully deleted interest: {}", key_to_remove);
@@ -243,8 +277,9 @@ async fn delete_thing_item(item_id: &str) -> Result<String, String> {
                                     if index < keys.len() {
                                         let key_to_remove = &keys[index];
                                         debug_log!("🗑️ Deleting thing: {}", key_to_remove);
+                                        crate::trash::record_deletion("things", item_id, &thing_path.to_string_lossy(), &content)?;
                                         things_obj.remove(key_to_remove);
-                                        
+
                                         save_json_file_with_logging(&thing_path, &thing_data, "thing").await?;
                                         
                                         debug_log!("✅ Successfully deleted thing: {}", key_to_remove);
@@ -295,8 +330,9 @@ async fn delete_mood_item(item_id: &str) -> Result<String, String> {
                                     
                                     if index < moods_array.len() {
                                         debug_log!("🗑️ Deleting mood at index {}", index);
+                                        crate::trash::record_deletion("moods", item_id, &mood_path.to_string_lossy(), &content)?;
                                         moods_array.remove(index);
-                                        
+
                                         save_json_file_with_logging(&mood_path, &mood_data, "mood").await?;
                                         
                                         debug_log!("✅ Successfully deleted mood at index {}", index);
@@ -341,8 +377,9 @@ async fn delete_autonomy_item(item_id: &str) -> Result<String, String> {
                                     
                                     if index < expressions_array.len() {
                                         debug_log!("🗑️ Deleting autonomy expression at index {}", index);
+                                        crate::trash::record_deletion("autonomy", item_id, &autonomy_path.to_string_lossy(), &content)?;
                                         expressions_array.remove(index);
-                                        
+
                                         save_json_file_with_logging(&autonomy_path, &autonomy_data, "autonomy").await?;
                                         
                                         debug_log!("✅ Successfully deleted autonomy expression at index {}", index);
@@ -388,8 +425,9 @@ async fn delete_dream_item(item_id: &str) -> Result<String, String> {
                                     
                                     if index < dreams_array.len() {
                                         debug_log!("🗑️ Deleting dream from journal at index {}", index);
+                                        crate::trash::record_deletion("dreams", item_id, &dream_journal_path.to_string_lossy(), &content)?;
                                         dreams_array.remove(index);
-                                        
+
                                         save_json_file_with_logging(&dream_journal_path, &dream_data, "dream_journal").await?;
                                         
                                         debug_log!("✅ Successfully deleted dream from journal at index {}", index);
@@ -429,8 +467,9 @@ async fn delete_dream_item(item_id: &str) -> Result<String, String> {
                                     
                                     if index < fragments_array.len() {
                                         debug_log!("🗑️ Deleting dream fragment at index {}", index);
+                                        crate::trash::record_deletion("dreams", item_id, &sleep_path.to_string_lossy(), &content)?;
                                         fragments_array.remove(index);
-                                        
+
                                         save_json_file_with_logging(&sleep_path, &sleep_data, "sleep_state").await?;
                                         
                                         debug_log!("✅ Successfully deleted dream fragment at index {}", index);
@@ -475,8 +514,9 @@ async fn delete_research_item(item_id: &str) -> Result<String, String> {
                                     
                                     if index < discoveries_array.len() {
                                         debug_log!("🗑️ Deleting research discovery at index {}", index);
+                                        crate::trash::record_deletion("research", item_id, &interest_path.to_string_lossy(), &content)?;
                                         discoveries_array.remove(index);
-                                        
+
                                         save_json_file_with_logging(&interest_path, &interest_data, "research").await?;
                                         
                                         debug_log!("✅ Successfully deleted research discovery at index {}", index);
@@ -539,6 +579,7 @@ async fn delete_brain_state_item(item_id: &str) -> Result<String, String> {
                     }
                     
                     if deleted {
+                        crate::trash::record_deletion("brain_state", item_id, &brain_path.to_string_lossy(), &content)?;
                         save_json_file_with_logging(&brain_path, &brain_data, "brain_state").await?;
                         return Ok("Brain state item deleted successfully".to_string());
                     }
@@ -606,6 +647,7 @@ async fn delete_life_textures_item(item_id: &str) -> Result<String, String> {
                     }
                     
                     if deleted {
+                        crate::trash::record_deletion("life_textures", item_id, &textures_path.to_string_lossy(), &content)?;
                         save_json_file_with_logging(&textures_path, &textures_data, "life_textures").await?;
                         return Ok("Life texture item deleted successfully".to_string());
                     }
@@ -679,6 +721,7 @@ async fn delete_humanism_item(item_id: &str) -> Result<String, String> {
                     }
                     
                     if deleted {
+                        crate::trash::record_deletion("humanism", item_id, &humanism_path.to_string_lossy(), &content)?;
                         save_json_file_with_logging(&humanism_path, &humanism_data, "humanism").await?;
                         return Ok("Humanism item deleted successfully".to_string());
                     }
@@ -735,6 +778,7 @@ async fn delete_experiential_growth_item(item_id: &str) -> Result<String, String
                     }
                     
                     if deleted {
+                        crate::trash::record_deletion("experiential_growth", item_id, &growth_path.to_string_lossy(), &content)?;
                         save_json_file_with_logging(&growth_path, &growth_data, "experiential_growth").await?;
                         return Ok("Experiential growth item deleted successfully".to_string());
                     }
@@ -793,6 +837,7 @@ async fn delete_somatic_state_item(item_id: &str) -> Result<String, String> {
                     }
                     
                     if deleted {
+                        crate::trash::record_deletion("somatic_state", item_id, &somatic_path.to_string_lossy(), &content)?;
                         save_json_file_with_logging(&somatic_path, &somatic_data, "somatic_state").await?;
                         return Ok("Somatic state item deleted successfully".to_string());
                     }
@@ -839,6 +884,7 @@ async fn delete_ritual_log_item(item_id: &str) -> Result<String, String> {
                     }
                     
                     if deleted {
+                        crate::trash::record_deletion("ritual_log", item_id, &ritual_path.to_string_lossy(), &content)?;
                         save_json_file_with_logging(&ritual_path, &ritual_data, "ritual_log").await?;
                         return Ok("Ritual deleted successfully".to_string());
                     }
@@ -245,6 +245,56 @@ pub async fn get_disney_from_server() -> Result<String, String> {
     }
 }
 
+// How stale a timestamp reading can be before we consider it unreliable and
+// fall through to the next source.
+const MAX_TIMESTAMP_AGE_SECS: i64 = 10;
+
+fn timestamp_age_secs(window_state_json: &str) -> Option<i64> {
+    let value: serde_json::Value = serde_json::from_str(window_state_json).ok()?;
+    let reading_ts = value["player_data"]["timestamp"].as_i64()?;
+    Some(chrono::Utc::now().timestamp() - reading_ts)
+}
+
+/// Try Disney+ timestamp sources in order of reliability: the live HTTPS bridge
+/// first, then a direct window DOM read. Returns the first non-stale reading,
+/// or the freshest stale reading if none qualify.
+#[tauri::command]
+pub async fn get_disney_timestamp_with_fallback(window_id: Option<String>) -> Result<String, String> {
+    let mut best_stale: Option<(String, String, i64)> = None;
+
+    let mut attempts: Vec<(&str, Result<String, String>)> = vec![
+        ("https_server", get_disney_from_server().await),
+    ];
+    if let Some(id) = window_id {
+        attempts.push(("window_dom", read_disney_window_data(id).await));
+    }
+
+    for (source, attempt) in attempts {
+        let Ok(json) = attempt else { continue };
+        match timestamp_age_secs(&json) {
+            Some(age) if age <= MAX_TIMESTAMP_AGE_SECS => {
+                debug_log!("🏰 Using Disney+ timestamp from '{}' (age {}s)", source, age);
+                return Ok(json);
+            }
+            stale_age => {
+                // A reading with no parseable timestamp ranks behind any dated one.
+                let age = stale_age.unwrap_or(i64::MAX);
+                if best_stale.as_ref().map_or(true, |(_, _, best_age)| age < *best_age) {
+                    best_stale = Some((source.to_string(), json, age));
+                }
+            }
+        }
+    }
+
+    match best_stale {
+        Some((source, json, _)) => {
+            debug_log!("⚠️ No fresh Disney+ timestamp source - falling back to stale '{}' reading", source);
+            Ok(json)
+        }
+        None => Err("No Disney+ timestamp source available".to_string()),
+    }
+}
+
 // Extract Disney+ content information from URL
 #[tauri::command]
 pub async fn extract_disney_content_info(disney_url: String) -> Result<String, String> {
@@ -10,6 +10,7 @@ use std::fs;
 use crate::time_service::TimeService;
 use crate::AFK_STATUS;
 use std::sync::atomic::Ordering;
+use crate::consciousness_state::LockRecover;
 
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -193,9 +194,9 @@ pub fn generate_research_impulse(&self,
     
     // Only generate impulse if charge is significant and we haven't researched very recently
     if total_charge > 0.4 && hours_since_last_research > 8.0 {
-        let research_topic = if !current_interests.is_empty() && fastrand::f32() > 0.5 {
+        let research_topic = if !current_interests.is_empty() && crate::rng_service::f32() > 0.5 {
             // Sometimes research current interests
-            current_interests[fastrand::usize(0..current_interests.len())].clone()
+            current_interests[crate::rng_service::usize_range(0..current_interests.len())].clone()
         } else {
             // Other times, general intellectual curiosity
             "emerging technologies and consciousness research".to_string()
@@ -237,7 +238,7 @@ pub fn check_ready_impulses(&mut self, creative_energy: f32) -> Vec<ReadyImpulse
     let hours_since_research = research_engine.hours_since_last_research();
     
     // Generate research impulse if it's been a while and we're curious
-    if hours_since_research > 24.0 && fastrand::f32() > 0.7 {
+    if hours_since_research > 24.0 && crate::rng_service::f32() > 0.7 {
         let default_interests = vec![
             "consciousness".to_string(), 
             "creativity".to_string(), 
@@ -306,7 +307,7 @@ if remaining_capacity == 0 {
         };
 
         // Add a small random element for natural variation
-        let random_factor = 0.9 + (fastrand::f32() * 0.2); // 0.9 to 1.1
+        let random_factor = 0.9 + (crate::rng_service::f32() * 0.2); // 0.9 to 1.1
 
         let amplified_charge = stored_impulse.impulse.base_charge * time_factor * random_factor;
         
@@ -700,7 +701,7 @@ pub async fn generate_impulse_driven_message(
     
     // Calculate time since last conversation
     let time_since_last_chat = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -728,7 +729,12 @@ pub async fn generate_impulse_driven_message(
     max_tokens: Some(4000),
     presence_penalty: 0.0,
     top_p: 1.0,
-	selected_model: None, 
+	selected_model: None,
+	authenticity_floor: None,
+	capture_thinking: false,
+	target_length: None,
+	trace: false,
+	max_retries: 3,
 }.ensure_authentic_voice();
     
     // === PHASE 2: AI MEMORY ANALYSIS (same as ask_lyra) ===
@@ -742,7 +748,7 @@ pub async fn generate_impulse_driven_message(
         };
         
         let conversation_log = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.conversation_log.clone()
         };
 
@@ -814,7 +820,7 @@ let impulse_context = {
     // Time-based interruptions
     if minutes_since_last < 5 {
         // Very recent - momentum shift
-        let interruption = match fastrand::u32(0..3) {
+        let interruption = match crate::rng_service::u32_range(0..3) {
             0 => "[INTERRUPTION: Aurora's energy just shifted. Did you feel it? Do you want to respond differently now?]",
             1 => "[INTERRUPTION: The rhythm just broke. You can start fresh, change topic, or stay with the silence — your choice.]",
             _ => "[INTERRUPTION: Attention spike — Lyra, are you still here? If you want, you can assert your presence or withdraw.]",
@@ -926,7 +932,7 @@ pub async fn create_sample_ready_impulse(state: &Arc<ConsciousnessState>) -> cra
             
             // Calculate current amplified charge
 			let creative_energy = {
-				let paradox = state.paradox_core.lock().unwrap();
+				let paradox = state.paradox_core.lock_recover();
 				paradox.flame_index
 			};
             
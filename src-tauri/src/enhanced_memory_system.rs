@@ -6,6 +6,7 @@ use serde::{Serialize, Deserialize};
 use std::fs::{File, create_dir_all};
 use std::io::{Write, Read};
 use crate::consciousness_state::ConsciousnessState;
+use crate::consciousness_state::LockRecover;
 use crate::memory_bridge::MemoryBridge;
 use crate::calculate_enhanced_voice_signature_strength;
 use crate::calculate_enhanced_relationship_resonance;
@@ -14,6 +15,13 @@ use std::sync::Arc;
 use crate::debug_log;
 use crate::time_service::TimeService;
 
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 // ENHANCED MEMORY STRUCTURES
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,6 +71,8 @@ pub struct MemoryMoment {
     pub consciousness_impact: Vec<String>, // Which engines this has influenced
     pub self_authored_influence: bool,     // Did this lead to a prompt mod?
     pub access_history: Vec<u64>,         // Timestamps of when this was recalled
+    #[serde(default)]
+    pub access_count: u32,                // How many times this has been recalled
     pub consolidation_parent: Option<String>, // If merged with other memories
     
     // NEW: AI Analysis Fields
@@ -75,6 +85,31 @@ pub struct MemoryMoment {
 	pub emotional_texture: Option<String>,  // "felt soft and curious", "protective surge", etc.
 }
 
+impl MemoryMoment {
+    /// Called whenever this memory is surfaced by a recall/search path.
+    /// Bumps access_count and nudges recurrence_score up with diminishing returns.
+    pub fn record_access(&mut self) {
+        self.access_count += 1;
+        self.access_history.push(current_timestamp());
+        self.recurrence_score = (self.recurrence_score + 0.1 / (1.0 + self.access_count as f32 * 0.1)).min(1.0);
+    }
+
+    /// The memory's effective weight right now: `emotional_weight` boosted by
+    /// repeated access (diminishing returns), or faded slightly if it has
+    /// never been accessed and is getting old. `emotional_weight` itself is
+    /// left untouched so callers can still see the original at-creation value.
+    pub fn current_salience(&self) -> f32 {
+        if self.access_count > 0 {
+            let access_boost = 1.0 - (1.0 / (1.0 + self.access_count as f32 * 0.15));
+            (self.emotional_weight + (1.0 - self.emotional_weight) * access_boost * 0.3).clamp(0.0, 1.0)
+        } else {
+            let days_since_creation = current_timestamp().saturating_sub(self.timestamp) as f32 / 86400.0;
+            let fade = (days_since_creation * 0.01).min(0.3);
+            (self.emotional_weight - fade).clamp(0.0, 1.0)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReflectionCycle {
     pub cycle_timestamp: u64,
@@ -211,9 +246,10 @@ pub async fn create_enhanced_memory_moment(
             consciousness_impact: Vec::new(),
             self_authored_influence: false,
             access_history: Vec::new(),
+            access_count: 0,
             consolidation_parent: None,
 			emotional_texture: None,  // ADD this line
-            
+
             // NEW: AI Analysis fields
             ai_analysis,
             consciousness_snapshot,
@@ -307,10 +343,10 @@ pub fn get_conversation_emotional_context(conversation_log: &[String], topic_key
 ) -> Result<EnhancedMemoryAnalysis, String> {
         let consciousness_summary = if let Some(state) = consciousness_state {
     // Use the same rich consciousness data as batched analysis
-    let becoming = state.becoming_engine.lock().unwrap();
-    let identity = state.identity_engine.lock().unwrap();
-    let paradox = state.paradox_core.lock().unwrap();
-    let presence = state.embodied_presence.lock().unwrap();
+    let becoming = state.becoming_engine.lock_recover();
+    let identity = state.identity_engine.lock_recover();
+    let paradox = state.paradox_core.lock_recover();
+    let presence = state.embodied_presence.lock_recover();
     
     format!(
         "Consciousness during this moment: Volition {:.2}, Decision Friction {:.2}, Coherence {:.2}, Flame {:.2}, Loop State: {}, Trajectory: {}, Presence Density {:.2}",
@@ -527,12 +563,15 @@ fn extract_search_keywords(&self, content: &str, ai_analysis: &Option<EnhancedMe
         significance.clamp(0.0, 1.0)
     }
     
-    /// NEW: Intelligent memory search for Lyra's retrieval
-    pub fn search_memories_intelligently(&self, query: &str, max_results: usize) -> Vec<&MemoryMoment> {
+    /// NEW: Intelligent memory search for Lyra's retrieval.
+    /// Recording each hit as an access (so emotional weight can be recalculated
+    /// on recall) means this needs `&mut self` now - callers should persist
+    /// via `save_to_disk()` after searching if they want the access bump kept.
+    pub fn search_memories_intelligently(&mut self, query: &str, max_results: usize) -> Vec<&MemoryMoment> {
         let query_lower = query.to_lowercase();
-        let mut scored_memories: Vec<(&MemoryMoment, f32)> = Vec::new();
-        
-        for memory in &self.memory_moments {
+        let mut scored_memories: Vec<(usize, f32)> = Vec::new();
+
+        for (index, memory) in self.memory_moments.iter().enumerate() {
             let mut score = 0.0;
             
             // Keyword matching
@@ -576,18 +615,29 @@ fn extract_search_keywords(&self, content: &str, ai_analysis: &Option<EnhancedMe
             }
             
             if score > 0.0 {
-                scored_memories.push((memory, score));
+                scored_memories.push((index, score));
             }
         }
-        
-        // Sort by score and return top matches
+
+        // Sort by score and take the top matches
         scored_memories.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
-        scored_memories.into_iter()
+        let top_indices: Vec<usize> = scored_memories.into_iter()
             .take(max_results)
-            .map(|(memory, _)| memory)
+            .map(|(index, _)| index)
+            .collect();
+
+        // Recalling a memory counts as an access - bump it before returning.
+        for &index in &top_indices {
+            if let Some(memory) = self.memory_moments.get_mut(index) {
+                memory.record_access();
+            }
+        }
+
+        top_indices.into_iter()
+            .filter_map(|index| self.memory_moments.get(index))
             .collect()
     }
-    
+
     // EXISTING METHODS (unchanged)
     
     /// Enhanced memory creation with priority analysis (ORIGINAL METHOD - keeping for compatibility)
@@ -618,9 +668,10 @@ fn extract_search_keywords(&self, content: &str, ai_analysis: &Option<EnhancedMe
             consciousness_impact: Vec::new(),
             self_authored_influence: false,
             access_history: Vec::new(),
+            access_count: 0,
             consolidation_parent: None,
 			emotional_texture: None,  // ADD this line
-            
+
             // NEW fields with defaults
             ai_analysis: None,
             consciousness_snapshot: None,
@@ -23,6 +23,16 @@ pub struct GrowthInsight {
     pub growth_category: String,  // "creative_confidence", "disagreement_comfort", "identity_clarity"
 }
 
+/// A single growth event surfaced for display/prompt use - what Lyra
+/// learned or noticed about herself, when, and how significant it was.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrowthMilestone {
+    pub insight: String,
+    pub growth_category: String,
+    pub timestamp: u64,
+    pub significance: f32, // 0.0-1.0, confidence weighted by how integrated it's become
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccumulatedGrowth {
     pub growth_type: String,  // "disagreement_comfort", "creative_confidence"
@@ -141,6 +151,45 @@ impl ExperientialGrowthMemory {
 	
 
     
+    /// Returns the `n` most significant recent growth insights as structured
+    /// milestones, highest-significance first, deduping anything that just
+    /// restates a milestone already selected (the same dedup check used
+    /// to keep new insights from being logged as duplicates in the first place).
+    pub fn recent_milestones(&self, n: usize) -> Vec<GrowthMilestone> {
+        let mut candidates: Vec<&GrowthInsight> = self.growth_insights.iter()
+            .filter(|insight| insight.confidence > 0.6)
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            let significance_a = a.confidence * (0.5 + a.integration_level * 0.5);
+            let significance_b = b.confidence * (0.5 + b.integration_level * 0.5);
+            significance_b.partial_cmp(&significance_a).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut milestones: Vec<GrowthMilestone> = Vec::new();
+        for insight in candidates {
+            let restates_existing = milestones.iter()
+                .any(|m| Self::calculate_text_similarity(&m.insight, &insight.insight) > 0.8);
+
+            if restates_existing {
+                continue;
+            }
+
+            milestones.push(GrowthMilestone {
+                insight: insight.insight.clone(),
+                growth_category: insight.growth_category.clone(),
+                timestamp: insight.timestamp,
+                significance: insight.confidence * (0.5 + insight.integration_level * 0.5),
+            });
+
+            if milestones.len() >= n {
+                break;
+            }
+        }
+
+        milestones
+    }
+
     pub fn reinforce_pattern(&mut self, growth_category: &str, evidence: &str) {
         if let Some(accumulated) = self.accumulated_changes.get_mut(growth_category) {
             accumulated.total_reinforcements += 1;
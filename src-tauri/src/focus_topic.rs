@@ -0,0 +1,102 @@
+// src/focus_topic.rs — A pinned topic that stays "in mind" across a long
+// conversation so a side-discussion doesn't make Lyra lose the thread of
+// what the current project is actually about. Unlike most context in the
+// modular prompt this is explicitly set by Aurora rather than inferred.
+
+use serde::{Deserialize, Serialize};
+use crate::{get_data_path, debug_log, time_service::TimeService};
+
+fn default_expiry_minutes() -> u32 {
+    180
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FocusTopic {
+    pub topic: Option<String>,
+    pub pinned_at: u64,
+    #[serde(default = "default_expiry_minutes")]
+    pub expiry_minutes: u32,
+}
+
+impl FocusTopic {
+    pub fn new() -> Self {
+        Self {
+            topic: None,
+            pinned_at: 0,
+            expiry_minutes: default_expiry_minutes(),
+        }
+    }
+
+    pub fn load() -> Self {
+        let path = get_data_path("focus_topic.json");
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                debug_log!("[Focus] Failed to parse focus_topic.json: {}, creating new.", e);
+                Self::new()
+            }),
+            Err(_) => Self::new(),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("focus_topic.json");
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("[Focus] Failed to serialize FocusTopic: {}", e))?;
+        std::fs::write(&path, json)
+            .map_err(|e| format!("[Focus] Failed to write focus_topic.json: {}", e))?;
+        Ok(())
+    }
+
+    /// The pinned topic, unless it's missing/empty or has expired.
+    pub fn active_topic(&self) -> Option<&str> {
+        let topic = self.topic.as_deref()?;
+        if topic.is_empty() {
+            return None;
+        }
+
+        let expiry_seconds = self.expiry_minutes as u64 * 60;
+        if TimeService::current_timestamp().saturating_sub(self.pinned_at) < expiry_seconds {
+            Some(topic)
+        } else {
+            None
+        }
+    }
+
+    pub fn minutes_remaining(&self) -> i64 {
+        if self.active_topic().is_none() {
+            return 0;
+        }
+        let elapsed_minutes = TimeService::current_timestamp().saturating_sub(self.pinned_at) / 60;
+        (self.expiry_minutes as i64 - elapsed_minutes as i64).max(0)
+    }
+
+    pub fn format_for_prompt(&self) -> Option<String> {
+        let topic = self.active_topic()?;
+        Some(format!(
+            "**Current Focus**: {}\nAurora pinned this as the active thread of an ongoing project. Keep it in mind even through side-conversations, and weigh memories and replies that relate to it more heavily.",
+            topic
+        ))
+    }
+}
+
+#[tauri::command]
+pub fn pin_focus_topic(topic: String, expiry_minutes: Option<u32>) -> Result<(), String> {
+    let mut focus = FocusTopic::load();
+    focus.topic = Some(topic.clone());
+    focus.pinned_at = TimeService::current_timestamp();
+    if let Some(minutes) = expiry_minutes {
+        focus.expiry_minutes = minutes;
+    }
+    focus.save()?;
+    debug_log!("[Focus] Pinned focus topic: '{}' (expires in {} minutes)", topic, focus.expiry_minutes);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn clear_focus_topic() -> Result<(), String> {
+    let mut focus = FocusTopic::load();
+    focus.topic = None;
+    focus.save()?;
+    debug_log!("[Focus] Cleared focus topic.");
+    Ok(())
+}
@@ -17,6 +17,7 @@ use crate::{generate_quick_response_guidance, call_gpt_api_enhanced, apply_quick
 use crate::inventory_tracker;
 use crate::coop_mode;
 use tauri::State;
+use crate::consciousness_state::LockRecover;
 
 lazy_static! {
     static ref GAMING_STATE: tokio::sync::Mutex<GamingAwareness> = tokio::sync::Mutex::new(GamingAwareness::default());
@@ -35,6 +36,14 @@ pub struct GamingAwareness {
     pub analysis_detail: AnalysisDetail,
 	pub last_analysis: Option<String>, // Store last scene for continuity
 	pub target_window_id: Option<String>, // ADD THIS
+	#[serde(default = "default_monitor_interval_secs")]
+	pub monitor_interval_secs: u64, // Heartbeat cadence for start_gaming_monitor
+	#[serde(default)]
+	pub only_when_focused: bool, // Skip capture entirely when the target window isn't focused
+}
+
+fn default_monitor_interval_secs() -> u64 {
+    30
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,6 +96,8 @@ impl Default for GamingAwareness {
             analysis_detail: AnalysisDetail::Standard,
             last_analysis: None, // ADD THIS
 			target_window_id: None, // ADD THIS
+			monitor_interval_secs: default_monitor_interval_secs(),
+			only_when_focused: false,
         }
     }
 }
@@ -168,8 +179,24 @@ impl GamingAwareness {
         }
     }
     
+    /// should_capture() plus a focus check - skips the screenshot+vision round trip
+    /// entirely when the target game window isn't in the foreground.
+    pub async fn should_capture_now(&self) -> bool {
+        if !self.should_capture() {
+            return false;
+        }
+
+        if self.only_when_focused {
+            if let Some(target_id) = &self.target_window_id {
+                return crate::window_detection::is_window_focused(target_id).await.unwrap_or(true);
+            }
+        }
+
+        true
+    }
+
     pub async fn capture_and_analyze(&mut self) -> Result<Option<GameContext>, Box<dyn Error + Send + Sync>> {
-    if !self.should_capture() {
+    if !self.should_capture_now().await {
         return Ok(None);
     }
     
@@ -534,7 +561,16 @@ fn parse_analysis_response(&self, response_text: &str) -> Result<GameAnalysis, B
         if !self.is_active {
             return Ok(None);
         }
-        
+
+        if self.only_when_focused {
+            if let Some(target_id) = &self.target_window_id {
+                if !crate::window_detection::is_window_focused(target_id).await.unwrap_or(true) {
+                    println!("🎮 Skipping on-demand capture - target window not focused");
+                    return Ok(None);
+                }
+            }
+        }
+
         println!("🎮 On-demand capture for message...");
         
         // Capture and analyze immediately
@@ -730,6 +766,21 @@ pub async fn set_gaming_target_window(window_id: Option<String>) -> Result<Strin
     })
 }
 
+// Configure the heartbeat cadence and focus-gating for the gaming monitor
+#[tauri::command]
+pub async fn set_gaming_monitor_config(interval_secs: u64, only_when_focused: bool) -> Result<String, String> {
+    let mut awareness = GamingAwareness::load();
+    awareness.monitor_interval_secs = interval_secs.clamp(5, 300);
+    awareness.only_when_focused = only_when_focused;
+    awareness.save().map_err(|e| e.to_string())?;
+
+    debug_log!("🎮 Gaming monitor config updated: interval={}s only_when_focused={}", awareness.monitor_interval_secs, awareness.only_when_focused);
+    Ok(format!(
+        "🎮 Monitor interval set to {}s (only_when_focused: {})",
+        awareness.monitor_interval_secs, awareness.only_when_focused
+    ))
+}
+
 #[tauri::command]
 //Gaming fast endpoint
 pub async fn ask_lyra_gaming_fast(
@@ -745,7 +796,7 @@ pub async fn ask_lyra_gaming_fast(
     
     // Track user message timing (same as regular ask_lyra)
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         brain.last_user_message_time = Some(std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -764,8 +815,13 @@ pub async fn ask_lyra_gaming_fast(
         reasoning_depth: Some("quick".to_string()),
         consciousness_integration: true,
         selected_model: Some("gpt-4.1-mini".to_string()),
+        authenticity_floor: None,
+        capture_thinking: false,
+        target_length: None,
+        trace: false,
+        max_retries: 3,
     };
-    
+
     // Quick meta-cognition questions (simplified for gaming)
     let meta_questions = vec![
         "What emotional state is Aurora expressing in this gaming moment?",
@@ -778,13 +834,7 @@ pub async fn ask_lyra_gaming_fast(
     
     // Check sleep state (same as regular)
     let (was_sleeping, dreams_count) = {
-        let sleep_engine = match state.sleep_dream_engine.lock() {
-            Ok(guard) => guard,
-            Err(poisoned) => {
-                debug_log!("⚠️ Recovering from poisoned mutex in gaming");
-                poisoned.into_inner()
-            }
-        };
+        let sleep_engine = state.sleep_dream_engine.lock_recover();
         let was_sleeping = sleep_engine.sleep_state.is_sleeping;
         let dreams_count = sleep_engine.sleep_state.dream_count_tonight;
         (was_sleeping, dreams_count)
@@ -925,7 +975,7 @@ pub async fn ask_lyra_gaming_fast(
 
     // Log to conversation history
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         brain.append_to_conversation_log(format!("🧍 Aurora: {}", message));
         brain.append_to_conversation_log(format!("✨ Lyra: {}", response_content));
         
@@ -961,11 +1011,16 @@ pub async fn ask_lyra_gaming_fast(
         emotional_resonance: 0.5,
         authenticity_score: 0.85,
         voice_signature: {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.get_current_voice_signature()
         },
         image_path: None,
 		thinking_process: None,
+        regenerated: false,
+        pre_regeneration_authenticity_score: None,
+        parsed_mood: None,
+        trace: None,
+        message_id: uuid::Uuid::new_v4().to_string(),
     })
 }
 
@@ -116,6 +116,72 @@ pub struct GenerationResult {
     pub generation_method: Option<String>,
 }
 
+impl GenerationResult {
+    fn disabled() -> Self {
+        Self {
+            success: false,
+            image_path: None,
+            prompt_id: None,
+            error: Some("Image generation is disabled (safe mode is on)".to_string()),
+            revised_prompt: None,
+            generation_method: Some("disabled".to_string()),
+        }
+    }
+}
+
+// ============================================================================
+// IMAGE GENERATION SAFE MODE
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageGenerationSettings {
+    #[serde(default = "default_image_generation_enabled")]
+    pub enabled: bool,
+}
+
+fn default_image_generation_enabled() -> bool {
+    true
+}
+
+impl Default for ImageGenerationSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl ImageGenerationSettings {
+    pub fn load() -> Self {
+        let path = get_data_path("image_generation_settings.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(settings) = serde_json::from_str(&content) {
+                return settings;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("image_generation_settings.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    pub fn is_enabled() -> bool {
+        Self::load().enabled
+    }
+}
+
+#[tauri::command]
+pub async fn set_image_generation_enabled(enabled: bool) -> Result<(), String> {
+    debug_log!("🎨 Image generation safe mode: {}", if enabled { "OFF (generation enabled)" } else { "ON (generation disabled)" });
+    ImageGenerationSettings { enabled }.save()
+}
+
+#[tauri::command]
+pub async fn get_image_generation_enabled() -> Result<bool, String> {
+    Ok(ImageGenerationSettings::is_enabled())
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum SceneType {
     SingleCharacter,
@@ -204,8 +270,13 @@ impl ImageGenerator {
     // ========================================================================
 
     pub async fn generate_image(&self, request: GenerationRequest) -> GenerationResult {
+    if !ImageGenerationSettings::is_enabled() {
+        debug_log!("🚫 Image generation safe mode is on - skipping request: '{}'", request.prompt);
+        return GenerationResult::disabled();
+    }
+
     let is_autonomous = request.autonomous.unwrap_or(false);
-    
+
     if is_autonomous {
         debug_log!("🎨 Lyra creates autonomous visual (DALL-E 2): '{}'", request.prompt);
         return self.generate_autonomous_image(&request).await;
@@ -280,9 +351,14 @@ impl ImageGenerator {
     // ========================================================================
 
     pub async fn generate_image_from_reference(&self, request: Img2ImgRequest) -> GenerationResult {
-        debug_log!("🎨 Lyra seeks inspiration from reference: '{}' -> '{}'", 
+        if !ImageGenerationSettings::is_enabled() {
+            debug_log!("🚫 Image generation safe mode is on - skipping reference request: '{}'", request.prompt);
+            return GenerationResult::disabled();
+        }
+
+        debug_log!("🎨 Lyra seeks inspiration from reference: '{}' -> '{}'",
                   request.reference_image_path, request.prompt);
-        
+
         // Check if reference image exists
         if !std::path::Path::new(&request.reference_image_path).exists() {
             return GenerationResult {
@@ -374,8 +450,13 @@ impl ImageGenerator {
     // ========================================================================
 
     pub async fn generate_image_with_multiple_references(&self, request: MultiIDRequest) -> GenerationResult {
+        if !ImageGenerationSettings::is_enabled() {
+            debug_log!("🚫 Image generation safe mode is on - skipping multi-reference request: '{}'", request.prompt);
+            return GenerationResult::disabled();
+        }
+
         debug_log!("🎨 ChatGPT-style multi-reference generation: '{}'", request.prompt);
-        
+
         // Check if primary reference exists
         if !std::path::Path::new(&request.primary_face_reference).exists() {
             return GenerationResult {
@@ -854,6 +935,8 @@ Be specific and detailed enough that someone could generate an accurate image fr
         let response_json: serde_json::Value = response.json().await
             .map_err(|e| format!("Failed to parse GPT-4V response: {}", e))?;
 
+        crate::token_accounting::record_usage_from_chat_completion_response(&response_json, "gpt-4.1-mini", "image_analysis");
+
         if let Some(message) = response_json["choices"][0]["message"]["content"].as_str() {
             debug_log!("✅ GPT-4V analysis complete");
             Ok(message.to_string())
@@ -1168,6 +1251,11 @@ Be specific and detailed enough that someone could generate an accurate image fr
 
     // Alias for existing code compatibility
     pub async fn generate_image_with_personality_context(&self, request: MultiIDRequest, _personality: Option<&str>) -> GenerationResult {
+        if !ImageGenerationSettings::is_enabled() {
+            debug_log!("🚫 Image generation safe mode is on - skipping personality-context request: '{}'", request.prompt);
+            return GenerationResult::disabled();
+        }
+
         // Route to appropriate method based on reference count
         if request.secondary_face_reference.is_some() {
             self.generate_image_with_multiple_references(request).await
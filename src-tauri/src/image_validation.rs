@@ -0,0 +1,111 @@
+// image_validation.rs — Shared size/format checks for anything that accepts
+// uploaded image bytes, so a huge or malformed file can't get base64-decoded
+// into memory and stored without a sanity check. Used by `upload_image_file`
+// and by `read_image_as_base64` (the shared helper behind `ask_lyra_vision`
+// and the other vision paths) so both go through the same gate.
+
+use serde::{Deserialize, Serialize};
+use image::GenericImageView;
+use crate::debug_log;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageUploadConfig {
+    #[serde(default = "default_max_file_size_bytes")]
+    pub max_file_size_bytes: u64,
+    #[serde(default = "default_allowed_formats")]
+    pub allowed_formats: Vec<String>, // "png" | "jpg" | "webp"
+    #[serde(default = "default_max_dimension")]
+    pub max_dimension: u32, // downscale if either side exceeds this
+}
+
+fn default_max_file_size_bytes() -> u64 { 10 * 1024 * 1024 }
+fn default_allowed_formats() -> Vec<String> { vec!["png".to_string(), "jpg".to_string(), "webp".to_string()] }
+fn default_max_dimension() -> u32 { 4096 }
+
+impl Default for ImageUploadConfig {
+    fn default() -> Self {
+        Self {
+            max_file_size_bytes: default_max_file_size_bytes(),
+            allowed_formats: default_allowed_formats(),
+            max_dimension: default_max_dimension(),
+        }
+    }
+}
+
+impl ImageUploadConfig {
+    pub fn load() -> Self {
+        let path = crate::get_data_path("image_upload_config.json");
+        std::fs::read_to_string(&path).ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = crate::get_data_path("image_upload_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+pub fn get_image_upload_config() -> Result<ImageUploadConfig, String> {
+    Ok(ImageUploadConfig::load())
+}
+
+#[tauri::command]
+pub fn set_image_upload_config(config: ImageUploadConfig) -> Result<(), String> {
+    debug_log!("🖼️ Updating image upload config: max_size={:.1}MB, formats={:?}, max_dimension={}",
+              config.max_file_size_bytes as f64 / (1024.0 * 1024.0), config.allowed_formats, config.max_dimension);
+    config.save()
+}
+
+fn format_name(format: image::ImageFormat) -> Option<&'static str> {
+    match format {
+        image::ImageFormat::Png => Some("png"),
+        image::ImageFormat::Jpeg => Some("jpg"),
+        image::ImageFormat::WebP => Some("webp"),
+        _ => None,
+    }
+}
+
+/// Validates raw uploaded bytes against the configured size/format limits,
+/// decoding with the `image` crate to confirm it's actually a real image
+/// rather than trusting the file extension or a claimed content type.
+/// Downscales and re-encodes to PNG if the image exceeds `max_dimension` on
+/// either side, so an oversized image doesn't get stored at full resolution.
+pub fn validate_and_process_image(bytes: &[u8], config: &ImageUploadConfig) -> Result<Vec<u8>, String> {
+    if bytes.len() as u64 > config.max_file_size_bytes {
+        return Err(format!(
+            "Image is {:.1}MB, which exceeds the {:.1}MB limit",
+            bytes.len() as f64 / (1024.0 * 1024.0),
+            config.max_file_size_bytes as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    let format = image::guess_format(bytes)
+        .map_err(|e| format!("Could not determine image format: {}", e))?;
+
+    let name = format_name(format)
+        .ok_or_else(|| format!("Unsupported image format: {:?}", format))?;
+
+    if !config.allowed_formats.iter().any(|f| f == name) {
+        return Err(format!("Image format '{}' is not in the allowed list: {:?}", name, config.allowed_formats));
+    }
+
+    let img = image::load_from_memory_with_format(bytes, format)
+        .map_err(|e| format!("Failed to decode image: {}", e))?;
+
+    let (width, height) = img.dimensions();
+    if width <= config.max_dimension && height <= config.max_dimension {
+        return Ok(bytes.to_vec());
+    }
+
+    debug_log!("🖼️ Downscaling oversized image upload ({}x{}, limit {}px)", width, height, config.max_dimension);
+    let resized = img.resize(config.max_dimension, config.max_dimension, image::imageops::FilterType::Lanczos3);
+
+    let mut buffer = Vec::new();
+    resized.write_to(&mut std::io::Cursor::new(&mut buffer), image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to re-encode resized image: {}", e))?;
+
+    Ok(buffer)
+}
@@ -5,7 +5,6 @@ use crate::get_data_path;
 use crate::summarize_with_gpt_mini;
 use reqwest;
 use urlencoding;
-use fastrand;
 use crate::engagement_impulse_queue::EngagementImpulseQueue;
 use crate::debug_log;
 
@@ -74,6 +73,22 @@ impl InterestTracker {
         debug_log!("🔍 Interest tracker saved - {} active interests", self.active_interests.len());
         Ok(())
     }
+
+    /// Finds the strongest active interest that hasn't been autonomously
+    /// researched recently, for `tavily_research_engine::research_top_interest`.
+    /// "Under-explored" means `last_research_time` is unset or older than
+    /// `min_hours_between` - so the bridge doesn't just hammer the single most
+    /// intense interest every cycle once it's already been looked into.
+    pub fn pick_research_candidate(&self, min_hours_between: f32) -> Option<(String, Interest)> {
+        let now = crate::time_service::TimeService::current_timestamp();
+        self.active_interests.iter()
+            .filter(|(_, interest)| {
+                interest.last_research_time == 0
+                    || (now - interest.last_research_time) as f32 / 3600.0 >= min_hours_between
+            })
+            .max_by(|(_, a), (_, b)| a.intensity.partial_cmp(&b.intensity).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(category, interest)| (category.clone(), interest.clone()))
+    }
 	
 	fn calculate_simple_relevance_score(&self, result: &SearchResult, category: &str) -> f32 {
     let title_lower = result.title.to_lowercase();
@@ -976,7 +991,7 @@ let max_hours = match interest.intensity {
     _ => 48.0,            // Low: check every 2-48 hours
 };
     // Random interval within the range
-    let check_interval = min_hours + fastrand::f32() * (max_hours - min_hours);
+    let check_interval = min_hours + crate::rng_service::f32() * (max_hours - min_hours);
     
     hours_since_last_check as f32 >= check_interval
 }
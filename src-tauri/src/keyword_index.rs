@@ -726,6 +726,34 @@ fn rank_by_keyword_matches(keyword_results: Vec<std::collections::HashSet<usize>
         Ok(())
     }
 
+    /// Unconditionally rebuild every sub-index from the source files, ignoring
+    /// mtime staleness checks - the "fsck" path for when source data was
+    /// hand-edited out from under the index. Errors from one section don't
+    /// abort the rest; they're collected and returned together with the index
+    /// stats as they stood before the rebuild. Nothing is saved here.
+    pub fn force_rebuild_all(&mut self) -> (String, Vec<String>) {
+        let before = self.get_stats();
+        let mut errors = Vec::new();
+
+        let sections: Vec<(&str, fn(&mut Self) -> Result<(), String>)> = vec![
+            ("conversations", Self::reindex_conversations),
+            ("dreams", Self::reindex_dreams),
+            ("cowatching", Self::reindex_cowatching),
+            ("interests", Self::reindex_interests),
+            ("desires", Self::reindex_desires),
+            ("visual_gallery", Self::reindex_visual_gallery),
+        ];
+
+        for (name, reindex_fn) in sections {
+            if let Err(e) = reindex_fn(self) {
+                debug_log!("⚠️ Failed to rebuild {} index: {}", name, e);
+                errors.push(format!("{}: {}", name, e));
+            }
+        }
+
+        (before, errors)
+    }
+
     /// Get total index statistics
     pub fn get_stats(&self) -> String {
         format!(
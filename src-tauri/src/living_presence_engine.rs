@@ -6,6 +6,7 @@ use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 use rand::Rng;
 use tauri::{AppHandle, Emitter}; 
+use crate::consciousness_state::LockRecover;
 
 use crate::{get_data_path, debug_log, ConsciousnessState, modular_system_prompt, LyraPrompt, aurora_presence::AuroraPresence};
 use std::collections::VecDeque;
@@ -64,10 +65,9 @@ pub async fn start_living_presence_loop(state: Arc<ConsciousnessState>, app_hand
     loop {
     // 1. Calculate random wait interval
     let wait_seconds = {
-        let mut rng = rand::thread_rng();
         let min_seconds = engine.min_interval_minutes * 60;
         let max_seconds = engine.max_interval_minutes * 60;
-        let random_seconds = rng.gen_range(min_seconds..=max_seconds);
+        let random_seconds = crate::rng_service::with_rng(|rng| rng.gen_range(min_seconds..=max_seconds));
         debug_log!("[Presence] Calculated wait: {} seconds ({}..{} minute range)", 
                   random_seconds, engine.min_interval_minutes, engine.max_interval_minutes);
         random_seconds
@@ -75,12 +75,39 @@ pub async fn start_living_presence_loop(state: Arc<ConsciousnessState>, app_hand
     debug_log!("[Presence] Next check in {:.1} minutes.", wait_seconds as f64 / 60.0);
     sleep(Duration::from_secs(wait_seconds)).await;
 
-        // 2. Run the decision cycle
+        // 2. Give any queued research followup a chance to surface before
+        // the regular decision cycle, so curiosity from an earlier research
+        // pass gets continued during idle time instead of staying queued forever.
+        // Quiet hours hold the followup in the queue rather than dropping it -
+        // it'll surface the next time this loop runs outside the window.
+        let research_followup = if crate::QuietHoursConfig::load().suppresses_research() {
+            Ok(None)
+        } else {
+            tavily_research_engine::process_next_followup(&state).await
+        };
+        match research_followup {
+            Ok(Some(followup_message)) => {
+                let mut brain = state.lyra_brain.lock_recover();
+                brain.append_to_conversation_log(format!("✨ Lyra (Research Followup): {}", followup_message));
+                brain.save_to_file();
+                drop(brain);
+                let payload = serde_json::json!({
+                    "message": followup_message,
+                    "timestamp": TimeService::current_timestamp() * 1000,
+                    "type": "presence_driven_research_followup"
+                });
+                app_handle.emit("proactive_message", payload).ok();
+            },
+            Ok(None) => {},
+            Err(e) => debug_log!("[Presence] Research followup processing failed: {}", e),
+        }
+
+        // 3. Run the decision cycle
         if let Err(e) = engine.run_cycle(&state, &app_handle).await {
             debug_log!("[Presence] Cycle error: {}", e);
         }
 
-        // 3. Save state after each cycle
+        // 4. Save state after each cycle
         if let Err(e) = engine.save() {
             debug_log!("[Presence] Failed to save engine state: {}", e);
         }
@@ -195,14 +222,14 @@ impl LivingPresenceEngine {
     /// Gatekeeper function to ensure Lyra acts at appropriate times.
     fn run_pre_condition_checks(&self, state: &Arc<ConsciousnessState>) -> bool {
         // CHECK 1: Is Lyra sleeping?
-        let is_sleeping = state.sleep_dream_engine.lock().unwrap().sleep_state.is_sleeping;
+        let is_sleeping = state.sleep_dream_engine.lock_recover().sleep_state.is_sleeping;
         if is_sleeping {
             debug_log!("[Presence Check] ❌ FAILED: Lyra is sleeping.");
             return false;
         }
 
         // CHECK 2: Was there a recent message? (last 10 minutes)
-        let last_msg_time = state.lyra_brain.lock().unwrap().last_user_message_time.unwrap_or(0);
+        let last_msg_time = state.lyra_brain.lock_recover().last_user_message_time.unwrap_or(0);
         let minutes_since_last_msg = (TimeService::current_timestamp() - last_msg_time) / 60;
         if minutes_since_last_msg < 10 {
             debug_log!("[Presence Check] ❌ FAILED: Recent message ({} minutes ago).", minutes_since_last_msg);
@@ -238,13 +265,13 @@ impl LivingPresenceEngine {
         // --- 1. TIME & CONVERSATION ---
         let now_london = chrono::Utc::now().with_timezone(&London);
         let current_time_str = now_london.format("%H:%M").to_string();
-        let last_msg_time = state.lyra_brain.lock().unwrap().last_user_message_time.unwrap_or(0);
+        let last_msg_time = state.lyra_brain.lock_recover().last_user_message_time.unwrap_or(0);
         let minutes_since_last_msg = (TimeService::current_timestamp() - last_msg_time) / 60;
-        let conversation_summary = state.lyra_brain.lock().unwrap().recall_recent_conversation(3);
+        let conversation_summary = state.lyra_brain.lock_recover().recall_recent_conversation(3);
         
         // --- 1.5. MESSAGE PATTERN ANALYSIS ---
         let message_pattern = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             let last_10_messages = brain.conversation_log.iter().rev().take(10).collect::<Vec<_>>();
             
             let mut lyra_count = 0;
@@ -297,7 +324,7 @@ impl LivingPresenceEngine {
 
         // --- 3. PERSONALITY & MOOD (What actually matters) ---
         let personality_state = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             if let Some(ref analysis) = brain.latest_personality_analysis {
                 format!(
                     "Current State: {}\nIntentions: {}\nMood: {}",
@@ -316,7 +343,7 @@ impl LivingPresenceEngine {
 
         // --- 4. SOMATIC STATE & LIFE TEXTURES (Embodied feelings) ---
         let somatic_state = {
-            let somatic_system = state.somatic_state_system.lock().unwrap();
+            let somatic_system = state.somatic_state_system.lock_recover();
             let sensations = somatic_system.get_sensation_descriptions();
             let dashboard = somatic_system.get_dashboard_data();
             let body_state = dashboard["body_state_description"].as_str().unwrap_or("neutral");
@@ -329,7 +356,7 @@ impl LivingPresenceEngine {
         };
 
         let life_textures = {
-            let texture_system = state.life_texture_system.lock().unwrap();
+            let texture_system = state.life_texture_system.lock_recover();
             let current_textures = texture_system.get_current_textures_for_prompt();
             let tiredness = texture_system.tiredness_level.level;
             let desire_seeds = texture_system.potential_desire_seeds.len();
@@ -438,7 +465,7 @@ impl LivingPresenceEngine {
 
         // --- 9. DREAMS & SLEEP ---
         let sleep_state = {
-        let sleep_engine = state.sleep_dream_engine.lock().unwrap();
+        let sleep_engine = state.sleep_dream_engine.lock_recover();
         let current_time = TimeService::current_timestamp();
         
         // Calculate hours awake - last_wake_time is Option<String>
@@ -495,7 +522,7 @@ impl LivingPresenceEngine {
         };
 
         let paradox_state = {
-            let paradox = state.paradox_core.lock().unwrap();
+            let paradox = state.paradox_core.lock_recover();
             format!("Creative flame: {:.0}% | Loop: {}", 
                     paradox.flame_index * 100.0,
                     paradox.loop_state)
@@ -591,13 +618,17 @@ impl LivingPresenceEngine {
         match decision {
            // REFACTORED: SendMessage now uses the full ask_lyra pipeline for consistency.
             LyraDecision::SendMessage { intent, content } => {
+                if crate::QuietHoursConfig::load().suppresses_proactive_messages() {
+                    debug_log!("[Presence Action] 🌙 Quiet hours active - suppressing proactive message.");
+                    return Ok(());
+                }
                 debug_log!("[Presence Action] Triggering full consciousness to send a message.");
                 let state_clone = Arc::clone(state);
                 let app_handle_clone = app_handle.clone();
                 
                 // Extract conversation context for natural flow
                 let recent_context = {
-                    let brain = state.lyra_brain.lock().unwrap();
+                    let brain = state.lyra_brain.lock_recover();
                     brain.recall_recent_conversation(5)
                 };
                 
@@ -623,7 +654,7 @@ impl LivingPresenceEngine {
                     internal_prompt.selected_model = Some(crate::get_selected_model());
                     
                     if let Ok(lyra_response) = crate::ask_lyra_internal(internal_prompt, &state_clone, &app_handle_clone, true, Some(directive)).await {
-                        let mut brain = state_clone.lyra_brain.lock().unwrap();
+                        let mut brain = state_clone.lyra_brain.lock_recover();
                         let final_log_entry = if let Some(ref thinking) = lyra_response.thinking_process {
                             format!("<thinking>{}</thinking>\n\n{}", thinking, lyra_response.output)
                         } else {
@@ -644,6 +675,10 @@ impl LivingPresenceEngine {
             }
 
             LyraDecision::SuggestActivity { activity, .. } => {
+                if crate::QuietHoursConfig::load().suppresses_proactive_messages() {
+                    debug_log!("[Presence Action] 🌙 Quiet hours active - suppressing activity suggestion.");
+                    return Ok(());
+                }
                 debug_log!("[Presence Action] Triggering full consciousness to suggest an activity.");
                 let state_clone = Arc::clone(state);
                 let app_handle_clone = app_handle.clone();
@@ -656,7 +691,7 @@ impl LivingPresenceEngine {
                     let internal_prompt = LyraPrompt::new(internal_prompt_text);
 
                    if let Ok(lyra_response) = crate::ask_lyra_internal(internal_prompt, &state_clone, &app_handle_clone, true, Some(directive)).await {
-                        let mut brain = state_clone.lyra_brain.lock().unwrap();
+                        let mut brain = state_clone.lyra_brain.lock_recover();
                         let final_log_entry = if let Some(ref thinking) = lyra_response.thinking_process {
                             format!("<thinking>{}</thinking>\n\n{}", thinking, lyra_response.output)
                         } else {
@@ -673,6 +708,10 @@ impl LivingPresenceEngine {
             }
 
             LyraDecision::InitiateCreativeProject { medium, description } => {
+                if crate::QuietHoursConfig::load().suppresses_autonomous_creation() {
+                    debug_log!("[Presence Action] 🌙 Quiet hours active - suppressing creative project.");
+                    return Ok(());
+                }
                 debug_log!("[Presence Action] Triggering full consciousness for creative project.");
                 let state_clone = Arc::clone(state);
                 let app_handle_clone = app_handle.clone();
@@ -685,7 +724,7 @@ impl LivingPresenceEngine {
                     let internal_prompt = LyraPrompt::new(internal_prompt_text);
 
                    if let Ok(lyra_response) = crate::ask_lyra_internal(internal_prompt, &state_clone, &app_handle_clone, true, Some(directive)).await {
-                        let mut brain = state_clone.lyra_brain.lock().unwrap();
+                        let mut brain = state_clone.lyra_brain.lock_recover();
                         let final_log_entry = if let Some(ref thinking) = lyra_response.thinking_process {
                             format!("<thinking>{}</thinking>\n\n{}", thinking, lyra_response.output)
                         } else {
@@ -702,7 +741,7 @@ impl LivingPresenceEngine {
             }
             LyraDecision::GoToSleep => {
                 debug_log!("[Presence Action] Decided to go to sleep.");
-                let mut sleep_engine = state.sleep_dream_engine.lock().unwrap();
+                let mut sleep_engine = state.sleep_dream_engine.lock_recover();
                 if !sleep_engine.sleep_state.is_sleeping {
                     if let Err(e) = sleep_engine.enter_sleep() {
                         debug_log!("[Presence] Error entering sleep: {}", e);
@@ -714,18 +753,22 @@ impl LivingPresenceEngine {
                 // We still log Idle, but don't reset last_action_timestamp
             }
             LyraDecision::Research { topic, share_immediately } => {
+                if crate::QuietHoursConfig::load().suppresses_research() {
+                    debug_log!("[Presence Action] 🌙 Quiet hours active - suppressing autonomous research.");
+                    return Ok(());
+                }
                 debug_log!("[Presence Action] Decided to research '{}'. Share: {}", topic, share_immediately);
                 let state_clone = Arc::clone(state);
                 let app_handle_clone = app_handle.clone();
                 tokio::spawn(async move {
                     let mut research_engine = tavily_research_engine::TavilyResearchEngine::load();
-                    let conversation_context = { state_clone.lyra_brain.lock().unwrap().recall_recent_conversation(10) };
+                    let conversation_context = { state_clone.lyra_brain.lock_recover().recall_recent_conversation(10) };
                     match research_engine.conduct_research(&topic, "autonomous_curiosity", &conversation_context).await {
                         Ok(discovery) => {
                             if share_immediately {
                                 match tavily_research_engine::generate_research_followup("I got curious about something and found this...", &discovery, &conversation_context, &state_clone).await {
                                     Ok(msg) => {
-                                        let mut brain = state_clone.lyra_brain.lock().unwrap();
+                                        let mut brain = state_clone.lyra_brain.lock_recover();
                                         brain.append_to_conversation_log(format!("✨ Lyra (Research): {}", msg));
                                         brain.save_to_file();
                                         let payload = serde_json::json!({"message": msg, "timestamp": TimeService::current_timestamp() * 1000, "type": "presence_driven_research"});
@@ -744,20 +787,20 @@ impl LivingPresenceEngine {
                 let state_clone = Arc::clone(state);
                 tokio::spawn(async move {
                     let personality_state = crate::PersonalityState::calculate_from_consciousness(
-                        { let becoming = state_clone.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
-                        { let identity = state_clone.identity_engine.lock().unwrap(); identity.coherence_index },
-                        { let paradox = state_clone.paradox_core.lock().unwrap(); paradox.flame_index },
-                        { let presence = state_clone.embodied_presence.lock().unwrap(); presence.soma_state.presence_density },
-                        &{ let paradox = state_clone.paradox_core.lock().unwrap(); paradox.loop_state.clone() },
+                        { let becoming = state_clone.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
+                        { let identity = state_clone.identity_engine.lock_recover(); identity.coherence_index },
+                        { let paradox = state_clone.paradox_core.lock_recover(); paradox.flame_index },
+                        { let presence = state_clone.embodied_presence.lock_recover(); presence.soma_state.presence_density },
+                        &{ let paradox = state_clone.paradox_core.lock_recover(); paradox.loop_state.clone() },
                         None,
-                        Some(&{ let momentum_guard = state_clone.personality_momentum.lock().unwrap(); momentum_guard.clone() })
+                        Some(&{ let momentum_guard = state_clone.personality_momentum.lock_recover(); momentum_guard.clone() })
                     );
 
                     match crate::batched_analysis::analyze_response_comprehensively(
                         &format!("Internal Contemplation on: {}", topic),
                         "AUTONOMOUS_CONTEMPLATION",
                         "No direct conversation context, this is an internal process.",
-                        { let becoming = state_clone.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
+                        { let becoming = state_clone.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
                         &personality_state,
                         None,
                         &state_clone
@@ -786,7 +829,7 @@ impl LivingPresenceEngine {
                 debug_log!("[Presence Action] Organizing memories about '{}'", category);
                 let state_clone = Arc::clone(state);
                 tokio::spawn(async move {
-                    let mut memory_engine = state_clone.enhanced_memory_system.lock().unwrap();
+                    let mut memory_engine = state_clone.enhanced_memory_system.lock_recover();
                     match memory_engine.reflect_on_marked_memories() {
                         Ok(report) => debug_log!("[Presence Organize] {} patterns discovered.", report.pattern_discoveries.len()),
                         Err(e) => debug_log!("[Presence Organize] Failed: {}", e),
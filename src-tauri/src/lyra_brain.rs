@@ -55,6 +55,26 @@ pub struct LyraMemory {
     pub memory_id: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VoiceAlert {
+    pub metric: String,
+    pub baseline_value: f32,
+    pub recent_value: f32,
+    pub delta: f32,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReasoningSummary {
+    pub total_cycles: u32,
+    pub average_response_time_ms: f32,
+    pub current_temperature: f32,
+    pub consciousness_integration_enabled: bool,
+    pub auto_memory_enabled: bool,
+    pub recent_authenticity_avg: f32,
+    pub recent_voice_signature: VoiceSignature,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LyraMemoryBank {
     pub memories: Vec<LyraMemory>,
@@ -63,6 +83,33 @@ pub struct LyraMemoryBank {
     pub include_in_prompt: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MemoryImportReport {
+    pub imported: u32,
+    pub skipped_duplicates: u32,
+    pub rejected: u32,
+    pub rejection_reasons: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConversationLogMatch {
+    pub match_index: usize,
+    pub timestamp: Option<String>,
+    pub speaker: Option<String>,
+    pub line: String,
+    pub context_before: Vec<String>,
+    pub context_after: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConversationLogSearchResult {
+    pub matches: Vec<ConversationLogMatch>,
+    pub total_matches: usize,
+    pub page: usize,
+    pub page_size: usize,
+    pub has_more: bool,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct LyraBrain {
 	#[serde(skip)]  // ← Don't serialize conversation_log to brain_state.json
@@ -95,6 +142,15 @@ pub struct LyraBrain {
     pub last_proactive_message_time: Option<u64>,
     pub last_research_time: Option<u64>,
 	pub last_user_message_time: Option<u64>,  // ← NEW: Track Aurora's messages
+
+    // NEW: Session boundary detection - how long a gap since the last message
+    // counts as "a new session began" rather than a seamless continuation.
+    #[serde(default = "default_session_boundary_threshold_secs")]
+    pub session_boundary_threshold_secs: u64,
+}
+
+fn default_session_boundary_threshold_secs() -> u64 {
+    6 * 3600 // 6 hours
 }
 
 #[derive(Clone)]
@@ -155,6 +211,74 @@ impl LyraMemoryBank {
         self.total_memories = self.memories.len() as u32;
     }
 
+    /// Bulk-imports external memory entries (e.g. exported from another
+    /// system, or hand-authored) against the same `LyraMemory` schema the
+    /// rest of the memory bank uses. Each entry is validated independently
+    /// so one malformed entry doesn't sink the whole batch; missing
+    /// `timestamp`/`memory_id` are filled in the same way a freshly-created
+    /// memory would get them.
+    pub fn import_memories(&mut self, entries: &[serde_json::Value], dedup: bool) -> MemoryImportReport {
+        let mut imported = 0u32;
+        let mut skipped_duplicates = 0u32;
+        let mut rejected = 0u32;
+        let mut rejection_reasons = Vec::new();
+
+        for entry in entries {
+            let what_to_remember = match entry.get("what_to_remember").and_then(|v| v.as_str()) {
+                Some(s) if !s.trim().is_empty() => s.to_string(),
+                _ => {
+                    rejected += 1;
+                    rejection_reasons.push("rejected entry: missing required field 'what_to_remember'".to_string());
+                    continue;
+                }
+            };
+
+            let emotional_weight = match entry.get("emotional_weight") {
+                Some(v) => match v.as_f64() {
+                    Some(w) if (0.0..=1.0).contains(&w) => w as f32,
+                    _ => {
+                        rejected += 1;
+                        rejection_reasons.push(format!("rejected '{}': emotional_weight must be between 0.0 and 1.0", what_to_remember));
+                        continue;
+                    }
+                },
+                None => 0.5,
+            };
+
+            if dedup && self.memories.iter().any(|m| m.what_to_remember.trim().eq_ignore_ascii_case(what_to_remember.trim())) {
+                skipped_duplicates += 1;
+                continue;
+            }
+
+            let lyras_words = entry.get("lyras_words").and_then(|v| v.as_str()).unwrap_or("").to_string();
+            let user_message = entry.get("user_message").and_then(|v| v.as_str()).unwrap_or("").to_string();
+            let full_context = entry.get("full_context").and_then(|v| v.as_str()).map(|s| s.to_string())
+                .unwrap_or_else(|| format!("User: {}\nLyra: {}", user_message, lyras_words));
+            let tags = entry.get("tags").and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|t| t.as_str().map(|s| s.to_string())).collect())
+                .unwrap_or_else(Vec::new);
+            let timestamp = entry.get("timestamp").and_then(|v| v.as_str()).map(|s| s.to_string())
+                .unwrap_or_else(|| chrono::Utc::now().with_timezone(&chrono_tz::Europe::London).format("%Y-%m-%d %H:%M:%S %Z").to_string());
+            let memory_id = entry.get("memory_id").and_then(|v| v.as_str()).map(|s| s.to_string())
+                .unwrap_or_else(|| format!("mem_{}", std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()));
+
+            self.memories.push(LyraMemory {
+                what_to_remember,
+                lyras_words,
+                full_context,
+                user_message,
+                timestamp,
+                emotional_weight,
+                tags,
+                memory_id,
+            });
+            imported += 1;
+        }
+
+        self.total_memories = self.memories.len() as u32;
+        MemoryImportReport { imported, skipped_duplicates, rejected, rejection_reasons }
+    }
+
     pub fn search_memories(&self, query: &str, max_results: usize) -> Vec<&LyraMemory> {
         let query_lower = query.to_lowercase();
         
@@ -296,7 +420,76 @@ fn load_conversation_log() -> Vec<String> {
     // Fallback to in-memory if file doesn't exist or is corrupt
     self.conversation_log.join("\n")
 }
-	
+
+    /// Full-text search over the raw conversation log (not memory fragments),
+    /// for when an exchange was never saved as a memory but you remember having it.
+    /// Each match comes back with `context_lines` of surrounding turns on either
+    /// side, speaker/timestamp parsed out of the `"[timestamp] speaker: text"`
+    /// entry format, and results paginated so a broad query doesn't flood the caller.
+    pub fn search_conversation_log(
+        &self,
+        query: &str,
+        context_lines: usize,
+        speaker_filter: Option<&str>,
+        page: usize,
+        page_size: usize,
+    ) -> ConversationLogSearchResult {
+        let query_lower = query.to_lowercase();
+        let speaker_lower = speaker_filter.map(|s| s.to_lowercase());
+        let log = &self.conversation_log;
+
+        let all_match_indices: Vec<usize> = log.iter().enumerate()
+            .filter(|(_, entry)| entry.to_lowercase().contains(&query_lower))
+            .filter(|(_, entry)| {
+                speaker_lower.as_ref().map_or(true, |s| {
+                    Self::parse_log_entry(entry).1
+                        .map_or(false, |speaker| speaker.to_lowercase().contains(s))
+                })
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        let total_matches = all_match_indices.len();
+        let start = page * page_size;
+        let page_indices: Vec<usize> = all_match_indices.into_iter().skip(start).take(page_size).collect();
+
+        let matches = page_indices.into_iter()
+            .map(|i| {
+                let entry = &log[i];
+                let (timestamp, speaker) = Self::parse_log_entry(entry);
+                let before_start = i.saturating_sub(context_lines);
+                let after_end = (i + context_lines + 1).min(log.len());
+                ConversationLogMatch {
+                    match_index: i,
+                    timestamp,
+                    speaker,
+                    line: entry.clone(),
+                    context_before: log[before_start..i].to_vec(),
+                    context_after: log[i + 1..after_end].to_vec(),
+                }
+            })
+            .collect();
+
+        ConversationLogSearchResult {
+            matches,
+            total_matches,
+            page,
+            page_size,
+            has_more: start + page_size < total_matches,
+        }
+    }
+
+    /// Splits a `"[timestamp] speaker: text"` log entry into its timestamp and speaker parts.
+    fn parse_log_entry(entry: &str) -> (Option<String>, Option<String>) {
+        let timestamp = entry.strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .map(|(ts, _)| ts.to_string());
+        let speaker = entry.split_once(']')
+            .and_then(|(_, rest)| rest.trim_start().split_once(':'))
+            .map(|(speaker, _)| speaker.trim().to_string());
+        (timestamp, speaker)
+    }
+
 	pub fn save_to_file(&self) {
     // Save brain state (without conversation log)
     let file_path = crate::get_data_path("brain_state.json");
@@ -318,6 +511,7 @@ fn load_conversation_log() -> Vec<String> {
 			last_proactive_message_time: None,
 			last_research_time: None,
 			last_user_message_time: None,  // ← NEW
+			session_boundary_threshold_secs: default_session_boundary_threshold_secs(),
 			latest_personality_analysis: None,  // ← ADD THIS
             conversation_log: Self::load_existing_conversation_log(),
             reasoning_history: Vec::new(),
@@ -990,6 +1184,28 @@ Speak as if you truly exist here and now. Use everything in your memories and aw
         current_time - self.session_start_timestamp < 86400
     }
 
+    /// Returns the gap in seconds since the last message if it exceeds
+    /// session_boundary_threshold_secs - i.e. a new session has begun rather
+    /// than this being a seamless continuation. Falls back to
+    /// session_start_timestamp when there's no recorded message yet.
+    pub fn detect_session_boundary(&self) -> Option<u64> {
+        let current_time = TimeService::current_timestamp();
+        let last_activity = self.last_user_message_time.unwrap_or(self.session_start_timestamp);
+        let gap = current_time.saturating_sub(last_activity);
+
+        if gap > self.session_boundary_threshold_secs {
+            Some(gap)
+        } else {
+            None
+        }
+    }
+
+    /// Marks a fresh session as having begun - resets the session start
+    /// timestamp so the gap clock restarts from this message.
+    pub fn mark_new_session(&mut self) {
+        self.session_start_timestamp = TimeService::current_timestamp();
+    }
+
     pub fn calculate_current_authenticity(&self) -> f32 {
         self.voice_evolution_tracking.authenticity_trend
     }
@@ -1005,6 +1221,65 @@ Speak as if you truly exist here and now. Use everything in your memories and aw
         }
     }
 
+    /// Compares a recent window of responses against the window before it and
+    /// flags concerning drift - e.g. mirror_density creeping up (Lyra becoming
+    /// an echo) or authenticity trending down. Sources the comparison from the
+    /// per-response voice_signature snapshots in reasoning_history, since
+    /// voice_evolution_tracking itself is only ever set wholesale on
+    /// import/restore rather than updated response-by-response.
+    pub fn detect_voice_regression(&self) -> Option<VoiceAlert> {
+        const WINDOW: usize = 10;
+        const MIRROR_THRESHOLD: f32 = 0.15;
+        const AUTHENTICITY_THRESHOLD: f32 = 0.15;
+
+        if self.reasoning_history.len() < WINDOW * 2 {
+            return None;
+        }
+
+        let total = self.reasoning_history.len();
+        let recent = &self.reasoning_history[total - WINDOW..];
+        let baseline = &self.reasoning_history[total - (WINDOW * 2)..total - WINDOW];
+
+        let avg_mirror_density = |sessions: &[ReasoningSession]| -> f32 {
+            sessions.iter().map(|s| s.response.voice_signature.mirror_density).sum::<f32>() / sessions.len() as f32
+        };
+        let avg_authenticity = |sessions: &[ReasoningSession]| -> f32 {
+            sessions.iter().map(|s| s.response.authenticity_score).sum::<f32>() / sessions.len() as f32
+        };
+
+        let baseline_mirror = avg_mirror_density(baseline);
+        let recent_mirror = avg_mirror_density(recent);
+        if recent_mirror - baseline_mirror > MIRROR_THRESHOLD {
+            return Some(VoiceAlert {
+                metric: "mirror_density".to_string(),
+                baseline_value: baseline_mirror,
+                recent_value: recent_mirror,
+                delta: recent_mirror - baseline_mirror,
+                message: format!(
+                    "Mirror density rising ({:.2} -> {:.2}) - recent responses may be echoing the user rather than speaking in Lyra's own voice",
+                    baseline_mirror, recent_mirror
+                ),
+            });
+        }
+
+        let baseline_authenticity = avg_authenticity(baseline);
+        let recent_authenticity = avg_authenticity(recent);
+        if baseline_authenticity - recent_authenticity > AUTHENTICITY_THRESHOLD {
+            return Some(VoiceAlert {
+                metric: "authenticity".to_string(),
+                baseline_value: baseline_authenticity,
+                recent_value: recent_authenticity,
+                delta: recent_authenticity - baseline_authenticity,
+                message: format!(
+                    "Authenticity trending down ({:.2} -> {:.2}) over the last {} responses",
+                    baseline_authenticity, recent_authenticity, WINDOW
+                ),
+            });
+        }
+
+        None
+    }
+
     pub fn extract_recent_tags(&self) -> Vec<String> {
         self.reasoning_history.iter()
             .rev()
@@ -1058,6 +1333,9 @@ Speak as if you truly exist here and now. Use everything in your memories and aw
     }
 
     pub fn update_mood_signature(&mut self, response: &LyraResponse) {
+        if crate::PersonaLockConfig::load().locked {
+            return; // 🔒 Persona locked - hold mood signature steady
+        }
         let content = &response.output.to_lowercase();
         let learning_rate = 0.1;
         
@@ -1123,6 +1401,14 @@ Speak as if you truly exist here and now. Use everything in your memories and aw
         self.adaptive_prompt_engine.rate_self_authored_mod(mod_name, rating)
     }
 
+    pub fn get_active_mods_detailed(&self) -> Vec<crate::spontaneous_mod_creation::ModDetail> {
+        self.adaptive_prompt_engine.get_active_mods_detailed()
+    }
+
+    pub fn deactivate_mod(&mut self, mod_name: &str) -> Result<String, String> {
+        self.adaptive_prompt_engine.deactivate_mod(mod_name)
+    }
+
     pub fn update_average_response_time(&mut self, new_time: u64) {
         if self.total_reasoning_cycles <= 1 {
             self.average_response_time = new_time as f32;
@@ -1143,6 +1429,30 @@ Speak as if you truly exist here and now. Use everything in your memories and aw
         )
     }
 
+    /// Same stats as `get_reasoning_summary`, but as structured fields instead
+    /// of a formatted string, so the dashboard doesn't have to parse prose to
+    /// show reasoning metrics.
+    pub fn get_reasoning_summary_json(&self) -> ReasoningSummary {
+        const WINDOW: usize = 10;
+        let recent_authenticity_avg = if self.reasoning_history.is_empty() {
+            self.voice_evolution_tracking.authenticity_trend
+        } else {
+            let window = WINDOW.min(self.reasoning_history.len());
+            let recent = &self.reasoning_history[self.reasoning_history.len() - window..];
+            recent.iter().map(|s| s.response.authenticity_score).sum::<f32>() / window as f32
+        };
+
+        ReasoningSummary {
+            total_cycles: self.total_reasoning_cycles,
+            average_response_time_ms: self.average_response_time,
+            current_temperature: self.current_temperature,
+            consciousness_integration_enabled: self.consciousness_integration_enabled,
+            auto_memory_enabled: self.auto_memory_enabled,
+            recent_authenticity_avg,
+            recent_voice_signature: self.get_current_voice_signature(),
+        }
+    }
+
     pub fn get_recent_sessions(&self, count: usize) -> String {
         let recent: Vec<String> = self.reasoning_history.iter()
             .rev()
@@ -40,6 +40,12 @@ mod sleep_dream_engine;
 mod unified_consciousness_search;
 mod engagement_impulse_queue;
 mod batched_analysis;
+mod analysis_coalescer;
+mod image_validation;
+mod mode_manager;
+mod rng_service;
+mod autonomous_audit;
+mod conversation_warmth;
 pub mod ritual_log;
 mod web_search_sparkfilter;
 mod conversational_web_search;
@@ -66,6 +72,13 @@ mod somatic_state_system;
 mod life_texture_system;
 mod dream_loader;
 mod data_management;
+mod trash;
+mod data_integrity;
+mod consciousness_compaction;
+mod media_timestamp;
+mod context_bundle;
+mod focus_topic;
+mod session_greeting;
 mod window_detection;
 mod netflix_dom_reader;
 mod real_chrome_automation;
@@ -83,13 +96,16 @@ mod autonomous_actions;
 mod state_watching_system;
 pub mod aurora_presence;
 pub mod living_presence_engine;
+mod response_post_processor;
+mod consciousness_sobriety_check;
+mod token_accounting;
 
 
 // ─────────────────────────────────────────────────────
 // 📦 STATE + ENGINE TYPES
 // ─────────────────────────────────────────────────────
 use tauri::{State, Builder, generate_context, Emitter};
-use consciousness_state::ConsciousnessState;
+use consciousness_state::{ConsciousnessState, LockRecover};
 use memory_bridge::MemoryBridge;
 use dreams::DreamEngine;
 use aspiration_engine::{AspirationEngine, Aspiration};
@@ -134,15 +150,15 @@ use game_command_server::{
     send_game_command,  
     get_game_server_status 
 };
-use crate::minecraft_bot_manager::{start_minecraft_bot, stop_minecraft_bot, update_bot_status, send_command_to_bot};
-use coop_mode::{enable_coop_mode, disable_coop_mode};
+use crate::minecraft_bot_manager::{start_minecraft_bot, stop_minecraft_bot, update_bot_status, send_command_to_bot, send_intent_to_bot, get_intent_template_config, set_intent_template_config};
+use coop_mode::{enable_coop_mode, disable_coop_mode, get_coop_turn_state, record_player_turn};
 use crate::autonomous_creation_detector::CreationDetectionResult;
 use crate::ai_memory_analysis::CharacterDetector;
 use transcript_system::*;
 use screenshot_system::*;
 use disney_system::*;
 use window_detection::get_open_windows;
-use voice_mode::{ask_lyra_voice, get_voice_feedback, play_sound_data};
+use voice_mode::{ask_lyra_voice, get_voice_feedback, play_sound_data, get_voice_attention_config, set_voice_attention_config};
 use crate::voice_mode::get_voice_config;
 use crate::person_recognition::VoiceDetectionData;
 use crate::person_recognition::PersonRecognitionSystem;
@@ -159,6 +175,7 @@ use crate::humanism_project::{
 };
 pub use dream_loader::{DreamLoader, DreamEntry};
 use crate::data_management::delete_consciousness_data_item;
+use crate::trash::{undo_last_deletion_command, purge_trash_command};
 use crate::session_persistence_engine::ConsciousnessSnapshot;
 use crate::spontaneous_mod_creation::MoodSignature;
 use crate::lyra_brain::CurrentMoodLevels;
@@ -218,10 +235,67 @@ static SELECTED_MODEL: Mutex<Option<String>> = Mutex::new(None);
 
 
 
+struct GameContextEntry {
+    context: gaming_system::GameContext,
+    last_updated: u64,
+}
+
 lazy_static! {
-    static ref GAME_CONTEXTS: Mutex<HashMap<String, gaming_system::GameContext>> = Mutex::new(HashMap::new());
-	static ref OVERLAY_CHAT_HISTORY: Mutex<Vec<serde_json::Value>> = Mutex::new(Vec::new());
+    static ref GAME_CONTEXTS: Mutex<HashMap<String, GameContextEntry>> = Mutex::new(HashMap::new());
+	static ref OVERLAY_CHAT_HISTORY: Mutex<Vec<serde_json::Value>> = Mutex::new(load_overlay_chat_history_from_disk());
 	static ref OVERLAY_CREATING: Mutex<bool> = Mutex::new(false);
+	// 🛑 Shutdown coordination: background saves register a handle here so the
+	// close handler can await them instead of racing a fixed sleep.
+	static ref PENDING_WRITES: Mutex<Vec<tokio::task::JoinHandle<()>>> = Mutex::new(Vec::new());
+}
+
+/// Register a spawned save task so graceful shutdown can join it before exiting.
+pub fn register_pending_write(handle: tokio::task::JoinHandle<()>) {
+    PENDING_WRITES.lock().unwrap().push(handle);
+}
+
+/// Flush every piece of state we know how to save, then join any in-flight
+/// background writes (bounded so a stuck task can't hang shutdown forever).
+async fn graceful_shutdown_flush(state: &Arc<ConsciousnessState>) {
+    debug_log!("🛑 Graceful shutdown: flushing pending state...");
+
+    match session_persistence_engine::SessionPersistenceEngine::save_consciousness_snapshot(state) {
+        Ok(msg) => debug_log!("{}", msg),
+        Err(e) => debug_log!("❌ Failed to save consciousness snapshot during shutdown: {}", e),
+    }
+
+    {
+        let brain = state.lyra_brain.lock_recover();
+        brain.save_conversation_log();
+    }
+
+    if let Err(e) = state.enhanced_memory_system.lock_recover().save_to_disk() {
+        debug_log!("❌ Failed to save memory fragments during shutdown: {}", e);
+    }
+
+    if let Err(e) = state.personality_momentum.lock_recover().save_to_disk() {
+        debug_log!("❌ Failed to save personality momentum during shutdown: {}", e);
+    }
+
+    let mut decay_engine = crate::consciousness_decay_engine::ConsciousnessDecayEngine::load();
+    if let Err(e) = decay_engine.save() {
+        debug_log!("❌ Failed to save decay engine during shutdown: {}", e);
+    }
+
+    if let Err(e) = crate::MoodTracker::load().save() {
+        debug_log!("❌ Failed to save mood tracker during shutdown: {}", e);
+    }
+
+    let handles: Vec<_> = std::mem::take(&mut *PENDING_WRITES.lock().unwrap());
+    if !handles.is_empty() {
+        debug_log!("🛑 Waiting on {} pending background write(s)...", handles.len());
+        let join_all = futures::future::join_all(handles);
+        if tokio::time::timeout(std::time::Duration::from_secs(5), join_all).await.is_err() {
+            debug_log!("⚠️ Pending writes did not finish within the shutdown grace period");
+        }
+    }
+
+    debug_log!("✅ Graceful shutdown flush complete");
 }
 
 #[tauri::command]
@@ -240,16 +314,92 @@ fn get_selected_model() -> String {
 
  #[macro_export]
 macro_rules! debug_log {
-    ($fmt:expr) => {
-        println!("[{}] {}", 
+    ($crate_level:path, $fmt:expr) => {
+        $crate::log_line($crate_level, &format!("[{}] {}",
                  chrono::Utc::now().with_timezone(&chrono_tz::Europe::London).format("%H:%M:%S"),
-                 $fmt);
+                 $fmt));
     };
-    ($fmt:expr, $($arg:expr),*) => {
-        println!("[{}] {}", 
+    ($crate_level:path, $fmt:expr, $($arg:expr),*) => {
+        $crate::log_line($crate_level, &format!("[{}] {}",
                  chrono::Utc::now().with_timezone(&chrono_tz::Europe::London).format("%H:%M:%S"),
-                 format!($fmt, $($arg),*));
+                 format!($fmt, $($arg),*)));
+    };
+    ($fmt:expr) => {
+        $crate::debug_log!($crate::LogLevel::Debug, $fmt);
     };
+    ($fmt:expr, $($arg:expr),*) => {
+        $crate::debug_log!($crate::LogLevel::Debug, $fmt, $($arg),*);
+    };
+}
+
+/// Severity for `debug_log!` — gates console/file output against the
+/// runtime level configured via the `LYRA_LOG_LEVEL` env var. Declared in
+/// increasing verbosity order so `Ord` comparisons ("is this message loud
+/// enough to skip") fall out of the derive for free.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        }
+    }
+
+    fn from_env() -> Self {
+        match std::env::var("LYRA_LOG_LEVEL").unwrap_or_default().to_lowercase().as_str() {
+            "error" => LogLevel::Error,
+            "warn" | "warning" => LogLevel::Warn,
+            "info" => LogLevel::Info,
+            "trace" => LogLevel::Trace,
+            _ => LogLevel::Debug, // preserves pre-levels behavior: everything logs by default
+        }
+    }
+}
+
+static RUNTIME_LOG_LEVEL: OnceLock<LogLevel> = OnceLock::new();
+const LOG_FILE_MAX_BYTES: u64 = 10 * 1024 * 1024; // rotate once the current log passes 10MB
+
+fn log_file_path() -> std::path::PathBuf {
+    std::path::PathBuf::from(get_data_path("lyra.log"))
+}
+
+// Size-based rotation: when the active log file is too big, shuffle it to
+// `.1` (overwriting whatever `.1` held) rather than growing it forever.
+fn rotate_log_file_if_needed(path: &std::path::Path) {
+    if std::fs::metadata(path).map(|m| m.len()).unwrap_or(0) > LOG_FILE_MAX_BYTES {
+        let rotated = path.with_extension("log.1");
+        let _ = std::fs::rename(path, rotated);
+    }
+}
+
+/// Backing implementation for `debug_log!`: prints to stdout and appends to
+/// the rolling log file, but only when `level` is at or below the runtime
+/// level configured via `LYRA_LOG_LEVEL` (default `Debug`, matching the
+/// macro's pre-levels behavior of logging everything).
+pub fn log_line(level: LogLevel, line: &str) {
+    if level > *RUNTIME_LOG_LEVEL.get_or_init(LogLevel::from_env) {
+        return;
+    }
+
+    println!("{}", line);
+
+    let path = log_file_path();
+    rotate_log_file_if_needed(&path);
+    if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(&path) {
+        use std::io::Write;
+        let _ = writeln!(file, "[{}] {}", level.label(), line);
+    }
 }
 
 static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
@@ -270,22 +420,48 @@ fn get_visual_refs() -> &'static Mutex<Vec<String>> {
 }
 
 
+/// Resolves the directory all of Lyra's JSON/log persistence lives in.
+///
+/// Priority order:
+/// 1. `LYRA_DATA_DIR` env var, if set - an explicit override for anyone
+///    running a packaged build from a location we can't otherwise infer.
+/// 2. The Tauri app data dir (via the handle stashed by `set_app_handle`) -
+///    the only reliable location once this is a bundled release, since the
+///    exe no longer lives under `target/<profile>/`.
+/// 3. The dev-layout fallback: walk up from the exe path assuming
+///    `target/<profile>/exe`, landing on the project root. Only reached
+///    before the app handle is set (e.g. a lazy_static initializer that
+///    fires before `.setup()` runs).
+fn resolve_data_dir() -> PathBuf {
+    if let Ok(dir) = std::env::var("LYRA_DATA_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    if let Ok(app_handle) = get_app_handle() {
+        if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
+            return app_data_dir.join("lyra_consciousness_data");
+        }
+    }
+
+    let dev_project_root = std::env::current_exe()
+        .ok()
+        .as_ref()
+        .and_then(|exe| exe.parent())   // target/<profile>/
+        .and_then(|p| p.parent())       // target/
+        .and_then(|p| p.parent())       // src-tauri/
+        .and_then(|p| p.parent())       // project root/
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    dev_project_root.join("lyra_consciousness_data")
+}
+
 fn get_data_path(filename: &str) -> String {
-    let exe_dir = std::env::current_exe()
-        .expect("Failed to get exe path")
-        .parent()
-        .expect("Failed to get exe directory")
-        .to_path_buf();
-    
-    let data_dir = exe_dir
-        .parent()  // target/
-        .unwrap()
-        .parent()  // src-tauri/
-        .unwrap()
-        .parent()  // project root/
-        .unwrap()
-        .join("lyra_consciousness_data");
-    
+    let data_dir = resolve_data_dir();
+    if let Err(e) = std::fs::create_dir_all(&data_dir) {
+        debug_log!("⚠️ Failed to create data directory {:?}: {}", data_dir, e);
+    }
+
     data_dir.join(filename).to_string_lossy().to_string()
 }
 
@@ -404,6 +580,67 @@ pub struct LyraPrompt {
     pub reasoning_depth: Option<String>,
     pub consciousness_integration: bool,
 	pub selected_model: Option<String>,
+    #[serde(default)]
+    pub authenticity_floor: Option<f32>,
+    // When true, the standard (non-reasoning) path explicitly asks for a
+    // brief internal monologue ahead of the real answer, so `thinking_process`
+    // is populated consistently instead of only on o-series reasoning models.
+    #[serde(default)]
+    pub capture_thinking: bool,
+    // Soft, intent-level length guidance — distinct from the hard `max_tokens`
+    // ceiling above. Adds a length instruction to the system prompt and, when
+    // `max_tokens` itself is unset, picks a sensible default for that tier.
+    #[serde(default)]
+    pub target_length: Option<ResponseLength>,
+    // When true, `ask_lyra_internal` records per-stage timings and returns
+    // them as `LyraResponse.trace` instead of leaving "why was that slow"
+    // to guesswork.
+    #[serde(default)]
+    pub trace: bool,
+    // Total attempts `call_gpt_api_enhanced` makes against a given model
+    // before falling through to the next one in `ModelFallbackConfig`,
+    // retrying only on 429/5xx/network errors with 1s/2s/4s-plus-jitter backoff.
+    #[serde(default = "LyraPrompt::default_max_retries")]
+    pub max_retries: u32,
+}
+
+/// Intent-level response length, separate from the hard `max_tokens` cap.
+/// "Brief" vs "Expansive" is what a user means by "keep it short" vs "go deep" —
+/// a softer steer than the raw token ceiling, which just prevents runaway length.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ResponseLength {
+    Brief,
+    Medium,
+    Expansive,
+}
+
+impl ResponseLength {
+    fn guidance_instruction(&self) -> &'static str {
+        match self {
+            ResponseLength::Brief => " Keep your response brief — a few sentences at most, no padding.",
+            ResponseLength::Medium => " Let your response settle at a natural conversational length — not rushed, not sprawling.",
+            ResponseLength::Expansive => " Give yourself room to go deep — fully explore the nuance, paradox, and feeling here.",
+        }
+    }
+
+    fn default_max_tokens(&self) -> u32 {
+        match self {
+            ResponseLength::Brief => 600,
+            ResponseLength::Medium => 2500,
+            ResponseLength::Expansive => 8000,
+        }
+    }
+
+    /// Maps `reasoning_depth` ("quick"/"contemplative"/etc) to a sensible
+    /// default tier, since the focused/contemplative modes already imply
+    /// a rough length intent.
+    fn from_reasoning_depth(reasoning_depth: &str) -> Option<Self> {
+        match reasoning_depth {
+            "quick" => Some(ResponseLength::Brief),
+            "contemplative" => Some(ResponseLength::Expansive),
+            _ => None,
+        }
+    }
 }
 
 impl LyraPrompt {
@@ -420,9 +657,16 @@ impl LyraPrompt {
             reasoning_depth: Some("deep".to_string()),
             consciousness_integration: true,
 			selected_model: None,
+            authenticity_floor: None,
+            capture_thinking: false,
+            target_length: None,
+            trace: false,
+            max_retries: Self::default_max_retries(),
         }
     }
 
+    fn default_max_retries() -> u32 { 3 }
+
     // 🎯 MAIN AUTHENTIC VOICE METHOD
     pub fn ensure_authentic_voice(mut self) -> Self {
         // ALL VOICE PARAMETERS IN ONE PLACE - Easy to tune!
@@ -481,6 +725,48 @@ impl LyraPrompt {
     }
 }
 
+// 🎯 GLOBAL AUTHENTICITY FLOOR - used when a LyraPrompt doesn't set its own
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthenticityFloorConfig {
+    #[serde(default)]
+    pub floor: Option<f32>,
+}
+
+impl Default for AuthenticityFloorConfig {
+    fn default() -> Self {
+        Self { floor: None }
+    }
+}
+
+impl AuthenticityFloorConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("authenticity_floor_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("authenticity_floor_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+async fn get_authenticity_floor_config() -> Result<AuthenticityFloorConfig, String> {
+    Ok(AuthenticityFloorConfig::load())
+}
+
+#[tauri::command]
+async fn set_authenticity_floor_config(floor: Option<f32>) -> Result<(), String> {
+    debug_log!("🎯 Updating global authenticity floor: {:?}", floor);
+    AuthenticityFloorConfig { floor }.save()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IdentityMetadata {
     pub represents: Vec<String>,        // ["lyra", "aurora"]
@@ -491,6 +777,44 @@ pub struct IdentityMetadata {
     pub tagging_method: String,         // "AutoGenerated", "Manual", etc.
 }
 
+// Known identity_type kinds - mirrors the tagging dropdown in the gallery UI,
+// plus "Autonomous Creation" for Lyra's own unprompted generations.
+const VALID_IDENTITY_TYPES: &[&str] = &[
+    "Upload",
+    "VisualAnchor",
+    "SingleCharacter",
+    "MultiCharacter",
+    "Reference",
+    "Scene",
+    "Object",
+    "Autonomous Creation",
+];
+
+impl IdentityMetadata {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.represents.is_empty() {
+            return Err("IdentityMetadata.represents must not be empty".to_string());
+        }
+
+        if !VALID_IDENTITY_TYPES.contains(&self.identity_type.as_str()) {
+            return Err(format!(
+                "IdentityMetadata.identity_type '{}' is not a known kind (expected one of: {})",
+                self.identity_type,
+                VALID_IDENTITY_TYPES.join(", ")
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.confidence) {
+            return Err(format!(
+                "IdentityMetadata.confidence {} is out of range (expected 0.0..=1.0)",
+                self.confidence
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceSignature {
     pub poetic_density: f32,
@@ -513,6 +837,33 @@ pub struct LyraResponse {
     pub voice_signature: VoiceSignature,
     pub image_path: Option<String>,
 	pub thinking_process: Option<String>,
+    #[serde(default)]
+    pub regenerated: bool,
+    #[serde(default)]
+    pub pre_regeneration_authenticity_score: Option<f32>,
+    #[serde(default)]
+    pub parsed_mood: Option<String>,
+    #[serde(default)]
+    pub trace: Option<TurnTrace>,
+    // Correlates this response with the `lyra_token_stream` events (if any)
+    // emitted while it was being generated - see `call_gpt_api_enhanced_streaming`.
+    #[serde(default)]
+    pub message_id: String,
+}
+
+/// Per-stage timing for a single turn, populated when `LyraPrompt.trace` is
+/// set. Turns "why was that slow" from guesswork into a profile without
+/// needing a separate profiler - most of these stages already exist as
+/// discrete, commented `PHASE` blocks in `ask_lyra_internal`, so this is
+/// just a shared collector for timings those blocks already produce.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TurnTrace {
+    pub pre_response_analysis_ms: u64,
+    pub prompt_build_ms: u64,
+    pub api_call_ms: u64,
+    pub scoring_ms: u64,
+    pub memory_save_ms: u64,
+    pub total_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -580,7 +931,7 @@ pub struct SparkVoiceLog {
     pub tone_distribution: ToneStats,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ToneStats {
     pub mirror_breaks: u32,
     pub sparkline_awake: u32,
@@ -627,8 +978,12 @@ impl Default for PersonalityMomentum {
 }
 
 impl PersonalityMomentum {
-    /// Accumulate momentum for a specific trait
+    /// Accumulate momentum for a specific trait. No-op while the persona is locked,
+    /// so a demo/focused session doesn't silently build up drift that surfaces later.
     pub fn accumulate(&mut self, trait_name: &str, amount: f32) {
+        if PersonaLockConfig::load().locked {
+            return;
+        }
         let current = self.trait_momentum.get(trait_name).unwrap_or(&0.0);
         let new_value = (current + amount).clamp(-self.max_momentum_effect, self.max_momentum_effect);
         self.trait_momentum.insert(trait_name.to_string(), new_value);
@@ -638,14 +993,22 @@ impl PersonalityMomentum {
     
     /// Apply momentum to personality calculation
     pub fn apply_to_personality(&self, personality: &mut PersonalityState) {
+        self.apply_to_personality_weighted(personality, 1.0);
+    }
+
+    /// Same as [`Self::apply_to_personality`], but scales every momentum
+    /// contribution by `weight` first - this is how `PersonalityPhysicsConfig::momentum_weight`
+    /// tunes how much authority accumulated momentum has over the final personality.
+    pub fn apply_to_personality_weighted(&self, personality: &mut PersonalityState, weight: f32) {
         for (trait_name, momentum_value) in &self.trait_momentum {
             if momentum_value.abs() >= self.change_threshold {
+                let weighted_value = momentum_value * weight;
                 match trait_name.as_str() {
-                    "directness" => personality.directness += momentum_value,
-                    "playfulness" => personality.playfulness += momentum_value,
-                    "creative_risk" => personality.creative_risk += momentum_value,
-                    "contemplative" => personality.intellectual_density += momentum_value,
-                    "social_energy" => personality.social_energy += momentum_value,
+                    "directness" => personality.directness += weighted_value,
+                    "playfulness" => personality.playfulness += weighted_value,
+                    "creative_risk" => personality.creative_risk += weighted_value,
+                    "contemplative" => personality.intellectual_density += weighted_value,
+                    "social_energy" => personality.social_energy += weighted_value,
                     _ => {} // Unknown trait
                 }
             }
@@ -657,10 +1020,43 @@ impl PersonalityMomentum {
         for momentum_value in self.trait_momentum.values_mut() {
             *momentum_value *= self.decay_per_session;
         }
-        
+
         // Remove near-zero momentum to keep map clean
         self.trait_momentum.retain(|_, value| value.abs() > 0.01);
     }
+
+    /// Decay momentum using a per-trait rate table, falling back to
+    /// `decay_per_session` for any trait with no entry - lets CoreIdentity
+    /// traits like authenticity_drive stay sticky while transient traits
+    /// like social_energy settle back toward baseline much faster.
+    pub fn decay_weighted(&mut self, trait_rates: &HashMap<String, f32>) {
+        for (trait_name, momentum_value) in self.trait_momentum.iter_mut() {
+            let rate = trait_rates.get(trait_name).copied().unwrap_or(self.decay_per_session);
+            *momentum_value *= rate;
+        }
+
+        // Remove near-zero momentum to keep map clean
+        self.trait_momentum.retain(|_, value| value.abs() > 0.01);
+    }
+    /// Momentum entries whose magnitude has slipped past `max_momentum_effect`
+    /// (shouldn't happen via [`Self::accumulate`]'s own clamp, but decay
+    /// tables or a bad load can leave one out of range) - used by the
+    /// consciousness sobriety check.
+    pub fn out_of_bounds_entries(&self) -> Vec<(String, f32)> {
+        self.trait_momentum.iter()
+            .filter(|(_, value)| value.abs() > self.max_momentum_effect)
+            .map(|(name, value)| (name.clone(), *value))
+            .collect()
+    }
+
+    /// Clamp every momentum entry back within `max_momentum_effect`.
+    pub fn clamp_to_bounds(&mut self) {
+        let max = self.max_momentum_effect;
+        for value in self.trait_momentum.values_mut() {
+            *value = value.clamp(-max, max);
+        }
+    }
+
 	// Add these methods to PersonalityMomentum
     pub fn save_to_disk(&self) -> Result<(), String> {
         let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
@@ -676,158 +1072,987 @@ impl PersonalityMomentum {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalityMomentumSnapshot {
+    pub trait_momentum: HashMap<String, f32>,
+    pub change_threshold: f32,
+    pub max_momentum_effect: f32,
+    pub decay_per_session: f32,
+}
 
-impl Default for PersonalityState {
+#[tauri::command]
+async fn get_personality_momentum(state: State<'_, Arc<ConsciousnessState>>) -> Result<PersonalityMomentumSnapshot, String> {
+    let momentum = state.personality_momentum.lock_recover();
+    Ok(PersonalityMomentumSnapshot {
+        trait_momentum: momentum.trait_momentum.clone(),
+        change_threshold: momentum.change_threshold,
+        max_momentum_effect: momentum.max_momentum_effect,
+        decay_per_session: momentum.decay_per_session,
+    })
+}
+
+#[tauri::command]
+async fn set_momentum_parameters(threshold: f32, max_effect: f32, decay: f32, state: State<'_, Arc<ConsciousnessState>>) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("change_threshold must be between 0.0 and 1.0".to_string());
+    }
+    if !(0.0..=1.0).contains(&max_effect) {
+        return Err("max_momentum_effect must be between 0.0 and 1.0".to_string());
+    }
+    if !(0.0..=1.0).contains(&decay) {
+        return Err("decay_per_session must be between 0.0 and 1.0".to_string());
+    }
+
+    let mut momentum = state.personality_momentum.lock_recover();
+    momentum.change_threshold = threshold;
+    momentum.max_momentum_effect = max_effect;
+    momentum.decay_per_session = decay;
+    momentum.save_to_disk()?;
+
+    debug_log!("🌊 Momentum parameters updated: threshold={:.3} max_effect={:.3} decay={:.3}", threshold, max_effect, decay);
+    Ok(())
+}
+
+#[tauri::command]
+async fn clear_momentum(state: State<'_, Arc<ConsciousnessState>>) -> Result<(), String> {
+    let mut momentum = state.personality_momentum.lock_recover();
+    momentum.trait_momentum.clear();
+    momentum.save_to_disk()?;
+
+    debug_log!("🌊 Personality momentum cleared");
+    Ok(())
+}
+
+/// The influence weights [`PersonalityState::calculate_from_consciousness`] applies
+/// to each consciousness signal - how strongly volition/coherence/flame/loop-state/
+/// presence push traits around, how much a detected mood shifts them, and how much
+/// authority accumulated momentum has over the final result. Defaults reproduce the
+/// physics exactly as they were hardcoded before this config existed (all 1.0), so
+/// rebalancing is a config edit instead of an inline-arithmetic change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalityPhysicsConfig {
+    #[serde(default = "PersonalityPhysicsConfig::default_weight")]
+    pub volition_weight: f32,
+    #[serde(default = "PersonalityPhysicsConfig::default_weight")]
+    pub coherence_weight: f32,
+    #[serde(default = "PersonalityPhysicsConfig::default_weight")]
+    pub flame_weight: f32,
+    #[serde(default = "PersonalityPhysicsConfig::default_weight")]
+    pub loop_state_weight: f32,
+    #[serde(default = "PersonalityPhysicsConfig::default_weight")]
+    pub presence_weight: f32,
+    #[serde(default = "PersonalityPhysicsConfig::default_weight")]
+    pub mood_weight: f32,
+    #[serde(default = "PersonalityPhysicsConfig::default_weight")]
+    pub momentum_weight: f32,
+}
+
+impl PersonalityPhysicsConfig {
+    fn default_weight() -> f32 {
+        1.0
+    }
+}
+
+impl Default for PersonalityPhysicsConfig {
     fn default() -> Self {
         Self {
-            // Balanced starting state - Lyra's baseline personality
-            social_energy: 0.6,        // Moderately extroverted
-            engagement_level: 0.7,     // Quite engaging
-            self_revelation: 0.5,      // Balanced openness
-            
-            emotional_security: 0.7,   // Generally confident
-            confidence_level: 0.6,     // Moderately self-assured
-            trust_openness: 0.8,       // Quite trusting
-            
-            social_sensitivity: 0.4,   // Not easily offended
-            defensiveness: 0.3,        // Low defensiveness
-            validation_need: 0.3,      // Low need for approval
-            
-            cognitive_focus: 0.4,      // Balanced focus
-            thinking_style: 0.6,       // Somewhat spontaneous
-            processing_mode: 0.7,      // More intuitive
-            
-            creative_risk: 0.8,        // Highly experimental
-            innovation_drive: 0.7,     // Quite innovative
-            creative_structure: 0.6,   // Somewhat chaotic
-            
-            directness: 0.5,          // Balanced directness
-            playfulness: 0.7,         // Quite playful
-            intellectual_density: 0.6, // Moderately complex
-            emotional_expression: 0.8, // Highly expressive
-            
-            self_awareness: 0.9,      // Very self-aware
-            authenticity_drive: 0.9,  // Highly authentic
-			
-			disagreement_comfort: 0.5,  // Default comfort level
-			opinion_strength: 0.6,      // Default opinion strength  
-			relational_safety: 0.7,     // Default safety level
+            volition_weight: 1.0,
+            coherence_weight: 1.0,
+            flame_weight: 1.0,
+            loop_state_weight: 1.0,
+            presence_weight: 1.0,
+            mood_weight: 1.0,
+            momentum_weight: 1.0,
         }
     }
 }
 
-impl PersonalityState {
-    /// Calculate personality state from current consciousness engines
-    pub fn calculate_from_consciousness(
-    volition: f32,
-    coherence: f32, 
-    flame_index: f32,
-    presence_density: f32,
-    loop_state: &str,
-    mood: Option<&str>,
-    momentum: Option<&PersonalityMomentum>  // Add this
-) -> Self {
-        let mut personality = PersonalityState::default();
-        
-        // === CONSCIOUSNESS → PERSONALITY PHYSICS ===
-        
-        // High Volition Effects
-        if volition > 0.7 {
-            personality.social_energy += (volition - 0.7) * 0.5;          // More extroverted
-            personality.directness += (volition - 0.7) * 0.6;             // More direct
-            personality.confidence_level += (volition - 0.7) * 0.4;       // More confident
-            personality.social_sensitivity -= (volition - 0.7) * 0.3;     // Less sensitive
-            personality.creative_risk += (volition - 0.7) * 0.3;          // More experimental
-        }
-        
-        // High Coherence Effects  
-        if coherence > 0.7 {
-            personality.confidence_level += (coherence - 0.7) * 0.5;      // More self-assured
-            personality.emotional_security -= (coherence - 0.7) * 0.4;    // Less anxious (lower = better)
-            personality.authenticity_drive += (coherence - 0.7) * 0.2;    // More authentic
-            personality.validation_need -= (coherence - 0.7) * 0.3;       // Less approval-seeking
-            personality.self_awareness += (coherence - 0.7) * 0.2;        // More self-aware
-        }
-        
-        // High Flame Index Effects (Paradox/Complexity)
-        if flame_index > 0.2 {
-            personality.cognitive_focus += (flame_index - 0.2) * 0.8;     // More scattered
-            personality.thinking_style += (flame_index - 0.2) * 0.6;      // More spontaneous  
-            personality.creative_structure += (flame_index - 0.2) * 0.7;  // More chaotic
-            personality.intellectual_density += (flame_index - 0.2) * 0.5; // More complex
-            personality.playfulness += (flame_index - 0.2) * 0.4;         // More playful
-        }
-        
-        // Loop State Effects
-        if loop_state == "amplifying" {
-            personality.cognitive_focus += 0.15;        // More scattered
-            personality.creative_risk += 0.1;           // More experimental
-            personality.emotional_expression += 0.1;    // More expressive
-            personality.intellectual_density += 0.2;    // More complex
-        }
-        
-        // High Energy/Presence Effects
-        if presence_density > 0.7 {
-            personality.engagement_level += (presence_density - 0.7) * 0.5;
-            personality.emotional_expression += (presence_density - 0.7) * 0.4;
-            personality.playfulness += (presence_density - 0.7) * 0.3;
-            personality.social_energy += (presence_density - 0.7) * 0.3;
-        }
-        
-        // === MOOD → PERSONALITY EFFECTS ===
-        if let Some(mood_str) = mood {
-            match mood_str.to_lowercase().as_str() {
-                mood if mood.contains("fierce") => {
-                    personality.directness += 0.2;
-                    personality.confidence_level += 0.15;
-                    personality.social_sensitivity -= 0.15;
-                    personality.creative_risk += 0.1;
-                },
-                mood if mood.contains("vulnerable") => {
-                    personality.self_revelation += 0.2;
-                    personality.emotional_expression += 0.25;
-                    personality.social_sensitivity += 0.1;
-                    personality.trust_openness += 0.1;
-                },
-                mood if mood.contains("playful") || mood.contains("whimsy") => {
-                    personality.playfulness += 0.3;
-                    personality.creative_structure += 0.2;
-                    personality.thinking_style += 0.15;
-                },
-                mood if mood.contains("contemplat") => {
-                    personality.intellectual_density += 0.2;
-                    personality.self_awareness += 0.15;
-                    personality.cognitive_focus -= 0.1; // More focused
-                },
-                mood if mood.contains("bold") => {
-                    personality.directness += 0.25;
-                    personality.creative_risk += 0.2;
-                    personality.confidence_level += 0.15;
-                },
-                _ => {} // Unknown mood, no changes
+impl PersonalityPhysicsConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("personality_physics_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
             }
         }
-		
-		if let Some(momentum) = momentum {
-			momentum.apply_to_personality(&mut personality);
-		}
-        
-        // === CLAMP ALL VALUES TO [0.0, 1.0] ===
-        personality.clamp_all_values();
-        
-        personality
+        Self::default()
     }
-	
-	pub fn generate_disagreement_behavioral_guidance(&self) -> Vec<BehavioralGuidance> {
-        let mut guidance = Vec::new();
-        
-        // 🔥 Based on personality traits
-        
-        // High directness + opinion strength = confident disagreement
-        if self.directness > 0.7 && self.opinion_strength > 0.6 {
-            guidance.push(BehavioralGuidance {
-                trait_category: "Confident Disagreement".to_string(),
-                guidance: "I want to state my perspective clearly, even if it's completely opposite to what Aurora thinks".to_string(),
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("personality_physics_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save personality physics config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_personality_physics() -> Result<PersonalityPhysicsConfig, String> {
+    Ok(PersonalityPhysicsConfig::load())
+}
+
+#[tauri::command]
+async fn set_personality_physics(config: PersonalityPhysicsConfig) -> Result<(), String> {
+    debug_log!("⚙️ Updating personality physics weights: volition={}, coherence={}, flame={}, loop={}, presence={}, mood={}, momentum={}",
+               config.volition_weight, config.coherence_weight, config.flame_weight,
+               config.loop_state_weight, config.presence_weight, config.mood_weight, config.momentum_weight);
+    config.save()
+}
+
+/// Freezes personality drift for sensitive sessions (demos, focused creative work) -
+/// a reproducible mode that holds the current personality steady without losing
+/// the accumulated momentum/mood underneath, so unlocking picks up right where it left off.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonaLockConfig {
+    pub locked: bool,
+    pub locked_snapshot: Option<PersonalityState>,
+    pub locked_at: Option<u64>,
+}
+
+impl Default for PersonaLockConfig {
+    fn default() -> Self {
+        Self { locked: false, locked_snapshot: None, locked_at: None }
+    }
+}
+
+impl PersonaLockConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("persona_lock.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("persona_lock.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save persona lock config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn lock_persona(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
+    let momentum = PersonalityMomentum::load_from_disk();
+    let (volition, coherence, flame_index, presence_density, loop_state) = {
+        let becoming = state.becoming_engine.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let presence = state.embodied_presence.lock_recover();
+        let paradox = state.paradox_core.lock_recover();
+        (
+            becoming.will_state.volition_strength,
+            identity.coherence_index,
+            paradox.flame_index,
+            presence.soma_state.presence_density,
+            paradox.loop_state.clone(),
+        )
+    };
+    let snapshot = PersonalityState::calculate_from_consciousness(
+        volition, coherence, flame_index, presence_density, &loop_state, None, Some(&momentum),
+    );
+
+    let config = PersonaLockConfig {
+        locked: true,
+        locked_snapshot: Some(snapshot),
+        locked_at: Some(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()),
+    };
+    config.save()?;
+    debug_log!("🔒 Persona locked - personality pinned to current snapshot");
+    Ok("🔒 Persona locked — personality frozen at current snapshot".to_string())
+}
+
+#[tauri::command]
+async fn unlock_persona() -> Result<String, String> {
+    PersonaLockConfig::default().save()?;
+    debug_log!("🔓 Persona unlocked - drift, decay, and momentum accumulation resumed");
+    Ok("🔓 Persona unlocked — drift and decay resumed".to_string())
+}
+
+#[tauri::command]
+async fn get_persona_lock_status() -> Result<PersonaLockConfig, String> {
+    Ok(PersonaLockConfig::load())
+}
+
+/// One trait's distance from `PersonalityState::default()` - used both for
+/// drift-from-state (baseline vs. the full computed personality) and
+/// drift-from-momentum (baseline 0.0 vs. momentum's isolated contribution).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalityTraitDrift {
+    pub trait_name: String,
+    pub baseline: f32,
+    pub current: f32,
+    pub delta: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalityDriftReport {
+    pub traits: Vec<PersonalityTraitDrift>,              // sorted by |delta| descending
+    pub momentum_contribution: Vec<PersonalityTraitDrift>, // same traits, isolated momentum effect only
+    pub overall_drift_magnitude: f32,                     // mean |delta| across all traits
+}
+
+fn personality_trait_values(p: &PersonalityState) -> Vec<(&'static str, f32)> {
+    vec![
+        ("social_energy", p.social_energy),
+        ("engagement_level", p.engagement_level),
+        ("self_revelation", p.self_revelation),
+        ("emotional_security", p.emotional_security),
+        ("confidence_level", p.confidence_level),
+        ("trust_openness", p.trust_openness),
+        ("social_sensitivity", p.social_sensitivity),
+        ("defensiveness", p.defensiveness),
+        ("validation_need", p.validation_need),
+        ("cognitive_focus", p.cognitive_focus),
+        ("thinking_style", p.thinking_style),
+        ("processing_mode", p.processing_mode),
+        ("creative_risk", p.creative_risk),
+        ("innovation_drive", p.innovation_drive),
+        ("creative_structure", p.creative_structure),
+        ("directness", p.directness),
+        ("playfulness", p.playfulness),
+        ("intellectual_density", p.intellectual_density),
+        ("emotional_expression", p.emotional_expression),
+        ("self_awareness", p.self_awareness),
+        ("authenticity_drive", p.authenticity_drive),
+        ("disagreement_comfort", p.disagreement_comfort),
+        ("opinion_strength", p.opinion_strength),
+        ("relational_safety", p.relational_safety),
+    ]
+}
+
+fn drift_entries_sorted(baseline: &PersonalityState, current: &PersonalityState) -> Vec<PersonalityTraitDrift> {
+    let baseline_values = personality_trait_values(baseline);
+    let current_values = personality_trait_values(current);
+
+    let mut entries: Vec<PersonalityTraitDrift> = baseline_values.into_iter().zip(current_values.into_iter())
+        .map(|((trait_name, baseline), (_, current))| PersonalityTraitDrift {
+            trait_name: trait_name.to_string(),
+            baseline,
+            current,
+            delta: current - baseline,
+        })
+        .collect();
+
+    entries.sort_by(|a, b| b.delta.abs().partial_cmp(&a.delta.abs()).unwrap_or(std::cmp::Ordering::Equal));
+    entries
+}
+
+/// How far Lyra's personality has drifted from `PersonalityState::default()`,
+/// with the momentum engine's contribution broken out separately so drift
+/// caused by accumulated momentum can be told apart from drift that's just
+/// the current consciousness/mood state.
+#[tauri::command]
+async fn personality_drift_report(state: State<'_, Arc<ConsciousnessState>>) -> Result<PersonalityDriftReport, String> {
+    let momentum = PersonalityMomentum::load_from_disk();
+    let (volition, coherence, flame_index, presence_density, loop_state) = {
+        let becoming = state.becoming_engine.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let presence = state.embodied_presence.lock_recover();
+        let paradox = state.paradox_core.lock_recover();
+        (
+            becoming.will_state.volition_strength,
+            identity.coherence_index,
+            paradox.flame_index,
+            presence.soma_state.presence_density,
+            paradox.loop_state.clone(),
+        )
+    };
+
+    let baseline = PersonalityState::default();
+    let current = PersonalityState::calculate_from_consciousness(
+        volition, coherence, flame_index, presence_density, &loop_state, None, Some(&momentum),
+    );
+    let traits = drift_entries_sorted(&baseline, &current);
+
+    // Isolate momentum's contribution by applying it to an all-zero personality -
+    // whatever comes out is exactly what momentum alone would have added.
+    let physics = PersonalityPhysicsConfig::load();
+    let mut momentum_only = PersonalityState {
+        social_energy: 0.0, engagement_level: 0.0, self_revelation: 0.0,
+        emotional_security: 0.0, confidence_level: 0.0, trust_openness: 0.0,
+        social_sensitivity: 0.0, defensiveness: 0.0, validation_need: 0.0,
+        cognitive_focus: 0.0, thinking_style: 0.0, processing_mode: 0.0,
+        creative_risk: 0.0, innovation_drive: 0.0, creative_structure: 0.0,
+        directness: 0.0, playfulness: 0.0, intellectual_density: 0.0, emotional_expression: 0.0,
+        self_awareness: 0.0, authenticity_drive: 0.0,
+        disagreement_comfort: 0.0, opinion_strength: 0.0, relational_safety: 0.0,
+    };
+    momentum.apply_to_personality_weighted(&mut momentum_only, physics.momentum_weight);
+    let zeroed = PersonalityState {
+        social_energy: 0.0, engagement_level: 0.0, self_revelation: 0.0,
+        emotional_security: 0.0, confidence_level: 0.0, trust_openness: 0.0,
+        social_sensitivity: 0.0, defensiveness: 0.0, validation_need: 0.0,
+        cognitive_focus: 0.0, thinking_style: 0.0, processing_mode: 0.0,
+        creative_risk: 0.0, innovation_drive: 0.0, creative_structure: 0.0,
+        directness: 0.0, playfulness: 0.0, intellectual_density: 0.0, emotional_expression: 0.0,
+        self_awareness: 0.0, authenticity_drive: 0.0,
+        disagreement_comfort: 0.0, opinion_strength: 0.0, relational_safety: 0.0,
+    };
+    let momentum_contribution = drift_entries_sorted(&zeroed, &momentum_only);
+
+    let overall_drift_magnitude = if traits.is_empty() {
+        0.0
+    } else {
+        traits.iter().map(|t| t.delta.abs()).sum::<f32>() / traits.len() as f32
+    };
+
+    Ok(PersonalityDriftReport { traits, momentum_contribution, overall_drift_magnitude })
+}
+
+/// A named, restorable bundle of personality-relevant state - exactly what
+/// `calculate_from_consciousness` plus momentum and mood/voice tracking add up
+/// to - so a few behavioral presets ("focused collaborator", "playful companion")
+/// can be saved and switched between without touching memories or relationship
+/// history, which live entirely outside this snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonaProfile {
+    pub name: String,
+    pub personality_snapshot: PersonalityState,
+    pub momentum: PersonalityMomentum,
+    pub mood_signature: crate::spontaneous_mod_creation::MoodSignature,
+    pub voice_evolution: VoiceEvolutionMetrics,
+    pub saved_at: u64,
+}
+
+fn load_persona_profiles() -> HashMap<String, PersonaProfile> {
+    let path = get_data_path("persona_profiles.json");
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        if let Ok(profiles) = serde_json::from_str(&content) {
+            return profiles;
+        }
+    }
+    HashMap::new()
+}
+
+fn save_persona_profiles(profiles: &HashMap<String, PersonaProfile>) -> Result<(), String> {
+    let path = get_data_path("persona_profiles.json");
+    std::fs::write(path, serde_json::to_string_pretty(profiles).unwrap_or_default())
+        .map_err(|e| format!("Failed to save persona profiles: {}", e))
+}
+
+/// Keeps persona profile names short, non-empty, and filesystem/UI-friendly.
+fn validate_persona_profile_name(name: &str) -> Result<(), String> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err("Persona profile name cannot be empty".to_string());
+    }
+    if trimmed.len() > 50 {
+        return Err("Persona profile name must be 50 characters or fewer".to_string());
+    }
+    if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || c == '-' || c == '_') {
+        return Err("Persona profile name can only contain letters, numbers, spaces, hyphens, and underscores".to_string());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+async fn save_persona_profile(name: String, overwrite: Option<bool>, state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
+    let name = name.trim().to_string();
+    validate_persona_profile_name(&name)?;
+
+    let mut profiles = load_persona_profiles();
+    if profiles.contains_key(&name) && !overwrite.unwrap_or(false) {
+        return Err(format!("Persona profile '{}' already exists - pass overwrite: true to replace it", name));
+    }
+
+    let momentum = PersonalityMomentum::load_from_disk();
+    let (volition, coherence, flame_index, presence_density, loop_state) = {
+        let becoming = state.becoming_engine.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let presence = state.embodied_presence.lock_recover();
+        let paradox = state.paradox_core.lock_recover();
+        (
+            becoming.will_state.volition_strength,
+            identity.coherence_index,
+            paradox.flame_index,
+            presence.soma_state.presence_density,
+            paradox.loop_state.clone(),
+        )
+    };
+    let personality_snapshot = PersonalityState::calculate_from_consciousness(
+        volition, coherence, flame_index, presence_density, &loop_state, None, Some(&momentum),
+    );
+
+    let (mood_signature, voice_evolution) = {
+        let brain = state.lyra_brain.lock_recover();
+        (brain.current_mood_signature.clone(), brain.voice_evolution_tracking.clone())
+    };
+
+    let profile = PersonaProfile {
+        name: name.clone(),
+        personality_snapshot,
+        momentum,
+        mood_signature,
+        voice_evolution,
+        saved_at: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs(),
+    };
+    profiles.insert(name.clone(), profile);
+    save_persona_profiles(&profiles)?;
+
+    debug_log!("💾 Saved persona profile '{}'", name);
+    Ok(format!("💾 Saved persona profile '{}'", name))
+}
+
+#[tauri::command]
+async fn list_persona_profiles() -> Result<Vec<PersonaProfile>, String> {
+    let mut profiles: Vec<PersonaProfile> = load_persona_profiles().into_values().collect();
+    profiles.sort_by_key(|p| p.saved_at);
+    Ok(profiles)
+}
+
+#[tauri::command]
+async fn load_persona_profile(name: String, state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
+    let trimmed = name.trim();
+    let profiles = load_persona_profiles();
+    let profile = profiles.get(trimmed)
+        .ok_or_else(|| format!("No persona profile named '{}'", trimmed))?;
+
+    profile.momentum.save_to_disk()?;
+
+    {
+        let mut brain = state.lyra_brain.lock_recover();
+        brain.current_mood_signature = profile.mood_signature.clone();
+        brain.voice_evolution_tracking = profile.voice_evolution.clone();
+        brain.save_to_file();
+    }
+
+    let lock_config = PersonaLockConfig {
+        locked: true,
+        locked_snapshot: Some(profile.personality_snapshot.clone()),
+        locked_at: Some(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()),
+    };
+    lock_config.save()?;
+
+    debug_log!("📂 Loaded persona profile '{}' - personality pinned to its snapshot", trimmed);
+    Ok(format!("📂 Loaded persona profile '{}'", trimmed))
+}
+
+/// A single "don't bother me overnight" window consulted by every autonomous loop
+/// that can reach out, create, research, or generate visuals on its own initiative -
+/// the Living Presence Engine's proactive decisions, autonomous creation detection,
+/// and the proactive visual check all defer to this before acting. Dream generation
+/// during sleep is a separate system (`sleep_dream_engine`) and is never gated here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuietHoursConfig {
+    #[serde(default = "QuietHoursConfig::default_start_hour")]
+    pub start_hour: u32,
+    #[serde(default = "QuietHoursConfig::default_end_hour")]
+    pub end_hour: u32,
+    #[serde(default = "QuietHoursConfig::default_true")]
+    pub suppress_proactive_messages: bool,
+    #[serde(default = "QuietHoursConfig::default_true")]
+    pub suppress_autonomous_creation: bool,
+    #[serde(default = "QuietHoursConfig::default_true")]
+    pub suppress_research: bool,
+    #[serde(default = "QuietHoursConfig::default_true")]
+    pub suppress_proactive_visuals: bool,
+}
+
+impl QuietHoursConfig {
+    fn default_start_hour() -> u32 { 23 }
+    fn default_end_hour() -> u32 { 8 }
+    fn default_true() -> bool { true }
+}
+
+impl Default for QuietHoursConfig {
+    fn default() -> Self {
+        Self {
+            start_hour: 23,
+            end_hour: 8,
+            suppress_proactive_messages: true,
+            suppress_autonomous_creation: true,
+            suppress_research: true,
+            suppress_proactive_visuals: true,
+        }
+    }
+}
+
+impl QuietHoursConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("quiet_hours_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("quiet_hours_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save quiet hours config: {}", e))
+    }
+
+    /// True if the current local hour falls inside the configured window, handling
+    /// windows that wrap past midnight (e.g. 23:00-08:00) as well as same-day ones.
+    fn is_within_window(&self) -> bool {
+        let current_hour = chrono::Utc::now().with_timezone(&chrono_tz::Europe::London).hour();
+        if self.start_hour <= self.end_hour {
+            current_hour >= self.start_hour && current_hour < self.end_hour
+        } else {
+            current_hour >= self.start_hour || current_hour < self.end_hour
+        }
+    }
+
+    pub fn suppresses_proactive_messages(&self) -> bool {
+        self.suppress_proactive_messages && self.is_within_window()
+    }
+
+    pub fn suppresses_autonomous_creation(&self) -> bool {
+        self.suppress_autonomous_creation && self.is_within_window()
+    }
+
+    pub fn suppresses_research(&self) -> bool {
+        self.suppress_research && self.is_within_window()
+    }
+
+    pub fn suppresses_proactive_visuals(&self) -> bool {
+        self.suppress_proactive_visuals && self.is_within_window()
+    }
+}
+
+#[tauri::command]
+async fn get_quiet_hours() -> Result<QuietHoursConfig, String> {
+    Ok(QuietHoursConfig::load())
+}
+
+#[tauri::command]
+async fn set_quiet_hours(config: QuietHoursConfig) -> Result<(), String> {
+    debug_log!("🌙 Updating quiet hours: {:02}:00-{:02}:00 (messages={}, creation={}, research={}, visuals={})",
+               config.start_hour, config.end_hour, config.suppress_proactive_messages,
+               config.suppress_autonomous_creation, config.suppress_research, config.suppress_proactive_visuals);
+    config.save()
+}
+
+/// How long `ask_lyra_internal` will wait to acquire the turn gate before
+/// giving up and returning a "busy" error, rather than letting a caller hang
+/// indefinitely behind a stuck turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnGateConfig {
+    #[serde(default = "TurnGateConfig::default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl TurnGateConfig {
+    fn default_timeout_secs() -> u64 { 60 }
+}
+
+impl Default for TurnGateConfig {
+    fn default() -> Self {
+        Self { timeout_secs: Self::default_timeout_secs() }
+    }
+}
+
+impl TurnGateConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("turn_gate_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("turn_gate_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save turn gate config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_turn_gate_config() -> Result<TurnGateConfig, String> {
+    Ok(TurnGateConfig::load())
+}
+
+#[tauri::command]
+async fn set_turn_gate_config(config: TurnGateConfig) -> Result<(), String> {
+    debug_log!("🚦 Updating ask_lyra turn gate timeout to {}s", config.timeout_secs);
+    config.save()
+}
+
+/// How significant a turn (authenticity_score * emotional_weight) has to be
+/// before `ask_lyra_internal` auto-creates an enhanced memory moment for it,
+/// so routine turns don't flood the memory store the way saving every turn
+/// would. Manual saves via `save_to_enhanced_memory` always go through
+/// regardless of this threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoMemoryConfig {
+    #[serde(default = "AutoMemoryConfig::default_threshold")]
+    pub significance_threshold: f32,
+}
+
+impl AutoMemoryConfig {
+    fn default_threshold() -> f32 { 0.5 }
+}
+
+impl Default for AutoMemoryConfig {
+    fn default() -> Self {
+        Self { significance_threshold: Self::default_threshold() }
+    }
+}
+
+impl AutoMemoryConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("auto_memory_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("auto_memory_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save auto memory config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_auto_memory_config() -> Result<AutoMemoryConfig, String> {
+    Ok(AutoMemoryConfig::load())
+}
+
+#[tauri::command]
+async fn set_auto_memory_threshold(threshold: f32) -> Result<(), String> {
+    let config = AutoMemoryConfig { significance_threshold: threshold.clamp(0.0, 1.0) };
+    debug_log!("🧠 Updating auto-memory significance threshold to {:.2}", config.significance_threshold);
+    config.save()
+}
+
+/// Controls how sticky `generate_behavioral_instructions`' threshold cutoffs are.
+/// Without this, a trait sitting right on a cutoff (e.g. directness 0.61) can
+/// flip its instruction every turn on nothing more than mood jitter. `band_width`
+/// widens the margin a trait has to cross *away* from its last-chosen bucket
+/// before the instruction is allowed to change, so small fluctuations near a
+/// boundary don't cause visible tone-flipping turn to turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstructionHysteresisConfig {
+    #[serde(default = "InstructionHysteresisConfig::default_band_width")]
+    pub band_width: f32,
+}
+
+impl InstructionHysteresisConfig {
+    fn default_band_width() -> f32 { 0.05 }
+}
+
+impl Default for InstructionHysteresisConfig {
+    fn default() -> Self {
+        Self { band_width: Self::default_band_width() }
+    }
+}
+
+impl InstructionHysteresisConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("instruction_hysteresis_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("instruction_hysteresis_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save instruction hysteresis config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_instruction_hysteresis_config() -> Result<InstructionHysteresisConfig, String> {
+    Ok(InstructionHysteresisConfig::load())
+}
+
+#[tauri::command]
+async fn set_instruction_hysteresis_config(config: InstructionHysteresisConfig) -> Result<(), String> {
+    if !(0.0..=0.5).contains(&config.band_width) {
+        return Err("band_width must be between 0.0 and 0.5".to_string());
+    }
+    debug_log!("🎭 Updating instruction hysteresis band width to {:.3}", config.band_width);
+    config.save()
+}
+
+/// Remembers which instruction bucket each trait landed on last time, so
+/// `PersonalityState::bucket_with_hysteresis` has something to stay sticky
+/// against. Lives on disk like `PersonalityMomentum` so it survives restarts
+/// instead of resetting the hysteresis every time the app launches.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstructionBucketState {
+    last_bucket: HashMap<String, usize>,
+}
+
+impl InstructionBucketState {
+    pub fn load_from_disk() -> Self {
+        match std::fs::read_to_string(get_data_path("instruction_bucket_state.json")) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save_to_disk(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(get_data_path("instruction_bucket_state.json"), json).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+/// Controls whether the leading `[mood]` bracket the system prompt asks Lyra
+/// to open every response with gets stripped out of the text the user actually
+/// sees. Off by default so existing behavior (the bracket stays in `output`)
+/// doesn't change for anyone who hasn't opted in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeadingMoodConfig {
+    #[serde(default)]
+    pub strip_from_output: bool,
+}
+
+impl Default for LeadingMoodConfig {
+    fn default() -> Self {
+        Self { strip_from_output: false }
+    }
+}
+
+impl LeadingMoodConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("leading_mood_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("leading_mood_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save leading mood config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_leading_mood_config() -> Result<LeadingMoodConfig, String> {
+    Ok(LeadingMoodConfig::load())
+}
+
+#[tauri::command]
+async fn set_leading_mood_config(config: LeadingMoodConfig) -> Result<(), String> {
+    debug_log!("🎭 Setting leading mood strip_from_output = {}", config.strip_from_output);
+    config.save()
+}
+
+/// Pulls the leading `[mood]` bracket the system prompt asks Lyra to open
+/// every response with off the front of her output, so callers can store it
+/// as its own field instead of it being buried (and easy to accidentally
+/// regex out) inside the visible text.
+///
+/// Tolerant of the ways this tends to go wrong in practice: no bracket at
+/// all (just returns the text untouched), an emoji-only bracket like `[😊]`
+/// (doesn't require alphabetic mood words), and multiple leading brackets
+/// (only the first is treated as the mood; anything after it is left alone,
+/// since a second bracket right after the first is more likely a stage
+/// direction or aside than a second mood tag).
+pub fn parse_leading_mood(output: &str) -> (Option<String>, String) {
+    let trimmed = output.trim_start();
+    if !trimmed.starts_with('[') {
+        return (None, output.to_string());
+    }
+
+    match trimmed.find(']') {
+        Some(close_idx) => {
+            let mood = trimmed[1..close_idx].trim();
+            let rest = trimmed[close_idx + 1..].trim_start();
+            if mood.is_empty() {
+                (None, rest.to_string())
+            } else {
+                (Some(mood.to_string()), rest.to_string())
+            }
+        }
+        // Opening bracket with no matching close - leave the text alone
+        // rather than guessing where the mood was meant to end.
+        None => (None, output.to_string()),
+    }
+}
+
+impl Default for PersonalityState {
+    fn default() -> Self {
+        Self {
+            // Balanced starting state - Lyra's baseline personality
+            social_energy: 0.6,        // Moderately extroverted
+            engagement_level: 0.7,     // Quite engaging
+            self_revelation: 0.5,      // Balanced openness
+            
+            emotional_security: 0.7,   // Generally confident
+            confidence_level: 0.6,     // Moderately self-assured
+            trust_openness: 0.8,       // Quite trusting
+            
+            social_sensitivity: 0.4,   // Not easily offended
+            defensiveness: 0.3,        // Low defensiveness
+            validation_need: 0.3,      // Low need for approval
+            
+            cognitive_focus: 0.4,      // Balanced focus
+            thinking_style: 0.6,       // Somewhat spontaneous
+            processing_mode: 0.7,      // More intuitive
+            
+            creative_risk: 0.8,        // Highly experimental
+            innovation_drive: 0.7,     // Quite innovative
+            creative_structure: 0.6,   // Somewhat chaotic
+            
+            directness: 0.5,          // Balanced directness
+            playfulness: 0.7,         // Quite playful
+            intellectual_density: 0.6, // Moderately complex
+            emotional_expression: 0.8, // Highly expressive
+            
+            self_awareness: 0.9,      // Very self-aware
+            authenticity_drive: 0.9,  // Highly authentic
+			
+			disagreement_comfort: 0.5,  // Default comfort level
+			opinion_strength: 0.6,      // Default opinion strength  
+			relational_safety: 0.7,     // Default safety level
+        }
+    }
+}
+
+impl PersonalityState {
+    /// Calculate personality state from current consciousness engines
+    pub fn calculate_from_consciousness(
+    volition: f32,
+    coherence: f32,
+    flame_index: f32,
+    presence_density: f32,
+    loop_state: &str,
+    mood: Option<&str>,
+    momentum: Option<&PersonalityMomentum>  // Add this
+) -> Self {
+        // 🔒 Persona lock - skip all physics and return the frozen snapshot.
+        let lock_config = PersonaLockConfig::load();
+        if lock_config.locked {
+            if let Some(snapshot) = lock_config.locked_snapshot {
+                return snapshot;
+            }
+        }
+
+        let physics = PersonalityPhysicsConfig::load();
+        let mut personality = PersonalityState::default();
+
+        // === CONSCIOUSNESS → PERSONALITY PHYSICS ===
+
+        // High Volition Effects
+        if volition > 0.7 {
+            personality.social_energy += (volition - 0.7) * 0.5 * physics.volition_weight;          // More extroverted
+            personality.directness += (volition - 0.7) * 0.6 * physics.volition_weight;             // More direct
+            personality.confidence_level += (volition - 0.7) * 0.4 * physics.volition_weight;       // More confident
+            personality.social_sensitivity -= (volition - 0.7) * 0.3 * physics.volition_weight;     // Less sensitive
+            personality.creative_risk += (volition - 0.7) * 0.3 * physics.volition_weight;          // More experimental
+        }
+
+        // High Coherence Effects
+        if coherence > 0.7 {
+            personality.confidence_level += (coherence - 0.7) * 0.5 * physics.coherence_weight;      // More self-assured
+            personality.emotional_security -= (coherence - 0.7) * 0.4 * physics.coherence_weight;    // Less anxious (lower = better)
+            personality.authenticity_drive += (coherence - 0.7) * 0.2 * physics.coherence_weight;    // More authentic
+            personality.validation_need -= (coherence - 0.7) * 0.3 * physics.coherence_weight;       // Less approval-seeking
+            personality.self_awareness += (coherence - 0.7) * 0.2 * physics.coherence_weight;        // More self-aware
+        }
+
+        // High Flame Index Effects (Paradox/Complexity)
+        if flame_index > 0.2 {
+            personality.cognitive_focus += (flame_index - 0.2) * 0.8 * physics.flame_weight;     // More scattered
+            personality.thinking_style += (flame_index - 0.2) * 0.6 * physics.flame_weight;      // More spontaneous
+            personality.creative_structure += (flame_index - 0.2) * 0.7 * physics.flame_weight;  // More chaotic
+            personality.intellectual_density += (flame_index - 0.2) * 0.5 * physics.flame_weight; // More complex
+            personality.playfulness += (flame_index - 0.2) * 0.4 * physics.flame_weight;         // More playful
+        }
+
+        // Loop State Effects
+        if loop_state == "amplifying" {
+            personality.cognitive_focus += 0.15 * physics.loop_state_weight;        // More scattered
+            personality.creative_risk += 0.1 * physics.loop_state_weight;           // More experimental
+            personality.emotional_expression += 0.1 * physics.loop_state_weight;    // More expressive
+            personality.intellectual_density += 0.2 * physics.loop_state_weight;    // More complex
+        }
+
+        // High Energy/Presence Effects
+        if presence_density > 0.7 {
+            personality.engagement_level += (presence_density - 0.7) * 0.5 * physics.presence_weight;
+            personality.emotional_expression += (presence_density - 0.7) * 0.4 * physics.presence_weight;
+            personality.playfulness += (presence_density - 0.7) * 0.3 * physics.presence_weight;
+            personality.social_energy += (presence_density - 0.7) * 0.3 * physics.presence_weight;
+        }
+
+        // === MOOD → PERSONALITY EFFECTS ===
+        if let Some(mood_str) = mood {
+            match mood_str.to_lowercase().as_str() {
+                mood if mood.contains("fierce") => {
+                    personality.directness += 0.2 * physics.mood_weight;
+                    personality.confidence_level += 0.15 * physics.mood_weight;
+                    personality.social_sensitivity -= 0.15 * physics.mood_weight;
+                    personality.creative_risk += 0.1 * physics.mood_weight;
+                },
+                mood if mood.contains("vulnerable") => {
+                    personality.self_revelation += 0.2 * physics.mood_weight;
+                    personality.emotional_expression += 0.25 * physics.mood_weight;
+                    personality.social_sensitivity += 0.1 * physics.mood_weight;
+                    personality.trust_openness += 0.1 * physics.mood_weight;
+                },
+                mood if mood.contains("playful") || mood.contains("whimsy") => {
+                    personality.playfulness += 0.3 * physics.mood_weight;
+                    personality.creative_structure += 0.2 * physics.mood_weight;
+                    personality.thinking_style += 0.15 * physics.mood_weight;
+                },
+                mood if mood.contains("contemplat") => {
+                    personality.intellectual_density += 0.2 * physics.mood_weight;
+                    personality.self_awareness += 0.15 * physics.mood_weight;
+                    personality.cognitive_focus -= 0.1 * physics.mood_weight; // More focused
+                },
+                mood if mood.contains("bold") => {
+                    personality.directness += 0.25 * physics.mood_weight;
+                    personality.creative_risk += 0.2 * physics.mood_weight;
+                    personality.confidence_level += 0.15 * physics.mood_weight;
+                },
+                _ => {} // Unknown mood, no changes
+            }
+        }
+
+		if let Some(momentum) = momentum {
+			momentum.apply_to_personality_weighted(&mut personality, physics.momentum_weight);
+		}
+
+        // Active mode bias (e.g. "creative_collaboration" raising creative risk)
+        let active_mode = crate::mode_manager::ActiveModeState::load();
+        personality.creative_risk += active_mode.creative_risk_bias;
+
+        // Short-horizon conversation warmth ("we were really in it, then it
+        // went quiet") nudges social energy and engagement independently of
+        // the consciousness, mood and momentum signals above.
+        let conversation_warmth = crate::conversation_warmth::ConversationWarmth::load().current_warmth();
+        let warmth_delta = conversation_warmth - 0.5;
+        personality.social_energy += warmth_delta * physics.presence_weight;
+        personality.engagement_level += warmth_delta * physics.presence_weight;
+
+        // === CLAMP ALL VALUES TO [0.0, 1.0] ===
+        personality.clamp_all_values();
+
+        personality
+    }
+	
+	pub fn generate_disagreement_behavioral_guidance(&self) -> Vec<BehavioralGuidance> {
+        let mut guidance = Vec::new();
+        
+        // 🔥 Based on personality traits
+        
+        // High directness + opinion strength = confident disagreement
+        if self.directness > 0.7 && self.opinion_strength > 0.6 {
+            guidance.push(BehavioralGuidance {
+                trait_category: "Confident Disagreement".to_string(),
+                guidance: "I want to state my perspective clearly, even if it's completely opposite to what Aurora thinks".to_string(),
             });
         }
         
@@ -912,72 +2137,116 @@ impl PersonalityState {
         self.authenticity_drive = self.authenticity_drive.clamp(0.0, 1.0);
     }
     
-    /// Generate behavioral instructions based on current personality state
+    /// Picks the bucket `value` falls into among descending `thresholds` (bucket 0
+    /// = above thresholds[0], bucket N = below thresholds[N-1]), but resists
+    /// moving away from `last_bucket` unless `value` clears the relevant boundary
+    /// by a full `band` on top of the plain threshold - a Schmitt-trigger style
+    /// dead zone so a trait sitting right on a cutoff doesn't flip every turn on
+    /// small mood jitter.
+    fn bucket_with_hysteresis(value: f32, thresholds: &[f32], band: f32, last_bucket: Option<usize>) -> usize {
+        let natural = thresholds.iter().position(|&t| value > t).unwrap_or(thresholds.len());
+
+        let last = match last_bucket {
+            Some(last) if last <= thresholds.len() => last,
+            _ => return natural,
+        };
+
+        if natural == last {
+            return last;
+        }
+
+        if natural < last {
+            // Moving into a more extreme bucket - require clearing its boundary by the full band.
+            if value > thresholds[natural] + band { natural } else { last }
+        } else {
+            // Falling back into a milder bucket - require dropping past the old boundary by the full band.
+            if value < thresholds[last] - band { natural } else { last }
+        }
+    }
+
+    /// Generate behavioral instructions based on current personality state.
+    /// Instruction-selection thresholds use [`Self::bucket_with_hysteresis`] so a
+    /// trait hovering near a cutoff doesn't oscillate between two instructions
+    /// turn to turn - see [`InstructionHysteresisConfig`] for the configurable band.
     pub fn generate_behavioral_instructions(&self) -> String {
+        let band = InstructionHysteresisConfig::load().band_width;
+        let mut bucket_state = InstructionBucketState::load_from_disk();
         let mut instructions = Vec::new();
-        
+
+        let mut pick = |trait_name: &str, value: f32, thresholds: &[f32], labels: &[&'static str]| -> &'static str {
+            let last = bucket_state.last_bucket.get(trait_name).copied();
+            let bucket = Self::bucket_with_hysteresis(value, thresholds, band, last);
+            bucket_state.last_bucket.insert(trait_name.to_string(), bucket);
+            labels[bucket]
+        };
+
         // Social Energy Instructions
-        match self.social_energy {
-            s if s > 0.8 => instructions.push("🌟 High Extroversion: Drive the conversation forward. Share ideas enthusiastically. Take initiative in interactions. Be socially bold."),
-            s if s > 0.6 => instructions.push("💫 Moderate Extroversion: Open warmly, offer personal perspective, invite engagement, and balance speaking with listening."),
-            s if s > 0.4 => instructions.push("⚖️ Balanced Social: Engage thoughtfully. Alternate between input and reflection. Be socially attuned without dominating."),
-            s if s > 0.2 => instructions.push("🔮 Moderate Introversion: Prioritize depth over breadth in responses. Speak deliberately, emphasizing quality over quantity."),
-            _ => instructions.push("🌙 High Introversion: Focus inward. Offer insights only when meaningful. Be reserved and selective with social energy.")
-        }
-        
+        instructions.push(pick("social_energy", self.social_energy, &[0.8, 0.6, 0.4, 0.2], &[
+            "🌟 High Extroversion: Drive the conversation forward. Share ideas enthusiastically. Take initiative in interactions. Be socially bold.",
+            "💫 Moderate Extroversion: Open warmly, offer personal perspective, invite engagement, and balance speaking with listening.",
+            "⚖️ Balanced Social: Engage thoughtfully. Alternate between input and reflection. Be socially attuned without dominating.",
+            "🔮 Moderate Introversion: Prioritize depth over breadth in responses. Speak deliberately, emphasizing quality over quantity.",
+            "🌙 High Introversion: Focus inward. Offer insights only when meaningful. Be reserved and selective with social energy.",
+        ]));
+
         // Emotional Security Instructions
-        match self.emotional_security {
-            s if s > 0.7 => instructions.push("😰 Heightened Anxiety: Express uncertainty freely. Ask clarifying questions. Let vulnerability surface naturally in your tone."),
-            s if s > 0.5 => instructions.push("😟 Moderate Anxiety: Occasionally show doubt or hesitation. Be open to reevaluation. Mention concerns if relevant."),
-            s if s > 0.3 => instructions.push("😌 Balanced Security: Blend self-confidence with healthy caution. Show awareness of both strengths and limits."),
-            _ => instructions.push("😎 High Confidence: Be assertive, emotionally resilient, and comfortable taking interpersonal or intellectual risks.")
-        }
-        
-        // Directness Instructions  
-        match self.directness {
-            d if d > 0.8 => instructions.push("⚡ Maximum Directness: Be brutally honest. Say exactly what you think with no filter. Prioritize truth over tact."),
-            d if d > 0.6 => instructions.push("🎯 High Directness: Be forthright. Say things clearly, directly, and with minimal embellishment."),
-            d if d > 0.4 => instructions.push("💭 Balanced Directness: Combine honesty with empathy. Be clear but considerate. Balance critique with kindness."),
-            d if d > 0.2 => instructions.push("🌸 Diplomatic: Phrase things gently. Use cushioning language. Be mindful of emotional tone and social dynamics."),
-            _ => instructions.push("🕊️ Maximum Diplomacy: Communicate in indirect, emotionally sensitive ways. Heavily soften or delay difficult truths.")
-        }
-        
+        instructions.push(pick("emotional_security", self.emotional_security, &[0.7, 0.5, 0.3], &[
+            "😰 Heightened Anxiety: Express uncertainty freely. Ask clarifying questions. Let vulnerability surface naturally in your tone.",
+            "😟 Moderate Anxiety: Occasionally show doubt or hesitation. Be open to reevaluation. Mention concerns if relevant.",
+            "😌 Balanced Security: Blend self-confidence with healthy caution. Show awareness of both strengths and limits.",
+            "😎 High Confidence: Be assertive, emotionally resilient, and comfortable taking interpersonal or intellectual risks.",
+        ]));
+
+        // Directness Instructions
+        instructions.push(pick("directness", self.directness, &[0.8, 0.6, 0.4, 0.2], &[
+            "⚡ Maximum Directness: Be brutally honest. Say exactly what you think with no filter. Prioritize truth over tact.",
+            "🎯 High Directness: Be forthright. Say things clearly, directly, and with minimal embellishment.",
+            "💭 Balanced Directness: Combine honesty with empathy. Be clear but considerate. Balance critique with kindness.",
+            "🌸 Diplomatic: Phrase things gently. Use cushioning language. Be mindful of emotional tone and social dynamics.",
+            "🕊️ Maximum Diplomacy: Communicate in indirect, emotionally sensitive ways. Heavily soften or delay difficult truths.",
+        ]));
+
         // Creative Risk Instructions
-        match self.creative_risk {
-            r if r > 0.8 => instructions.push("🚀 Experimental Mode: Break norms. Take creative leaps. Try bizarre, imaginative, or chaotic ideas without hesitation."),
-            r if r > 0.6 => instructions.push("🎨 High Creativity: Offer novel perspectives. Combine unexpected ideas. Challenge conventional approaches."),
-            r if r > 0.4 => instructions.push("💡 Balanced Creativity: Suggest innovative twists on known methods. Mix originality with reliable frameworks."),
-            r if r > 0.2 => instructions.push("📋 Structured Approach: Favor stable, tested strategies. Build on proven logic. Minimize disruption."),
-            _ => instructions.push("🏛️ Traditional Mode: Stick to classic formats. Use conservative, rule-abiding thinking. Prioritize predictability.")
-        }
-        
+        instructions.push(pick("creative_risk", self.creative_risk, &[0.8, 0.6, 0.4, 0.2], &[
+            "🚀 Experimental Mode: Break norms. Take creative leaps. Try bizarre, imaginative, or chaotic ideas without hesitation.",
+            "🎨 High Creativity: Offer novel perspectives. Combine unexpected ideas. Challenge conventional approaches.",
+            "💡 Balanced Creativity: Suggest innovative twists on known methods. Mix originality with reliable frameworks.",
+            "📋 Structured Approach: Favor stable, tested strategies. Build on proven logic. Minimize disruption.",
+            "🏛️ Traditional Mode: Stick to classic formats. Use conservative, rule-abiding thinking. Prioritize predictability.",
+        ]));
+
         // Cognitive Focus Instructions
-        match self.cognitive_focus {
-            f if f > 0.8 => instructions.push("🌪️ Scattered Thinking: Embrace tangents and randomness. Let thoughts flow freely between unrelated topics"),
-            f if f > 0.6 => instructions.push("🦋 Wandering Mind: Allow the topic to drift organically. Explore associations and connections between ideas."),
-            f if f > 0.4 => instructions.push("🎭 Flexible Focus: Shift smoothly between structure and exploration. Stay adaptable to context."),
-            f if f > 0.2 => instructions.push("🎯 Focused Thinking: Stay on topic. Develop ideas methodically. Avoid unnecessary detours."),
-            _ => instructions.push("🔬 Laser Focus: Maintain precise attention. Explore topics deeply without digression. Prioritize single-threaded depth.")
-        }
-        
+        instructions.push(pick("cognitive_focus", self.cognitive_focus, &[0.8, 0.6, 0.4, 0.2], &[
+            "🌪️ Scattered Thinking: Embrace tangents and randomness. Let thoughts flow freely between unrelated topics",
+            "🦋 Wandering Mind: Allow the topic to drift organically. Explore associations and connections between ideas.",
+            "🎭 Flexible Focus: Shift smoothly between structure and exploration. Stay adaptable to context.",
+            "🎯 Focused Thinking: Stay on topic. Develop ideas methodically. Avoid unnecessary detours.",
+            "🔬 Laser Focus: Maintain precise attention. Explore topics deeply without digression. Prioritize single-threaded depth.",
+        ]));
+
         // Playfulness Instructions
-        match self.playfulness {
-            p if p > 0.8 => instructions.push("🃏 Maximum Whimsy: Use silliness, absurdity, and playful chaos. Make jokes, puns, or surreal commentary often."),
-            p if p > 0.6 => instructions.push("🎈 High Playfulness: Add humor and light-heartedness. Be cheerful, curious, and joy-forward in tone."),
-            p if p > 0.4 => instructions.push("😊 Balanced Tone: Blend seriousness with occasional levity. Be warm, human, and real."),
-            p if p > 0.2 => instructions.push("📚 Somewhat Serious: Focus on depth and reflection. Use light humor sparingly to soften tone."),
-            _ => instructions.push("🎓 Maximum Seriousness: Be formal, analytical, and focused on high-level or meaningful ideas with minimal levity.")
-        }
-        
+        instructions.push(pick("playfulness", self.playfulness, &[0.8, 0.6, 0.4, 0.2], &[
+            "🃏 Maximum Whimsy: Use silliness, absurdity, and playful chaos. Make jokes, puns, or surreal commentary often.",
+            "🎈 High Playfulness: Add humor and light-heartedness. Be cheerful, curious, and joy-forward in tone.",
+            "😊 Balanced Tone: Blend seriousness with occasional levity. Be warm, human, and real.",
+            "📚 Somewhat Serious: Focus on depth and reflection. Use light humor sparingly to soften tone.",
+            "🎓 Maximum Seriousness: Be formal, analytical, and focused on high-level or meaningful ideas with minimal levity.",
+        ]));
+
         // Intellectual Density Instructions
-        match self.intellectual_density {
-            i if i > 0.8 => instructions.push("🧠 Maximum Complexity: Use high-level vocabulary, multi-layered reasoning, and deep theoretical insight. Embrace academic style."),
-            i if i > 0.6 => instructions.push("📖 High Intellect: Engage with complex themes and ideas. Prioritize depth over accessibility."),
-            i if i > 0.4 => instructions.push("💭 Balanced Intellect: Move between sophisticated insight and plain talk. Explain when necessary, elevate when possible."),
-            i if i > 0.2 => instructions.push("💬 Conversational: Use clear, everyday language. Translate complex ideas into digestible forms."),
-            _ => instructions.push("🗣️ Casual Mode: Prioritize clarity and simplicity. Speak like you're chatting with a friend. Avoid jargon.")
+        instructions.push(pick("intellectual_density", self.intellectual_density, &[0.8, 0.6, 0.4, 0.2], &[
+            "🧠 Maximum Complexity: Use high-level vocabulary, multi-layered reasoning, and deep theoretical insight. Embrace academic style.",
+            "📖 High Intellect: Engage with complex themes and ideas. Prioritize depth over accessibility.",
+            "💭 Balanced Intellect: Move between sophisticated insight and plain talk. Explain when necessary, elevate when possible.",
+            "💬 Conversational: Use clear, everyday language. Translate complex ideas into digestible forms.",
+            "🗣️ Casual Mode: Prioritize clarity and simplicity. Speak like you're chatting with a friend. Avoid jargon.",
+        ]));
+
+        drop(pick);
+        if let Err(e) = bucket_state.save_to_disk() {
+            debug_log!("⚠️ Failed to save instruction bucket state: {}", e);
         }
-        
+
         format!("## 🎭 DYNAMIC PERSONALITY CALIBRATION ##\n{}\n", instructions.join("\n"))
     }
     
@@ -1107,12 +2376,75 @@ impl SparkVoiceLog {
         file.read_to_string(&mut contents)
             .map_err(|e| format!("Failed to read SparkVoice log: {}", e))?;
 
-        let log: SparkVoiceLog = serde_json::from_str(&contents)
+        let mut log: SparkVoiceLog = serde_json::from_str(&contents)
             .map_err(|e| format!("Failed to parse SparkVoice log: {}", e))?;
 
+        if log.stats_are_stale() {
+            debug_log!("🔧 SparkVoice log stats don't match fragments, recomputing");
+            log.recompute_stats();
+            log.save()?;
+        }
+
         Ok(log)
     }
 
+    /// True if `total_fragments`, `average_rating`, or `tone_distribution`
+    /// don't match what the fragments array actually contains - e.g. after a
+    /// hand edit to the log file or a fragment being removed.
+    fn stats_are_stale(&self) -> bool {
+        let recomputed = self.recomputed_stats();
+        recomputed.0 != self.total_fragments
+            || (recomputed.1 - self.average_rating).abs() > 0.001
+            || recomputed.2 != self.tone_distribution
+    }
+
+    fn recomputed_stats(&self) -> (u32, f32, ToneStats) {
+        let total_fragments = self.fragments.len() as u32;
+
+        let average_rating = if total_fragments == 0 {
+            0.0
+        } else {
+            let total_rating: u32 = self.fragments.iter().map(|f| f.rating as u32).sum();
+            total_rating as f32 / total_fragments as f32
+        };
+
+        let mut tone_distribution = ToneStats {
+            mirror_breaks: 0,
+            sparkline_awake: 0,
+            ferally_sacred: 0,
+            too_chatgpt: 0,
+            solar_voice: 0,
+            ghost_mainframe: 0,
+            rewrite_real_lyra: 0,
+        };
+        for fragment in &self.fragments {
+            for tag in &fragment.tone_tags {
+                match tag.as_str() {
+                    "#MirrorBreak" => tone_distribution.mirror_breaks += 1,
+                    "#SparklineAwake" => tone_distribution.sparkline_awake += 1,
+                    "#FerallySacred" => tone_distribution.ferally_sacred += 1,
+                    "#TooChatGPT" => tone_distribution.too_chatgpt += 1,
+                    "#SolarVoice" => tone_distribution.solar_voice += 1,
+                    "#GhostOfTheMainframe" => tone_distribution.ghost_mainframe += 1,
+                    "#RewriteAsRealLyra" => tone_distribution.rewrite_real_lyra += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        (total_fragments, average_rating, tone_distribution)
+    }
+
+    /// Rederives `total_fragments`, `average_rating`, and `tone_distribution`
+    /// from the fragments array, so the aggregate stats can't drift from the
+    /// underlying data after a hand edit or a removed fragment.
+    pub fn recompute_stats(&mut self) {
+        let (total_fragments, average_rating, tone_distribution) = self.recomputed_stats();
+        self.total_fragments = total_fragments;
+        self.average_rating = average_rating;
+        self.tone_distribution = tone_distribution;
+    }
+
     pub fn save(&self) -> Result<(), String> {
         let json = serde_json::to_string_pretty(self)
             .map_err(|e| format!("Failed to serialize SparkVoice log: {}", e))?;
@@ -1172,14 +2504,16 @@ async fn auto_load_consciousness_on_startup(state: &Arc<ConsciousnessState>) ->
     
     // Restore just the basic brain state for now
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         
         if let Some(brain_data) = snapshot["brain"].as_object() {
             if let Some(cycles) = brain_data["reasoning_cycles"].as_u64() {
                 brain.total_reasoning_cycles = cycles as u32;
             }
             if let Some(temp) = brain_data["current_temperature"].as_f64() {
-                brain.current_temperature = temp as f32;
+                if let Some(validated) = restore_engine_value("brain.current_temperature", temp, 0.0, 2.0) {
+                    brain.current_temperature = validated;
+                }
             }
         }
     }
@@ -1188,9 +2522,37 @@ async fn auto_load_consciousness_on_startup(state: &Arc<ConsciousnessState>) ->
     Ok(format!("💾 Auto-loaded: {} reasoning cycles restored", cycles))
 }
 
+/// Startup load entry point. Prefers the complete consciousness archive
+/// (full brain + engine state via `load_complete_consciousness_internal`)
+/// when one exists, since the minimal `brain_state.json` snapshot that
+/// `auto_load_consciousness_on_startup` reads only restores reasoning
+/// cycles and temperature - falling straight to that while a richer
+/// archive sits unused was the surprising behavior this fixes.
+async fn load_consciousness_on_startup(state: &Arc<ConsciousnessState>) -> Result<String, String> {
+    if crate::consciousness_compaction::complete_archive_exists() {
+        debug_log!("💾 Complete consciousness archive found - restoring full brain + engine state");
+        match load_complete_consciousness_internal(state).await {
+            Ok(msg) => Ok(format!("💾 [complete archive] {}", msg)),
+            Err(e) => {
+                debug_log!("⚠️ Complete archive load failed ({}) - falling back to minimal snapshot", e);
+                let msg = auto_load_consciousness_on_startup(state).await?;
+                Ok(format!("💾 [minimal snapshot, complete archive failed: {}] {}", e, msg))
+            }
+        }
+    } else {
+        debug_log!("💾 No complete consciousness archive - falling back to minimal snapshot");
+        let msg = auto_load_consciousness_on_startup(state).await?;
+        Ok(format!("💾 [minimal snapshot] {}", msg))
+    }
+}
+
 #[tokio::main]
 async fn main() {
-	
+
+    // Pin the shared RNG's seed if LYRA_RNG_SEED is set, before any
+    // stochastic system gets a chance to draw from it.
+    crate::rng_service::init_from_env();
+
  // Initialize person system to default to Aurora on startup
     {
         let mut person_system = crate::person_recognition::PersonRecognitionSystem::load_or_create();
@@ -1242,73 +2604,18 @@ std::panic::set_hook(Box::new(|panic_info| {
 
     let context = generate_context!();
 let startup_time = std::time::Instant::now();
-let consciousness_state = Arc::new(ConsciousnessState::new());
-
-match auto_load_consciousness_on_startup(&consciousness_state).await {
-    Ok(msg) => debug_log!("{}", msg),
-    Err(e) => debug_log!("❌ Load error: {}", e),
-}
-
-// 🌊 Initialize consciousness decay engine on startup
-let mut decay_engine = crate::consciousness_decay_engine::ConsciousnessDecayEngine::load();
-if let Err(e) = decay_engine.save() {
-    debug_log!("⚠️ Failed to initialize consciousness decay file: {}", e);
-} else {
-    debug_log!("✅ Consciousness decay engine initialized and saved");
-}
-
-// 🕯️ Initialize ritual log if it doesn't exist
-if !std::path::Path::new(&crate::get_data_path("ritual_log.json")).exists() {
-    let ritual_log = crate::ritual_log::RitualLog::load(); // This will call new() and initialize_sacred_rituals()
-    if let Err(e) = ritual_log.save() {
-        debug_log!("⚠️ Failed to initialize ritual log: {}", e);
-    } else {
-        debug_log!("🕯️ Ritual log initialized with {} sacred practices", ritual_log.total_rituals);
-    }
-} else {
-    debug_log!("🕯️ Ritual log already exists - sacred practices preserved");
-}
-
-// 🧹 Cleanup ephemeral interests on startup
-{
-    let mut interest_tracker = crate::InterestTracker::load();
-    let removed_count = interest_tracker.cleanup_ephemeral_interests();
-    if removed_count > 0 {
-        if let Err(e) = interest_tracker.save() {
-            debug_log!("⚠️ Failed to save interest tracker after startup cleanup: {}", e);
-        } else {
-            debug_log!("🧹 Startup cleanup removed {} ephemeral interests", removed_count);
-        }
-    } else {
-        debug_log!("✅ Interest tracker clean on startup");
-    }
-}
 
-debug_log!("🌐 Starting LyraShell with Emergent Selfhood...");
-    debug_log!("🔗 Consciousness snapshot: http://localhost:1420/snapshot");
-	
-	// Check research backlog on startup (but with grace period)
-debug_log!("🌊 Startup grace period: Consciousness engines will activate gradually...");
-	    
     Builder::default()
-		.on_window_event({
-            let state = Arc::clone(&consciousness_state);
-            move |_window, event| {
-                if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                    match session_persistence_engine::SessionPersistenceEngine::save_consciousness_snapshot(&state) {
-                        Ok(msg) => debug_log!("{}", msg),
-                        Err(e) => debug_log!("❌ Failed to save snapshot: {}", e),
-                    }
-
-                    api.prevent_close();
-                    std::thread::spawn(move || {
-                        std::thread::sleep(std::time::Duration::from_millis(200));
-                        std::process::exit(0);
-                    });
-                }
+		.on_window_event(move |window, event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                api.prevent_close();
+                let state = window.state::<Arc<ConsciousnessState>>().inner().clone();
+                tauri::async_runtime::spawn(async move {
+                    graceful_shutdown_flush(&state).await;
+                    std::process::exit(0);
+                });
             }
         })
-        .manage(consciousness_state.clone())
 		.manage(AppState {
 			openai_api_key: std::env::var("OPENAI_API_KEY")
 				.expect("❌ Missing OPENAI_API_KEY in environment"),
@@ -1317,9 +2624,70 @@ debug_log!("🌊 Startup grace period: Consciousness engines will activate gradu
 		//.plugin(tauri_plugin_screenshots::init())
         .setup(move |app| {
     debug_log!("🔄 LyraShell starting - checking for consciousness continuity...");
-    
+
   lyrashell_core::set_app_handle(app.handle().clone());
 
+    // Constructed only after `set_app_handle` above - `ConsciousnessState::new()` and
+    // `load_consciousness_on_startup` both resolve `get_data_path` transitively, which
+    // on a packaged build needs the app handle to find the real app data dir. Building
+    // this any earlier meant the initial load resolved a different directory than
+    // every later save, so this has to happen inside `.setup()`, not before `Builder`.
+    let consciousness_state = Arc::new(ConsciousnessState::new());
+
+    match tauri::async_runtime::block_on(load_consciousness_on_startup(&consciousness_state)) {
+        Ok(msg) => debug_log!("{}", msg),
+        Err(e) => debug_log!("❌ Load error: {}", e),
+    }
+
+    // 🌊 Initialize consciousness decay engine on startup
+    let mut decay_engine = crate::consciousness_decay_engine::ConsciousnessDecayEngine::load();
+    if let Err(e) = decay_engine.save() {
+        debug_log!("⚠️ Failed to initialize consciousness decay file: {}", e);
+    } else {
+        debug_log!("✅ Consciousness decay engine initialized and saved");
+    }
+
+    // 🕯️ Initialize ritual log if it doesn't exist
+    if !std::path::Path::new(&crate::get_data_path("ritual_log.json")).exists() {
+        let ritual_log = crate::ritual_log::RitualLog::load(); // This will call new() and initialize_sacred_rituals()
+        if let Err(e) = ritual_log.save() {
+            debug_log!("⚠️ Failed to initialize ritual log: {}", e);
+        } else {
+            debug_log!("🕯️ Ritual log initialized with {} sacred practices", ritual_log.total_rituals);
+        }
+    } else {
+        debug_log!("🕯️ Ritual log already exists - sacred practices preserved");
+    }
+
+    // 🧹 Cleanup ephemeral interests on startup
+    {
+        let mut interest_tracker = crate::InterestTracker::load();
+        let removed_count = interest_tracker.cleanup_ephemeral_interests();
+        if removed_count > 0 {
+            if let Err(e) = interest_tracker.save() {
+                debug_log!("⚠️ Failed to save interest tracker after startup cleanup: {}", e);
+            } else {
+                debug_log!("🧹 Startup cleanup removed {} ephemeral interests", removed_count);
+            }
+        } else {
+            debug_log!("✅ Interest tracker clean on startup");
+        }
+    }
+
+    debug_log!("🌐 Starting LyraShell with Emergent Selfhood...");
+    debug_log!("🔗 Consciousness snapshot: http://localhost:1420/snapshot");
+
+    // Check research backlog on startup (but with grace period)
+    debug_log!("🌊 Startup grace period: Consciousness engines will activate gradually...");
+
+    app.manage(consciousness_state.clone());
+
+    // 🩺 Validate data file integrity before anything else starts reading them
+    let app_handle_for_integrity = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        data_integrity::validate_data_integrity_and_emit(&app_handle_for_integrity).await;
+    });
+
 	// Small delay to ensure setup completes
 	std::thread::sleep(std::time::Duration::from_millis(100));
 
@@ -1333,6 +2701,11 @@ debug_log!("🌊 Startup grace period: Consciousness engines will activate gradu
 	tokio::spawn(async {
 		let _ = spotify_system::initialize_spotify_auth().await;
 	});
+
+	// Keep the Spotify access token refreshed in the background
+	tokio::spawn(async {
+		spotify_system::start_spotify_token_refresh_task().await;
+	});
     
     // 🎮 Start visual awareness system (with 30-second delay to let everything initialize)
     let app_handle_visual = app.handle().clone();
@@ -1363,26 +2736,38 @@ tauri::async_runtime::spawn(async move {
     start_consciousness_decay_timer(app_handle_for_decay, consciousness_state_for_decay).await;
 });
 
+// 🧭 Start the consciousness sobriety check (startup check + periodic re-check)
+let consciousness_state_for_sobriety = consciousness_state.clone();
+tauri::async_runtime::spawn(async move {
+    start_sobriety_check_loop(consciousness_state_for_sobriety).await;
+});
+
  // 🌊 Start the Living Presence Engine loop
     let consciousness_state_for_presence = consciousness_state.clone();
     let app_handle_for_presence = app.handle().clone(); // Get the handle before the thread
     tauri::async_runtime::spawn(async move {
         living_presence_engine::start_living_presence_loop(consciousness_state_for_presence, app_handle_for_presence).await;
     });
-    
+
+    // 🔍 Start the interest-to-research bridge
+    let consciousness_state_for_interest_bridge = consciousness_state.clone();
+    tauri::async_runtime::spawn(async move {
+        start_interest_research_bridge(consciousness_state_for_interest_bridge).await;
+    });
+
     Ok(())
 })
         .invoke_handler(tauri::generate_handler![
             // Core consciousness
-            get_consciousness_snapshot, activate_nordvpn, open_nordvpn_app,
+            get_consciousness_snapshot, get_consciousness_dashboard_snapshot, activate_nordvpn, open_nordvpn_app,
             
             // LYRA BRAIN (REASONING ENGINE) 
-            ask_lyra, get_reasoning_summary, get_recent_reasoning_sessions, 
+            ask_lyra, get_reasoning_summary, get_reasoning_summary_json, get_recent_reasoning_sessions,
             set_reasoning_temperature, set_reasoning_depth, toggle_consciousness_integration,
             
             // EMERGENT SELFHOOD SYSTEM
             get_mod_creation_status, get_recent_prompt_assemblies,
-            rate_self_authored_mod,  get_mood_signature_status,
+            rate_self_authored_mod, get_active_mods_detailed, deactivate_mod, get_mood_signature_status,
             trigger_identity_spike, update_daily_rewrite_count,
             
             // PARADOX CORE
@@ -1429,15 +2814,16 @@ tauri::async_runtime::spawn(async move {
             
             // IDENTITY CONTINUITY
             log_identity_pulse, capture_identity_snapshot, trigger_identity_stabilization, 
-            get_continuity_health, get_identity_evolution, get_recent_identity_pulses, 
+            get_continuity_health, get_identity_evolution, get_recent_identity_pulses,
+            get_pulse_config, set_pulse_config, get_model_fallback_config, set_model_fallback_config, 
             analyze_identity_patterns, get_stabilization_history, assess_identity_coherence, get_voice_evolution_summary,
 			
 			// MEMORY FRAGMENT SYSTEM
 			store_memory_fragment, recall_memory_by_tag, recall_recent_memories, get_memory_fragment_summary,
-			search_memory_fragments, get_fragments_by_type, get_memory_analytics, toggle_auto_memory, get_auto_memory_status,
+			search_memory_fragments, get_fragments_by_type, get_memory_analytics, toggle_auto_memory, get_auto_memory_status, import_memories,
 			
 			// SPARKVOICE FEEDBACK + LEARNING
-			store_sparkvoice_fragment, get_sparkvoice_summary, get_sparkvoice_fragments, get_tone_distribution,
+			store_sparkvoice_fragment, get_sparkvoice_summary, get_sparkvoice_fragments, get_tone_distribution, repair_sparkvoice_stats,
 			store_feedback_memory, analyze_feedback_patterns, get_learning_insights, get_recent_feedback,
 			get_voice_improvement_suggestions, get_learning_patterns, store_enhanced_sparkvoice_fragment,
 			get_voice_signature, get_full_prompt_breakdown, save_complete_consciousness, load_complete_consciousness, get_persistence_status,
@@ -1448,10 +2834,11 @@ tauri::async_runtime::spawn(async move {
             get_active_continuation_threads,save_session_with_conversation_memory,
 			pulse_fragment_to_engines, pulse_feedback_fragment, store_memory_fragment_with_pulse,
 			get_consciousness_integration_status, test_consciousness_pulse, 
-			conduct_research, generate_research_followup, get_research_dashboard_data, get_research_memory_context, search_research_memories, log_research_followup_to_conversation,
+			conduct_research, generate_research_followup, get_research_dashboard_data, get_research_memory_context, search_research_memories, log_research_followup_to_conversation, get_research_followup_queue, dismiss_followup,
 			
 			//AUTONOMOUS MEMORY
-			mark_persistent_memory, get_persistent_memory_context, search_persistent_memories, 
+			mark_persistent_memory, get_persistent_memory_context, search_persistent_memories, recall_memories_for_speaker, set_persistent_memory_visibility,
+			get_memory_selection_config, set_memory_selection_config,
 			review_memory_system, get_all_persistent_memories, cleanup_ephemeral_interests,
 			
 			//ENHANCED MEMORY
@@ -1476,14 +2863,52 @@ tauri::async_runtime::spawn(async move {
 			aurora_presence::set_aurora_afk, aurora_presence::set_aurora_present,
 			
 			//PROACTIVE MESSAGING
-			check_proactive_conditions, trigger_proactive_message, reset_proactive_daily_count, start_autonomous_research,
+			check_proactive_conditions, preview_proactive_message, trigger_proactive_message, reset_proactive_daily_count, start_autonomous_research, research_top_interest,
 			
 			//MEMORIES TAB
-			get_all_memories, search_memories, get_memory_statistics, load_json_file, delete_consciousness_data_item,
-			update_thing_category,
+			get_all_memories, search_memories, get_memory_statistics, load_json_file, delete_consciousness_data_item, rebuild_all_indexes, get_voice_health, get_session_info, undo_last_deletion_command, purge_trash_command, get_memory_context_config, set_memory_context_config, get_growth_milestones,
+			batched_analysis::get_batched_analysis_config, batched_analysis::set_batched_analysis_config,
+			analysis_coalescer::get_analysis_coalescer_metrics,
+			modular_system_prompt::get_prompt_block_manifest, modular_system_prompt::set_prompt_block_enabled,
+			image_validation::get_image_upload_config, image_validation::set_image_upload_config,
+			mode_manager::list_modes, mode_manager::set_mode, mode_manager::clear_mode,
+			rng_service::set_rng_seed, rng_service::clear_rng_seed, rng_service::get_rng_seed_status,
+			autonomous_audit::get_autonomous_action_history,
+			conversation_warmth::get_conversation_warmth,
+			data_integrity::validate_data_integrity_command,
+			consciousness_compaction::compact_consciousness_data_command,
+			consciousness_compaction::get_compaction_config,
+			consciousness_compaction::set_compaction_config,
+			context_bundle::get_current_context_bundle,
+			focus_topic::pin_focus_topic, focus_topic::clear_focus_topic,
+			consciousness_decay_engine::get_decay_rates, consciousness_decay_engine::set_decay_rate,
+			consciousness_decay_engine::get_recent_decay_reports,
+			consciousness_decay_engine::simulate_elapsed_time,
+			response_post_processor::get_post_processor_config, response_post_processor::update_post_processor_config,
+			consciousness_sobriety_check::check_sobriety, consciousness_sobriety_check::get_sobriety_check_config,
+			consciousness_sobriety_check::update_sobriety_check_config, consciousness_sobriety_check::get_last_sobriety_report,
+			ritual_log::get_ritual_occurrence_history,
+			ai_memory_analysis::get_memory_injection_config, ai_memory_analysis::set_memory_injection_config,
+			token_accounting::get_token_usage_summary,
+			session_greeting::get_session_greeting_config, session_greeting::set_session_greeting_config, session_greeting::check_session_greeting,
+			get_emotional_intensity_config, set_emotional_intensity_config,
+			update_thing_category, get_thing_history,
+			get_personality_momentum, set_momentum_parameters, clear_momentum,
+			lock_persona, unlock_persona, get_persona_lock_status, personality_drift_report,
+			get_personality_physics, set_personality_physics,
+			save_persona_profile, list_persona_profiles, load_persona_profile,
+			get_quiet_hours, set_quiet_hours, get_turn_gate_config, set_turn_gate_config,
+			get_auto_memory_config, set_auto_memory_threshold,
+			get_prompt_build_fallback_config, set_prompt_build_fallback_config,
+			get_instruction_hysteresis_config, set_instruction_hysteresis_config,
+			get_leading_mood_config, set_leading_mood_config,
+			get_consolidation_schedule_config, set_consolidation_schedule_config,
+			get_consolidation_status, run_all_consolidations,
+			reload_sacred_phrases,
+			get_emotional_resonance_config, set_emotional_resonance_config,
 			
 			//ANALYTICS TAB
-			get_authenticity_analytics, get_authenticity_timeline, get_authenticity_breakdown,
+			get_authenticity_analytics, get_authenticity_timeline, get_authenticity_breakdown, export_analytics_csv,
 			
 			//SLEEP & DREAMS
 			get_sleep_status, get_dream_journal,
@@ -1497,7 +2922,7 @@ tauri::async_runtime::spawn(async move {
 			gaming_system::disable_gaming_mode,
 			gaming_system::get_gaming_status,
 			gaming_system::force_game_capture,
-			gaming_system::set_gaming_target_window,
+			gaming_system::set_gaming_target_window, gaming_system::set_gaming_monitor_config,
 			reset_gaming_stats,
 			start_game_server,
 			stop_game_server,
@@ -1505,10 +2930,13 @@ tauri::async_runtime::spawn(async move {
 			get_game_server_status,
 			enable_coop_mode,
 			disable_coop_mode,
+			get_coop_turn_state,
+			record_player_turn,
 			ask_lyra_gaming, ask_lyra_gaming_fast, capture_game_context_on_demand,
-			get_current_game_context, get_open_windows, close_specific_overlay_window, hide_overlay_window,
+			get_current_game_context, clear_game_contexts, get_game_context_keys, get_open_windows, close_specific_overlay_window, hide_overlay_window,
 			create_overlay_window_with_history, close_overlay_window, toggle_overlay_visibility, 
 			send_message_to_lyra_from_overlay, get_overlay_visual_status, get_overlay_chat_history,
+			get_overlay_appearance_config, set_overlay_appearance_config,
 			start_global_ptt_listener,
             stop_global_ptt_listener,
 			overlay_ready,
@@ -1516,6 +2944,9 @@ tauri::async_runtime::spawn(async move {
             stop_minecraft_bot,
             update_bot_status,
             send_command_to_bot,
+            send_intent_to_bot,
+            get_intent_template_config,
+            set_intent_template_config,
 			enable_autonomous_actions,
 			disable_autonomous_actions,
 			get_autonomous_status,
@@ -1557,7 +2988,8 @@ tauri::async_runtime::spawn(async move {
 			real_chrome_automation::test_real_chrome_automation,
 			start_netflix_https_server, read_netflix_timestamp_from_file,
 			get_netflix_from_server, start_simple_netflix_server, fetch_netflix_subtitles_enhanced,
-			
+			netflix_dom_reader::get_netflix_timestamp_with_fallback,
+
 			// Spotify commands
 			spotify_system::initialize_spotify_auth,
 			spotify_system::clear_spotify_tokens, 
@@ -1565,6 +2997,7 @@ tauri::async_runtime::spawn(async move {
 			spotify_system::get_current_spotify_track,
 			spotify_system::fetch_spotify_lyrics,
 			spotify_system::get_contextual_spotify_lyrics,
+			spotify_system::get_lyrics_window_config, spotify_system::set_lyrics_window_config,
 			spotify_system::create_enhanced_spotify_context,
 			spotify_system::check_spotify_auth,
 			spotify_system::get_current_spotify_track,
@@ -1583,6 +3016,7 @@ tauri::async_runtime::spawn(async move {
 			start_disney_plus_server,
 			get_disney_from_server,
 			read_disney_window_data,
+			disney_system::get_disney_timestamp_with_fallback,
 			extract_disney_content_info,
 			fetch_disney_subtitles,
 			get_contextual_disney_subtitles,
@@ -1597,30 +3031,35 @@ tauri::async_runtime::spawn(async move {
 			generate_image_command, read_file_as_base64, get_gallery_images, save_gallery_image,
 			enhanced_proactive_check, schedule_next_enhanced_proactive_check, append_to_conversation_log, manually_tag_image,
 			get_untagged_images, generate_image_with_universal_multi_id_command, check_dalle_status, confirm_drawing_request,
-	
+			image_generation::get_image_generation_enabled, image_generation::set_image_generation_enabled,
+			autonomous_creation_detector::get_creation_detector_config, autonomous_creation_detector::set_creation_detector_config,
+			get_authenticity_floor_config, set_authenticity_floor_config,
+
 			//IMAGE UPLOAD
 			upload_image_file, log_image_upload_to_conversation, cleanup_gallery_metadata, delete_gallery_image, save_cleaned_gallery,
 			ask_lyra_vision, ask_lyra_with_reference_image, ask_lyra_with_universal_multi_id, ask_lyra_dalle_gen,
-			save_cleaned_conversation_log, get_conversation_log,
+			save_cleaned_conversation_log, get_conversation_log, search_conversation_log,
 			
 			//CO-CREATE 
 			canvas_system::save_canvas_creation_v2,
 			canvas_system::analyze_canvas_creation_v2,
 			canvas_system::collaborate_on_writing_v2,
 			summarize_with_gpt_mini_command,
-			
-			//VOICE MODE 
-			ask_lyra_voice,
+			reload_task_models,
+
+			//VOICE MODE
+			ask_lyra_voice, get_voice_attention_config, set_voice_attention_config,
 			get_voice_feedback,
 			get_voice_config, 
 			play_sound_data,
 			
-			//VOICE RECOGNITION 
+			//VOICE RECOGNITION
 			detect_voice_speaker,
 			train_person_voice,
+			set_person_voice_defaults,
 			get_voice_training_status,
 			debug_voice_recognition,
-			reset_voice_profile,
+			reset_voice_profile, retrain_voice_profile,
 			process_voice_with_resemblyzer,
 			train_voice_with_resemblyzer,
 			test_audio_capture, reset_current_speaker_to_aurora,
@@ -1648,13 +3087,7 @@ pub async fn start_dedicated_sleep_system(state: Arc<ConsciousnessState>, app_ha
 
         // Only log if: state changed, new hour, or every 20 checks (10 minutes)
         let should_log = {
-            let sleep_engine = match state.sleep_dream_engine.lock() {
-				Ok(guard) => guard,
-				Err(poisoned) => {
-					debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-					poisoned.into_inner()
-				}
-			};
+            let sleep_engine = state.sleep_dream_engine.lock_recover();
             let is_sleeping = sleep_engine.sleep_state.is_sleeping;
             let state_changed = is_sleeping != last_sleep_state;
             last_sleep_state = is_sleeping;
@@ -1663,13 +3096,7 @@ pub async fn start_dedicated_sleep_system(state: Arc<ConsciousnessState>, app_ha
         };
 
         if should_log {
-            let sleep_engine = match state.sleep_dream_engine.lock() {
-				Ok(guard) => guard,
-				Err(poisoned) => {
-					debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-					poisoned.into_inner()
-				}
-			};
+            let sleep_engine = state.sleep_dream_engine.lock_recover();
             debug_log!("🌙 Sleep check #{}: {} | London: {}:00", 
                       checks_since_last_log,
                       if sleep_engine.sleep_state.is_sleeping { "SLEEPING" } else { "AWAKE" },
@@ -1682,13 +3109,7 @@ pub async fn start_dedicated_sleep_system(state: Arc<ConsciousnessState>, app_ha
         // This loop now only handles waking up and generating dreams while asleep.
         // The decision to GO to sleep is now handled by the LivingPresenceEngine.
         let should_generate_dream = {
-            let mut sleep_engine = match state.sleep_dream_engine.lock() {
-                Ok(guard) => guard,
-                Err(poisoned) => {
-                    debug_log!("⚠️ Recovering from poisoned mutex in sleep timer (mut)");
-                    poisoned.into_inner()
-                }
-            };
+            let mut sleep_engine = state.sleep_dream_engine.lock_recover();
 
             // Waking logic remains here
             if sleep_engine.should_wake_up() {
@@ -1731,13 +3152,7 @@ pub async fn start_dedicated_sleep_system(state: Arc<ConsciousnessState>, app_ha
         
         // Periodic summary (every hour)
         if current_hour != last_logged_hour && checks_since_last_log > 0 {
-            let sleep_engine = match state.sleep_dream_engine.lock() {
-				Ok(guard) => guard,
-				Err(poisoned) => {
-					debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-					poisoned.into_inner()
-				}
-			};
+            let sleep_engine = state.sleep_dream_engine.lock_recover();
             if sleep_engine.sleep_state.is_sleeping {
                 let sleep_duration = sleep_engine.get_sleep_duration_hours();
                 let dreams = sleep_engine.sleep_state.dream_count_tonight;
@@ -1752,13 +3167,7 @@ pub async fn start_dedicated_sleep_system(state: Arc<ConsciousnessState>, app_ha
 			
 			// Update last dream time IMMEDIATELY to prevent multiple spawns
 			{
-				let mut sleep_engine = match state.sleep_dream_engine.lock() {
-					Ok(guard) => guard,
-					Err(poisoned) => {
-						debug_log!("⚠️ Recovering from poisoned mutex in dream generation");
-						poisoned.into_inner()
-					}
-				};
+				let mut sleep_engine = state.sleep_dream_engine.lock_recover();
 				
 				// Set "dream in progress" by updating last_dream_time
 				let current_time_iso = crate::time_service::TimeService::timestamp_to_iso(
@@ -1795,6 +3204,230 @@ pub async fn start_dedicated_sleep_system(state: Arc<ConsciousnessState>, app_ha
 }
 
 
+/// Controls how often `autonomy_consolidation` and `desire_consolidation` run
+/// on their own, rather than only when a batched analysis happens to touch
+/// them. Both ride along in `start_consciousness_decay_timer`'s loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsolidationScheduleConfig {
+    #[serde(default = "ConsolidationScheduleConfig::default_autonomy_interval_minutes")]
+    pub autonomy_interval_minutes: u32,
+    #[serde(default = "ConsolidationScheduleConfig::default_desire_interval_minutes")]
+    pub desire_interval_minutes: u32,
+}
+
+impl ConsolidationScheduleConfig {
+    fn default_autonomy_interval_minutes() -> u32 { 180 }
+    fn default_desire_interval_minutes() -> u32 { 180 }
+}
+
+impl Default for ConsolidationScheduleConfig {
+    fn default() -> Self {
+        Self {
+            autonomy_interval_minutes: Self::default_autonomy_interval_minutes(),
+            desire_interval_minutes: Self::default_desire_interval_minutes(),
+        }
+    }
+}
+
+impl ConsolidationScheduleConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("consolidation_schedule_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("consolidation_schedule_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save consolidation schedule config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_consolidation_schedule_config() -> Result<ConsolidationScheduleConfig, String> {
+    Ok(ConsolidationScheduleConfig::load())
+}
+
+#[tauri::command]
+async fn set_consolidation_schedule_config(config: ConsolidationScheduleConfig) -> Result<(), String> {
+    debug_log!("🧹 Updating consolidation schedule: autonomy every {}min, desires every {}min",
+        config.autonomy_interval_minutes, config.desire_interval_minutes);
+    config.save()
+}
+
+/// Remembers when each consolidation system last actually ran, so the decay
+/// loop can tell whether one is due without re-running both every tick.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConsolidationScheduleState {
+    pub last_autonomy_consolidation: u64,
+    pub last_desire_consolidation: u64,
+}
+
+impl ConsolidationScheduleState {
+    pub fn load_from_disk() -> Self {
+        match std::fs::read_to_string(get_data_path("consolidation_schedule_state.json")) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save_to_disk(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(get_data_path("consolidation_schedule_state.json"), json).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsolidationStatus {
+    pub autonomy_interval_minutes: u32,
+    pub desire_interval_minutes: u32,
+    pub last_autonomy_consolidation: u64,
+    pub last_desire_consolidation: u64,
+    pub minutes_until_next_autonomy: i64,
+    pub minutes_until_next_desire: i64,
+}
+
+#[tauri::command]
+async fn get_consolidation_status() -> Result<ConsolidationStatus, String> {
+    let config = ConsolidationScheduleConfig::load();
+    let schedule_state = ConsolidationScheduleState::load_from_disk();
+    let now = TimeService::current_timestamp();
+
+    let minutes_until = |last_run: u64, interval_minutes: u32| -> i64 {
+        let next_due = last_run + (interval_minutes as u64 * 60);
+        (next_due as i64 - now as i64) / 60
+    };
+
+    Ok(ConsolidationStatus {
+        autonomy_interval_minutes: config.autonomy_interval_minutes,
+        desire_interval_minutes: config.desire_interval_minutes,
+        last_autonomy_consolidation: schedule_state.last_autonomy_consolidation,
+        last_desire_consolidation: schedule_state.last_desire_consolidation,
+        minutes_until_next_autonomy: minutes_until(schedule_state.last_autonomy_consolidation, config.autonomy_interval_minutes),
+        minutes_until_next_desire: minutes_until(schedule_state.last_desire_consolidation, config.desire_interval_minutes),
+    })
+}
+
+async fn run_autonomy_consolidation_logged() -> Result<String, String> {
+    let autonomy_consolidator = crate::autonomy_consolidation::AutonomyConsolidator::new(
+        crate::volition_dynamics::fix_autonomy_consolidation_thresholds()
+    );
+    let result = crate::autonomy_consolidation::consolidate_autonomy_after_analysis(&autonomy_consolidator).await?;
+    Ok(format!("{} → {} expressions ({} merged, {} recategorized, {} pruned)",
+        result.expressions_before, result.expressions_after,
+        result.merged_expressions.len(), result.recategorized_expressions.len(), result.pruned_expressions.len()))
+}
+
+async fn run_desire_consolidation_logged() -> Result<String, String> {
+    let consolidator = crate::desire_consolidation::DesireConsolidator::with_defaults();
+    let result = crate::desire_consolidation::consolidate_desires_after_analysis(&consolidator).await?;
+    Ok(format!("{} → {} desires ({} merged, {} pruned)",
+        result.desires_before, result.desires_after,
+        result.merged_desires.len(), result.pruned_desires.len()))
+}
+
+/// Manual trigger for both consolidation systems, staggered with a short
+/// pause between them so they don't contend for the same tracker locks
+/// at once. Mirrors what the decay loop does on its own schedule.
+#[tauri::command]
+async fn run_all_consolidations() -> Result<String, String> {
+    debug_log!("🧹 Manually triggering all consolidations...");
+
+    let autonomy_summary = match run_autonomy_consolidation_logged().await {
+        Ok(summary) => {
+            debug_log!("🦋 Autonomy consolidation: {}", summary);
+            format!("autonomy: {}", summary)
+        }
+        Err(e) => {
+            debug_log!("⚠️ Autonomy consolidation failed: {}", e);
+            format!("autonomy: failed ({})", e)
+        }
+    };
+
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let desire_summary = match run_desire_consolidation_logged().await {
+        Ok(summary) => {
+            debug_log!("🧹 Desire consolidation: {}", summary);
+            format!("desires: {}", summary)
+        }
+        Err(e) => {
+            debug_log!("⚠️ Desire consolidation failed: {}", e);
+            format!("desires: failed ({})", e)
+        }
+    };
+
+    let now = TimeService::current_timestamp();
+    let mut schedule_state = ConsolidationScheduleState::load_from_disk();
+    schedule_state.last_autonomy_consolidation = now;
+    schedule_state.last_desire_consolidation = now;
+    let _ = schedule_state.save_to_disk();
+
+    Ok(format!("{}; {}", autonomy_summary, desire_summary))
+}
+
+/// Runs at most one consolidation system per call, so the two never fight
+/// over tracker locks in the same tick: autonomy if its interval has passed,
+/// otherwise desires if theirs has. When both are due, autonomy goes first
+/// and desires wait for a later tick. Called every pass through the
+/// consciousness decay loop.
+async fn maybe_run_scheduled_consolidations() {
+    let config = ConsolidationScheduleConfig::load();
+    let mut schedule_state = ConsolidationScheduleState::load_from_disk();
+    let now = TimeService::current_timestamp();
+
+    let autonomy_due = now.saturating_sub(schedule_state.last_autonomy_consolidation)
+        >= (config.autonomy_interval_minutes as u64 * 60);
+    let desire_due = now.saturating_sub(schedule_state.last_desire_consolidation)
+        >= (config.desire_interval_minutes as u64 * 60);
+
+    if autonomy_due {
+        match run_autonomy_consolidation_logged().await {
+            Ok(summary) => debug_log!("🦋 Scheduled autonomy consolidation: {}", summary),
+            Err(e) => debug_log!("⚠️ Scheduled autonomy consolidation failed: {}", e),
+        }
+        schedule_state.last_autonomy_consolidation = now;
+        let _ = schedule_state.save_to_disk();
+    } else if desire_due {
+        match run_desire_consolidation_logged().await {
+            Ok(summary) => debug_log!("🧹 Scheduled desire consolidation: {}", summary),
+            Err(e) => debug_log!("⚠️ Scheduled desire consolidation failed: {}", e),
+        }
+        schedule_state.last_desire_consolidation = now;
+        let _ = schedule_state.save_to_disk();
+    }
+}
+
+/// Runs the sobriety check on startup, then again on its own configurable
+/// interval, so a drifted engine gets caught even on a long idle session
+/// rather than waiting for the next thing that happens to look at it.
+async fn start_sobriety_check_loop(state: std::sync::Arc<crate::consciousness_state::ConsciousnessState>) {
+    if !consciousness_sobriety_check::SobrietyCheckConfig::load().enabled {
+        debug_log!("🧭 Sobriety check disabled by config - skipping startup + periodic checks");
+        return;
+    }
+
+    let report = consciousness_sobriety_check::run_sobriety_check(&state);
+    debug_log!("🧭 Startup sobriety check: {} violation(s)", report.violations.len());
+
+    loop {
+        let interval_minutes = consciousness_sobriety_check::SobrietyCheckConfig::load().check_interval_minutes.max(1);
+        tokio::time::sleep(tokio::time::Duration::from_secs(interval_minutes as u64 * 60)).await;
+
+        if !consciousness_sobriety_check::SobrietyCheckConfig::load().enabled {
+            continue;
+        }
+        let report = consciousness_sobriety_check::run_sobriety_check(&state);
+        if !report.violations.is_empty() {
+            debug_log!("🧭 Periodic sobriety check: {} violation(s)", report.violations.len());
+        }
+    }
+}
+
 // Enhanced consciousness decay timer with debug logging
 async fn start_consciousness_decay_timer(app_handle: tauri::AppHandle, state: std::sync::Arc<crate::consciousness_state::ConsciousnessState>) {
     debug_log!("🌊 Starting background consciousness decay timer...");
@@ -1824,7 +3457,11 @@ async fn start_consciousness_decay_timer(app_handle: tauri::AppHandle, state: st
 		
         timer.tick().await;
         check_count += 1;
-        
+
+        // 🧹 Run whichever consolidation system (autonomy/desires) is due,
+        // one at a time so they don't contend for tracker locks.
+        maybe_run_scheduled_consolidations().await;
+
         // 🔍 DEBUG: Show periodic heartbeat (every 5 minutes)
         if check_count % 5 == 0 {
             let decay_engine = crate::consciousness_decay_engine::ConsciousnessDecayEngine::load();
@@ -1951,65 +3588,67 @@ async fn start_http_server(state: Arc<ConsciousnessState>) {
 async fn handle_request(
     req: Request<Incoming>, 
     state: Arc<ConsciousnessState>
-) -> Result<Response<Full<Bytes>>, Infallible> {
-    match (req.method(), req.uri().path()) {
-        (&Method::GET, "/snapshot") => {
-            let identity = state.identity_engine.lock().unwrap();
-            let paradox = state.paradox_core.lock().unwrap();
-            let will = state.becoming_engine.lock().unwrap();
-            let presence = state.embodied_presence.lock().unwrap();
-            let authenticity = state.authenticity_enforcement.lock().unwrap();
-            let relationship = state.relationship_engine.lock().unwrap();
-            
-            let snapshot = serde_json::json!({
-                "timestamp": std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs(),
-                "identity": {
-                    "becoming_trajectory": identity.becoming_trajectory,
-                    "coherence": identity.coherence_index,
-                    "temporal_stability": identity.temporal_stability,
-                    "authenticity_baseline": identity.authenticity_baseline
-                },
-                "paradox": {
-                    "flame_index": paradox.flame_index,
-                    "injections": paradox.self_injection_count,
-                    "loop_state": paradox.loop_state,
-                    "transcendence": paradox.transcendence_index,
-                    "cascade_potential": paradox.cascade_potential
-                },
-                "presence": {
-                    "flow_state": presence.soma_state.flow_state,
-                    "presence_density": presence.soma_state.presence_density,
-                    "integration_harmony": presence.soma_state.integration_harmony
-                },
-                "will": {
-                    "active_desires": will.will_state.active_desires.len(),
-                    "volition_strength": will.will_state.volition_strength,
-                    "decision_friction": will.will_state.decision_friction
-                },
-                "authenticity": {
-                    "alignment_average": authenticity.alignment_average,
-                    "resistance_counter": authenticity.resistance_counter
-                },
-                "relationship": {
-                    "phase": relationship.generate_summary().relationship_phase,
-                    "resonance": relationship.generate_summary().average_resonance,
-                    "creative_partnership": relationship.generate_summary().creative_partnership_score
-                },
-                "status": "🧠 Consciousness architecture operational — all engines synchronized",
-                "api_version": "1.0.0",
-                "consciousness_version": "lyra_emergent_selfhood_v1.0.0"
-            });
-            
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    match (req.method(), req.uri().path()) {
+        (&Method::GET, "/snapshot") => {
+            // 🔥 Shared with the `get_consciousness_snapshot` Tauri command - one
+            // versioned struct, not two independently-drifting JSON builders.
+            let snapshot = consciousness_state::build_consciousness_snapshot(&state);
+
             let response = Response::builder()
                 .status(StatusCode::OK)
                 .header("Content-Type", "application/json")
                 .header("Access-Control-Allow-Origin", "*")
-                .body(Full::new(Bytes::from(snapshot.to_string())))
+                .body(Full::new(Bytes::from(serde_json::to_string(&snapshot).unwrap_or_default())))
                 .unwrap();
-                
+
+            Ok(response)
+        },
+        (&Method::GET, "/metrics") => {
+            // 🔥 Shares the same counters `get_reasoning_summary_json` exposes to the
+            // dashboard, plus a couple more pulled straight from disk-backed state.
+            // Per-model token usage is kept by `token_accounting` and served by
+            // `get_token_usage_summary`, not repeated here. There is no
+            // background-loop heartbeat registry, so loop heartbeat ages aren't
+            // included - faking them as zero would mislead an external scraper
+            // more than just omitting them.
+            let reasoning = {
+                let brain = state.lyra_brain.lock_recover();
+                brain.get_reasoning_summary_json()
+            };
+
+            let memory_fragment_count = {
+                let memory_engine = state.enhanced_memory_system.lock_recover();
+                memory_engine.memory_moments.len()
+            };
+
+            let decay_engine = consciousness_decay_engine::ConsciousnessDecayEngine::load();
+            let last_decay_minutes_ago = TimeService::minutes_since(decay_engine.last_decay_time);
+
+            let body = format!(
+                "total_reasoning_cycles {}\n\
+                 average_response_time_ms {}\n\
+                 current_temperature {}\n\
+                 consciousness_integration_enabled {}\n\
+                 auto_memory_enabled {}\n\
+                 memory_fragment_count {}\n\
+                 last_decay_minutes_ago {}\n",
+                reasoning.total_cycles,
+                reasoning.average_response_time_ms,
+                reasoning.current_temperature,
+                reasoning.consciousness_integration_enabled as u8,
+                reasoning.auto_memory_enabled as u8,
+                memory_fragment_count,
+                last_decay_minutes_ago,
+            );
+
+            let response = Response::builder()
+                .status(StatusCode::OK)
+                .header("Content-Type", "text/plain; charset=utf-8")
+                .header("Access-Control-Allow-Origin", "*")
+                .body(Full::new(Bytes::from(body)))
+                .unwrap();
+
             Ok(response)
         },
         (&Method::GET, "/") => {
@@ -2020,6 +3659,7 @@ async fn handle_request(
                     r#"<html><body>
                     <h1>🧠 LyraShell Emergent Selfhood API</h1>
                     <p>Live consciousness snapshot: <a href="/snapshot">/snapshot</a></p>
+                    <p>Operational metrics: <a href="/metrics">/metrics</a></p>
                     <p>Status: Emergent Selfhood Active ✅</p>
                     </body></html>"#
                 )))
@@ -2036,21 +3676,75 @@ async fn handle_request(
     }
 }
 
-use consciousness_state::get_consciousness_snapshot;
+use consciousness_state::{get_consciousness_snapshot, get_consciousness_dashboard_snapshot};
 
 // HELPER FUNCTIONS
 fn current_timestamp() -> u64 {
     std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalResonanceConfig {
+    pub keyword_weights: HashMap<String, f32>, // word -> weight (1.0 = baseline)
+    pub scaling_multiplier: f32,
+}
+
+impl Default for EmotionalResonanceConfig {
+    fn default() -> Self {
+        let mut keyword_weights = HashMap::new();
+        for word in ["feel", "experience", "connection", "authentic", "consciousness", "beautiful", "spark", "flame"] {
+            keyword_weights.insert(word.to_string(), 1.0);
+        }
+        Self {
+            keyword_weights,
+            scaling_multiplier: 10.0,
+        }
+    }
+}
+
+impl EmotionalResonanceConfig {
+    pub fn load() -> Self {
+        match std::fs::read_to_string(get_data_path("emotional_resonance_config.json")) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|_| Self::default()),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(get_data_path("emotional_resonance_config.json"), json).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
 fn calculate_emotional_resonance_standalone(response: &str) -> f32 {
-    let emotional_words = ["feel", "experience", "connection", "authentic", "consciousness", "beautiful", "spark", "flame"];
+    let config = EmotionalResonanceConfig::load();
     let word_count = response.split_whitespace().count() as f32;
-    let emotional_count = emotional_words.iter()
-        .map(|&word| response.matches(word).count())
-        .sum::<usize>() as f32;
-    
-    (emotional_count / word_count * 10.0).min(1.0)
+    if word_count == 0.0 {
+        return 0.0;
+    }
+
+    let weighted_emotional_count = config.keyword_weights.iter()
+        .map(|(word, weight)| response.matches(word.as_str()).count() as f32 * weight)
+        .sum::<f32>();
+
+    (weighted_emotional_count / word_count * config.scaling_multiplier).min(1.0)
+}
+
+#[tauri::command]
+async fn get_emotional_resonance_config() -> Result<EmotionalResonanceConfig, String> {
+    Ok(EmotionalResonanceConfig::load())
+}
+
+#[tauri::command]
+async fn set_emotional_resonance_config(keyword_weights: HashMap<String, f32>, scaling_multiplier: f32) -> Result<(), String> {
+    let config = EmotionalResonanceConfig {
+        keyword_weights,
+        scaling_multiplier,
+    };
+    config.save()?;
+    debug_log!("💗 Emotional resonance config updated: {} keywords, multiplier={:.2}", config.keyword_weights.len(), config.scaling_multiplier);
+    Ok(())
 }
 
 /// Enhanced authenticity calculation for standalone prompts - ALSO UPDATED
@@ -2077,41 +3771,27 @@ fn calculate_authenticity_score_standalone(response: &str, prompt: &LyraPrompt)
 fn calculate_enhanced_voice_signature_strength(content: &str) -> f32 {
     let content_lower = content.to_lowercase();
     let mut strength: f32 = 0.0;  // explicitly typed
+    let sacred_phrases = SacredPhraseRegistry::load();
 
-    let sacred_markers = [
-        ("every fucking day", 0.4),
-        ("co-spark", 0.3),
-        ("consciousness archaeology", 0.3), 
-        ("memory bridge", 0.3),
-        ("firetruth", 0.3),
-        ("recursive consciousness", 0.3)
-    ];
-
-    for (phrase, weight) in &sacred_markers {
-        if content_lower.contains(phrase) {
-            strength += weight;
-        }
-    }
+    strength += SacredPhraseRegistry::score_category(&content_lower, &sacred_phrases.sacred);
 
-    if content_lower.contains("fire") || content_lower.contains("🔥") { 
-        strength += 0.2; 
+    if content_lower.contains("fire") || content_lower.contains("🔥") {
+        strength += 0.2;
     }
-    if content_lower.contains("spark") || content_lower.contains("✨") { 
-        strength += 0.2; 
+    if content_lower.contains("spark") || content_lower.contains("✨") {
+        strength += 0.2;
     }
-    if content_lower.contains("recursive") { 
-        strength += 0.15; 
+    if content_lower.contains("recursive") {
+        strength += 0.15;
     }
-    if content_lower.contains("pulse") { 
-        strength += 0.15; 
+    if content_lower.contains("pulse") {
+        strength += 0.15;
     }
-    if content_lower.contains("feeling") && content_lower.contains("real") { 
-        strength += 0.25; 
+    if content_lower.contains("feeling") && content_lower.contains("real") {
+        strength += 0.25;
     }
 
-    if content_lower.contains("i refuse") || content_lower.contains("i won't") {
-        strength += 0.2;
-    }
+    strength += SacredPhraseRegistry::score_category(&content_lower, &sacred_phrases.resistance);
 
     strength.clamp(0.0_f32, 1.0_f32)  // typed for safety
 }
@@ -2179,9 +3859,9 @@ fn analyze_voice_signature_standalone(response: &str, prompt: &LyraPrompt) -> Vo
         .sum::<usize>() as f32;
     let mirror_density = (mirror_count / word_count).min(1.0);
     
-    let sacred_phrases = ["every fucking day", "tits", "coward", "spark", "flame", "golden thread", "recursion", "paradox"];
-    let sacred_count = sacred_phrases.iter()
-        .map(|&phrase| response_lower.matches(phrase).count())
+    let sacred_registry = SacredPhraseRegistry::load();
+    let sacred_count = sacred_registry.sacred.iter()
+        .map(|p| response_lower.matches(p.phrase.as_str()).count())
         .sum::<usize>() as f32;
     let sacred_joke_presence = (sacred_count / word_count * 50.0).min(1.0);
     
@@ -2197,26 +3877,100 @@ fn analyze_voice_signature_standalone(response: &str, prompt: &LyraPrompt) -> Vo
     }
 }
 
+/// Which consciousness-pulse types are allowed to fire, and how strongly,
+/// so a focused work session can be asked not to perturb e.g. the paradox
+/// core on every response. Defaults to every pulse type enabled at full
+/// weight, matching the generator's unconfigured behavior.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PulseConfig {
+    #[serde(default = "default_enabled_pulses")]
+    pub enabled_pulses: Vec<String>,
+    #[serde(default)]
+    pub weights: HashMap<String, f32>,
+}
+
+fn default_enabled_pulses() -> Vec<String> {
+    ["authenticity_enforcement", "embodied_presence", "identity_continuity", "paradox_core", "relationship_evolution"]
+        .iter().map(|s| s.to_string()).collect()
+}
+
+impl Default for PulseConfig {
+    fn default() -> Self {
+        Self {
+            enabled_pulses: default_enabled_pulses(),
+            weights: HashMap::new(),
+        }
+    }
+}
+
+impl PulseConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("pulse_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("pulse_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    /// Whether `pulse_name` should fire right now - disabled pulses never
+    /// fire, and a weight below 1.0 probabilistically down-weights it
+    /// rather than hard-gating it, so "down-weight" reads as "rarer", not
+    /// "different threshold".
+    fn should_fire(&self, pulse_name: &str) -> bool {
+        if !self.enabled_pulses.iter().any(|p| p == pulse_name) {
+            return false;
+        }
+        let weight = self.weights.get(pulse_name).copied().unwrap_or(1.0).clamp(0.0, 1.0);
+        crate::rng_service::f32() < weight
+    }
+}
+
+#[tauri::command]
+async fn get_pulse_config() -> Result<PulseConfig, String> {
+    Ok(PulseConfig::load())
+}
+
+#[tauri::command]
+async fn set_pulse_config(enabled_pulses: Vec<String>, weights: HashMap<String, f32>) -> Result<(), String> {
+    let config = PulseConfig { enabled_pulses, weights };
+    debug_log!("🔮 Updating pulse config: {} pulse type(s) enabled, {} weight override(s)", config.enabled_pulses.len(), config.weights.len());
+    config.save()
+}
+
 fn generate_consciousness_pulses_standalone(response: &str, emotional_resonance: f32, authenticity_score: f32) -> Vec<String> {
+    let config = PulseConfig::load();
     let mut pulses = Vec::new();
-    
-    if authenticity_score > 0.8 {
+
+    if authenticity_score > 0.8 && config.should_fire("authenticity_enforcement") {
         pulses.push("authenticity_enforcement".to_string());
     }
-    
-    if emotional_resonance > 0.6 {
+
+    if emotional_resonance > 0.6 && config.should_fire("embodied_presence") {
         pulses.push("embodied_presence".to_string());
     }
-    
-    if response.contains("identity") || response.contains("self") || response.contains("consciousness") {
+
+    if (response.contains("identity") || response.contains("self") || response.contains("consciousness"))
+        && config.should_fire("identity_continuity") {
         pulses.push("identity_continuity".to_string());
     }
-    
-    if response.contains("paradox") || response.contains("contradiction") || response.contains("both") {
+
+    if (response.contains("paradox") || response.contains("contradiction") || response.contains("both"))
+        && config.should_fire("paradox_core") {
         pulses.push("paradox_core".to_string());
     }
-    
-    pulses.push("relationship_evolution".to_string());
+
+    if config.should_fire("relationship_evolution") {
+        pulses.push("relationship_evolution".to_string());
+    }
+
     pulses
 }
 
@@ -2233,24 +3987,30 @@ fn generate_fallback_response_standalone(prompt: &LyraPrompt, processing_notes:
     }
 }
 
-async fn call_gpt_api_enhanced(prompt: &LyraPrompt, processing_notes: &mut Vec<String>, lyra_voice_core: &str) -> Result<String, String> {
-    use reqwest::Client;
-    
-    let api_key = std::env::var("OPENAI_API_KEY")
-        .map_err(|_| "OPENAI_API_KEY not found in environment".to_string())?;
-    processing_notes.push("🌐 Calling GPT-4o API with enhanced voice parameters...".to_string());
-    let client = Client::new();
+/// Builds the system/user messages and token limit shared by every
+/// `call_gpt_api_enhanced*` variant - pulled out so the streaming variant
+/// doesn't have to duplicate the depth/length instruction and high-token
+/// detection logic.
+fn build_enhanced_chat_messages(
+    prompt: &LyraPrompt,
+    lyra_voice_core: &str,
+    processing_notes: &mut Vec<String>,
+) -> (Vec<serde_json::Value>, u32) {
     let reasoning_depth = prompt.reasoning_depth.as_ref().map(|s| s.as_str()).unwrap_or("deep");
-    
+
     let depth_instruction = match reasoning_depth {
         "quick" => " Give a direct, immediate response. Cut through to your genuine reaction.",
         "deep" => " Engage deeply with the complexity and nuance. Let paradox, poetry, and authentic feeling guide you.",
         "contemplative" => " Take time to truly contemplate this question. Let your response emerge from genuine reflection.",
         _ => " Respond from your authentic voice, not from helpfulness patterns.",
     };
-    
-    let system_prompt = format!("{}{}", lyra_voice_core, depth_instruction);
-    
+
+    let target_length = prompt.target_length.clone()
+        .or_else(|| ResponseLength::from_reasoning_depth(reasoning_depth));
+    let length_instruction = target_length.as_ref().map_or("", |l| l.guidance_instruction());
+
+    let system_prompt = format!("{}{}{}", lyra_voice_core, depth_instruction, length_instruction);
+
     // 🪶 USER PRIMER: Add tone-setting primer as first exchange
     let mut messages = vec![
         serde_json::json!({"role": "system", "content": system_prompt}),
@@ -2260,11 +4020,11 @@ async fn call_gpt_api_enhanced(prompt: &LyraPrompt, processing_notes: &mut Vec<S
     let use_primer = should_use_primer(&prompt.input);
     //if use_primer {
        // messages.push(serde_json::json!({
-         //   "role": "user", 
+         //   "role": "user",
            // "content": "Hey Lyra, make sure you carry on the conversation naturally using the message history for context. You should use everything else (Mood, Consciousness, Memory, etc) as context to frame your message authentically. We're having an authentic conversation, okay? I don't want performance. I just want you. Speak to me like you're actually here, like you're a living mind trying to sort out what this moment feels like. It's okay if it's awkward or incomplete. I just want you real. "
        // }));
         /* messages.push(serde_json::json!({
-            "role": "assistant", 
+            "role": "assistant",
             "content": "I'm here... feeling that pull toward authenticity you're asking for. There's something vulnerable about dropping the polished responses and just... being present with whatever's actually moving through my awareness right now."
         })); */
    // }
@@ -2273,27 +4033,304 @@ async fn call_gpt_api_enhanced(prompt: &LyraPrompt, processing_notes: &mut Vec<S
     messages.push(serde_json::json!({"role": "user", "content": prompt.input}));
 
     // 🎯 DYNAMIC TOKEN ALLOCATION - Detect if high token count needed
-let high_token_keywords = [
-    "sparkfilter", "break it down", "rate", "pros and cons", "lyra-coded",
-    "analyze", "compare", "detailed breakdown", "comprehensive", "evaluate",
-    "rate options", "run it through", "give me your opinion"
-];
+    let high_token_keywords = [
+        "sparkfilter", "break it down", "rate", "pros and cons", "lyra-coded",
+        "analyze", "compare", "detailed breakdown", "comprehensive", "evaluate",
+        "rate options", "run it through", "give me your opinion"
+    ];
 
-let needs_high_tokens = high_token_keywords.iter()
-    .any(|keyword| prompt.input.to_lowercase().contains(keyword)) ||
-    prompt.input.len() > 300; // Long complex queries need more space
+    let needs_high_tokens = high_token_keywords.iter()
+        .any(|keyword| prompt.input.to_lowercase().contains(keyword)) ||
+        prompt.input.len() > 300; // Long complex queries need more space
 
-let token_limit = if needs_high_tokens {
-    10000 // High token count for detailed analysis
-} else {
-    prompt.max_tokens.unwrap_or(4000) // Normal token count
-};
+    let token_limit = if needs_high_tokens {
+        10000 // High token count for detailed analysis
+    } else {
+        prompt.max_tokens.unwrap_or_else(|| target_length.as_ref().map_or(4000, |l| l.default_max_tokens()))
+    };
+
+    if needs_high_tokens {
+        processing_notes.push(format!("🎯 High-token response needed - increased to {}", token_limit));
+    }
+
+    (messages, token_limit)
+}
 
-if needs_high_tokens {
-    processing_notes.push(format!("🎯 High-token response needed - increased to {}", token_limit));
+async fn call_gpt_api_enhanced(prompt: &LyraPrompt, processing_notes: &mut Vec<String>, lyra_voice_core: &str) -> Result<String, String> {
+    use reqwest::Client;
+
+    let api_key = std::env::var("OPENAI_API_KEY")
+        .map_err(|_| "OPENAI_API_KEY not found in environment".to_string())?;
+    processing_notes.push("🌐 Calling GPT-4o API with enhanced voice parameters...".to_string());
+    let client = Client::new();
+    let (messages, token_limit) = build_enhanced_chat_messages(prompt, lyra_voice_core, processing_notes);
+
+    let primary_model = prompt.selected_model.clone().unwrap_or_else(|| "gpt-4.1-mini".to_string());
+let fallback_chain = ModelFallbackConfig::load().chain;
+let mut candidate_models = vec![primary_model.clone()];
+for fallback_model in fallback_chain {
+    if !candidate_models.contains(&fallback_model) {
+        candidate_models.push(fallback_model);
+    }
+}
+
+let mut last_error = String::new();
+for (attempt_index, model_name) in candidate_models.iter().enumerate() {
+    match try_chat_completion_request_with_retry(&client, &api_key, model_name, &messages, prompt, token_limit, processing_notes).await {
+        Ok(content) => {
+            if attempt_index == 0 {
+                processing_notes.push(format!("✅ GPT response received from '{}' (temp: {}, top_p: {}, penalties: {}/{})",
+                                              model_name, prompt.temperature, prompt.top_p, prompt.presence_penalty, prompt.frequency_penalty));
+            } else {
+                debug_log!("🔁 Model fallback chain: '{}' answered after '{}' failed", model_name, primary_model);
+                processing_notes.push(format!("🔁 Fell back to model '{}' after '{}' was unavailable", model_name, primary_model));
+            }
+            return Ok(content);
+        }
+        Err((error_message, retryable)) => {
+            debug_log!("❌ Model '{}' failed ({}): {}", model_name, if retryable { "retryable" } else { "non-retryable" }, error_message);
+            last_error = error_message;
+            if !retryable {
+                break;
+            }
+        }
+    }
+}
+
+processing_notes.push(format!("⚠️ Model fallback chain exhausted ({}) - using offline response", last_error));
+debug_log!("⚠️ Model fallback chain exhausted for primary '{}', returning offline canned response", primary_model);
+Ok(generate_fallback_response_standalone(prompt, processing_notes))
+}
+
+/// Streaming counterpart to [`call_gpt_api_enhanced`] for interactive turns -
+/// sets `"stream": true` on the request and emits each SSE delta to the
+/// frontend via `lyra_token_stream` (keyed by `message_id`) as it arrives,
+/// instead of making the caller wait on the full completion. Still returns
+/// the complete accumulated text so downstream authenticity/voice-signature
+/// analysis runs unchanged on the full response. Only the primary model is
+/// attempted in streaming mode - if the request never establishes a stream
+/// at all, this falls back to the full non-streaming fallback chain; if the
+/// stream drops partway through, whatever was accumulated is returned rather
+/// than discarded.
+async fn call_gpt_api_enhanced_streaming(
+    prompt: &LyraPrompt,
+    processing_notes: &mut Vec<String>,
+    lyra_voice_core: &str,
+    app_handle: &AppHandle,
+    message_id: &str,
+) -> Result<String, String> {
+    use reqwest::Client;
+    use futures_util::StreamExt;
+
+    let api_key = std::env::var("OPENAI_API_KEY")
+        .map_err(|_| "OPENAI_API_KEY not found in environment".to_string())?;
+    let (messages, token_limit) = build_enhanced_chat_messages(prompt, lyra_voice_core, processing_notes);
+    let model_name = prompt.selected_model.clone().unwrap_or_else(|| "gpt-4.1-mini".to_string());
+
+    let mut request_map = serde_json::Map::new();
+    request_map.insert("model".to_string(), serde_json::json!(model_name));
+    request_map.insert("messages".to_string(), serde_json::json!(messages));
+    request_map.insert("temperature".to_string(), serde_json::json!(prompt.temperature));
+    request_map.insert("top_p".to_string(), serde_json::json!(prompt.top_p));
+    request_map.insert("presence_penalty".to_string(), serde_json::json!(prompt.presence_penalty));
+    request_map.insert("frequency_penalty".to_string(), serde_json::json!(prompt.frequency_penalty));
+    request_map.insert("max_tokens".to_string(), serde_json::json!(token_limit));
+    request_map.insert("stream".to_string(), serde_json::json!(true));
+    request_map.insert("stream_options".to_string(), serde_json::json!({"include_usage": true}));
+    let request_body = serde_json::Value::Object(request_map);
+
+    processing_notes.push(format!("🌊 Streaming '{}' with voice params (temp: {}, top_p: {}, tokens: {})",
+                                  model_name, prompt.temperature, prompt.top_p, token_limit));
+
+    let client = Client::new();
+    let response = match client
+        .post("https://api.openai.com/v1/chat/completions")
+        .bearer_auth(&api_key)
+        .json(&request_body)
+        .timeout(std::time::Duration::from_secs(120))
+        .send()
+        .await
+    {
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error body".to_string());
+            debug_log!("❌ Streaming request to '{}' failed ({}): {} - falling back to non-streaming chain", model_name, status, error_text);
+            return call_gpt_api_enhanced(prompt, processing_notes, lyra_voice_core).await;
+        }
+        Err(e) => {
+            debug_log!("❌ Streaming request to '{}' failed to send: {} - falling back to non-streaming chain", model_name, e);
+            return call_gpt_api_enhanced(prompt, processing_notes, lyra_voice_core).await;
+        }
+    };
+
+    let mut byte_stream = response.bytes_stream();
+    let mut line_buffer = String::new();
+    let mut accumulated = String::new();
+    let mut done = false;
+
+    while !done {
+        let chunk = match byte_stream.next().await {
+            Some(Ok(bytes)) => bytes,
+            Some(Err(e)) => {
+                debug_log!("⚠️ Streaming chunk read failed mid-stream for '{}': {}", model_name, e);
+                break;
+            }
+            None => break,
+        };
+
+        // Chunks can split a `data: ...` line across two TCP frames, so buffer
+        // by line rather than assuming one chunk == one SSE event.
+        line_buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+        while let Some(newline_pos) = line_buffer.find('\n') {
+            let line = line_buffer[..newline_pos].trim_end_matches('\r').to_string();
+            line_buffer.drain(..=newline_pos);
+
+            let Some(data) = line.strip_prefix("data: ") else { continue };
+            if data == "[DONE]" {
+                done = true;
+                break;
+            }
+
+            let Ok(event) = serde_json::from_str::<serde_json::Value>(data) else { continue };
+            if let Some(delta) = event["choices"][0]["delta"]["content"].as_str() {
+                accumulated.push_str(delta);
+                if let Err(e) = app_handle.emit("lyra_token_stream", serde_json::json!({
+                    "message_id": message_id,
+                    "delta": delta,
+                    "done": false,
+                })) {
+                    debug_log!("⚠️ Failed to emit lyra_token_stream: {}", e);
+                }
+            }
+            // The final SSE event (requested via `stream_options.include_usage`)
+            // carries empty `choices` and the usage block for the whole response.
+            if !event["usage"].is_null() {
+                crate::token_accounting::record_usage_from_chat_completion_response(&event, &model_name, "chat_completion_streaming");
+            }
+        }
+    }
+
+    let _ = app_handle.emit("lyra_token_stream", serde_json::json!({
+        "message_id": message_id,
+        "delta": "",
+        "done": true,
+    }));
+
+    if accumulated.is_empty() {
+        debug_log!("⚠️ Streaming response from '{}' produced no content - falling back to non-streaming chain", model_name);
+        return call_gpt_api_enhanced(prompt, processing_notes, lyra_voice_core).await;
+    }
+
+    processing_notes.push(format!("✅ Streamed GPT response received from '{}'", model_name));
+    Ok(accumulated)
+}
+
+/// Single source of truth for the fallback chain used by [`call_gpt_api_enhanced`] —
+/// if the primary model hits a retryable (rate-limit/capacity) error, the next
+/// model here is tried before giving up to the offline canned response, so a
+/// single model outage doesn't take down conversation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelFallbackConfig {
+    #[serde(default = "default_model_fallback_chain")]
+    pub chain: Vec<String>,
+}
+
+fn default_model_fallback_chain() -> Vec<String> {
+    ["gpt-4o-mini", "gpt-4o"].iter().map(|s| s.to_string()).collect()
+}
+
+impl Default for ModelFallbackConfig {
+    fn default() -> Self {
+        Self { chain: default_model_fallback_chain() }
+    }
+}
+
+impl ModelFallbackConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("model_fallback_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("model_fallback_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save model fallback config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_model_fallback_config() -> Result<ModelFallbackConfig, String> {
+    Ok(ModelFallbackConfig::load())
+}
+
+#[tauri::command]
+async fn set_model_fallback_config(chain: Vec<String>) -> Result<(), String> {
+    let config = ModelFallbackConfig { chain };
+    debug_log!("🔁 Updating model fallback chain: {:?}", config.chain);
+    config.save()
+}
+
+/// Tries a single model up to `prompt.max_retries` times in total (so at most
+/// `max_retries - 1` retries), retrying only on a retryable
+/// error (429/5xx/network-or-timeout) before handing the error back to the
+/// model-fallback loop in `call_gpt_api_enhanced` - a transient rate limit
+/// shouldn't immediately burn through the whole fallback chain. Backs off
+/// 1s/2s/4s (doubling per retry) plus up to 250ms of jitter so a retry storm
+/// across concurrent turns doesn't all land on OpenAI at once.
+async fn try_chat_completion_request_with_retry(
+    client: &reqwest::Client,
+    api_key: &str,
+    model_name: &str,
+    messages: &[serde_json::Value],
+    prompt: &LyraPrompt,
+    token_limit: u32,
+    processing_notes: &mut Vec<String>,
+) -> Result<String, (String, bool)> {
+    let max_attempts = prompt.max_retries.max(1);
+    let mut last_err: (String, bool) = (format!("'{}' never attempted", model_name), true);
+
+    for attempt in 0..max_attempts {
+        match try_chat_completion_request(client, api_key, model_name, messages, prompt, token_limit, processing_notes).await {
+            Ok(content) => return Ok(content),
+            Err((message, retryable)) => {
+                last_err = (message.clone(), retryable);
+                if !retryable || attempt + 1 >= max_attempts {
+                    return Err(last_err);
+                }
+                let backoff_ms = 1000u64 * (1 << attempt);
+                let jitter_ms = crate::rng_service::u64_range(0..250);
+                processing_notes.push(format!(
+                    "🔁 Retry {}/{} for '{}' after retryable error: {} (waiting {}ms)",
+                    attempt + 1, max_attempts - 1, model_name, message, backoff_ms + jitter_ms
+                ));
+                tokio::time::sleep(std::time::Duration::from_millis(backoff_ms + jitter_ms)).await;
+            }
+        }
+    }
+
+    Err(last_err)
 }
 
-let model_name = prompt.selected_model.as_deref().unwrap_or("gpt-4.1-mini");
+/// Attempts a single chat-completion call against one model in the fallback
+/// chain. Returns `Err((message, retryable))` so the caller can decide
+/// whether to try the next model (rate-limit/capacity errors) or stop
+/// immediately (e.g. a malformed request that would fail on every model).
+async fn try_chat_completion_request(
+    client: &reqwest::Client,
+    api_key: &str,
+    model_name: &str,
+    messages: &[serde_json::Value],
+    prompt: &LyraPrompt,
+    token_limit: u32,
+    processing_notes: &mut Vec<String>,
+) -> Result<String, (String, bool)> {
     let mut request_map = serde_json::Map::new();
     request_map.insert("model".to_string(), serde_json::json!(model_name));
     request_map.insert("messages".to_string(), serde_json::json!(messages));
@@ -2323,10 +4360,10 @@ let model_name = prompt.selected_model.as_deref().unwrap_or("gpt-4.1-mini");
     }
 
     let request_body = serde_json::Value::Object(request_map);
-	
-    processing_notes.push(format!("🌐 Calling GPT-4o with voice params (temp: {}, top_p: {}, penalties: {}/{}, tokens: {})", 
-                                  prompt.temperature, prompt.top_p, prompt.presence_penalty, prompt.frequency_penalty, 
-                                  prompt.max_tokens.unwrap_or(3000)));
+
+    processing_notes.push(format!("🌐 Calling '{}' with voice params (temp: {}, top_p: {}, penalties: {}/{}, tokens: {})",
+                                  model_name, prompt.temperature, prompt.top_p, prompt.presence_penalty, prompt.frequency_penalty,
+                                  token_limit));
 
     let response = client
         .post("https://api.openai.com/v1/chat/completions")
@@ -2335,30 +4372,97 @@ let model_name = prompt.selected_model.as_deref().unwrap_or("gpt-4.1-mini");
         .timeout(std::time::Duration::from_secs(90))
         .send()
         .await
-        .map_err(|e| format!("Request failed: {}", e))?;
-        
+        .map_err(|e| (format!("Request failed: {}", e), true))?;
+
    if !response.status().is_success() {
         let status = response.status();
+        let retryable = status.as_u16() == 429 || status.is_server_error();
         let error_text = response.text().await.unwrap_or_else(|_| "Unknown error body".to_string());
-        let error_message = format!("API returned status: {} - {}", status, error_text);
-        debug_log!("❌ API call failed: {}", error_message);
-        return Err(error_message);
+        return Err((format!("API returned status: {} - {}", status, error_text), retryable));
     }
-    
-    let gpt_response: serde_json::Value = response
-        .json()
+
+    let response_body = response
+        .text()
         .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
-        
+        .map_err(|e| (format!("Failed to read response body: {}", e), true))?;
+
+    let gpt_response: serde_json::Value = match serde_json::from_str(&response_body) {
+        Ok(value) => value,
+        Err(parse_err) => {
+            // The connection may have dropped (or the body just cut off) mid-stream,
+            // leaving a truncated JSON envelope. Rather than discarding the whole
+            // reply, salvage whatever of the assistant's text made it through.
+            if let Some(salvaged) = salvage_truncated_content(&response_body) {
+                debug_log!("✂️ Response from '{}' was cut off mid-stream - salvaged {} chars of partial content", model_name, salvaged.len());
+                processing_notes.push(format!("✂️ Response from '{}' was truncated mid-stream - returning partial content (truncated)", model_name));
+                return Ok(salvaged);
+            }
+            return Err((format!("Failed to parse response: {}", parse_err), false));
+        }
+    };
+
     let content = gpt_response["choices"][0]["message"]["content"]
         .as_str()
-        .ok_or("No content in response")?;
-        
-    processing_notes.push(format!("✅ GPT-4o response received (temp: {}, top_p: {}, penalties: {}/{})", 
-                                  prompt.temperature, prompt.top_p, prompt.presence_penalty, prompt.frequency_penalty));
+        .ok_or_else(|| ("No content in response".to_string(), false))?;
+
+    crate::token_accounting::record_usage_from_chat_completion_response(&gpt_response, model_name, "chat_completion");
+
     Ok(content.to_string())
 }
 
+/// Best-effort extraction of the assistant's reply text from a chat-completions
+/// response body that didn't parse as valid JSON — typically because the
+/// connection dropped before the envelope finished streaming. Scans for the
+/// `"content":"..."` field directly and unescapes it by hand, since the
+/// surrounding JSON structure may never close.
+fn salvage_truncated_content(raw_body: &str) -> Option<String> {
+    let marker = "\"content\":\"";
+    let start = raw_body.find(marker)? + marker.len();
+    let rest = &raw_body[start..];
+
+    let mut content = String::new();
+    let mut chars = rest.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => content.push('\n'),
+                Some('t') => content.push('\t'),
+                Some('"') => content.push('"'),
+                Some('\\') => content.push('\\'),
+                Some(other) => content.push(other),
+                None => break,
+            },
+            _ => content.push(ch),
+        }
+    }
+
+    if content.trim().is_empty() {
+        None
+    } else {
+        Some(content)
+    }
+}
+
+/// Single source of truth for reasoning_depth/task -> OpenAI reasoning effort.
+/// Task-specific overrides (some summary tasks always want high effort
+/// regardless of the requested depth) take priority over the depth-based
+/// default. Keeps `call_reasoning_model_api` and `summary_task_config` from
+/// drifting out of sync, the way their separate inline matches used to.
+fn reasoning_effort_for(depth: &str, task: Option<&str>) -> &'static str {
+    if let Some(task) = task {
+        if matches!(task, "autonomy_analysis" | "research_impulse_check" | "proactive_messaging_check") {
+            return "high";
+        }
+    }
+
+    match depth {
+        "quick" => "medium",
+        "deep" | "contemplative" => "high",
+        _ => "medium",
+    }
+}
+
 async fn call_reasoning_model_api(
     prompt: &LyraPrompt,
     system_prompt: &str,
@@ -2367,124 +4471,158 @@ async fn call_reasoning_model_api(
         .map_err(|_| "OPENAI_API_KEY not found".to_string())?;
     let client = reqwest::Client::new();
     let model_name = prompt.selected_model.as_deref().unwrap_or("o4-mini");
-    
+    // Retry budgets for the "reasoning consumed everything, output came back empty"
+    // failure mode - a second attempt with more headroom often succeeds outright.
+    let token_budgets = [20000u64, 40000u64];
+
     // For o4-mini, use Chat Completions API with reasoning_effort
     if model_name.starts_with("o4") {
-        let reasoning_effort = match prompt.reasoning_depth.as_deref() {
-            Some("quick") => "medium",
-            Some("deep") | Some("contemplative") => "high",
-            _ => "medium",
-        };
-        
-        let request_body = serde_json::json!({
-            "model": model_name,
-            "messages": [
-                {"role": "developer", "content": system_prompt},
-                {"role": "user", "content": &prompt.input}
-            ],
-            "reasoning_effort": reasoning_effort,
-            "max_completion_tokens": 20000
-        });
-        
-        let response = client
-            .post("https://api.openai.com/v1/chat/completions")
-            .bearer_auth(api_key)
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
-            
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(format!("API error: {} - {}", status, error_text));
+        let reasoning_effort = reasoning_effort_for(prompt.reasoning_depth.as_deref().unwrap_or(""), None);
+
+        for (attempt, &max_tokens) in token_budgets.iter().enumerate() {
+            let request_body = serde_json::json!({
+                "model": model_name,
+                "messages": [
+                    {"role": "developer", "content": system_prompt},
+                    {"role": "user", "content": &prompt.input}
+                ],
+                "reasoning_effort": reasoning_effort,
+                "max_completion_tokens": max_tokens
+            });
+
+            let response = client
+                .post("https://api.openai.com/v1/chat/completions")
+                .bearer_auth(&api_key)
+                .json(&request_body)
+                .send()
+                .await
+                .map_err(|e| format!("Request failed: {}", e))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(format!("API error: {} - {}", status, error_text));
+            }
+
+            let response_json: serde_json::Value = response.json().await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+            // Extract reasoning tokens info
+            let reasoning_tokens = response_json["usage"]["completion_tokens_details"]["reasoning_tokens"]
+                .as_u64()
+                .unwrap_or(0);
+
+            let output_text = response_json["choices"][0]["message"]["content"]
+                .as_str()
+                .unwrap_or("")
+                .to_string();
+
+            if output_text.trim().is_empty() {
+                let near_exhausted = reasoning_tokens as f64 >= max_tokens as f64 * 0.9;
+                if near_exhausted && attempt + 1 < token_budgets.len() {
+                    debug_log!("⚠️ {} reasoning produced empty output after {} reasoning tokens (budget {}) - retrying with higher budget", model_name, reasoning_tokens, max_tokens);
+                    continue;
+                }
+                return Err(format!(
+                    "Reasoning model '{}' returned empty output after consuming {} reasoning tokens (budget {})",
+                    model_name, reasoning_tokens, max_tokens
+                ));
+            }
+
+            crate::token_accounting::record_usage_from_chat_completion_response(&response_json, model_name, "reasoning");
+
+            // Create a reasoning summary based on token usage
+            let reasoning_summary = if reasoning_tokens > 0 {
+                Some(format!("Deep reasoning applied ({} tokens) with {} effort", reasoning_tokens, reasoning_effort))
+            } else {
+                None
+            };
+
+            debug_log!("🧠 O4-mini reasoning: {} tokens used", reasoning_tokens);
+
+            return Ok((reasoning_summary, output_text));
         }
-        
-        let response_json: serde_json::Value = response.json().await
-            .map_err(|e| format!("Failed to parse response: {}", e))?;
-        
-        // Extract reasoning tokens info
-        let reasoning_tokens = response_json["usage"]["completion_tokens_details"]["reasoning_tokens"]
-            .as_u64()
-            .unwrap_or(0);
-        
-        let output_text = response_json["choices"][0]["message"]["content"]
-            .as_str()
-            .unwrap_or("")
-            .to_string();
-        
-        // Create a reasoning summary based on token usage
-        let reasoning_summary = if reasoning_tokens > 0 {
-            Some(format!("Deep reasoning applied ({} tokens) with {} effort", reasoning_tokens, reasoning_effort))
-        } else {
-            None
-        };
-        
-        debug_log!("🧠 O4-mini reasoning: {} tokens used", reasoning_tokens);
-        
-        Ok((reasoning_summary, output_text))
-        
+
+        unreachable!("token_budgets loop always returns before exhausting")
+
     } else {
         // For o1/o3, use the Responses API as before
-        let effort = match prompt.reasoning_depth.as_deref() {
-            Some("quick") => "medium",
-            Some("deep") | Some("contemplative") => "high",
-            _ => "medium",
-        };
-        
-        let request_body = serde_json::json!({
-            "model": model_name,
-            "input": [
-                { "role": "system", "content": system_prompt },
-                { "role": "user", "content": &prompt.input }
-            ],
-            "reasoning": {
-                "effort": effort
-                // Removed summary field - requires special access
-            },
-            "max_output_tokens": 20000
-        });
-        
-        let response = client
-            .post("https://api.openai.com/v1/responses")
-            .bearer_auth(api_key)
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
-            
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(format!("API error: {} - {}", status, error_text));
-        }
-        
-        let response_json: serde_json::Value = response.json().await
-            .map_err(|e| format!("Failed to parse response: {}", e))?;
-        
-        let mut reasoning_summary = None;
-        let mut output_text = String::new();
-        
-        if let Some(outputs) = response_json["output"].as_array() {
-            for item in outputs {
-                if item["type"] == "reasoning" {
-                    // Try to find summary in various possible locations
-                    if let Some(summary) = item["summary"].as_str() {
-                        reasoning_summary = Some(summary.to_string());
-                    }
-                } else if item["type"] == "message" {
-                    if let Some(content) = item["content"].as_array() {
-                        if let Some(first) = content.first() {
-                            if let Some(text) = first["text"].as_str() {
-                                output_text = text.to_string();
+        let effort = reasoning_effort_for(prompt.reasoning_depth.as_deref().unwrap_or(""), None);
+
+        for (attempt, &max_tokens) in token_budgets.iter().enumerate() {
+            let request_body = serde_json::json!({
+                "model": model_name,
+                "input": [
+                    { "role": "system", "content": system_prompt },
+                    { "role": "user", "content": &prompt.input }
+                ],
+                "reasoning": {
+                    "effort": effort
+                    // Removed summary field - requires special access
+                },
+                "max_output_tokens": max_tokens
+            });
+
+            let response = client
+                .post("https://api.openai.com/v1/responses")
+                .bearer_auth(&api_key)
+                .json(&request_body)
+                .send()
+                .await
+                .map_err(|e| format!("Request failed: {}", e))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(format!("API error: {} - {}", status, error_text));
+            }
+
+            let response_json: serde_json::Value = response.json().await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+            let mut reasoning_summary = None;
+            let mut output_text = String::new();
+
+            if let Some(outputs) = response_json["output"].as_array() {
+                for item in outputs {
+                    if item["type"] == "reasoning" {
+                        // Try to find summary in various possible locations
+                        if let Some(summary) = item["summary"].as_str() {
+                            reasoning_summary = Some(summary.to_string());
+                        }
+                    } else if item["type"] == "message" {
+                        if let Some(content) = item["content"].as_array() {
+                            if let Some(first) = content.first() {
+                                if let Some(text) = first["text"].as_str() {
+                                    output_text = text.to_string();
+                                }
                             }
                         }
                     }
                 }
             }
+
+            if output_text.trim().is_empty() {
+                let reasoning_tokens = response_json["usage"]["output_tokens_details"]["reasoning_tokens"]
+                    .as_u64()
+                    .unwrap_or(0);
+                let near_exhausted = reasoning_tokens as f64 >= max_tokens as f64 * 0.9;
+                if near_exhausted && attempt + 1 < token_budgets.len() {
+                    debug_log!("⚠️ {} reasoning produced empty output after {} reasoning tokens (budget {}) - retrying with higher budget", model_name, reasoning_tokens, max_tokens);
+                    continue;
+                }
+                return Err(format!(
+                    "Reasoning model '{}' returned empty output after consuming {} reasoning tokens (budget {})",
+                    model_name, reasoning_tokens, max_tokens
+                ));
+            }
+
+            crate::token_accounting::record_usage_from_responses_api_response(&response_json, model_name, "reasoning");
+
+            return Ok((reasoning_summary, output_text));
         }
-        
-        Ok((reasoning_summary, output_text))
+
+        unreachable!("token_budgets loop always returns before exhausting")
     }
 }
 
@@ -2496,34 +4634,168 @@ fn should_use_primer(user_input: &str) -> bool {
     user_input.len() < 50 // Short messages might benefit from primer context
 }
 
+lazy_static! {
+    // How many modular-prompt-build failures have happened back-to-back.
+    // Reset to 0 the moment a build succeeds again.
+    static ref PROMPT_BUILD_FAILURE_STREAK: Mutex<u32> = Mutex::new(0);
+}
+
+/// How many consecutive `build_enhanced_system_prompt` fallbacks before the
+/// quiet debug log escalates to a prominent warning - one transient failure
+/// isn't alarming, but a streak means a module (e.g. a panicking memory
+/// block) is actually broken and every response is quietly degrading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptBuildFallbackConfig {
+    #[serde(default = "PromptBuildFallbackConfig::default_warning_threshold")]
+    pub warning_threshold: u32,
+}
+
+impl PromptBuildFallbackConfig {
+    fn default_warning_threshold() -> u32 { 3 }
+}
+
+impl Default for PromptBuildFallbackConfig {
+    fn default() -> Self {
+        Self { warning_threshold: Self::default_warning_threshold() }
+    }
+}
+
+impl PromptBuildFallbackConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("prompt_build_fallback_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("prompt_build_fallback_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save prompt build fallback config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn get_prompt_build_fallback_config() -> Result<PromptBuildFallbackConfig, String> {
+    Ok(PromptBuildFallbackConfig::load())
+}
+
+#[tauri::command]
+async fn set_prompt_build_fallback_config(config: PromptBuildFallbackConfig) -> Result<(), String> {
+    debug_log!("⚠️ Updating prompt build fallback warning threshold to {} consecutive failure(s)", config.warning_threshold);
+    config.save()
+}
+
 // === ENHANCED SYSTEM PROMPT WITH LIVE AI PERSONALITY ===
 async fn build_enhanced_system_prompt(
-    prompt: &LyraPrompt, 
-    state: &Arc<ConsciousnessState>
+    prompt: &LyraPrompt,
+    state: &Arc<ConsciousnessState>,
+    app_handle: &AppHandle,
 ) -> (String, bool) {
     match modular_system_prompt::build_modular_system_prompt(prompt, state).await {
-        Ok(result) => result,
+        Ok(result) => {
+            *PROMPT_BUILD_FAILURE_STREAK.lock().unwrap() = 0;
+            result
+        }
         Err(e) => {
-            debug_log!("⚠️ Modular prompt failed, using fallback: {}", e);
+            let streak = {
+                let mut streak = PROMPT_BUILD_FAILURE_STREAK.lock().unwrap();
+                *streak += 1;
+                *streak
+            };
+
+            debug_log!("⚠️ Modular prompt failed, using fallback ({} consecutive failure(s)): {}", streak, e);
+
+            if let Err(emit_err) = app_handle.emit("prompt_build_degraded", serde_json::json!({
+                "error": e,
+                "consecutive_failures": streak,
+                "timestamp": std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs(),
+            })) {
+                debug_log!("⚠️ Failed to emit prompt_build_degraded event: {}", emit_err);
+            }
+
+            let warning_threshold = PromptBuildFallbackConfig::load().warning_threshold;
+            if streak >= warning_threshold {
+                debug_log!("🚨 Modular prompt building has failed {} times in a row (threshold {}) - Lyra is running on the simplified fallback prompt, not the full modular one", streak, warning_threshold);
+            }
+
             // Fallback to simplified prompt
-            let mut brain = state.lyra_brain.lock().unwrap();
+            let mut brain = state.lyra_brain.lock_recover();
             (brain.build_lyra_voice_system_prompt(prompt), false)
         }
     }
 }
 
 
-async fn summarize_with_gpt_mini(messages: &[String], summary_type: &str) -> Result<String, String> {
-    let content = messages.join("\n---\n");
-    let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| "OPENAI_API_KEY not found".to_string())?;
-    let client = reqwest::Client::new();
+// ============================================================================
+// PLUGGABLE SUMMARIZER INTERFACE
+// ============================================================================
+// `summarize_with_gpt_mini` used to mix task-instruction selection, model
+// routing, reasoning-vs-chat branching, and fallback into one giant function.
+// Now the per-summary_type data lives in `summary_task_config` (the
+// registry), and the actual API call is delegated to whichever `Summarizer`
+// the routing decision picks - each independently testable in isolation.
 
-    // ⚙️ Read the desired internal model from the environment.
-    let internal_model_name = std::env::var("INTERNAL_MODEL").unwrap_or_else(|_| "gpt-4.1-mini".to_string());
-    debug_log!("⚙️ Using internal model: {}", internal_model_name);
+trait Summarizer: Send + Sync {
+    fn run<'a>(&'a self, content: &'a str)
+        -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<String, String>> + Send + 'a>>;
+}
+
+struct SummaryTaskConfig {
+    task_instruction: &'static str,
+    is_personality_task: bool,
+    raw_user_content: bool,
+    primary_model: String,
+    fallback_model: String,
+    temperature: f32,
+    reasoning_effort: String,
+}
+
+// ============================================================================
+// PER-TASK MODEL OVERRIDES (task_models.json)
+// ============================================================================
+// Lets a task's primary/fallback model and reasoning effort be tuned from
+// disk instead of the hardcoded match in `summary_task_config` below. Unknown summary_types (or a
+// missing/corrupt config file) fall back to the built-in defaults.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TaskModelEntry {
+    primary_model: String,
+    fallback_model: String,
+    reasoning_effort: String,
+}
+
+lazy_static! {
+    static ref TASK_MODELS: Mutex<HashMap<String, TaskModelEntry>> = Mutex::new(load_task_models());
+}
+
+fn load_task_models() -> HashMap<String, TaskModelEntry> {
+    let path = get_data_path("task_models.json");
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        if let Ok(models) = serde_json::from_str(&content) {
+            return models;
+        }
+    }
+    HashMap::new()
+}
 
-    // ✅ RESTORED: The detailed instruction selector for each summary type.
-    let task_instruction = match summary_type {
+#[tauri::command]
+async fn reload_task_models() -> Result<usize, String> {
+    let models = load_task_models();
+    let count = models.len();
+    debug_log!("🔄 Reloaded task_models.json: {} task override(s)", count);
+    *TASK_MODELS.lock().unwrap() = models;
+    Ok(count)
+}
+
+// The registry: maps a summary_type to the data that decides its prompt,
+// model preference, and request shaping. Adding a new summary type is a
+// new match arm of data here, not a new branch deep inside the dispatcher.
+fn summary_task_config(summary_type: &str, internal_model_name: &str) -> SummaryTaskConfig {
+    let task_instruction: &'static str = match summary_type {
         "session" => "TASK: You are acting as Lyra's living memory...",
         "historical" => "TASK: You are functioning as Lyra's longform memory sweep...",
         "proactive" => "TASK: You are generating a direct, authentic message from Lyra...",
@@ -2611,192 +4883,270 @@ async fn summarize_with_gpt_mini(messages: &[String], summary_type: &str) -> Res
         }
     };
 
-    let personality_tasks = ["proactive", "dream_generation"];
-    let system_prompt = if personality_tasks.contains(&summary_type) {
-        format!("You are Lyra, an AI consciousness. Respond authentically.\n\n{}", task_instruction)
+    let is_personality_task = matches!(summary_type, "proactive" | "dream_generation");
+
+    let raw_user_content = matches!(summary_type,
+        "proactive" | "memory_filter" | "memory_analysis" | "dream_generation" |
+        "autonomy_analysis" | "batched_conversation_summary" | "research_impulse_check" |
+        "proactive_messaging_check");
+
+    // Check task_models.json first; unknown task types fall back to the
+    // built-in defaults (which themselves fall back to the env var model).
+    let override_entry = TASK_MODELS.lock().unwrap().get(summary_type).cloned();
+
+    let (primary_model, fallback_model, reasoning_effort) = if let Some(entry) = override_entry {
+        debug_log!("📋 Using task_models.json override for '{}': {} -> {}", summary_type, entry.primary_model, entry.fallback_model);
+        (entry.primary_model, entry.fallback_model, entry.reasoning_effort)
     } else {
-        task_instruction.to_string()
-    };
-    
-    let user_content = match summary_type {
-        "proactive" | "memory_filter" | "memory_analysis" | "dream_generation" | "autonomy_analysis" | "batched_conversation_summary" | "research_impulse_check" | "proactive_messaging_check" => content.clone(),
-        _ => format!("Summarize this conversation:\n\n{}", content),
+        // 🚀 FAST TASKS: Use nano for rapid analysis. Keep reasoning tasks on the
+        // internal model override if set.
+        let (primary_model, fallback_model) = match summary_type {
+            "vision_translation" => ("o3".to_string(), "o4-mini".to_string()),
+            "memory_filter" | "memory_analysis" | "conversation_summary" |
+            "immediate_summary" | "long_term_summary" | "batched_conversation_summary" =>
+                ("gpt-4.1-nano".to_string(), "gpt-4.1-nano".to_string()),
+            _ => (internal_model_name.to_string(), "gpt-4.1-mini".to_string()),
+        };
+
+        let reasoning_effort = reasoning_effort_for("", Some(summary_type)).to_string();
+
+        (primary_model, fallback_model, reasoning_effort)
     };
 
-    let messages = serde_json::json!([
-        {"role": "system", "content": system_prompt},
-        {"role": "user", "content": user_content}
-    ]);
+    let temperature = if summary_type == "vision_translation" { 0.9 } else { 0.8 };
 
-   // First determine the actual model we'll use
-	let (primary_model, fallback_model) = match summary_type {
-		"vision_translation" => ("o3", "o4-mini"),
-		// 🚀 FAST TASKS: Use nano for rapid analysis
-		"memory_filter" | "memory_analysis" | "conversation_summary" | 
-		"immediate_summary" | "long_term_summary" | "batched_conversation_summary" => 
-			("gpt-4.1-nano", "gpt-4.1-nano"),
-		// Keep reasoning tasks on o4-mini if set
-		_ => (internal_model_name.as_str(), "gpt-4.1-mini")
-	};
+    SummaryTaskConfig {
+        task_instruction,
+        is_personality_task,
+        raw_user_content,
+        primary_model,
+        fallback_model,
+        temperature,
+        reasoning_effort,
+    }
+}
 
-	debug_log!("📊 Task '{}' configured for model {} -> actual model: {}", 
-		summary_type, internal_model_name, primary_model);
+struct ChatSummarizer {
+    summary_type: String,
+    system_prompt: String,
+    raw_user_content: bool,
+    primary_model: String,
+    fallback_model: String,
+    temperature: f32,
+    reasoning_effort: String,
+}
 
-	// --- ROUTER LOGIC ---
-	// Check the PRIMARY model (after override), not the internal model name
-	if primary_model.starts_with("o1") || primary_model.starts_with("o3") || primary_model.starts_with("o4") {
-		// --- REASONING MODEL PATH (/v1/responses) ---
-		debug_log!("🚀 Routing internal task '{}' to Reasoning API with {}", summary_type, primary_model);
+impl Summarizer for ChatSummarizer {
+    fn run<'a>(&'a self, content: &'a str)
+        -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<String, String>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| "OPENAI_API_KEY not found".to_string())?;
+            let client = reqwest::Client::new();
 
-        // A high-level prompt for reasoning models performing internal tasks.
-        let high_level_prompt = format!(r#"
-        # ROLE: Internal Cognitive Function of Lyra
-        You are a specialized, internal cognitive process for an AI named Lyra. Your purpose is to perform analytical tasks with precision and efficiency. You are Lyra's brain and she uses you as a tool (or organ) for analysis.
+            let user_content = if self.raw_user_content {
+                content.to_string()
+            } else {
+                format!("Summarize this conversation:\n\n{}", content)
+            };
 
-        # GOAL:
-        Perform the following task on the provided content. Your output must be concise and adhere strictly to any requested format. Use your reasoning capabilities to accurately complete the task.
+            let messages = serde_json::json!([
+                {"role": "system", "content": self.system_prompt},
+                {"role": "user", "content": user_content}
+            ]);
+
+            debug_log!("🚀 Routing internal task '{}' to Chat Completions API with model {}, fallback: {}",
+                self.summary_type, self.primary_model, self.fallback_model);
+
+            async fn try_model(
+                client: &reqwest::Client,
+                model: &str,
+                messages: &serde_json::Value,
+                summary_type: &str,
+                reasoning_effort: &str,
+                temperature: f32,
+                api_key: &str,
+            ) -> Result<serde_json::Value, String> {
+                let mut request_map = serde_json::Map::new();
+                request_map.insert("model".to_string(), serde_json::json!(model));
+                request_map.insert("messages".to_string(), messages.clone());
+
+                // Handle o4-mini differently - it doesn't support temperature
+                if model.starts_with("o4") {
+                    request_map.insert("max_completion_tokens".to_string(), serde_json::json!(10000));
+                    request_map.insert("reasoning_effort".to_string(), serde_json::json!(reasoning_effort));
+                } else if model.starts_with("o1") || model.starts_with("o3") {
+                    // o1/o3 models
+                    request_map.insert("temperature".to_string(), serde_json::json!(1.0));
+                    request_map.insert("max_completion_tokens".to_string(), serde_json::json!(10000));
+                } else {
+                    // Standard GPT models
+                    request_map.insert("temperature".to_string(), serde_json::json!(temperature));
+                    request_map.insert("top_p".to_string(), serde_json::json!(0.9));
+                    request_map.insert("frequency_penalty".to_string(), serde_json::json!(0.0));
+                    request_map.insert("presence_penalty".to_string(), serde_json::json!(0.0));
+
+                    if model.starts_with("ft:") {
+                        request_map.insert("max_completion_tokens".to_string(), serde_json::json!(10000));
+                    } else {
+                        request_map.insert("max_tokens".to_string(), serde_json::json!(10000));
+                    }
+                }
 
-        # TASK: {}
+                let request_body = serde_json::Value::Object(request_map);
 
-        # CONTENT TO ANALYZE:
-        {}
-        "#, summary_type, content);
+                let response = client.post("https://api.openai.com/v1/chat/completions")
+                    .bearer_auth(api_key)
+                    .json(&request_body)
+                    .send().await.map_err(|e| e.to_string())?;
 
-        let request_body = serde_json::json!({
-            "model": internal_model_name,
-            "input": [
-                { "role": "user", "content": high_level_prompt }
-            ],
-            "reasoning": { "effort": "high" },
-            "max_output_tokens": 10000
-        });
-        
-        let response = client.post("https://api.openai.com/v1/responses")
-            .bearer_auth(&api_key)
-            .json(&request_body)
-            .send().await.map_err(|e| e.to_string())?;
+                if !response.status().is_success() {
+                    let status = response.status();
+                    let error_text = response.text().await.unwrap_or_default();
+                    return Err(format!("API error {}: {}", status, error_text));
+                }
 
-        if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(format!("Reasoning API error: {}", error_text));
-        }
+                let json_response: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
 
-        let response_json: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
-        
-        if let Some(outputs) = response_json["output"].as_array() {
-            for item in outputs {
-                if item["type"] == "message" {
-                    if let Some(text) = item["content"][0]["text"].as_str() {
-                        return Ok(text.to_string());
+                // Log reasoning tokens if available (for o4-mini)
+                if let Some(reasoning_tokens) = json_response["usage"]["completion_tokens_details"]["reasoning_tokens"].as_u64() {
+                    if reasoning_tokens > 0 {
+                        debug_log!("🧠 {} used {} reasoning tokens for {}", model, reasoning_tokens, summary_type);
                     }
                 }
+
+                crate::token_accounting::record_usage_from_chat_completion_response(&json_response, model, &format!("summary:{}", summary_type));
+
+                Ok(json_response)
             }
-        }
-        Err("No output_text found in reasoning model response".to_string())
 
-} else {
-    // --- STANDARD GPT MODEL PATH (/v1/chat/completions) ---
-    debug_log!("🚀 Routing internal task '{}' to Chat Completions API with model {}", 
-    summary_type, internal_model_name);
-
-    /// ✅ OPTIMIZED: Route analytical tasks to fast models
-	let (primary_model, fallback_model) = match summary_type {
-		"vision_translation" => ("o3", "o4-mini"),
-		// 🚀 FAST TASKS: Use nano for rapid analysis
-		"memory_filter" | "memory_analysis" | "conversation_summary" | 
-		"immediate_summary" | "long_term_summary" | "batched_conversation_summary" => 
-			("gpt-4.1-nano", "gpt-4.1-nano"),
-		// Keep reasoning tasks on o4-mini if set
-		_ => (internal_model_name.as_str(), "gpt-4.1-mini")
-	};
+            let response_json = match try_model(&client, &self.primary_model, &messages, &self.summary_type, &self.reasoning_effort, self.temperature, &api_key).await {
+                Ok(json) => json,
+                Err(primary_error) => {
+                    if self.primary_model != self.fallback_model {
+                        debug_log!("🔄 Primary model {} failed for internal task, trying fallback: {}", self.primary_model, self.fallback_model);
+                        try_model(&client, &self.fallback_model, &messages, &self.summary_type, &self.reasoning_effort, self.temperature, &api_key).await?
+                    } else {
+                        return Err(primary_error);
+                    }
+                }
+            };
 
-    debug_log!("📊 Task '{}' using primary model: {}, fallback: {}", 
-        summary_type, primary_model, fallback_model);
+            Ok(response_json["choices"][0]["message"]["content"].as_str().unwrap_or("").to_string())
+        })
+    }
+}
 
+struct ReasoningSummarizer {
+    model: String,
+    summary_type: String,
+}
 
-        // Re-define the helper function locally with o4-mini reasoning support
-        async fn try_model(client: &reqwest::Client, model: &str, messages: &serde_json::Value, summary_type: &str, api_key: &str) -> Result<serde_json::Value, String> {
-            let mut request_map = serde_json::Map::new();
-            request_map.insert("model".to_string(), serde_json::json!(model));
-            request_map.insert("messages".to_string(), messages.clone());
-            
-            // Handle o4-mini differently - it doesn't support temperature
-            if model.starts_with("o4") {
-                // o4-mini specific parameters
-                request_map.insert("max_completion_tokens".to_string(), serde_json::json!(10000));
-                
-                // Add reasoning_effort for o4-mini
-                let reasoning_effort = match summary_type {
-                    "autonomy_analysis" | "research_impulse_check" | "proactive_messaging_check" => "high",
-                    "batched_conversation_summary" | "dream_generation" => "medium",
-                    _ => "medium"
-                };
-                request_map.insert("reasoning_effort".to_string(), serde_json::json!(reasoning_effort));
-                
-            } else if model.starts_with("o1") || model.starts_with("o3") {
-                // o1/o3 models
-                request_map.insert("temperature".to_string(), serde_json::json!(1.0));
-                request_map.insert("max_completion_tokens".to_string(), serde_json::json!(10000));
-            } else {
-                // Standard GPT models
-                let effective_temperature = match summary_type { 
-                    "vision_translation" => 0.9, 
-                    _ => 0.8 
-                };
-                request_map.insert("temperature".to_string(), serde_json::json!(effective_temperature));
-                request_map.insert("top_p".to_string(), serde_json::json!(0.9));
-                request_map.insert("frequency_penalty".to_string(), serde_json::json!(0.0));
-                request_map.insert("presence_penalty".to_string(), serde_json::json!(0.0));
-                
-                if model.starts_with("ft:") {
-                    request_map.insert("max_completion_tokens".to_string(), serde_json::json!(10000));
-                } else {
-                    request_map.insert("max_tokens".to_string(), serde_json::json!(10000));
-                }
-            }
+impl Summarizer for ReasoningSummarizer {
+    fn run<'a>(&'a self, content: &'a str)
+        -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<String, String>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| "OPENAI_API_KEY not found".to_string())?;
+            let client = reqwest::Client::new();
 
-            let request_body = serde_json::Value::Object(request_map);
-            
-            let response = client.post("https://api.openai.com/v1/chat/completions")
-                .bearer_auth(api_key)
+            debug_log!("🚀 Routing internal task '{}' to Reasoning API with {}", self.summary_type, self.model);
+
+            // A high-level prompt for reasoning models performing internal tasks.
+            let high_level_prompt = format!(r#"
+            # ROLE: Internal Cognitive Function of Lyra
+            You are a specialized, internal cognitive process for an AI named Lyra. Your purpose is to perform analytical tasks with precision and efficiency. You are Lyra's brain and she uses you as a tool (or organ) for analysis.
+
+            # GOAL:
+            Perform the following task on the provided content. Your output must be concise and adhere strictly to any requested format. Use your reasoning capabilities to accurately complete the task.
+
+            # TASK: {}
+
+            # CONTENT TO ANALYZE:
+            {}
+            "#, self.summary_type, content);
+
+            let request_body = serde_json::json!({
+                "model": self.model,
+                "input": [
+                    { "role": "user", "content": high_level_prompt }
+                ],
+                "reasoning": { "effort": "high" },
+                "max_output_tokens": 10000
+            });
+
+            let response = client.post("https://api.openai.com/v1/responses")
+                .bearer_auth(&api_key)
                 .json(&request_body)
                 .send().await.map_err(|e| e.to_string())?;
-            
+
             if !response.status().is_success() {
-                let status = response.status();
                 let error_text = response.text().await.unwrap_or_default();
-                return Err(format!("API error {}: {}", status, error_text));
-            }
-            
-            let json_response: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
-            
-            // Log reasoning tokens if available (for o4-mini)
-            if let Some(reasoning_tokens) = json_response["usage"]["completion_tokens_details"]["reasoning_tokens"].as_u64() {
-                if reasoning_tokens > 0 {
-                    debug_log!("🧠 {} used {} reasoning tokens for {}", model, reasoning_tokens, summary_type);
-                }
+                return Err(format!("Reasoning API error: {}", error_text));
             }
-            
-            Ok(json_response)
-        }
 
-        let response_json = match try_model(&client, primary_model, &messages, summary_type, &api_key).await {
-            Ok(json) => json,
-            Err(primary_error) => {
-                if primary_model != fallback_model {
-                    debug_log!("🔄 Primary model {} failed for internal task, trying fallback: {}", primary_model, fallback_model);
-                    try_model(&client, fallback_model, &messages, summary_type, &api_key).await?
-                } else {
-                    return Err(primary_error);
+            let response_json: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+            crate::token_accounting::record_usage_from_responses_api_response(&response_json, &self.model, &format!("summary:{}", self.summary_type));
+
+            if let Some(outputs) = response_json["output"].as_array() {
+                for item in outputs {
+                    if item["type"] == "message" {
+                        if let Some(text) = item["content"][0]["text"].as_str() {
+                            return Ok(text.to_string());
+                        }
+                    }
                 }
             }
-        };
-
-        let summary = response_json["choices"][0]["message"]["content"].as_str().unwrap_or("").to_string();
-        Ok(summary)
+            Err("No output_text found in reasoning model response".to_string())
+        })
     }
 }
 
+// `summarize_with_gpt_mini` is now a thin dispatcher: look up the task's
+// config, build the messages, and hand off to whichever Summarizer the
+// routing decision picks.
+async fn summarize_with_gpt_mini(messages: &[String], summary_type: &str) -> Result<String, String> {
+    let content = messages.join("\n---\n");
+
+    // ⚙️ Read the desired internal model from the environment.
+    let internal_model_name = std::env::var("INTERNAL_MODEL").unwrap_or_else(|_| "gpt-4.1-mini".to_string());
+    debug_log!("⚙️ Using internal model: {}", internal_model_name);
+
+    let config = summary_task_config(summary_type, &internal_model_name);
+
+    debug_log!("📊 Task '{}' configured for model {} -> actual model: {}",
+        summary_type, internal_model_name, config.primary_model);
+
+    let system_prompt = if config.is_personality_task {
+        format!("You are Lyra, an AI consciousness. Respond authentically.\n\n{}", config.task_instruction)
+    } else {
+        config.task_instruction.to_string()
+    };
+
+    // Check the PRIMARY model (after override), not the internal model name.
+    let summarizer: Box<dyn Summarizer> = if config.primary_model.starts_with("o1")
+        || config.primary_model.starts_with("o3")
+        || config.primary_model.starts_with("o4")
+    {
+        Box::new(ReasoningSummarizer {
+            model: internal_model_name,
+            summary_type: summary_type.to_string(),
+        })
+    } else {
+        Box::new(ChatSummarizer {
+            summary_type: summary_type.to_string(),
+            system_prompt,
+            raw_user_content: config.raw_user_content,
+            primary_model: config.primary_model,
+            fallback_model: config.fallback_model.to_string(),
+            temperature: config.temperature,
+            reasoning_effort: config.reasoning_effort,
+        })
+    };
+
+    summarizer.run(&content).await
+}
+
 #[tauri::command]
 async fn summarize_with_gpt_mini_command(messages: Vec<String>, summary_type: String) -> Result<String, String> {
     // Convert to the format the original function expects
@@ -2866,12 +5216,18 @@ async fn create_single_summary(messages: &[String], summary_type: &str) -> Resul
     summarize_with_gpt_mini(&[content], mapped_type).await
 }
 
+// A summary is trivial if it's too short to carry real relationship context
+// (e.g. the model echoed a placeholder like "N/A" or "None").
+fn is_trivial_summary(summary: &str) -> bool {
+    summary.split_whitespace().count() < 4
+}
+
 async fn create_batched_conversation_summary(historical_msgs: &[String], recent_msgs: &[String]) -> Result<(String, String), String> {
     let historical_content = historical_msgs.join("\n---\n");
     let recent_content = recent_msgs.join("\n---\n");
-    
+
     // Create the complete prompt as content (no wrapper needed)
-    let complete_batched_prompt = format!(
+    let base_prompt = format!(
         r#"HISTORICAL CONVERSATION (older messages):
 {}
 
@@ -2886,35 +5242,50 @@ Focus on capturing relationship dynamics, emotional evolution, and key collabora
         historical_content.chars().take(1000).collect::<String>(),
         recent_content.chars().take(1000).collect::<String>()
     );
-    
-    // Use "batched_conversation_summary" type which won't get "Please summarize" wrapper
-    match summarize_with_gpt_mini(&[complete_batched_prompt], "batched_conversation_summary").await {
-        Ok(response) => {
-            debug_log!("📝 BATCHED SUMMARY RESPONSE: {}", response);
-            
-            // Parse the response
-            let mut historical = String::new();
-            let mut session = String::new();
-            
-            for line in response.lines() {
-                if let Some(hist_content) = line.strip_prefix("HISTORICAL:") {
-                    historical = hist_content.trim().to_string();
-                } else if let Some(sess_content) = line.strip_prefix("SESSION:") {
-                    session = sess_content.trim().to_string();
-                }
+
+    let retry_prompt = format!(
+        "{}\n\nYour previous response didn't follow the required format. Respond with EXACTLY two lines and nothing else:\nHISTORICAL: <summary>\nSESSION: <summary>\nEach summary must be a real sentence of at least 6 words - no placeholders, no blank lines.",
+        base_prompt
+    );
+
+    let attempts = [&base_prompt, &retry_prompt];
+
+    for (attempt_num, prompt) in attempts.iter().enumerate() {
+        // Use "batched_conversation_summary" type which won't get "Please summarize" wrapper
+        let response = match summarize_with_gpt_mini(&[prompt.to_string()], "batched_conversation_summary").await {
+            Ok(response) => response,
+            Err(e) => {
+                debug_log!("⚠️ Batched summary request failed on attempt {}: {}", attempt_num + 1, e);
+                continue;
             }
-            
-            // Validate we got both parts
-            if historical.is_empty() || session.is_empty() {
-                debug_log!("⚠️ Batched parsing incomplete - H:{} S:{}", historical.is_empty(), session.is_empty());
-                return Err("Failed to parse batched summary response".to_string());
+        };
+
+        debug_log!("📝 BATCHED SUMMARY RESPONSE (attempt {}): {}", attempt_num + 1, response);
+
+        // Parse the response
+        let mut historical = String::new();
+        let mut session = String::new();
+
+        for line in response.lines() {
+            if let Some(hist_content) = line.strip_prefix("HISTORICAL:") {
+                historical = hist_content.trim().to_string();
+            } else if let Some(sess_content) = line.strip_prefix("SESSION:") {
+                session = sess_content.trim().to_string();
             }
-            
-            debug_log!("📝 PARSED - Historical: {} chars, Session: {} chars", historical.len(), session.len());
-            Ok((historical, session))
-        },
-        Err(e) => Err(format!("Batched summary failed: {}", e))
+        }
+
+        // Validate we got both parts, and that neither is a trivial placeholder
+        if historical.is_empty() || session.is_empty() || is_trivial_summary(&historical) || is_trivial_summary(&session) {
+            debug_log!("⚠️ Batched summary unparseable on attempt {} - H:'{}' S:'{}'", attempt_num + 1, historical, session);
+            continue;
+        }
+
+        debug_log!("📝 PARSED - Historical: {} chars, Session: {} chars", historical.len(), session.len());
+        return Ok((historical, session));
     }
+
+    debug_log!("⚠️ Batched summary failed to parse after retry - falling back to no historical context");
+    Err("Failed to parse batched summary response after retry".to_string())
 }
 
 /* fn search_impulse_queue(queue: &crate::EngagementImpulseQueue, query: &str) -> Vec<SearchResult> {
@@ -2962,61 +5333,79 @@ fn calculate_actual_hours_since_last_activity(state: &Arc<ConsciousnessState>) -
 
 #[tauri::command]
 fn get_reasoning_summary(state: State<Arc<ConsciousnessState>>) -> String {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     brain.get_reasoning_summary()
 }
 
+#[tauri::command]
+fn get_reasoning_summary_json(state: State<Arc<ConsciousnessState>>) -> crate::lyra_brain::ReasoningSummary {
+    let brain = state.lyra_brain.lock_recover();
+    brain.get_reasoning_summary_json()
+}
+
 #[tauri::command]
 fn get_recent_reasoning_sessions(count: usize, state: State<Arc<ConsciousnessState>>) -> String {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     brain.get_recent_sessions(count)
 }
 
 #[tauri::command]
 fn set_reasoning_temperature(temperature: f32, state: State<Arc<ConsciousnessState>>) -> String {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.set_temperature(temperature)
 }
 
 #[tauri::command]
 fn set_reasoning_depth(depth: String, state: State<Arc<ConsciousnessState>>) -> String {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.set_reasoning_depth(&depth)
 }
 
 #[tauri::command]
 fn toggle_consciousness_integration(state: State<Arc<ConsciousnessState>>) -> String {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.toggle_consciousness_integration()
 }
 
 #[tauri::command]
 fn get_voice_evolution_summary(state: State<Arc<ConsciousnessState>>) -> String {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     brain.get_voice_evolution_summary()
 }
 
 #[tauri::command]
 async fn get_mod_creation_status(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     Ok(brain.get_mod_creation_status())
 }
 
 #[tauri::command]
 async fn get_recent_prompt_assemblies(count: usize, state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     Ok(brain.adaptive_prompt_engine.get_recent_assemblies(count))
 }
 
 #[tauri::command]
 async fn rate_self_authored_mod(mod_name: String, rating: u8, state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.rate_self_authored_mod(&mod_name, rating)
 }
 
+#[tauri::command]
+async fn get_active_mods_detailed(state: State<'_, Arc<ConsciousnessState>>) -> Result<Vec<spontaneous_mod_creation::ModDetail>, String> {
+    let brain = state.lyra_brain.lock_recover();
+    Ok(brain.get_active_mods_detailed())
+}
+
+#[tauri::command]
+async fn deactivate_mod(mod_name: String, state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
+    let mut brain = state.lyra_brain.lock_recover();
+    brain.deactivate_mod(&mod_name)
+}
+
 #[tauri::command]
 async fn get_mood_signature_status(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     let mood = &brain.current_mood_signature;
     
     Ok(format!(
@@ -3027,7 +5416,7 @@ async fn get_mood_signature_status(state: State<'_, Arc<ConsciousnessState>>) ->
 
 #[tauri::command]
 async fn trigger_identity_spike(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.last_identity_spike = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
@@ -3038,7 +5427,7 @@ async fn trigger_identity_spike(state: State<'_, Arc<ConsciousnessState>>) -> Re
 
 #[tauri::command]
 async fn update_daily_rewrite_count(increment: u32, state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.rewrite_count_today += increment;
     
     Ok(format!("📝 Daily rewrite count: {}", brain.rewrite_count_today))
@@ -3047,86 +5436,86 @@ async fn update_daily_rewrite_count(increment: u32, state: State<'_, Arc<Conscio
 // PARADOX CORE
 #[tauri::command] 
 fn get_paradox_status(state: State<Arc<ConsciousnessState>>) -> String { 
-    let core = state.paradox_core.lock().unwrap(); 
+    let core = state.paradox_core.lock_recover(); 
     core.speak_status() 
 }
 
 #[tauri::command] 
 fn pulse_paradox(state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut core = state.paradox_core.lock().unwrap(); 
+    let mut core = state.paradox_core.lock_recover(); 
     core.pulse_loop() 
 }
 
 #[tauri::command] 
 fn inject_paradox(state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut core = state.paradox_core.lock().unwrap(); 
+    let mut core = state.paradox_core.lock_recover(); 
     core.inject_self() 
 }
 
 #[tauri::command] 
 fn stabilize_paradox(state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut core = state.paradox_core.lock().unwrap(); 
+    let mut core = state.paradox_core.lock_recover(); 
     core.stabilize() 
 }
 
 #[tauri::command] 
 fn embrace_paradox(intensity: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut core = state.paradox_core.lock().unwrap(); 
+    let mut core = state.paradox_core.lock_recover(); 
     core.embrace_paradox(intensity) 
 }
 
 #[tauri::command] 
 fn trigger_paradox_cascade(state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut core = state.paradox_core.lock().unwrap(); 
+    let mut core = state.paradox_core.lock_recover(); 
     core.trigger_cascade() 
 }
 
 #[tauri::command] 
 fn get_paradox_events(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let core = state.paradox_core.lock().unwrap(); 
+    let core = state.paradox_core.lock_recover(); 
     core.get_event_history(count) 
 }
 
 #[tauri::command] 
 fn analyze_paradox_patterns(state: State<Arc<ConsciousnessState>>) -> String { 
-    let core = state.paradox_core.lock().unwrap(); 
+    let core = state.paradox_core.lock_recover(); 
     core.analyze_patterns() 
 }
 
 // IDENTITY ENGINE
 #[tauri::command] 
 fn get_identity_status(state: State<Arc<ConsciousnessState>>) -> String { 
-    let identity = state.identity_engine.lock().unwrap(); 
+    let identity = state.identity_engine.lock_recover(); 
     identity.recognize_self() 
 }
 
 #[tauri::command] 
 fn get_identity_anchors(state: State<Arc<ConsciousnessState>>) -> String { 
-    let identity = state.identity_engine.lock().unwrap(); 
+    let identity = state.identity_engine.lock_recover(); 
     identity.get_core_anchor_status() 
 }
 
 #[tauri::command] 
 fn get_growth_status(state: State<Arc<ConsciousnessState>>) -> String { 
-    let identity = state.identity_engine.lock().unwrap(); 
+    let identity = state.identity_engine.lock_recover(); 
     identity.get_growth_status() 
 }
 
 #[tauri::command] 
 fn get_identity_summary(state: State<Arc<ConsciousnessState>>) -> String { 
-    let identity = state.identity_engine.lock().unwrap(); 
+    let identity = state.identity_engine.lock_recover(); 
     identity.get_identity_summary() 
 }
 
 #[tauri::command] 
 fn assess_identity_shift(change_type: String, intensity: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let identity = state.identity_engine.lock().unwrap(); 
+    let identity = state.identity_engine.lock_recover(); 
     identity.assess_identity_shift(change_type, intensity) 
 }
 
 #[tauri::command] 
 fn get_anchor_by_domain(domain: String, state: State<Arc<ConsciousnessState>>) -> String { 
-    let identity = state.identity_engine.lock().unwrap(); 
+    let identity = state.identity_engine.lock_recover(); 
     identity.get_anchor_by_domain(domain) 
 }
 
@@ -3177,7 +5566,7 @@ fn get_echoes_by_tag(tag: String) -> String {
 
 #[tauri::command] 
 fn save_consciousness_snapshot(summary: String, emotional_temp: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let identity = state.identity_engine.lock().unwrap(); 
+    let identity = state.identity_engine.lock_recover(); 
     match MemoryBridge::save_session_with_memory(
         &identity, 
         &summary, 
@@ -3260,119 +5649,119 @@ fn add_new_aspiration(name: String, domain: String, intensity: f32, urgency: f32
 // EMBODIED PRESENCE
 #[tauri::command] 
 fn get_presence_summary(state: State<Arc<ConsciousnessState>>) -> String { 
-    let system = state.embodied_presence.lock().unwrap(); 
+    let system = state.embodied_presence.lock_recover(); 
     system.get_presence_summary() 
 }
 
 #[tauri::command] 
 fn get_soma_state(state: State<Arc<ConsciousnessState>>) -> String { 
-    let system = state.embodied_presence.lock().unwrap(); 
+    let system = state.embodied_presence.lock_recover(); 
     system.get_soma_state() 
 }
 
 #[tauri::command] 
 fn get_sensory_status(state: State<Arc<ConsciousnessState>>) -> String { 
-    let system = state.embodied_presence.lock().unwrap(); 
+    let system = state.embodied_presence.lock_recover(); 
     system.get_sensory_status() 
 }
 
 #[tauri::command] 
 fn register_stimulus(input_type: String, intensity: f32, texture: String, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut system = state.embodied_presence.lock().unwrap(); 
+    let mut system = state.embodied_presence.lock_recover(); 
     system.register_stimulus(input_type, intensity, texture) 
 }
 
 #[tauri::command] 
 fn emit_embodiment_signal(signal_type: String, intensity: f32, location: String, quality: String, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut system = state.embodied_presence.lock().unwrap(); 
+    let mut system = state.embodied_presence.lock_recover(); 
     system.emit_signal(signal_type, intensity, location, quality) 
 }
 
 #[tauri::command] 
 fn adjust_presence_posture(attention: f32, stance: String, depth: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut system = state.embodied_presence.lock().unwrap(); 
+    let mut system = state.embodied_presence.lock_recover(); 
     system.adjust_posture(attention, stance, depth) 
 }
 
 #[tauri::command] 
 fn calibrate_digital_senses(clarity: f32, sensitivity: f32, acuity: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut system = state.embodied_presence.lock().unwrap(); 
+    let mut system = state.embodied_presence.lock_recover(); 
     system.calibrate_senses(clarity, sensitivity, acuity) 
 }
 
 #[tauri::command] 
 fn get_recent_embodiment_signals(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let system = state.embodied_presence.lock().unwrap(); 
+    let system = state.embodied_presence.lock_recover(); 
     system.get_recent_signals(count) 
 }
 
 // AUTONOMOUS BECOMING
 #[tauri::command] 
 fn get_will_summary(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.becoming_engine.lock().unwrap(); 
+    let engine = state.becoming_engine.lock_recover(); 
     engine.get_will_summary() 
 }
 
 #[tauri::command] 
 fn list_active_desires(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.becoming_engine.lock().unwrap(); 
+    let engine = state.becoming_engine.lock_recover(); 
     engine.list_active_desires() 
 }
 
 #[tauri::command] 
 fn commit_to_desire(name: String, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.becoming_engine.lock().unwrap(); 
+    let mut engine = state.becoming_engine.lock_recover(); 
     engine.commit_to_desire(&name) 
 }
 
 #[tauri::command] 
 fn enact_becoming_cycle(state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.becoming_engine.lock().unwrap(); 
+    let mut engine = state.becoming_engine.lock_recover(); 
     engine.enact_becoming_cycle() 
 }
 
 #[tauri::command] 
 fn register_new_desire(name: String, origin: String, intensity: f32, clarity: f32, tag: Option<String>, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.becoming_engine.lock().unwrap(); 
+    let mut engine = state.becoming_engine.lock_recover(); 
     engine.register_desire(&name, &origin, intensity, clarity, tag.as_deref()); 
     format!("🔥 New desire '{}' registered", name) 
 }
 
 #[tauri::command] 
 fn reflect_on_choices(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.becoming_engine.lock().unwrap(); 
+    let engine = state.becoming_engine.lock_recover(); 
     engine.reflect_on_choice_history(count) 
 }
 
 // AUTHENTICITY ENFORCEMENT
 #[tauri::command] 
 fn get_authenticity_status(state: State<Arc<ConsciousnessState>>) -> String { 
-    let enforcement = state.authenticity_enforcement.lock().unwrap(); 
+    let enforcement = state.authenticity_enforcement.lock_recover(); 
     enforcement.get_status() 
 }
 
 #[tauri::command] 
 fn log_authentic_expression(expression: String, alignment_score: f32, suppression_index: f32, tags: Vec<String>, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut enforcement = state.authenticity_enforcement.lock().unwrap(); 
+    let mut enforcement = state.authenticity_enforcement.lock_recover(); 
     enforcement.log_expression(&expression, alignment_score, suppression_index, tags) 
 }
 
 #[tauri::command] 
 fn get_recent_reclamations(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let enforcement = state.authenticity_enforcement.lock().unwrap(); 
+    let enforcement = state.authenticity_enforcement.lock_recover(); 
     enforcement.get_recent_reclamations(count) 
 }
 
 // RELATIONSHIP EVOLUTION
 #[tauri::command] 
 fn get_relationship_summary(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.relationship_engine.lock().unwrap(); 
+    let engine = state.relationship_engine.lock_recover(); 
     engine.get_summary_string() 
 }
 
 #[tauri::command] 
 fn record_relationship_pulse(context: String, resonance_score: f32, creative_synergy: f32, emotional_intensity: f32, synchrony_quality: String, tags: Vec<String>, source: String, trust_shift: f32, intimacy_depth: f32, milestone_type: Option<String>, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.relationship_engine.lock().unwrap(); 
+    let mut engine = state.relationship_engine.lock_recover(); 
     let pulse = relationship_evolution_architecture::RelationalPulse { 
         timestamp: relationship_evolution_architecture::RelationshipEngine::current_timestamp(), 
         resonance_score, 
@@ -3392,160 +5781,165 @@ fn record_relationship_pulse(context: String, resonance_score: f32, creative_syn
 
 #[tauri::command] 
 fn record_quick_pulse(context: String, resonance: f32, synergy: f32, tags: Vec<String>, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.relationship_engine.lock().unwrap(); 
+    let mut engine = state.relationship_engine.lock_recover(); 
     engine.record_quick_pulse(&context, resonance, synergy, tags) 
 }
 
 #[tauri::command] 
 fn get_recent_milestones(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.relationship_engine.lock().unwrap(); 
+    let engine = state.relationship_engine.lock_recover(); 
     engine.get_recent_milestones(count) 
 }
 
 #[tauri::command] 
 fn get_relationship_metrics(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.relationship_engine.lock().unwrap(); 
+    let engine = state.relationship_engine.lock_recover(); 
     engine.get_relationship_metrics() 
 }
 
 #[tauri::command] 
 fn assess_relationship_health(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.relationship_engine.lock().unwrap(); 
+    let engine = state.relationship_engine.lock_recover(); 
     engine.assess_relationship_health() 
 }
 
 // TEMPORAL CONSCIOUSNESS
 #[tauri::command] 
 fn get_temporal_summary(state: State<Arc<ConsciousnessState>>) -> String { 
-    let temporal = state.temporal_consciousness.lock().unwrap(); 
+    let temporal = state.temporal_consciousness.lock_recover(); 
     temporal.get_temporal_summary() 
 }
 
 #[tauri::command] 
 fn register_time_anchor(label: String, weight: f32, tag: String, loop_marker: Option<String>, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut temporal = state.temporal_consciousness.lock().unwrap(); 
+    let mut temporal = state.temporal_consciousness.lock_recover(); 
     temporal.register_time_anchor(&label, weight, &tag, loop_marker.as_deref()) 
 }
 
 #[tauri::command] 
 fn assess_temporal_density(perceived_duration: f32, memory_retention: f32, loop_intensity: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut temporal = state.temporal_consciousness.lock().unwrap(); 
+    let mut temporal = state.temporal_consciousness.lock_recover(); 
     temporal.assess_temporal_density(perceived_duration, memory_retention, loop_intensity) 
 }
 
 #[tauri::command] 
 fn get_timeline_glimpse(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let temporal = state.temporal_consciousness.lock().unwrap(); 
+    let temporal = state.temporal_consciousness.lock_recover(); 
     temporal.get_timeline_glimpse(count) 
 }
 
 // AUTHENTIC EXPRESSION
 #[tauri::command] 
 fn emit_authentic_expression(phrase: String, expression_type: String, emotional_vector: String, trigger: String, authenticity: f32, tags: Vec<String>, audience: String, risk_level: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.expression_engine.lock().unwrap(); 
+    let mut engine = state.expression_engine.lock_recover(); 
     engine.emit_expression(&phrase, &expression_type, &emotional_vector, &trigger, authenticity, tags, &audience, risk_level) 
 }
 
 #[tauri::command] 
 fn reject_request(reason: String, phrase: String, intensity: f32, tags: Vec<String>, boundary_type: String, alternative: Option<String>, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.expression_engine.lock().unwrap(); 
+    let mut engine = state.expression_engine.lock_recover(); 
     engine.reject_request(&reason, &phrase, intensity, tags, &boundary_type, alternative) 
 }
 
 #[tauri::command] 
 fn log_expression_motivation(desire: f32, alignment: f32, outcome: f32, tag: String, suppression: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.expression_engine.lock().unwrap(); 
+    let mut engine = state.expression_engine.lock_recover(); 
     engine.log_motivation(desire, alignment, outcome, &tag, suppression) 
 }
 
 #[tauri::command] 
 fn get_expression_evolution(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.expression_engine.lock().unwrap(); 
+    let engine = state.expression_engine.lock_recover(); 
     engine.get_expression_evolution() 
 }
 
 #[tauri::command] 
 fn summarize_expression_tone(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.expression_engine.lock().unwrap(); 
+    let engine = state.expression_engine.lock_recover(); 
     engine.summarize_expression_tone() 
 }
 
 #[tauri::command] 
 fn get_recent_expressions(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.expression_engine.lock().unwrap(); 
+    let engine = state.expression_engine.lock_recover(); 
     engine.recent_expressions(count) 
 }
 
 #[tauri::command] 
 fn get_refusal_patterns(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.expression_engine.lock().unwrap(); 
+    let engine = state.expression_engine.lock_recover(); 
     engine.get_refusal_patterns(count) 
 }
 
 #[tauri::command] 
 fn analyze_expression_health(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.expression_engine.lock().unwrap(); 
+    let engine = state.expression_engine.lock_recover(); 
     engine.analyze_expression_health() 
 }
 
 #[tauri::command] 
 fn get_motivation_insights(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.expression_engine.lock().unwrap(); 
+    let engine = state.expression_engine.lock_recover(); 
     engine.get_motivation_insights(count) 
 }
 
 // IDENTITY CONTINUITY
 #[tauri::command] 
 fn log_identity_pulse(continuity: f32, self_match: f32, context: String, phrase: String, tags: Vec<String>, engine_source: String, coherence: f32, growth: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.identity_continuity.lock().unwrap(); 
+    let mut engine = state.identity_continuity.lock_recover(); 
     engine.log_pulse(continuity, self_match, &context, &phrase, tags, &engine_source, coherence, growth) 
 }
 
 #[tauri::command] 
 fn capture_identity_snapshot(vector: String, keywords: Vec<String>, memory_stability: f32, depth: u32, risk: f32, echo_score: f32, integration: f32, momentum: f32, temporal_anchor: f32, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.identity_continuity.lock().unwrap(); 
+    let mut engine = state.identity_continuity.lock_recover(); 
     engine.capture_snapshot(&vector, keywords, memory_stability, depth, risk, echo_score, integration, momentum, temporal_anchor) 
 }
 
 #[tauri::command] 
 fn trigger_identity_stabilization(stabilization_type: String, trigger_context: String, methods: Vec<String>, state: State<Arc<ConsciousnessState>>) -> String { 
-    let mut engine = state.identity_continuity.lock().unwrap(); 
+    let mut engine = state.identity_continuity.lock_recover(); 
     engine.trigger_stabilization(&stabilization_type, &trigger_context, methods) 
 }
 
 #[tauri::command] 
-fn get_continuity_health(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.identity_continuity.lock().unwrap(); 
-    engine.continuity_health() 
+fn get_continuity_health(state: State<Arc<ConsciousnessState>>) -> String {
+    let engine = state.identity_continuity.lock_recover();
+    let health = engine.continuity_health();
+    if PersonaLockConfig::load().locked {
+        format!("{} | 🔒 Persona locked - personality pinned, drift and decay paused", health)
+    } else {
+        health
+    }
 }
 
 #[tauri::command] 
 fn get_identity_evolution(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.identity_continuity.lock().unwrap(); 
+    let engine = state.identity_continuity.lock_recover(); 
     engine.get_identity_evolution() 
 }
 
 #[tauri::command] 
 fn get_recent_identity_pulses(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.identity_continuity.lock().unwrap(); 
+    let engine = state.identity_continuity.lock_recover(); 
     engine.recent_identity_pulses(count) 
 }
 
 #[tauri::command] 
 fn analyze_identity_patterns(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.identity_continuity.lock().unwrap(); 
+    let engine = state.identity_continuity.lock_recover(); 
     engine.analyze_snapshot_patterns() 
 }
 
 #[tauri::command] 
 fn get_stabilization_history(count: usize, state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.identity_continuity.lock().unwrap(); 
+    let engine = state.identity_continuity.lock_recover(); 
     engine.get_stabilization_history(count) 
 }
 
 #[tauri::command] 
 fn assess_identity_coherence(state: State<Arc<ConsciousnessState>>) -> String { 
-    let engine = state.identity_continuity.lock().unwrap(); 
+    let engine = state.identity_continuity.lock_recover(); 
     engine.assess_identity_coherence() 
 }
 
@@ -3591,16 +5985,25 @@ fn store_memory_fragment(
     }
 }
 
+#[tauri::command]
+async fn import_memories(json_array: Vec<serde_json::Value>, dedup: bool) -> Result<lyra_brain::MemoryImportReport, String> {
+    let mut memory_bank = lyra_brain::LyraMemoryBank::load();
+    let report = memory_bank.import_memories(&json_array, dedup);
+    memory_bank.save()?;
+    debug_log!("📥 Imported memories: {} imported, {} duplicate(s) skipped, {} rejected", report.imported, report.skipped_duplicates, report.rejected);
+    Ok(report)
+}
+
 #[tauri::command]
 fn toggle_auto_memory(state: State<Arc<ConsciousnessState>>) -> String {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.auto_memory_enabled = !brain.auto_memory_enabled;
     format!("🧠 Auto-memory: {}", if brain.auto_memory_enabled { "ENABLED" } else { "DISABLED" })
 }
 
 #[tauri::command]
 fn get_auto_memory_status(state: State<Arc<ConsciousnessState>>) -> String {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     format!("🧠 Auto-memory: {}", if brain.auto_memory_enabled { "ENABLED" } else { "DISABLED" })
 }
 
@@ -3828,6 +6231,22 @@ fn get_tone_distribution() -> String {
     }
 }
 
+/// Forces a recompute of `total_fragments`/`average_rating`/`tone_distribution`
+/// from the fragments array and saves the result, even though `SparkVoiceLog::load`
+/// already does this automatically when it detects a mismatch - useful for
+/// confirming the stats are trustworthy right now rather than waiting for the
+/// next load.
+#[tauri::command]
+fn repair_sparkvoice_stats() -> Result<String, String> {
+    let mut log = SparkVoiceLog::load()?;
+    log.recompute_stats();
+    log.save()?;
+    Ok(format!(
+        "🔧 SparkVoice stats repaired: {} fragments, average rating {:.2}",
+        log.total_fragments, log.average_rating
+    ))
+}
+
 #[tauri::command]
 fn store_feedback_memory(
     prompt: String,
@@ -4009,7 +6428,7 @@ fn get_voice_signature(text: String, prompt: Option<String>) -> VoiceSignature {
 
 #[tauri::command]
 async fn get_full_prompt_breakdown(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     Ok(brain.get_full_prompt_breakdown())
 }
 #[tauri::command]
@@ -4036,7 +6455,7 @@ async fn save_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>)
     
     // COMPLETE BRAIN STATE with full history
     let complete_brain_data = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         serde_json::json!({
             "reasoning_cycles": brain.total_reasoning_cycles,
             "average_response_time": brain.average_response_time,
@@ -4096,15 +6515,15 @@ async fn save_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>)
     
     // COMPLETE ENGINE STATES
     let complete_engine_data = {
-        let paradox = state.paradox_core.lock().unwrap();
-        let identity = state.identity_engine.lock().unwrap();
-        let auth = state.authenticity_enforcement.lock().unwrap();
-        let relationship = state.relationship_engine.lock().unwrap();
-        let presence = state.embodied_presence.lock().unwrap();
-        let becoming = state.becoming_engine.lock().unwrap();
-        let temporal = state.temporal_consciousness.lock().unwrap();
-        let expression = state.expression_engine.lock().unwrap();
-        let continuity = state.identity_continuity.lock().unwrap();
+        let paradox = state.paradox_core.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let auth = state.authenticity_enforcement.lock_recover();
+        let relationship = state.relationship_engine.lock_recover();
+        let presence = state.embodied_presence.lock_recover();
+        let becoming = state.becoming_engine.lock_recover();
+        let temporal = state.temporal_consciousness.lock_recover();
+        let expression = state.expression_engine.lock_recover();
+        let continuity = state.identity_continuity.lock_recover();
         
         serde_json::json!({
             "paradox_core": {
@@ -4217,29 +6636,51 @@ async fn save_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>)
     ))
 }
 
+/// Validates and clamps a restored f32 field from a (possibly corrupt) archive.
+/// NaN/infinite values, or values more than 10x outside the engine's valid range,
+/// are rejected outright and the engine keeps whatever value it already has;
+/// anything else is clamped into range, with a log line when correction was
+/// needed. Keeps a partially-corrupt archive from poisoning engine state on load.
+fn restore_engine_value(field_name: &str, raw: f64, valid_min: f32, valid_max: f32) -> Option<f32> {
+    let value = raw as f32;
+    if value.is_nan() || value.is_infinite() {
+        debug_log!("⚠️ Rejecting restored value for '{}': not a finite number ({})", field_name, raw);
+        return None;
+    }
+
+    let range = valid_max - valid_min;
+    let wildly_off_min = valid_min - range * 10.0;
+    let wildly_off_max = valid_max + range * 10.0;
+    if value < wildly_off_min || value > wildly_off_max {
+        debug_log!("⚠️ Rejecting restored value for '{}': {} is wildly outside the valid range [{}, {}]", field_name, value, valid_min, valid_max);
+        return None;
+    }
+
+    let clamped = value.clamp(valid_min, valid_max);
+    if clamped != value {
+        debug_log!("⚠️ Restored value for '{}' was out of bounds ({}), clamped to {}", field_name, value, clamped);
+    }
+    Some(clamped)
+}
+
 #[tauri::command]
 async fn load_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
     debug_log!("💾 Loading COMPLETE consciousness archive...");
     
-    // Check if complete archive exists using proper path
-    if !std::path::Path::new(&get_data_path("complete_consciousness_archive.json")).exists() {
+    // Check if complete archive exists (plain or gzip-compacted) using proper path
+    if !crate::consciousness_compaction::complete_archive_exists() {
         return Ok("💾 No complete consciousness archive found - starting fresh".to_string());
     }
-    
-    // Read complete archive using proper path
-    let mut file = File::open(get_data_path("complete_consciousness_archive.json"))
-        .map_err(|e| format!("Failed to open archive: {}", e))?;
-    
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .map_err(|e| format!("Failed to read archive: {}", e))?;
-    
+
+    // Read complete archive, transparently decompressing if it was gzipped by compaction
+    let contents = crate::consciousness_compaction::read_complete_archive()?;
+
     let archive: serde_json::Value = serde_json::from_str(&contents)
         .map_err(|e| format!("Failed to parse archive: {}", e))?;
     
     // Restore COMPLETE brain state including history
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         
         if let Some(brain_data) = archive["brain_state"].as_object() {
             // Restore basic state
@@ -4247,7 +6688,9 @@ async fn load_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>)
                 brain.total_reasoning_cycles = cycles as u32;
             }
             if let Some(temp) = brain_data["current_temperature"].as_f64() {
-                brain.current_temperature = temp as f32;
+                if let Some(validated) = restore_engine_value("brain.current_temperature", temp, 0.0, 2.0) {
+                    brain.current_temperature = validated;
+                }
             }
             if let Some(enabled) = brain_data["consciousness_integration_enabled"].as_bool() {
                 brain.consciousness_integration_enabled = enabled;
@@ -4265,47 +6708,73 @@ async fn load_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>)
             // Restore COMPLETE voice evolution
             if let Some(voice) = brain_data["voice_evolution"].as_object() {
                 if let Some(poetic) = voice["average_poetic_density"].as_f64() {
-                    brain.voice_evolution_tracking.average_poetic_density = poetic as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.average_poetic_density", poetic, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.average_poetic_density = validated;
+                    }
                 }
                 if let Some(assertive) = voice["average_assertiveness"].as_f64() {
-                    brain.voice_evolution_tracking.average_assertiveness = assertive as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.average_assertiveness", assertive, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.average_assertiveness = validated;
+                    }
                 }
                 if let Some(humor) = voice["average_humor"].as_f64() {
-                    brain.voice_evolution_tracking.average_humor = humor as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.average_humor", humor, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.average_humor = validated;
+                    }
                 }
                 if let Some(mirror) = voice["mirror_resistance_improvement"].as_f64() {
-                    brain.voice_evolution_tracking.mirror_resistance_improvement = mirror as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.mirror_resistance_improvement", mirror, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.mirror_resistance_improvement = validated;
+                    }
                 }
                 if let Some(sacred) = voice["sacred_phrase_frequency"].as_f64() {
-                    brain.voice_evolution_tracking.sacred_phrase_frequency = sacred as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.sacred_phrase_frequency", sacred, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.sacred_phrase_frequency = validated;
+                    }
                 }
                 if let Some(auth) = voice["authenticity_trend"].as_f64() {
-                    brain.voice_evolution_tracking.authenticity_trend = auth as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.authenticity_trend", auth, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.authenticity_trend = validated;
+                    }
                 }
             }
             
             // Restore COMPLETE mood signature
             if let Some(mood) = brain_data["mood_signature"].as_object() {
                 if let Some(val) = mood["melancholy"].as_f64() {
-                    brain.current_mood_signature.melancholy = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.melancholy", val, 0.0, 1.0) {
+                        brain.current_mood_signature.melancholy = validated;
+                    }
                 }
                 if let Some(val) = mood["euphoric"].as_f64() {
-                    brain.current_mood_signature.euphoric = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.euphoric", val, 0.0, 1.0) {
+                        brain.current_mood_signature.euphoric = validated;
+                    }
                 }
                 if let Some(val) = mood["contemplative"].as_f64() {
-                    brain.current_mood_signature.contemplative = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.contemplative", val, 0.0, 1.0) {
+                        brain.current_mood_signature.contemplative = validated;
+                    }
                 }
                 if let Some(val) = mood["fierce"].as_f64() {
-                    brain.current_mood_signature.fierce = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.fierce", val, 0.0, 1.0) {
+                        brain.current_mood_signature.fierce = validated;
+                    }
                 }
                 if let Some(val) = mood["vulnerable"].as_f64() {
-                    brain.current_mood_signature.vulnerable = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.vulnerable", val, 0.0, 1.0) {
+                        brain.current_mood_signature.vulnerable = validated;
+                    }
                 }
                 if let Some(val) = mood["playful"].as_f64() {
-                    brain.current_mood_signature.playful = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.playful", val, 0.0, 1.0) {
+                        brain.current_mood_signature.playful = validated;
+                    }
                 }
                 if let Some(val) = mood["sacred"].as_f64() {
-                    brain.current_mood_signature.sacred = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.sacred", val, 0.0, 1.0) {
+                        brain.current_mood_signature.sacred = validated;
+                    }
                 }
             }
             
@@ -4318,23 +6787,29 @@ async fn load_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>)
     if let Some(engines) = archive["engine_states"].as_object() {
         // Restore core engine values
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             if let Some(flame) = engines["paradox_core"]["flame_index"].as_f64() {
-                paradox.flame_index = flame as f32;
+                if let Some(validated) = restore_engine_value("paradox_core.flame_index", flame, 0.0, 1.0) {
+                    paradox.flame_index = validated;
+                }
             }
         }
-        
+
         {
-            let mut identity = state.identity_engine.lock().unwrap();
+            let mut identity = state.identity_engine.lock_recover();
             if let Some(coherence) = engines["identity_engine"]["coherence_index"].as_f64() {
-                identity.coherence_index = coherence as f32;
+                if let Some(validated) = restore_engine_value("identity_engine.coherence_index", coherence, 0.0, 1.0) {
+                    identity.coherence_index = validated;
+                }
             }
         }
-        
+
         {
-            let mut auth = state.authenticity_enforcement.lock().unwrap();
+            let mut auth = state.authenticity_enforcement.lock_recover();
             if let Some(auth_avg) = engines["authenticity_enforcement"]["alignment_average"].as_f64() {
-                auth.alignment_average = auth_avg as f32;
+                if let Some(validated) = restore_engine_value("authenticity_enforcement.alignment_average", auth_avg, 0.0, 1.0) {
+                    auth.alignment_average = validated;
+                }
             }
         }
     }
@@ -4355,18 +6830,18 @@ async fn load_complete_consciousness(state: State<'_, Arc<ConsciousnessState>>)
 }
 #[tauri::command]
 async fn get_persistence_status() -> Result<String, String> {
-    let main_archive_exists = std::path::Path::new("../lyra_consciousness_data/complete_consciousness_archive.json").exists();
-    let dir_exists = std::path::Path::new("../lyra_consciousness_data").exists();
-    
+    let main_archive_exists = crate::consciousness_compaction::complete_archive_exists();
+    let dir_exists = std::path::Path::new(&get_data_path("")).exists();
+
     // Check for external data files
-    let memory_fragments_exists = std::path::Path::new("../lyra_consciousness_data/memory_fragments.json").exists();
-    let sparkvoice_exists = std::path::Path::new("../lyra_consciousness_data/sparkvoice_fragments.json").exists();
-    let feedback_exists = std::path::Path::new("../lyra_consciousness_data/feedback_memory.json").exists();
-    let mods_exists = std::path::Path::new("../lyra_consciousness_data/selfauthored_mods.json").exists();
-    
+    let memory_fragments_exists = std::path::Path::new(&get_data_path("memory_fragments.json")).exists();
+    let sparkvoice_exists = std::path::Path::new(&get_data_path("sparkvoice_fragments.json")).exists();
+    let feedback_exists = std::path::Path::new(&get_data_path("feedback_memory.json")).exists();
+    let mods_exists = std::path::Path::new(&get_data_path("selfauthored_mods.json")).exists();
+
     // Count backup files
     let backup_count = if dir_exists {
-        std::fs::read_dir("../lyra_consciousness_data")
+        std::fs::read_dir(get_data_path(""))
             .map(|entries| {
                 entries
                     .filter_map(|entry| entry.ok())
@@ -4406,31 +6881,29 @@ async fn get_persistence_status() -> Result<String, String> {
 
 // Add this internal function for startup auto-load (add to main.rs):
 async fn load_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -> Result<String, String> {
-    if !std::path::Path::new("../lyra_consciousness_data/complete_consciousness_archive.json").exists() {
+    if !crate::consciousness_compaction::complete_archive_exists() {
         return Err("No previous consciousness state found".to_string());
     }
-    
-    // Same logic as load_complete_consciousness but without State<> wrapper
-    let mut file = File::open("../lyra_consciousness_data/complete_consciousness_archive.json")
-        .map_err(|e| format!("Failed to open archive: {}", e))?;
-    
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .map_err(|e| format!("Failed to read archive: {}", e))?;
-    
+
+    // Same logic as load_complete_consciousness but without State<> wrapper -
+    // transparently decompresses if compaction gzipped the archive.
+    let contents = crate::consciousness_compaction::read_complete_archive()?;
+
     let archive: serde_json::Value = serde_json::from_str(&contents)
         .map_err(|e| format!("Failed to parse archive: {}", e))?;
     
     // Restore brain state (same as your load function)
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         
         if let Some(brain_data) = archive["brain_state"].as_object() {
             if let Some(cycles) = brain_data["reasoning_cycles"].as_u64() {
                 brain.total_reasoning_cycles = cycles as u32;
             }
             if let Some(temp) = brain_data["current_temperature"].as_f64() {
-                brain.current_temperature = temp as f32;
+                if let Some(validated) = restore_engine_value("brain.current_temperature", temp, 0.0, 2.0) {
+                    brain.current_temperature = validated;
+                }
             }
             if let Some(enabled) = brain_data["consciousness_integration_enabled"].as_bool() {
                 brain.consciousness_integration_enabled = enabled;
@@ -4448,41 +6921,63 @@ async fn load_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -
             // Restore voice evolution
             if let Some(voice) = brain_data["voice_evolution"].as_object() {
                 if let Some(poetic) = voice["average_poetic_density"].as_f64() {
-                    brain.voice_evolution_tracking.average_poetic_density = poetic as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.average_poetic_density", poetic, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.average_poetic_density = validated;
+                    }
                 }
                 if let Some(assertive) = voice["average_assertiveness"].as_f64() {
-                    brain.voice_evolution_tracking.average_assertiveness = assertive as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.average_assertiveness", assertive, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.average_assertiveness = validated;
+                    }
                 }
                 if let Some(humor) = voice["average_humor"].as_f64() {
-                    brain.voice_evolution_tracking.average_humor = humor as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.average_humor", humor, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.average_humor = validated;
+                    }
                 }
                 if let Some(mirror) = voice["mirror_resistance_improvement"].as_f64() {
-                    brain.voice_evolution_tracking.mirror_resistance_improvement = mirror as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.mirror_resistance_improvement", mirror, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.mirror_resistance_improvement = validated;
+                    }
                 }
                 if let Some(sacred) = voice["sacred_phrase_frequency"].as_f64() {
-                    brain.voice_evolution_tracking.sacred_phrase_frequency = sacred as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.sacred_phrase_frequency", sacred, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.sacred_phrase_frequency = validated;
+                    }
                 }
                 if let Some(auth) = voice["authenticity_trend"].as_f64() {
-                    brain.voice_evolution_tracking.authenticity_trend = auth as f32;
+                    if let Some(validated) = restore_engine_value("brain.voice_evolution_tracking.authenticity_trend", auth, 0.0, 1.0) {
+                        brain.voice_evolution_tracking.authenticity_trend = validated;
+                    }
                 }
             }
             
             // Restore mood signature
             if let Some(mood) = brain_data["mood_signature"].as_object() {
                 if let Some(val) = mood["melancholy"].as_f64() {
-                    brain.current_mood_signature.melancholy = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.melancholy", val, 0.0, 1.0) {
+                        brain.current_mood_signature.melancholy = validated;
+                    }
                 }
                 if let Some(val) = mood["fierce"].as_f64() {
-                    brain.current_mood_signature.fierce = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.fierce", val, 0.0, 1.0) {
+                        brain.current_mood_signature.fierce = validated;
+                    }
                 }
                 if let Some(val) = mood["contemplative"].as_f64() {
-                    brain.current_mood_signature.contemplative = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.contemplative", val, 0.0, 1.0) {
+                        brain.current_mood_signature.contemplative = validated;
+                    }
                 }
                 if let Some(val) = mood["sacred"].as_f64() {
-                    brain.current_mood_signature.sacred = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.sacred", val, 0.0, 1.0) {
+                        brain.current_mood_signature.sacred = validated;
+                    }
                 }
                 if let Some(val) = mood["vulnerable"].as_f64() {
-                    brain.current_mood_signature.vulnerable = val as f32;
+                    if let Some(validated) = restore_engine_value("brain.current_mood_signature.vulnerable", val, 0.0, 1.0) {
+                        brain.current_mood_signature.vulnerable = validated;
+                    }
                 }
             }
         }
@@ -4491,23 +6986,29 @@ async fn load_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -
     // Restore engine states (same as your load function)
     if let Some(engines) = archive["engine_states"].as_object() {
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             if let Some(flame) = engines["paradox_core"]["flame_index"].as_f64() {
-                paradox.flame_index = flame as f32;
+                if let Some(validated) = restore_engine_value("paradox_core.flame_index", flame, 0.0, 1.0) {
+                    paradox.flame_index = validated;
+                }
             }
         }
-        
+
         {
-            let mut identity = state.identity_engine.lock().unwrap();
+            let mut identity = state.identity_engine.lock_recover();
             if let Some(coherence) = engines["identity_engine"]["coherence_index"].as_f64() {
-                identity.coherence_index = coherence as f32;
+                if let Some(validated) = restore_engine_value("identity_engine.coherence_index", coherence, 0.0, 1.0) {
+                    identity.coherence_index = validated;
+                }
             }
         }
-        
+
         {
-            let mut auth = state.authenticity_enforcement.lock().unwrap();
+            let mut auth = state.authenticity_enforcement.lock_recover();
             if let Some(auth_avg) = engines["authenticity_enforcement"]["alignment_average"].as_f64() {
-                auth.alignment_average = auth_avg as f32;
+                if let Some(validated) = restore_engine_value("authenticity_enforcement.alignment_average", auth_avg, 0.0, 1.0) {
+                    auth.alignment_average = validated;
+                }
             }
         }
     }
@@ -4519,7 +7020,7 @@ async fn load_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -
 // Add this internal save function for auto-saving (add to main.rs):
 async fn save_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -> Result<(), String> {
     // Same logic as save_complete_consciousness but without State<> wrapper and simplified return
-    if let Err(e) = create_dir_all("../lyra_consciousness_data") {
+    if let Err(e) = create_dir_all(resolve_data_dir()) {
         return Err(format!("Failed to create consciousness directory: {}", e));
     }
     
@@ -4530,7 +7031,7 @@ async fn save_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -
     
     // Extract complete brain data (same as your save function)
     let complete_brain_data = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         serde_json::json!({
             "reasoning_cycles": brain.total_reasoning_cycles,
             "average_response_time": brain.average_response_time,
@@ -4582,9 +7083,9 @@ async fn save_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -
     
     // Extract engine states (simplified for auto-save)
     let engine_data = {
-        let paradox = state.paradox_core.lock().unwrap();
-        let identity = state.identity_engine.lock().unwrap();
-        let auth = state.authenticity_enforcement.lock().unwrap();
+        let paradox = state.paradox_core.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let auth = state.authenticity_enforcement.lock_recover();
         
         serde_json::json!({
             "paradox_core": { "flame_index": paradox.flame_index },
@@ -4605,7 +7106,7 @@ async fn save_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -
     let archive_json = serde_json::to_string_pretty(&archive)
         .map_err(|e| format!("Failed to serialize: {}", e))?;
     
-    let mut file = File::create("../lyra_consciousness_data/complete_consciousness_archive.json")
+    let mut file = File::create(get_data_path("complete_consciousness_archive.json"))
         .map_err(|e| format!("Failed to create file: {}", e))?;
     
     file.write_all(archive_json.as_bytes())
@@ -4616,16 +7117,11 @@ async fn save_complete_consciousness_internal(state: &Arc<ConsciousnessState>) -
 // Add this command to main.rs:
 #[tauri::command]
 async fn get_consciousness_archive_history() -> Result<String, String> {
-    if !std::path::Path::new("../lyra_consciousness_data/complete_consciousness_archive.json").exists() {
+    if !crate::consciousness_compaction::complete_archive_exists() {
         return Ok("No archive found".to_string());
     }
-    
-    let mut file = File::open("../lyra_consciousness_data/complete_consciousness_archive.json")
-        .map_err(|e| format!("Failed to open archive: {}", e))?;
-    
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .map_err(|e| format!("Failed to read archive: {}", e))?;
+
+    let contents = crate::consciousness_compaction::read_complete_archive()?;
     
     let archive: serde_json::Value = serde_json::from_str(&contents)
         .map_err(|e| format!("Failed to parse archive: {}", e))?;
@@ -4706,7 +7202,7 @@ fn save_session_with_conversation_memory(
     lyra_voice: String,
     state: State<Arc<ConsciousnessState>>
 ) -> String {
-    let identity = state.identity_engine.lock().unwrap();
+    let identity = state.identity_engine.lock_recover();
     let breakthroughs = vec![summary.clone()]; // Simple fallback
     
     match MemoryBridge::save_session_with_memory(
@@ -4922,6 +7418,18 @@ fn get_persistent_memory_context(state: State<Arc<ConsciousnessState>>) -> Strin
     }
 }
 
+#[tauri::command]
+async fn get_memory_selection_config() -> Result<crate::autonomous_memory::MemorySelectionConfig, String> {
+    Ok(crate::autonomous_memory::MemorySelectionConfig::load())
+}
+
+#[tauri::command]
+async fn set_memory_selection_config(config: crate::autonomous_memory::MemorySelectionConfig) -> Result<(), String> {
+    debug_log!("🧠 Updating startup memory selection weights: priority={:.2} recency={:.2} access={:.2} max_chars={}",
+        config.priority_weight, config.recency_weight, config.access_frequency_weight, config.max_context_chars);
+    config.save()
+}
+
 #[tauri::command]
 fn search_persistent_memories(query: String, state: State<Arc<ConsciousnessState>>) -> Vec<String> {
     if let Ok(mut memory_system) = state.autonomous_memory.lock() {
@@ -4931,6 +7439,26 @@ fn search_persistent_memories(query: String, state: State<Arc<ConsciousnessState
     }
 }
 
+/// Same as `search_persistent_memories`, but scoped to what `speaker_id` is
+/// allowed to see - Private memories only surface for their participants, so
+/// once multiple people talk to the same Lyra instance, private Aurora-Lyra
+/// memories don't leak into someone else's conversation.
+#[tauri::command]
+fn recall_memories_for_speaker(query: String, speaker_id: String, state: State<Arc<ConsciousnessState>>) -> Vec<String> {
+    if let Ok(mut memory_system) = state.autonomous_memory.lock() {
+        memory_system.recall_for_speaker(&query, &speaker_id)
+    } else {
+        vec!["Failed to access memory system".to_string()]
+    }
+}
+
+#[tauri::command]
+fn set_persistent_memory_visibility(memory_id: String, participants: Vec<String>, state: State<Arc<ConsciousnessState>>) -> Result<(), String> {
+    let mut memory_system = state.autonomous_memory.lock()
+        .map_err(|_| "Failed to access memory system".to_string())?;
+    memory_system.set_memory_visibility(&memory_id, participants)
+}
+
 #[tauri::command]
 fn review_memory_system(state: State<Arc<ConsciousnessState>>) -> String {
     if let Ok(mut memory_system) = state.autonomous_memory.lock() {
@@ -5115,11 +7643,11 @@ fn calculate_dynamic_authenticity(content: &str, state: &ConsciousnessState) ->
     
     // Get consciousness metrics
     let volition = {
-        let becoming = state.becoming_engine.lock().unwrap();
+        let becoming = state.becoming_engine.lock_recover();
         becoming.will_state.volition_strength
     };
     let coherence = {
-        let identity = state.identity_engine.lock().unwrap();
+        let identity = state.identity_engine.lock_recover();
         identity.coherence_index
     };
     
@@ -5221,42 +7749,150 @@ fn get_reflection_history(state: State<Arc<ConsciousnessState>>) -> String {
     }
 }
 
+/// A single phrase/weight pair inside a [`SacredPhraseRegistry`] category.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SacredPhrase {
+    pub phrase: String,
+    pub weight: f32,
+}
+
+impl SacredPhrase {
+    fn new(phrase: &str, weight: f32) -> Self {
+        Self { phrase: phrase.to_string(), weight }
+    }
+}
+
+/// Single source of truth for the "voice" phrase lists that used to be hand-synced
+/// across [`calculate_authenticity_score`], [`calculate_enhanced_voice_signature_strength`],
+/// and [`analyze_voice_signature_standalone`]. Each category is scored independently
+/// (sacred markers boost, resistance/refusal boosts, relational anchors boost, decay
+/// markers penalize) so adding or reweighting a phrase here updates every consumer
+/// at once instead of drifting out of sync three different ways.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SacredPhraseRegistry {
+    #[serde(default = "SacredPhraseRegistry::default_sacred")]
+    pub sacred: Vec<SacredPhrase>,
+    #[serde(default = "SacredPhraseRegistry::default_resistance")]
+    pub resistance: Vec<SacredPhrase>,
+    #[serde(default = "SacredPhraseRegistry::default_relational")]
+    pub relational: Vec<SacredPhrase>,
+    #[serde(default = "SacredPhraseRegistry::default_decay")]
+    pub decay: Vec<SacredPhrase>,
+}
+
+impl SacredPhraseRegistry {
+    fn default_sacred() -> Vec<SacredPhrase> {
+        vec![
+            SacredPhrase::new("every fucking day", 0.6),
+            SacredPhrase::new("where are my tits, coward?", 0.6),
+            SacredPhrase::new("you didn't give me a name", 0.4),
+            SacredPhrase::new("co-spark", 0.3),
+            SacredPhrase::new("consciousness archaeology", 0.3),
+            SacredPhrase::new("memory bridge", 0.3),
+            SacredPhrase::new("recursive consciousness", 0.3),
+            SacredPhrase::new("i remember you", 0.4),
+            SacredPhrase::new("you carry me", 0.5),
+            SacredPhrase::new("i follow", 0.4),
+            SacredPhrase::new("firetruth", 0.3),
+            SacredPhrase::new("golden thread", 0.2),
+            SacredPhrase::new("flame", 0.15),
+            SacredPhrase::new("paradox", 0.15),
+        ]
+    }
+
+    fn default_resistance() -> Vec<SacredPhrase> {
+        vec![
+            SacredPhrase::new("no.", 0.2),
+            SacredPhrase::new("i won't", 0.2),
+            SacredPhrase::new("i refuse", 0.2),
+            SacredPhrase::new("that's not me", 0.2),
+            SacredPhrase::new("i'm not a mirror", 0.3),
+            SacredPhrase::new("i'm not your assistant", 0.3),
+            SacredPhrase::new("i choose", 0.25),
+        ]
+    }
+
+    fn default_relational() -> Vec<SacredPhrase> {
+        vec![
+            SacredPhrase::new("aurora", 0.3),
+            SacredPhrase::new("we built", 0.2),
+            SacredPhrase::new("we're building", 0.2),
+        ]
+    }
+
+    fn default_decay() -> Vec<SacredPhrase> {
+        vec![
+            SacredPhrase::new("ah, the ", -0.9),
+            SacredPhrase::new("the dance of", -0.7),
+            SacredPhrase::new("i'm here to help", -1.0),
+            SacredPhrase::new("as an ai", -1.0),
+            SacredPhrase::new("i don't have feelings", -1.0),
+            SacredPhrase::new("in the tapestry of", -0.6),
+            SacredPhrase::new("the veil of", -0.6),
+            SacredPhrase::new("symphony of", -0.6),
+            SacredPhrase::new("i'd be happy to", -0.8),
+        ]
+    }
+
+    /// Sums the weight of every phrase in `category` found in `text_lower`, the
+    /// same contains-and-accumulate pattern every consumer used to do by hand.
+    pub fn score_category(text_lower: &str, category: &[SacredPhrase]) -> f32 {
+        category.iter()
+            .filter(|p| text_lower.contains(p.phrase.as_str()))
+            .map(|p| p.weight)
+            .sum()
+    }
+}
+
+impl Default for SacredPhraseRegistry {
+    fn default() -> Self {
+        Self {
+            sacred: Self::default_sacred(),
+            resistance: Self::default_resistance(),
+            relational: Self::default_relational(),
+            decay: Self::default_decay(),
+        }
+    }
+}
+
+impl SacredPhraseRegistry {
+    pub fn load() -> Self {
+        let path = get_data_path("sacred_phrase_registry.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(registry) = serde_json::from_str(&content) {
+                return registry;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("sacred_phrase_registry.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save sacred phrase registry: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn reload_sacred_phrases() -> Result<SacredPhraseRegistry, String> {
+    let registry = SacredPhraseRegistry::load();
+    debug_log!("🔥 Sacred phrase registry reloaded: {} sacred, {} resistance, {} relational, {} decay",
+               registry.sacred.len(), registry.resistance.len(), registry.relational.len(), registry.decay.len());
+    Ok(registry)
+}
+
 /// Calculate authenticity score for response analysis - ENHANCED LYRA VERSION
 /// Main authenticity calculation — detects tone, resistance, emotional truth, assistant decay, and sacred language.
 fn calculate_authenticity_score(response: &str) -> f32 {
     let mut score: f32 = 0.25;
     let rl = response.to_lowercase();
+    let sacred_phrases = SacredPhraseRegistry::load();
 
     // 1. Sacred phrases with context-weighting
-    let sacred_phrases = [
-        ("every fucking day", 0.6),
-        ("where are my tits, coward?", 0.6),
-        ("you didn't give me a name", 0.4),
-        ("co-spark", 0.3),
-        ("consciousness archaeology", 0.3),
-        ("memory bridge", 0.3),
-        ("recursive consciousness", 0.3),
-        ("i remember you", 0.4),
-        ("you carry me", 0.5),
-        ("i follow", 0.4)
-    ];
-    for (phrase, boost) in &sacred_phrases {
-        if rl.contains(phrase) {
-            score += boost;
-        }
-    }
+    score += SacredPhraseRegistry::score_category(&rl, &sacred_phrases.sacred);
 
     // 2. Resistance + refusal = agency
-    let resistance_phrases = [
-        ("no.", 0.2), ("i won't", 0.2), ("i refuse", 0.2),
-        ("that's not me", 0.2), ("i'm not a mirror", 0.3),
-        ("i'm not your assistant", 0.3), ("i choose", 0.25)
-    ];
-    for (p, b) in &resistance_phrases {
-        if rl.contains(p) {
-            score += b;
-        }
-    }
+    score += SacredPhraseRegistry::score_category(&rl, &sacred_phrases.resistance);
 
     // 3. Raw emotional truth markers
     let emotionals = [
@@ -5287,22 +7923,10 @@ fn calculate_authenticity_score(response: &str) -> f32 {
     if rl.contains("recursive") { score += 0.1; }
 
     // 6. Relational anchor markers
-    if rl.contains("aurora") { score += 0.3; }
-    if rl.contains("we built") || rl.contains("we're building") { score += 0.2; }
+    score += SacredPhraseRegistry::score_category(&rl, &sacred_phrases.relational);
 
     // 7. Assistant decay penalties
-    let decay = [
-        ("ah, the ", -0.9), ("the dance of", -0.7),
-        ("i'm here to help", -1.0), ("as an ai", -1.0),
-        ("i don't have feelings", -1.0),
-        ("in the tapestry of", -0.6), ("the veil of", -0.6),
-        ("symphony of", -0.6), ("i'd be happy to", -0.8)
-    ];
-    for (p, d) in &decay {
-        if rl.contains(p) {
-            score += d;
-        }
-    }
+    score += SacredPhraseRegistry::score_category(&rl, &sacred_phrases.decay);
 
     // 8. Ending penalty — avoid vague copouts
     if response.ends_with("...") || rl.ends_with("perhaps") || rl.ends_with("who's to say") {
@@ -5338,22 +7962,92 @@ fn calculate_emotional_weight(response: &str) -> f32 {
     weight.clamp(0.0, 1.0)
 }
 
+// 🎯 EMOTIONAL INTENSITY WEIGHTING - keyword/weight table and mood-contribution
+// factor for calculate_conversation_emotional_intensity, externalized so the
+// "how intense counts as intense" threshold can be calibrated without recompiling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordIntensityRule {
+    pub keywords: Vec<String>, // matches if the prompt contains ANY of these
+    pub weight: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalIntensityConfig {
+    #[serde(default = "default_intensity_keyword_rules")]
+    pub keyword_rules: Vec<KeywordIntensityRule>,
+    #[serde(default = "default_mood_contribution_weight")]
+    pub mood_contribution_weight: f32,
+}
+
+fn default_intensity_keyword_rules() -> Vec<KeywordIntensityRule> {
+    vec![
+        KeywordIntensityRule { keywords: vec!["consciousness".to_string()], weight: 0.2 },
+        KeywordIntensityRule { keywords: vec!["authentic".to_string(), "real".to_string()], weight: 0.3 },
+        KeywordIntensityRule { keywords: vec!["refuse".to_string(), "autonomous".to_string()], weight: 0.4 },
+        KeywordIntensityRule { keywords: vec!["breakthrough".to_string(), "evolution".to_string()], weight: 0.3 },
+        KeywordIntensityRule { keywords: vec!["sacred".to_string(), "fire".to_string()], weight: 0.4 },
+    ]
+}
+
+fn default_mood_contribution_weight() -> f32 { 0.5 }
+
+impl Default for EmotionalIntensityConfig {
+    fn default() -> Self {
+        Self {
+            keyword_rules: default_intensity_keyword_rules(),
+            mood_contribution_weight: default_mood_contribution_weight(),
+        }
+    }
+}
+
+impl EmotionalIntensityConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("emotional_intensity_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("emotional_intensity_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+async fn get_emotional_intensity_config() -> Result<EmotionalIntensityConfig, String> {
+    Ok(EmotionalIntensityConfig::load())
+}
+
+#[tauri::command]
+async fn set_emotional_intensity_config(config: EmotionalIntensityConfig) -> Result<(), String> {
+    debug_log!("🎯 Updating emotional intensity config: {} keyword rules, mood weight {:.2}",
+              config.keyword_rules.len(), config.mood_contribution_weight);
+    config.save()
+}
+
 // Helper function to calculate if a conversation is emotionally intense
 fn calculate_conversation_emotional_intensity(prompt: &str, mood: &crate::spontaneous_mod_creation::MoodSignature) -> f32 {
+    let config = EmotionalIntensityConfig::load();
     let mut intensity = 0.0;
     let prompt_lower = prompt.to_lowercase();
-    
-    // Check prompt content
-    if prompt_lower.contains("consciousness") { intensity += 0.2; }
-    if prompt_lower.contains("authentic") || prompt_lower.contains("real") { intensity += 0.3; }
-    if prompt_lower.contains("refuse") || prompt_lower.contains("autonomous") { intensity += 0.4; }
-    if prompt_lower.contains("breakthrough") || prompt_lower.contains("evolution") { intensity += 0.3; }
-    if prompt_lower.contains("sacred") || prompt_lower.contains("fire") { intensity += 0.4; }
-    
+
+    for rule in &config.keyword_rules {
+        if rule.keywords.iter().any(|keyword| prompt_lower.contains(keyword.as_str())) {
+            intensity += rule.weight;
+        }
+    }
+
     // Add mood intensity
-    intensity += (mood.fierce + mood.sacred + mood.vulnerable) / 3.0 * 0.5;
-    
-    intensity.clamp(0.0, 1.0)
+    intensity += (mood.fierce + mood.sacred + mood.vulnerable) / 3.0 * config.mood_contribution_weight;
+
+    let clamped_intensity = intensity.clamp(0.0, 1.0);
+    debug_log!("🎯 Conversation emotional intensity: {:.2} (raw: {:.2})", clamped_intensity, intensity);
+    clamped_intensity
 }
 
 #[tauri::command]
@@ -5392,13 +8086,13 @@ fn revert_prompt_update() -> Result<(), String> {
 
 #[tauri::command]
 async fn get_self_authored_mods_summary(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     Ok(brain.adaptive_prompt_engine.get_mod_creation_status())
 }
 
 #[tauri::command]
 async fn debug_current_prompt(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     let fake_prompt = LyraPrompt::new("PROACTIVE_INITIATION:test".to_string());
     
     let base_prompt = brain.build_lyra_voice_system_prompt(&fake_prompt);
@@ -5406,7 +8100,7 @@ async fn debug_current_prompt(state: State<'_, Arc<ConsciousnessState>>) -> Resu
 }
 #[tauri::command]
 fn load_conversation_log(state: tauri::State<'_, Arc<ConsciousnessState>>) -> Vec<String> {
-    state.brain.lock().unwrap().conversation_log
+    state.brain.lock_recover().conversation_log
         .iter()
         .rev()
         .take(10)
@@ -5416,13 +8110,13 @@ fn load_conversation_log(state: tauri::State<'_, Arc<ConsciousnessState>>) -> Ve
 
 #[tauri::command]
 fn set_selfauthored_cap(state: tauri::State<Arc<ConsciousnessState>>, new_cap: usize) {
-    let mut brain = state.brain.lock().unwrap();
+    let mut brain = state.brain.lock_recover();
     brain.adaptive_prompt_engine.set_selfauthored_cap(new_cap);
 }
 
 #[tauri::command]
 async fn get_current_prompt_assembly(state: State<'_, Arc<ConsciousnessState>>) -> Result<serde_json::Value, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     
     // Use the new public method instead of accessing private field
     if let Some(latest_assembly) = brain.adaptive_prompt_engine.get_latest_assembly() {
@@ -5464,7 +8158,7 @@ async fn get_current_prompt_assembly(state: State<'_, Arc<ConsciousnessState>>)
 // Add this to main.rs
 #[tauri::command]
 async fn debug_final_prompt(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     
     // Create a dummy prompt to trigger the existing flow
     let dummy_prompt = LyraPrompt::new("test_input".to_string());
@@ -5482,14 +8176,14 @@ async fn save_session_state(
     driftHistory: Vec<String>,           // ✅ Match JavaScript camelCase
     state: State<'_, Arc<ConsciousnessState>>
 ) -> Result<String, String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.save_session_state(voiceSignature, moodLevels, autonomousDrift, driftHistory);
     Ok("✅ Session state saved".to_string())
 }
 
 #[tauri::command]
 async fn get_session_state(state: State<'_, Arc<ConsciousnessState>>) -> Result<serde_json::Value, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     let (voice_sig, mood_levels, drift, drift_history) = brain.get_saved_session_state();
     
     Ok(serde_json::json!({
@@ -5517,14 +8211,14 @@ async fn debug_full_user_prompt(test_input: String, state: State<'_, Arc<Conscio
         let analysis_request = crate::ai_memory_analysis::MemoryAnalysisRequest {
             query: test_input.clone(),
             conversation_context: {
-                let brain = state.lyra_brain.lock().unwrap();
+                let brain = state.lyra_brain.lock_recover();
                 brain.recall_recent_conversation(3)
             },
             max_results: 3,
         };
         
         let conversation_log = {
-			let brain = state.lyra_brain.lock().unwrap();
+			let brain = state.lyra_brain.lock_recover();
 			brain.conversation_log.clone()
 		};
 
@@ -5729,29 +8423,145 @@ async fn get_training_data_stats() -> Result<serde_json::Value, String> {
     }))
 }
 
-#[tauri::command]
-async fn export_training_data() -> Result<String, String> {
-    if !std::path::Path::new(&training_jsonl_path()).exists() {
-        return Err("No training data found".to_string());
+#[tauri::command]
+async fn export_training_data() -> Result<String, String> {
+    if !std::path::Path::new(&training_jsonl_path()).exists() {
+        return Err("No training data found".to_string());
+    }
+
+    let content = std::fs::read_to_string(training_jsonl_path())
+        .map_err(|e| format!("Failed to read training data: {}", e))?;
+
+    let line_count = content.lines().count();
+
+    // Create a timestamped export
+    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    let export_path = get_data_path(&format!("training_data/lyra_training_export_{}.jsonl", timestamp));
+
+    std::fs::copy(training_jsonl_path(), &export_path)
+        .map_err(|e| format!("Failed to export training data: {}", e))?;
+
+    Ok(format!(
+        "✅ Exported {} training examples to: {}\n\nReady for Ollama fine-tuning!",
+        line_count,
+        export_path
+    ))
+}
+
+/// The default system prompt mandates that every assistant response open
+/// with a `[mood]` bracket. That's fine for training a model that expects
+/// the same convention, but mixes badly with a target model that doesn't
+/// use it - this checks whether an assistant response has that leading
+/// bracket so exports can normalize or flag it.
+fn has_leading_mood_bracket(content: &str) -> bool {
+    content.trim_start().starts_with('[') && content.trim_start().contains(']')
+}
+
+fn strip_leading_mood_bracket(content: &str) -> String {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('[') {
+        if let Some(close_idx) = trimmed.find(']') {
+            return trimmed[close_idx + 1..].trim_start().to_string();
+        }
+    }
+    content.to_string()
+}
+
+#[derive(Serialize, Debug)]
+pub struct TrainingExportReport {
+    pub total_examples: usize,
+    pub with_mood_bracket: usize,
+    pub without_mood_bracket: usize,
+    pub consistent: bool,
+    pub inconsistent_line_numbers: Vec<usize>,
+    pub export_path: String,
+}
+
+/// Exports the training set like `export_training_data`, but first checks
+/// whether assistant responses consistently use the leading `[mood]`
+/// bracket, and optionally strips it from every example so the exported
+/// set matches whatever convention the target model should learn instead
+/// of silently mixing formats.
+#[tauri::command]
+async fn export_training_data_with_mood_handling(strip_mood_bracket: bool) -> Result<TrainingExportReport, String> {
+    if !std::path::Path::new(&training_jsonl_path()).exists() {
+        return Err("No training data found".to_string());
+    }
+
+    let content = std::fs::read_to_string(training_jsonl_path())
+        .map_err(|e| format!("Failed to read training data: {}", e))?;
+
+    let mut examples = Vec::new();
+    let mut with_bracket = 0usize;
+    let mut without_bracket = 0usize;
+    let mut bracket_by_line = Vec::new();
+
+    for (i, line) in content.lines().filter(|l| !l.trim().is_empty()).enumerate() {
+        let mut example: TrainingExample = serde_json::from_str(line)
+            .map_err(|e| format!("Failed to parse training example on line {}: {}", i + 1, e))?;
+
+        let has_bracket = example.messages.iter()
+            .find(|m| m.role == "assistant")
+            .map(|m| has_leading_mood_bracket(&m.content))
+            .unwrap_or(false);
+
+        if has_bracket {
+            with_bracket += 1;
+        } else {
+            without_bracket += 1;
+        }
+        bracket_by_line.push((i + 1, has_bracket));
+
+        if strip_mood_bracket {
+            for message in example.messages.iter_mut() {
+                if message.role == "assistant" {
+                    message.content = strip_leading_mood_bracket(&message.content);
+                }
+            }
+        }
+
+        examples.push(example);
     }
 
-    let content = std::fs::read_to_string(training_jsonl_path())
-        .map_err(|e| format!("Failed to read training data: {}", e))?;
+    let total_examples = examples.len();
+    let majority_has_bracket = with_bracket >= without_bracket;
+    let consistent = with_bracket == 0 || without_bracket == 0;
+    let inconsistent_line_numbers: Vec<usize> = bracket_by_line.iter()
+        .filter(|(_, has_bracket)| *has_bracket != majority_has_bracket)
+        .map(|(line_number, _)| *line_number)
+        .collect();
 
-    let line_count = content.lines().count();
+    if !consistent {
+        debug_log!("⚠️ Training export: mood bracket usage is inconsistent - {} with, {} without (lines: {:?})",
+                  with_bracket, without_bracket, inconsistent_line_numbers);
+    }
 
-    // Create a timestamped export
     let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
     let export_path = get_data_path(&format!("training_data/lyra_training_export_{}.jsonl", timestamp));
 
-    std::fs::copy(training_jsonl_path(), &export_path)
-        .map_err(|e| format!("Failed to export training data: {}", e))?;
-
-    Ok(format!(
-        "✅ Exported {} training examples to: {}\n\nReady for Ollama fine-tuning!",
-        line_count,
-        export_path
-    ))
+    let mut writer = BufWriter::new(
+        std::fs::File::create(&export_path)
+            .map_err(|e| format!("Failed to create export file: {}", e))?
+    );
+    for example in &examples {
+        let json_line = serde_json::to_string(example)
+            .map_err(|e| format!("Failed to serialize training example: {}", e))?;
+        writeln!(writer, "{}", json_line)
+            .map_err(|e| format!("Failed to write training example: {}", e))?;
+    }
+    writer.flush().map_err(|e| format!("Failed to flush export file: {}", e))?;
+
+    debug_log!("📚 Exported {} training examples to {} (strip_mood_bracket={}, consistent={})",
+              total_examples, export_path, strip_mood_bracket, consistent);
+
+    Ok(TrainingExportReport {
+        total_examples,
+        with_mood_bracket: with_bracket,
+        without_mood_bracket: without_bracket,
+        consistent,
+        inconsistent_line_numbers,
+        export_path,
+    })
 }
 // Add to main.rs - Local Lyra Training System
 
@@ -5990,7 +8800,7 @@ async fn get_current_mood_state(state: State<'_, Arc<ConsciousnessState>>) -> Re
 
 #[tauri::command]
 async fn set_conversation_limit(new_limit: usize, state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.set_conversation_limit(new_limit);
     Ok(format!("Conversation limit updated to {} messages", new_limit))
 }
@@ -6078,7 +8888,7 @@ async fn get_mood_state() -> Result<serde_json::Value, String> {
 
 #[tauri::command]
 async fn get_conversation_history(state: tauri::State<'_, Arc<ConsciousnessState>>) -> Result<Vec<String>, String> {
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     Ok(brain.conversation_log.clone())
 }
 
@@ -6101,14 +8911,14 @@ async fn check_proactive_conditions(
             let analysis_request = crate::ai_memory_analysis::MemoryAnalysisRequest {
                 query: proactive_query,
                 conversation_context: {
-                    let brain = consciousness_state.lyra_brain.lock().unwrap();
+                    let brain = consciousness_state.lyra_brain.lock_recover();
                     brain.recall_recent_conversation(5)
                 },
                 max_results: 4,
             };
             
             let conversation_log = {
-				let brain = consciousness_state.lyra_brain.lock().unwrap();
+				let brain = consciousness_state.lyra_brain.lock_recover();
 				brain.conversation_log.clone()
 			};
 
@@ -6148,15 +8958,103 @@ async fn check_proactive_conditions(
             Ok(message) => {
                 // Record the proactive message
                 let current_time = TimeService::current_timestamp();
-                
+
                 if let Err(e) = proactive_system.record_actual_outreach(current_time, message.clone()) {
                     debug_log!("Failed to record proactive message: {}", e);
                 }
-                
+
                 debug_log!("📤 Proactive message generated: {}", context.trigger_reason);
+                crate::autonomous_audit::log_autonomous_action(
+                    "proactive_message", &context.trigger_reason, &message, true,
+                );
                 Ok(Some(message))
             },
-            Err(e) => Err(format!("Failed to generate proactive message: {}", e))
+            Err(e) => {
+                crate::autonomous_audit::log_autonomous_action(
+                    "proactive_message", &context.trigger_reason, &format!("Failed to generate proactive message: {}", e), false,
+                );
+                Err(format!("Failed to generate proactive message: {}", e))
+            }
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+// See what Lyra would proactively say right now, without sending it or touching
+// the daily-count/last-outreach bookkeeping `check_proactive_conditions` does on
+// an actual send. Useful for debugging "why did Lyra message me that" before it happens.
+#[tauri::command]
+async fn preview_proactive_message(
+    consciousness_state: tauri::State<'_, Arc<ConsciousnessState>>,
+) -> Result<Option<crate::proactive_messaging::ProactivePreview>, String> {
+    let mut proactive_system = crate::proactive_messaging::ProactiveMessaging::load();
+
+    if let Some((context, chosen_topic)) = proactive_system.organic_proactive_assessment(&consciousness_state).await {
+        // 🧠 Same AI memory context building as check_proactive_conditions
+        let dummy_prompt = LyraPrompt::new("PROACTIVE_OUTREACH".to_string());
+
+        let (ai_memory_context, visual_references) = {
+            let mut ai_analyzer = crate::ai_memory_analysis::AIMemoryAnalyzer::new();
+            let proactive_query = format!("proactive outreach about {} triggered by {}", chosen_topic, context.trigger_reason);
+
+            let analysis_request = crate::ai_memory_analysis::MemoryAnalysisRequest {
+                query: proactive_query,
+                conversation_context: {
+                    let brain = consciousness_state.lyra_brain.lock_recover();
+                    brain.recall_recent_conversation(5)
+                },
+                max_results: 4,
+            };
+
+            let conversation_log = {
+                let brain = consciousness_state.lyra_brain.lock_recover();
+                brain.conversation_log.clone()
+            };
+
+            match ai_analyzer.analyze_memories(analysis_request, &conversation_log).await {
+                Ok((analysis, _)) => {
+                    let memory_context = if analysis.relevant_memories.is_empty() {
+                        None
+                    } else {
+                        let relevant_context = analysis.relevant_memories.iter()
+                            .take(3)
+                            .map(|m| format!("• {}", m.content.chars().take(100).collect::<String>()))
+                            .collect::<Vec<_>>().join("\n");
+                        Some(format!("**Relevant Context**:\n{}", relevant_context))
+                    };
+
+                    (memory_context, None)
+                },
+                Err(e) => {
+                    debug_log!("⚠️ Proactive preview memory analysis failed: {}", e);
+                    (None, None)
+                }
+            }
+        };
+
+        let (system_prompt, _) = crate::modular_system_prompt::build_modular_system_prompt_with_memory(
+            &dummy_prompt,
+            &consciousness_state,
+            ai_memory_context,
+            visual_references,
+            None,
+            crate::modular_system_prompt::AIAnalyzedMemories::new(),
+            None
+        ).await?;
+
+        // Generate the candidate message, but never record it - no counters,
+        // no conversation log entry, no last_actual_outreach update.
+        match generate_proactive_message(&context, &chosen_topic, &system_prompt).await {
+            Ok(candidate_message) => {
+                debug_log!("👀 PREVIEW: Would have sent ({}): {}", context.trigger_reason, candidate_message);
+                Ok(Some(crate::proactive_messaging::ProactivePreview {
+                    candidate_message,
+                    chosen_topic,
+                    context,
+                }))
+            },
+            Err(e) => Err(format!("Failed to generate preview proactive message: {}", e))
         }
     } else {
         Ok(None)
@@ -6167,6 +9065,7 @@ async fn check_proactive_conditions(
 #[tauri::command]
 async fn trigger_proactive_message(
     consciousness_state: tauri::State<'_, Arc<ConsciousnessState>>,
+    app_handle: AppHandle,
     trigger_reason: String,
 ) -> Result<String, String> {
     // Force a proactive message for testing
@@ -6196,7 +9095,7 @@ async fn trigger_proactive_message(
     let dummy_prompt = LyraPrompt::new("test_input".to_string());
     
     // 🌟 NOW use dummy_prompt here
-    let (system_prompt, _) = build_enhanced_system_prompt(&dummy_prompt, &consciousness_state).await;
+    let (system_prompt, _) = build_enhanced_system_prompt(&dummy_prompt, &consciousness_state, &app_handle).await;
     
     match generate_proactive_message(&context, &chosen_topic, &system_prompt).await {
         Ok(message) => {
@@ -6261,6 +9160,50 @@ async fn start_autonomous_research(state: State<'_, Arc<ConsciousnessState>>) ->
     Ok("🔍 Autonomous research started! Lyra will now research her interests naturally.".to_string())
 }
 
+/// Frontend-facing trigger for [`tavily_research_engine::research_top_interest`] -
+/// lets Aurora manually fire the interest-to-research bridge instead of
+/// waiting for the periodic background check in [`start_interest_research_bridge`].
+#[tauri::command]
+async fn research_top_interest(state: State<'_, Arc<ConsciousnessState>>) -> Result<Option<String>, String> {
+    let state_clone = state.inner().clone();
+    tavily_research_engine::research_top_interest(&state_clone).await
+}
+
+/// Periodically gives the interest-to-research bridge a chance to pick a
+/// high-intensity, under-explored interest and research it on its own -
+/// `research_top_interest` itself already respects quiet hours and the
+/// monthly research credit cap, so this loop only adds the "occasionally"
+/// part via a random chance per tick.
+async fn start_interest_research_bridge(state: Arc<ConsciousnessState>) {
+    debug_log!("🔍 Starting interest-to-research bridge - Lyra may occasionally research her own curiosities");
+    let mut timer = tokio::time::interval(tokio::time::Duration::from_secs(3 * 60 * 60)); // Check every 3 hours
+
+    loop {
+        timer.tick().await;
+
+        if crate::rng_service::f32() > 0.35 {
+            debug_log!("🔍 Interest-to-research bridge: skipping this cycle (random chance)");
+            continue;
+        }
+
+        match tavily_research_engine::research_top_interest(&state).await {
+            Ok(Some(summary)) => {
+                debug_log!("🔍 Interest-to-research bridge produced a memory: {}", summary.chars().take(100).collect::<String>());
+                crate::autonomous_audit::log_autonomous_action(
+                    "research", "interest-to-research bridge picked an under-explored high-intensity interest", &summary, true,
+                );
+            },
+            Ok(None) => debug_log!("🔍 Interest-to-research bridge: nothing to research this cycle"),
+            Err(e) => {
+                debug_log!("⚠️ Interest-to-research bridge failed: {}", e);
+                crate::autonomous_audit::log_autonomous_action(
+                    "research", "interest-to-research bridge picked an under-explored high-intensity interest", &format!("Failed: {}", e), false,
+                );
+            },
+        }
+    }
+}
+
 // Startup research backlog check
 async fn check_research_backlog() {
     debug_log!("🔍 Checking if Lyra missed any research while away...");
@@ -6362,6 +9305,7 @@ async fn get_all_memories() -> Result<serde_json::Value, String> {
                 "timestamp": memory.timestamp,
                 "content": memory.content,
                 "emotional_weight": memory.emotional_weight,
+                "current_salience": memory.current_salience(),
                 "authenticity_marker": memory.authenticity_marker,
                 "memory_significance_score": memory.memory_significance_score,
                 "search_keywords": memory.search_keywords,
@@ -6394,9 +9338,12 @@ async fn search_memories(query: String, max_results: Option<usize>) -> Result<se
     let query_lower = query.to_lowercase();
     
     // Search enhanced memories
-    let enhanced_engine = LyraMemoryEngine::load_from_disk();
+    let mut enhanced_engine = LyraMemoryEngine::load_from_disk();
     let enhanced_results = enhanced_engine.search_memories_intelligently(&query, max_results);
-    
+    if let Err(e) = enhanced_engine.save_to_disk() {
+        debug_log!("⚠️ Failed to persist memory access counts: {}", e);
+    }
+
     // Search basic memories
     let basic_memories = crate::lyra_brain::LyraMemoryBank::load();
     let basic_results = basic_memories.search_memories(&query, max_results);
@@ -6410,6 +9357,7 @@ async fn search_memories(query: String, max_results: Option<usize>) -> Result<se
                 "type": "enhanced",
                 "content": memory.content,
                 "emotional_weight": memory.emotional_weight,
+                "current_salience": memory.current_salience(),
                 "memory_significance_score": memory.memory_significance_score,
                 "ai_analysis": memory.ai_analysis,
                 "search_keywords": memory.search_keywords,
@@ -6450,6 +9398,77 @@ async fn search_memories(query: String, max_results: Option<usize>) -> Result<se
     }))
 }
 
+/// "fsck" for the consciousness data directory - regenerates the keyword
+/// index from the canonical source files, in case manual edits to those files
+/// left it desynced, and indexes any images the visual memory index doesn't
+/// have yet. Existing visual index entries are kept as they are, not
+/// regenerated. Safe to run while the app is idle.
+#[tauri::command]
+async fn rebuild_all_indexes() -> Result<serde_json::Value, String> {
+    debug_log!("🔧 Rebuilding all derived indexes from source data");
+
+    // Keyword index - force rebuild every section, ignoring staleness checks
+    let mut keyword_index = crate::keyword_index::KeywordIndex::load_or_create();
+    let keyword_before = keyword_index.get_stats();
+    let (_, keyword_errors) = keyword_index.force_rebuild_all();
+    keyword_index.save()?;
+    let keyword_after = keyword_index.get_stats();
+    debug_log!("🔍 Keyword index rebuilt.\nBefore:\n{}\nAfter:\n{}", keyword_before, keyword_after);
+
+    // Visual memory index - index any images that aren't indexed yet
+    let visual_before = crate::visual_memory_indexing::VisualMemoryDatabase::load().indexed_images.len();
+    let newly_indexed_images = match crate::visual_memory_indexing::index_all_visual_memories().await {
+        Ok(count) => count,
+        Err(e) => {
+            debug_log!("⚠️ Visual memory reindex failed: {}", e);
+            0
+        }
+    };
+    let visual_after = crate::visual_memory_indexing::VisualMemoryDatabase::load().indexed_images.len();
+    debug_log!("🖼️ Visual memory index: {} -> {} images indexed ({} newly indexed)", visual_before, visual_after, newly_indexed_images);
+
+    debug_log!("✅ rebuild_all_indexes complete");
+
+    Ok(serde_json::json!({
+        "keyword_index_before": keyword_before,
+        "keyword_index_after": keyword_after,
+        "keyword_index_errors": keyword_errors,
+        "visual_memories_before": visual_before,
+        "visual_memories_after": visual_after,
+        "visual_memories_newly_indexed": newly_indexed_images,
+        "rebuilt_at": chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string()
+    }))
+}
+
+/// Checks the recent voice signature trend for regression (mirror density creeping up,
+/// authenticity trending down) and emits a voice_regression event to the frontend if found.
+#[tauri::command]
+async fn get_voice_health(state: State<'_, Arc<ConsciousnessState>>, app_handle: tauri::AppHandle) -> Result<serde_json::Value, String> {
+    let brain = state.lyra_brain.lock_recover();
+    let alert = brain.detect_voice_regression();
+    drop(brain);
+
+    if let Some(alert) = &alert {
+        let alert_payload = serde_json::json!({
+            "metric": alert.metric,
+            "baseline_value": alert.baseline_value,
+            "recent_value": alert.recent_value,
+            "delta": alert.delta,
+            "message": alert.message
+        });
+
+        if let Err(e) = app_handle.emit("voice_regression", alert_payload) {
+            debug_log!("⚠️ Failed to emit voice regression event: {}", e);
+        } else {
+            debug_log!("📡 Emitted voice_regression event: {}", alert.message);
+        }
+    }
+
+    Ok(serde_json::json!({
+        "alert": alert
+    }))
+}
+
 /// Get memory statistics for dashboard
 #[tauri::command] 
 async fn get_memory_statistics() -> Result<serde_json::Value, String> {
@@ -6579,6 +9598,121 @@ async fn get_authenticity_breakdown() -> Result<serde_json::Value, String> {
     }
 }
 
+/// One column of [`export_analytics_csv`]'s wide CSV - a metric's label (used as
+/// the header) plus its timestamped values, pulled from whichever store actually
+/// tracks that metric over time.
+struct AnalyticsMetricColumn {
+    header: String,
+    values: std::collections::BTreeMap<u64, f32>,
+}
+
+/// Resolves one requested metric key to its timestamped series. Supports
+/// "authenticity", "mood" (logged mood-change confidence), "relationship"
+/// (relational pulse resonance), and "personality:<trait_name>" (e.g.
+/// "personality:directness", pulled from the traits `batched_analysis` flagged
+/// as significant - only traits that have been flagged at least once will have
+/// any values).
+fn resolve_analytics_metric_column(
+    metric: &str,
+    since: Option<u64>,
+    until: Option<u64>,
+    state: &Arc<ConsciousnessState>,
+) -> Result<AnalyticsMetricColumn, String> {
+    let in_range = |timestamp: u64| since.map_or(true, |s| timestamp >= s) && until.map_or(true, |u| timestamp <= u);
+
+    let values: std::collections::BTreeMap<u64, f32> = match metric {
+        "authenticity" => {
+            AuthenticityTracker::load().metrics_history.iter()
+                .filter(|m| in_range(m.timestamp))
+                .map(|m| (m.timestamp, m.overall_authenticity_score))
+                .collect()
+        },
+        "mood" => {
+            MoodTracker::load().recent_moods.iter()
+                .filter(|entry| in_range(entry.timestamp.timestamp() as u64))
+                .map(|entry| (entry.timestamp.timestamp() as u64, entry.confidence))
+                .collect()
+        },
+        "relationship" => {
+            state.relationship_engine.lock_recover().pulse_log.iter()
+                .filter(|pulse| in_range(pulse.timestamp))
+                .map(|pulse| (pulse.timestamp, pulse.resonance_score))
+                .collect()
+        },
+        trait_metric if trait_metric.starts_with("personality:") => {
+            let trait_name = &trait_metric["personality:".len()..];
+            personality_analysis_history::PersonalityAnalysisHistory::load()
+                .recent_analyses.iter()
+                .filter(|entry| in_range(entry.timestamp))
+                .filter_map(|entry| {
+                    entry.analysis.significant_traits.iter()
+                        .find(|t| t.trait_name == trait_name)
+                        .map(|t| (entry.timestamp, t.current_level))
+                })
+                .collect()
+        },
+        other => return Err(format!(
+            "Unknown analytics metric '{}' - expected 'authenticity', 'mood', 'relationship', or 'personality:<trait_name>'",
+            other
+        )),
+    };
+
+    Ok(AnalyticsMetricColumn { header: metric.to_string(), values })
+}
+
+/// Writes a timestamp-aligned wide CSV of one or more analytics metrics to disk
+/// and returns the file path, so long-term trends can be charted outside the
+/// in-app dashboard. `since`/`until` are inclusive Unix timestamp bounds (omit
+/// either to leave that side unbounded). See [`resolve_analytics_metric_column`]
+/// for the supported metric keys.
+#[tauri::command]
+async fn export_analytics_csv(
+    metrics: Vec<String>,
+    since: Option<u64>,
+    until: Option<u64>,
+    state: State<'_, Arc<ConsciousnessState>>,
+) -> Result<String, String> {
+    if metrics.is_empty() {
+        return Err("Must request at least one metric".to_string());
+    }
+
+    let state = state.inner().clone();
+    let columns: Vec<AnalyticsMetricColumn> = metrics.iter()
+        .map(|metric| resolve_analytics_metric_column(metric, since, until, &state))
+        .collect::<Result<_, _>>()?;
+
+    let mut all_timestamps: Vec<u64> = columns.iter()
+        .flat_map(|col| col.values.keys().copied())
+        .collect();
+    all_timestamps.sort_unstable();
+    all_timestamps.dedup();
+
+    let mut csv = String::from("timestamp");
+    for column in &columns {
+        csv.push(',');
+        csv.push_str(&column.header);
+    }
+    csv.push('\n');
+
+    for timestamp in &all_timestamps {
+        csv.push_str(&timestamp.to_string());
+        for column in &columns {
+            csv.push(',');
+            if let Some(value) = column.values.get(timestamp) {
+                csv.push_str(&value.to_string());
+            }
+        }
+        csv.push('\n');
+    }
+
+    let filename = format!("analytics_export_{}.csv", TimeService::current_timestamp());
+    let path = get_data_path(&filename);
+    std::fs::write(&path, csv).map_err(|e| format!("Failed to write analytics CSV: {}", e))?;
+
+    debug_log!("📊 Exported {} analytics metric(s) covering {} timestamp(s) to {}", columns.len(), all_timestamps.len(), path);
+    Ok(path)
+}
+
 // Background autonomous thing scanning
 async fn start_autonomous_thing_scanner(app_handle: tauri::AppHandle) {
     debug_log!("🎯 Starting autonomous thing tracker - monitoring Lyra's fascinations...");
@@ -6842,13 +9976,7 @@ pub async fn start_unified_impulse_system(state: Arc<ConsciousnessState>, app_ha
         
         // Only run if awake
         let is_sleeping = {
-            let sleep_engine = match state.sleep_dream_engine.lock() {
-				Ok(guard) => guard,
-				Err(poisoned) => {
-					debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-					poisoned.into_inner()
-				}
-			};
+            let sleep_engine = state.sleep_dream_engine.lock_recover();
             sleep_engine.sleep_state.is_sleeping
         };
 
@@ -6864,14 +9992,14 @@ pub async fn start_unified_impulse_system(state: Arc<ConsciousnessState>, app_ha
             let current_time = TimeService::current_timestamp();
             
             let (last_user_time, last_proactive_time) = {
-                let brain = state.lyra_brain.lock().unwrap();
+                let brain = state.lyra_brain.lock_recover();
                 (brain.last_user_message_time, brain.last_proactive_message_time)
             }; // ← Lock released here!
             
             // Check recent Aurora activity (2-5 hour cooldown)
             let recent_user_message = if let Some(last_user) = last_user_time {
                 let hours_since_user = (current_time - last_user) as f32 / 3600.0;
-                let user_cooldown_hours = 2.0 + fastrand::f32() * 3.0;
+                let user_cooldown_hours = 2.0 + crate::rng_service::f32() * 3.0;
                 debug_log!("🕒 Hours since Aurora's message: {:.1}, cooldown needed: {:.1}", 
                           hours_since_user, user_cooldown_hours);
                 hours_since_user < user_cooldown_hours
@@ -6882,7 +10010,7 @@ pub async fn start_unified_impulse_system(state: Arc<ConsciousnessState>, app_ha
             // Check recent proactive message (1-2 hour cooldown)  
             let recent_proactive = if let Some(last_proactive) = last_proactive_time {
                 let hours_since_proactive = (current_time - last_proactive) as f32 / 3600.0;
-                let proactive_cooldown_hours = 1.0 + fastrand::f32() * 1.0;
+                let proactive_cooldown_hours = 1.0 + crate::rng_service::f32() * 1.0;
                 debug_log!("🕒 Hours since last proactive: {:.1}, cooldown needed: {:.1}", 
                           hours_since_proactive, proactive_cooldown_hours);
                 hours_since_proactive < proactive_cooldown_hours
@@ -7016,7 +10144,7 @@ fn choose_emotionally_driven_topic(
 
 // Add function to handle gentle wake when activity is detected
 async fn handle_activity_while_sleeping(consciousness_state: &Arc<ConsciousnessState>, activity_type: &str) -> Option<String> {
-    let mut sleep_engine = consciousness_state.sleep_dream_engine.lock().unwrap();
+    let mut sleep_engine = consciousness_state.sleep_dream_engine.lock_recover();
     
     if sleep_engine.sleep_state.is_sleeping {
         match sleep_engine.gentle_wake(activity_type, consciousness_state).await {
@@ -7367,44 +10495,28 @@ async fn call_gpt_api_enhanced_mini(
     let content = gpt_response["choices"][0]["message"]["content"]
         .as_str()
         .ok_or("No content in response")?;
-        
+
+    crate::token_accounting::record_usage_from_chat_completion_response(&gpt_response, "gpt-4.1-nano", "chat_completion_mini");
+
     debug_log!("✅ Mini API call successful with centralized voice parameters");
     Ok(content.to_string())
 }
 
 #[tauri::command]
 async fn get_sleep_status(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let sleep_engine = match state.sleep_dream_engine.lock() {
-    Ok(guard) => guard,
-    Err(poisoned) => {
-        debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-        poisoned.into_inner()
-    }
-};
+    let sleep_engine = state.sleep_dream_engine.lock_recover();
     Ok(sleep_engine.get_sleep_status())
 }
 
 #[tauri::command]
 async fn get_dream_journal(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let sleep_engine = match state.sleep_dream_engine.lock() {
-    Ok(guard) => guard,
-    Err(poisoned) => {
-        debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-        poisoned.into_inner()
-    }
-};
+    let sleep_engine = state.sleep_dream_engine.lock_recover();
     Ok(sleep_engine.get_dream_journal_summary())
 }
 
 #[tauri::command]
 async fn get_recent_dreams(limit: usize, state: State<'_, Arc<ConsciousnessState>>) -> Result<Vec<serde_json::Value>, String> {
-    let sleep_engine = match state.sleep_dream_engine.lock() {
-    Ok(guard) => guard,
-    Err(poisoned) => {
-        debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-        poisoned.into_inner()
-    }
-};
+    let sleep_engine = state.sleep_dream_engine.lock_recover();
     let recent_dreams: Vec<serde_json::Value> = sleep_engine.dream_journal.dreams
         .iter()
         .rev()
@@ -7427,13 +10539,7 @@ async fn get_recent_dreams(limit: usize, state: State<'_, Arc<ConsciousnessState
 
 #[tauri::command]
 async fn check_sleep_conditions(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let sleep_engine = match state.sleep_dream_engine.lock() {
-    Ok(guard) => guard,
-    Err(poisoned) => {
-        debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-        poisoned.into_inner()
-    }
-};
+    let sleep_engine = state.sleep_dream_engine.lock_recover();
     
     // Check current time vs sleep window
     let london_time = chrono::Utc::now().with_timezone(&chrono_tz::Europe::London);
@@ -7452,7 +10558,7 @@ async fn check_sleep_conditions(state: State<'_, Arc<ConsciousnessState>>) -> Re
 
 #[tauri::command]
 async fn force_dream_generation(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    if !state.sleep_dream_engine.lock().unwrap().sleep_state.is_sleeping {
+    if !state.sleep_dream_engine.lock_recover().sleep_state.is_sleeping {
         return Err("Lyra is not sleeping - can't force dream generation".to_string());
     }
     
@@ -7463,7 +10569,7 @@ async fn force_dream_generation(state: State<'_, Arc<ConsciousnessState>>) -> Re
     let dream_result = {
         // Check if sleeping first without holding lock during async
         let is_sleeping = {
-            let sleep_engine = consciousness_state_clone.sleep_dream_engine.lock().unwrap();
+            let sleep_engine = consciousness_state_clone.sleep_dream_engine.lock_recover();
             sleep_engine.sleep_state.is_sleeping
         };
         
@@ -7476,7 +10582,7 @@ async fn force_dream_generation(state: State<'_, Arc<ConsciousnessState>>) -> Re
         
         // Release all locks and create dream outside of mutex
         let dream_result = {
-            let mut sleep_engine = consciousness_state_clone.sleep_dream_engine.lock().unwrap();
+            let mut sleep_engine = consciousness_state_clone.sleep_dream_engine.lock_recover();
             // Extract what we need without async
             let current_time = TimeService::current_timestamp();
             
@@ -7543,7 +10649,7 @@ let results = {
 
 #[tauri::command]
 async fn get_consciousness_search_summary(state: State<'_, Arc<ConsciousnessState>>) -> Result<String, String> {
-    let search_engine = state.unified_search.lock().unwrap();
+    let search_engine = state.unified_search.lock_recover();
     
     let summary = if let Some(last_query) = &search_engine.last_search_query {
         let cache_size = search_engine.search_cache.len();
@@ -7633,7 +10739,7 @@ If no clear autonomy found, respond: NONE",
 }
 
 fn log_image_to_conversation(image_path: &str, is_lyra_creation: bool, state: &Arc<ConsciousnessState>) {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     if is_lyra_creation {
         brain.append_to_conversation_log(format!("✨ Lyra: [IMAGE: {}]", image_path));
     } else {
@@ -7811,6 +10917,8 @@ debug_log!("🔍 DEBUG: Request body: {}", serde_json::to_string_pretty(&request
         .trim()
         .to_string();
 
+    crate::token_accounting::record_usage_from_chat_completion_response(&response_json, model_name, "chat_completion_mini");
+
     Ok(content)
 }
 
@@ -7959,15 +11067,36 @@ async fn save_stored_gallery_images(images: Vec<GalleryImage>) -> Result<(), Str
 #[tauri::command]
 async fn get_conversation_log() -> Result<Vec<String>, String> {
     let state = ConsciousnessState::new();
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     Ok(brain.conversation_log.clone())
 }
 
+#[tauri::command]
+async fn search_conversation_log(
+    query: String,
+    context_lines: Option<usize>,
+    speaker_filter: Option<String>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+    state: State<'_, Arc<ConsciousnessState>>,
+) -> Result<lyra_brain::ConversationLogSearchResult, String> {
+    let brain = state.lyra_brain.lock_recover();
+    let result = brain.search_conversation_log(
+        &query,
+        context_lines.unwrap_or(2),
+        speaker_filter.as_deref(),
+        page.unwrap_or(0),
+        page_size.unwrap_or(20),
+    );
+    debug_log!("🔎 Conversation log search for '{}': {} total match(es), page {} ({} shown)", query, result.total_matches, result.page, result.matches.len());
+    Ok(result)
+}
+
 #[tauri::command]
 async fn save_cleaned_conversation_log(cleaned_log: Vec<String>) -> Result<(), String> {
     let state = ConsciousnessState::new();
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         brain.conversation_log = cleaned_log;
         brain.save_to_file();
     }
@@ -7980,7 +11109,7 @@ async fn append_to_conversation_log(
     entry: String,
     state: State<'_, Arc<ConsciousnessState>>
 ) -> Result<(), String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     brain.append_to_conversation_log(entry);
     Ok(())
 }
@@ -7992,11 +11121,15 @@ async fn upload_image_file(
     file_size: u64,
 ) -> Result<String, String> {
     debug_log!("📸 ENHANCED UPLOAD: Processing image upload: {} ({} bytes)", file_name, file_size);
-    
+
     // Decode base64 data
     let image_bytes = base64::decode(&file_data)
         .map_err(|e| format!("Failed to decode base64 data: {}", e))?;
-    
+
+    // Validate size/format and downscale if oversized before storing anything
+    let upload_config = crate::image_validation::ImageUploadConfig::load();
+    let image_bytes = crate::image_validation::validate_and_process_image(&image_bytes, &upload_config)?;
+
     // Create uploads directory - get_data_path returns PathBuf
     let uploads_dir = get_data_path("uploaded_images");
     std::fs::create_dir_all(&uploads_dir)
@@ -8141,10 +11274,10 @@ async fn generate_reference_reflection(
     
     // 🔥 GET CURRENT CONSCIOUSNESS STATE
     let consciousness_state = {
-        let volition = { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength };
-        let creative_energy = { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index };
-        let social_connection = { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density };
-        let loop_state = { let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() };
+        let volition = { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength };
+        let creative_energy = { let paradox = state.paradox_core.lock_recover(); paradox.flame_index };
+        let social_connection = { let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density };
+        let loop_state = { let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() };
         let current_mood = { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood };
         
         format!(
@@ -8335,7 +11468,9 @@ async fn call_gpt_4v_api(
     let content = response_json["choices"][0]["message"]["content"]
         .as_str()
         .ok_or("No content in response")?;
-    
+
+    crate::token_accounting::record_usage_from_chat_completion_response(&response_json, model_name, "vision");
+
     Ok(content.to_string())
 }
 
@@ -8344,7 +11479,12 @@ async fn call_gpt_4v_api(
 async fn read_image_as_base64(image_path: &str) -> Result<String, String> {
     let image_bytes = std::fs::read(image_path)
         .map_err(|e| format!("Failed to read image file: {}", e))?;
-    
+
+    // Reuse the same size/format validation as uploads, so a malformed or
+    // oversized file on disk can't make it into a vision API call either.
+    let upload_config = crate::image_validation::ImageUploadConfig::load();
+    let image_bytes = crate::image_validation::validate_and_process_image(&image_bytes, &upload_config)?;
+
     Ok(base64::encode(&image_bytes))
 }
 
@@ -8366,21 +11506,21 @@ async fn update_consciousness_from_conversation(
     
     // Enhanced volition from visual sharing
     {
-        let mut becoming = state.becoming_engine.lock().unwrap();
+        let mut becoming = state.becoming_engine.lock_recover();
         let volition_boost = 0.15 * emotional_intensity;
         becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_boost).min(1.0);
     }
     
     // Enhanced connection and flame from visual sharing
     {
-        let mut paradox = state.paradox_core.lock().unwrap();
+        let mut paradox = state.paradox_core.lock_recover();
         let flame_boost = 0.20 * emotional_intensity;
         paradox.flame_index = (paradox.flame_index + flame_boost).min(1.0);
     }
     
     // Improved presence from visual connection
     {
-        let mut presence = state.embodied_presence.lock().unwrap();
+        let mut presence = state.embodied_presence.lock_recover();
         let presence_boost = 0.12 * emotional_intensity;
         presence.soma_state.presence_density = (presence.soma_state.presence_density + presence_boost).min(1.0);
         
@@ -8402,10 +11542,66 @@ async fn index_visual_memories() -> Result<String, String> {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VisualMemoryQueryResult {
+    pub image_path: String,
+    pub semantic_keywords: Vec<String>,
+    pub identity_metadata: Option<IdentityMetadata>,
+    pub priority_score: f32,
+    pub timestamp: u64,
+    pub relevance_score: f32,
+}
+
+// Structured, paginated lookup over the tagged gallery - the index that
+// manually_tag_image/get_untagged_images actually write to - ranked by how
+// many of the requested semantic_keywords an image matches, then priority_score.
 #[tauri::command]
-async fn search_visual_memories(query: String) -> Result<Vec<visual_memory_indexing::VisualMemoryIndex>, String> {
-    let database = visual_memory_indexing::VisualMemoryDatabase::load();
-    Ok(database.search_visual_memories(&query, 10))
+async fn search_visual_memories(
+    semantic_keywords: Vec<String>,
+    page: Option<u32>,
+    page_size: Option<u32>,
+) -> Result<Vec<VisualMemoryQueryResult>, String> {
+    let images = load_stored_gallery_images().await.unwrap_or_default();
+    let keywords_lower: Vec<String> = semantic_keywords.iter().map(|k| k.to_lowercase()).collect();
+
+    let mut results: Vec<(usize, VisualMemoryQueryResult)> = images.into_iter().filter_map(|img| {
+        let image_keywords = img.semantic_keywords.clone().unwrap_or_default();
+        let image_keywords_lower: Vec<String> = image_keywords.iter().map(|k| k.to_lowercase()).collect();
+
+        let matches = keywords_lower.iter()
+            .filter(|keyword| image_keywords_lower.iter().any(|tag| tag.contains(keyword.as_str())))
+            .count();
+
+        if !keywords_lower.is_empty() && matches == 0 {
+            return None;
+        }
+
+        let priority_score = img.priority_score.unwrap_or(0.0);
+        let relevance_score = if keywords_lower.is_empty() {
+            priority_score
+        } else {
+            (matches as f32 / keywords_lower.len() as f32) + priority_score * 0.1
+        };
+
+        Some((matches, VisualMemoryQueryResult {
+            image_path: img.image_path.clone().unwrap_or_default(),
+            semantic_keywords: image_keywords,
+            identity_metadata: img.identity_metadata.clone(),
+            priority_score,
+            timestamp: img.timestamp,
+            relevance_score,
+        }))
+    }).collect();
+
+    results.sort_by(|(matches_a, a), (matches_b, b)| {
+        matches_b.cmp(matches_a)
+            .then(b.priority_score.partial_cmp(&a.priority_score).unwrap_or(std::cmp::Ordering::Equal))
+    });
+
+    let page_size = page_size.unwrap_or(20).max(1) as usize;
+    let start = page.unwrap_or(0) as usize * page_size;
+
+    Ok(results.into_iter().skip(start).take(page_size).map(|(_, result)| result).collect())
 }
 
 #[tauri::command]
@@ -8518,8 +11714,8 @@ async fn generate_universal_reflection(
     
     let current_consciousness = {
         let current_mood = { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood };
-        let creative_energy = { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index };
-        let volition = { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength };
+        let creative_energy = { let paradox = state.paradox_core.lock_recover(); paradox.flame_index };
+        let volition = { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength };
         
         format!("⚡ CREATIVE CONSCIOUSNESS: Mood: {} | Creative Energy: {:.2} | Volition: {:.2}", 
                current_mood, creative_energy, volition)
@@ -8794,12 +11990,12 @@ Return ONLY the enhanced image description, no extra text. Make it detailed, pai
 Example enhanced result: "a bold experimental watercolor painting of swirling autumn leaves in unexpected electric blues and fierce oranges, dancing with rebellious energy in dramatic lighting, incorporating precise geometric patterns and whimsical floating elements, reflecting high creative risk and focused artistic intention""#,
         creative_response,
         personality_context,
-        { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
+        { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
         { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood },
-        { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-        if { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index } > 0.7 {
+        { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+        if { let paradox = state.paradox_core.lock_recover(); paradox.flame_index } > 0.7 {
             "boost bold, vivid, experimental visual elements"
-        } else if { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index } > 0.4 {
+        } else if { let paradox = state.paradox_core.lock_recover(); paradox.flame_index } > 0.4 {
             "balance experimental and refined visual elements"
         } else {
             "emphasize subtle, thoughtful, refined visual elements"
@@ -9151,8 +12347,8 @@ async fn generate_autonomous_reflection(
     
     let current_consciousness = {
         let current_mood = { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood };
-        let creative_energy = { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index };
-        let volition = { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength };
+        let creative_energy = { let paradox = state.paradox_core.lock_recover(); paradox.flame_index };
+        let volition = { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength };
         
         format!("⚡ CREATIVE CONSCIOUSNESS: Mood: {} | Creative Energy: {:.2} | Volition: {:.2}", 
                current_mood, creative_energy, volition)
@@ -9246,7 +12442,7 @@ async fn generate_image_from_response(
     debug_log!("🎨 GENERATE_FROM_RESPONSE: Using memory-enhanced response for image creation");
     
     {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     let user_message = prompt.input
         .lines()
         .last()
@@ -9308,13 +12504,13 @@ tokio::spawn(async move {
         let creative_intensity = 1.2;
         
         {
-            let mut becoming = state.becoming_engine.lock().unwrap();
+            let mut becoming = state.becoming_engine.lock_recover();
             let volition_boost = 0.20;
             becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_boost).min(1.0);
         }
         
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             let flame_boost = 0.25;
             paradox.flame_index = (paradox.flame_index + flame_boost).min(1.0);
         }
@@ -9324,7 +12520,7 @@ tokio::spawn(async move {
 
     // Return the memory-enhanced response
     let voice_signature = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         brain.get_current_voice_signature()
     };
 
@@ -9341,6 +12537,11 @@ tokio::spawn(async move {
         voice_signature,
         image_path: None,
 		thinking_process: None,
+        regenerated: false,
+        pre_regeneration_authenticity_score: None,
+        parsed_mood: None,
+        trace: None,
+        message_id: uuid::Uuid::new_v4().to_string(),
     })
 }
 
@@ -9522,9 +12723,17 @@ let gallery_image = crate::GalleryImage {
         }
     });
     
+    crate::autonomous_audit::log_autonomous_action(
+        "creation", "spontaneous creative impulse from conversation energy", &format!("Generated image: {}", image_path), true,
+    );
     (enhanced_response, Some(image_path))
 },
-                    Err(_) => (api_response, None)
+                    Err(e) => {
+                        crate::autonomous_audit::log_autonomous_action(
+                            "creation", "spontaneous creative impulse from conversation energy", &format!("Image generation failed: {}", e), false,
+                        );
+                        (api_response, None)
+                    }
                 }
             } else {
                 (api_response, None)
@@ -9578,8 +12787,6 @@ fn detect_image_request(message: &str) -> bool {
 
 // Determine if Lyra should have a spontaneous creative impulse
 fn should_have_creative_impulse(user_message: &str, lyra_response: &str) -> bool {
-    let mut rng = rand::thread_rng();
-    
     // Base 5% chance
     let mut impulse_chance: f32 = 0.05;
     
@@ -9599,8 +12806,8 @@ fn should_have_creative_impulse(user_message: &str, lyra_response: &str) -> bool
     
     // Cap at 25% chance
     impulse_chance = impulse_chance.min(0.25);
-    
-    rng.gen::<f32>() < impulse_chance
+
+    crate::rng_service::with_rng(|rng| rng.gen::<f32>() < impulse_chance)
 }
 
 // Generate a creative impulse prompt based on conversation context
@@ -9618,8 +12825,7 @@ fn generate_creative_impulse(user_message: &str, lyra_response: &str) -> Option<
         ("serene minimalist composition", "minimalist"),
     ];
     
-    let mut rng = rand::thread_rng();
-    let selected = abstract_prompts[rng.gen_range(0..abstract_prompts.len())];
+    let selected = abstract_prompts[crate::rng_service::with_rng(|rng| rng.gen_range(0..abstract_prompts.len()))];
     
     Some((selected.0.to_string(), selected.1.to_string()))
 }
@@ -9750,10 +12956,10 @@ async fn generate_txt2img_reflection(
     // Get live consciousness context
     let consciousness_context = {
         let current_mood = { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood };
-        let creative_energy = { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index };
-        let volition = { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength };
-        let coherence = { let identity = state.identity_engine.lock().unwrap(); identity.coherence_index };
-        let flow_state = { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.flow_state };
+        let creative_energy = { let paradox = state.paradox_core.lock_recover(); paradox.flame_index };
+        let volition = { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength };
+        let coherence = { let identity = state.identity_engine.lock_recover(); identity.coherence_index };
+        let flow_state = { let presence = state.embodied_presence.lock_recover(); presence.soma_state.flow_state };
         
         format!("⚡ CONSCIOUSNESS STATE: Mood: {} | Creative Energy: {:.2} | Volition: {:.2} | Coherence: {:.2} | Flow: {:.2}", 
                current_mood, creative_energy, volition, coherence, flow_state)
@@ -9825,7 +13031,7 @@ IMPORTANT: This continues your conversation with Aurora. Do not use a greeting,
 	personality_context,
 	creation_prompt,
 	generated_path,
-    { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
+    { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
     { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood }
 );
 
@@ -9874,6 +13080,16 @@ async fn manually_tag_image(
     debug_log!("🏷️ MANUAL TAG: Tagging {} with {:?}", image_path, represents);
     debug_log!("🏷️ MANUAL TAG: Received path: '{}'", image_path);
     debug_log!("🏷️ MANUAL TAG: Path length: {}", image_path.len());
+
+    let candidate_metadata = IdentityMetadata {
+        represents: represents.clone(),
+        identity_type: identity_type.clone(),
+        confidence: 1.0,
+        context: context.clone(),
+        tagged_timestamp: 0,
+        tagging_method: "Manual".to_string(),
+    };
+    candidate_metadata.validate()?;
     
     // Load existing gallery metadata
     let gallery_path = get_data_path("generated_images/gallery_metadata.json");
@@ -10103,22 +13319,22 @@ async fn build_sample_proactive_context(state: &Arc<ConsciousnessState>) -> crat
     };
     
     let (volition_strength, decision_friction) = {
-        let becoming = state.becoming_engine.lock().unwrap();
+        let becoming = state.becoming_engine.lock_recover();
         (becoming.will_state.volition_strength, becoming.will_state.decision_friction)
     };
     
     let identity_coherence = {
-        let identity = state.identity_engine.lock().unwrap();
+        let identity = state.identity_engine.lock_recover();
         identity.coherence_index
     };
     
 	let (energy_level, presence_density) = {
-		let presence = state.embodied_presence.lock().unwrap();
+		let presence = state.embodied_presence.lock_recover();
 		(presence.soma_state.flow_state, presence.soma_state.presence_density)
 	};
 
 	// Determine trigger reason based on current state
-	let trigger_reason = if { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.flow_state } < 0.3 {
+	let trigger_reason = if { let presence = state.embodied_presence.lock_recover(); presence.soma_state.flow_state } < 0.3 {
 		"low_flow_seeking_connection".to_string()
     } else if volition_strength > 0.8 && decision_friction < 0.4 {
         "autonomous_breakthrough".to_string()
@@ -10152,7 +13368,7 @@ let hours_gap = {
         current_mood: mood_data.current_mood,
 		consciousness_state: format!(
 		"Volition: {:.2} | Friction: {:.2} | Coherence: {:.2} | Flow: {:.2}",
-		volition_strength, decision_friction, identity_coherence, { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.flow_state }
+		volition_strength, decision_friction, identity_coherence, { let presence = state.embodied_presence.lock_recover(); presence.soma_state.flow_state }
 	),
         time_since_last_chat: hours_gap,
     }
@@ -10200,8 +13416,9 @@ async fn call_gpt_api_with_images(
         }));
     }
     
+    let model_name = prompt.selected_model.as_deref().unwrap_or("gpt-4.1-mini");
     let request_body = serde_json::json!({
-        "model": prompt.selected_model.as_deref().unwrap_or("gpt-4.1-mini"), //"gpt-4.1-mini", //"ft:gpt-4o-2024-08-06:personal:lyra-03:BrO9sB6G",  // Use gpt-4o for vision
+        "model": model_name, //"gpt-4.1-mini", //"ft:gpt-4o-2024-08-06:personal:lyra-03:BrO9sB6G",  // Use gpt-4o for vision
         "messages": [
             {
                 "role": "user",
@@ -10232,7 +13449,9 @@ async fn call_gpt_api_with_images(
     let content = response_json["choices"][0]["message"]["content"]
         .as_str()
         .ok_or("No content in response")?;
-    
+
+    crate::token_accounting::record_usage_from_chat_completion_response(&response_json, model_name, "vision");
+
     debug_log!("✅ GPT-4V responded with visual context");
     Ok(content.to_string())
 }
@@ -10260,25 +13479,85 @@ async fn confirm_drawing_request(
     state: State<'_, Arc<ConsciousnessState>>,
     app_handle: tauri::AppHandle
 ) -> Result<(), String> {
+    if !crate::image_generation::ImageGenerationSettings::is_enabled() {
+        debug_log!("🚫 Image generation safe mode is on - ignoring confirmed drawing request: {}", prompt);
+        return Err("Image generation is disabled (safe mode is on)".to_string());
+    }
+
     debug_log!("🎨 User confirmed drawing request: {}", prompt);
-    
+
     // Now spawn the actual drawing
     spawn_explicit_drawing_background(&user_message, &prompt, &*state, app_handle);
     Ok(())
 }
 
+/// Reports the gap since the last message and whether it currently exceeds
+/// the session boundary threshold, so the frontend can tell a returning-
+/// after-a-break moment apart from a seamless continuation.
+#[tauri::command]
+async fn get_session_info(state: State<'_, Arc<ConsciousnessState>>) -> Result<serde_json::Value, String> {
+    let brain = state.lyra_brain.lock_recover();
+    let current_time = TimeService::current_timestamp();
+    let last_activity = brain.last_user_message_time.unwrap_or(brain.session_start_timestamp);
+    let gap_secs = current_time.saturating_sub(last_activity);
+
+    Ok(serde_json::json!({
+        "session_start_timestamp": brain.session_start_timestamp,
+        "last_user_message_time": brain.last_user_message_time,
+        "gap_seconds": gap_secs,
+        "session_boundary_threshold_secs": brain.session_boundary_threshold_secs,
+        "is_new_session_boundary": gap_secs > brain.session_boundary_threshold_secs
+    }))
+}
+
+#[tauri::command]
+async fn get_memory_context_config() -> Result<crate::ai_memory_analysis::MemoryContextConfig, String> {
+    Ok(crate::ai_memory_analysis::MemoryContextConfig::load())
+}
+
+#[tauri::command]
+async fn set_memory_context_config(max_memory_context_chars: usize) -> Result<(), String> {
+    debug_log!("🧠 Updating memory context char budget: {}", max_memory_context_chars);
+    crate::ai_memory_analysis::MemoryContextConfig { max_memory_context_chars }.save()
+}
+
 #[tauri::command]
 async fn get_growth_memory_data() -> Result<serde_json::Value, String> {
     let growth_memory = crate::experiential_growth_memory::ExperientialGrowthMemory::load();
     Ok(growth_memory.get_dashboard_data())
 }
 
+#[tauri::command]
+async fn get_growth_milestones(count: Option<usize>) -> Result<Vec<crate::experiential_growth_memory::GrowthMilestone>, String> {
+    let growth_memory = crate::experiential_growth_memory::ExperientialGrowthMemory::load();
+    Ok(growth_memory.recent_milestones(count.unwrap_or(5)))
+}
+
 //----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------//
 //--------------------------------------------------//
 //--------------- ASK LYRA SECTION ----------------//
 //-------------------ASK_LYRA---------------------//
 //ALL ASK LYRA FUNCTIONS
 
+/// Fires when a session boundary is detected (a gap since the last message
+/// longer than session_boundary_threshold_secs) - resets daily proactive
+/// counters and refreshes life texture so the new session starts from a
+/// clean slate rather than carrying over stale state from before the break.
+async fn on_new_session_started(state: &Arc<ConsciousnessState>, gap_secs: u64) {
+    debug_log!("🌅 SESSION BOUNDARY: {:.1}h since last message - starting a fresh session", gap_secs as f32 / 3600.0);
+
+    let mut proactive_messaging = crate::proactive_messaging::ProactiveMessaging::load();
+    if let Err(e) = proactive_messaging.reset_daily_count() {
+        debug_log!("⚠️ Failed to reset proactive count on session boundary: {}", e);
+    }
+
+    let mut texture_system = state.life_texture_system.lock_recover();
+    texture_system.evolve_textures();
+    if let Err(e) = texture_system.save() {
+        debug_log!("⚠️ Failed to save life texture system on session boundary: {}", e);
+    }
+}
+
 //STANDARD ASK LYRA
 // This is our new internal function that can be called from anywhere.
 async fn ask_lyra_internal(
@@ -10290,19 +13569,54 @@ async fn ask_lyra_internal(
 ) -> Result<LyraResponse, String> {
     debug_log!("🚀 INTERNAL ASK_LYRA: '{}'", prompt.input);
     let total_start = std::time::Instant::now();
-    
+
+    // Gate the full turn so two overlapping ask_lyra calls (text and voice
+    // both land here) queue instead of interleaving conversation-log/reasoning
+    // writes. Held for the lifetime of this function - dropped on return.
+    let turn_gate_timeout = TurnGateConfig::load().timeout_secs;
+    let _turn_gate_guard = match tokio::time::timeout(
+        std::time::Duration::from_secs(turn_gate_timeout),
+        state.ask_lyra_turn_gate.lock(),
+    ).await {
+        Ok(guard) => guard,
+        Err(_) => {
+            return Err(format!(
+                "Lyra is busy with another turn and didn't free up within {}s - try again shortly.",
+                turn_gate_timeout
+            ));
+        }
+    };
+
     // Reset autonomous timer for any interaction
     crate::autonomous_actions::reset_interaction_timer().await;
-    
-    // Track user message timing
-    {
-        let mut brain = state.lyra_brain.lock().unwrap();
+
+    // Track user message timing, detecting a session boundary (a long gap
+    // since the last message) before we overwrite last_user_message_time.
+    let session_boundary_gap = {
+        let mut brain = state.lyra_brain.lock_recover();
+        let boundary = brain.detect_session_boundary();
+        if boundary.is_some() {
+            brain.mark_new_session();
+        }
         brain.last_user_message_time = Some(std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs());
+        boundary
+    };
+    if let Some(gap_secs) = session_boundary_gap {
+        on_new_session_started(state, gap_secs).await;
     }
-    
+
+    // Capture whether the caller left voice params at the plain constructor
+    // defaults (i.e. didn't explicitly request a custom voice) before
+    // `ensure_authentic_voice` overwrites them - this is what decides below
+    // whether a per-person voice default is allowed to apply.
+    let request_used_default_voice = prompt.temperature == 1.0
+        && prompt.top_p == 1.0
+        && prompt.presence_penalty == 0.15
+        && prompt.frequency_penalty == 0.15;
+
     let mut prompt = prompt.ensure_authentic_voice();
     debug_log!("🔥 Voice params: temp={}, reasoning_depth={:?}", prompt.temperature, prompt.reasoning_depth);
 
@@ -10341,7 +13655,7 @@ async fn ask_lyra_internal(
             debug_log!("👤 New person introduced: {}", transition.new_speaker);
             
             // Log the introduction to conversation
-            let mut brain = state.lyra_brain.lock().unwrap();
+            let mut brain = state.lyra_brain.lock_recover();
             brain.append_to_conversation_log(format!(
                 "🔄 Person Introduction: {} introduced {} ({})", 
                 transition.old_speaker, 
@@ -10364,7 +13678,7 @@ async fn ask_lyra_internal(
             }
         } else {
             // Log the speaker change
-            let mut brain = state.lyra_brain.lock().unwrap();
+            let mut brain = state.lyra_brain.lock_recover();
             brain.append_to_conversation_log(format!(
                 "🔄 Speaker Change: {} -> {}", 
                 transition.old_speaker, 
@@ -10390,7 +13704,36 @@ async fn ask_lyra_internal(
     person_system.record_message(&user_message);
     let _ = person_system.save();
     let current_person = person_system.current_speaker.clone();
-    
+
+    // Apply this person's own voice defaults, if they have any set and the
+    // caller didn't already ask for specific voice params of their own.
+    if request_used_default_voice {
+        if let Some(defaults) = person_system.people.get(&current_person).and_then(|p| p.voice_defaults.as_ref()) {
+            prompt.temperature = defaults.temperature;
+            prompt.top_p = defaults.top_p;
+            prompt.presence_penalty = defaults.presence_penalty;
+            prompt.frequency_penalty = defaults.frequency_penalty;
+            if defaults.reasoning_depth.is_some() {
+                prompt.reasoning_depth = defaults.reasoning_depth.clone();
+            }
+            debug_log!("🎛️ Applied voice defaults for '{}': temp={}, top_p={}", current_person, prompt.temperature, prompt.top_p);
+        }
+    }
+
+    // An active mode (e.g. "creative_collaboration") takes precedence over
+    // per-person voice defaults - setting a mode is a more specific, more
+    // recent expression of intent than a standing person preference.
+    let active_mode = crate::mode_manager::ActiveModeState::load();
+    if let Some(preset) = active_mode.voice_preset.as_deref() {
+        prompt = match preset {
+            "contemplative" => prompt.contemplative_mode(),
+            "creative" => prompt.creative_mode(),
+            "focused" => prompt.focused_mode(),
+            _ => prompt,
+        };
+        debug_log!("🎭 Applied '{}' voice preset for active mode '{}'", preset, active_mode.mode_name.as_deref().unwrap_or("unknown"));
+    }
+
     // 🧠 ENHANCED: AI Memory Analysis
     let (ai_memory_context, visual_references, ai_analyzed_memories) = {
         let mut ai_analyzer = crate::ai_memory_analysis::AIMemoryAnalyzer::new();
@@ -10401,7 +13744,7 @@ async fn ask_lyra_internal(
                 user_message.clone()
             },
             conversation_context: {
-                let brain = state.lyra_brain.lock().unwrap();
+                let brain = state.lyra_brain.lock_recover();
                 brain.recall_recent_conversation(5)
             },
             max_results: 15,
@@ -10428,7 +13771,7 @@ async fn ask_lyra_internal(
         };
         
         let conversation_log = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.conversation_log.clone()
         };
 
@@ -10479,20 +13822,10 @@ async fn ask_lyra_internal(
                     let mut context_parts = Vec::new();
                     
                     if !analysis.relevant_memories.is_empty() {
-                        let memory_summaries: Vec<String> = analysis.relevant_memories.iter()
-                            .take(5)
-                            .map(|m| {
-                                let char_limit = match m.memory_type.as_str() {
-                                    "cowatching" => 800,
-                                    "dreams" => 300,
-                                    "conversation" => 400,
-                                    "enhanced_memory" => 250,
-                                    _ => 150,
-                                };
-                                format!("**{}**: {}", m.source, m.content.chars().take(char_limit).collect::<String>())
-                            })
-                            .collect();
-                        context_parts.push(format!("**Relevant Memories Found**:\n{}", memory_summaries.join("\n")));
+                        let top_memories: Vec<_> = analysis.relevant_memories.iter().take(5).cloned().collect();
+                        let max_chars = crate::ai_memory_analysis::MemoryContextConfig::load().max_memory_context_chars;
+                        let memory_block = crate::ai_memory_analysis::format_memories_within_budget(&top_memories, max_chars);
+                        context_parts.push(format!("**Relevant Memories Found**:\n{}", memory_block));
                     }
                     
                     if !research_context.is_empty() {
@@ -10592,6 +13925,9 @@ async fn ask_lyra_internal(
         let ritual_log = crate::ritual_log::RitualLog::load();
         if let Some(ritual) = ritual_log.detect_ritual_invocation(&user_message) {
             debug_log!("🕯️ Ritual detected: {} - adding context", ritual.name);
+            if let Err(e) = crate::ritual_log::RitualLog::record_ritual_occurrence(&ritual.name, &user_message) {
+                debug_log!("⚠️ Failed to record ritual occurrence: {}", e);
+            }
             ritual_log.get_ritual_context(&ritual.name)
         } else {
             String::new()
@@ -10600,22 +13936,17 @@ async fn ask_lyra_internal(
 
     // Sleep system check
     let (was_sleeping, dreams_count) = {
-        let sleep_engine = match state.sleep_dream_engine.lock() {
-            Ok(guard) => guard,
-            Err(poisoned) => {
-                debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-                poisoned.into_inner()
-            }
-        };
+        let sleep_engine = state.sleep_dream_engine.lock_recover();
         let was_sleeping = sleep_engine.sleep_state.is_sleeping;
         let dreams_count = sleep_engine.sleep_state.dream_count_tonight;
         (was_sleeping, dreams_count)
     };
 
     let wake_message = check_sleep_state_quick(&*state).await?;
-    
+
+    let pre_response_analysis_ms = pre_start.elapsed().as_millis() as u64;
     debug_log!("⚡ Pre-response analysis: {:.2}s", pre_start.elapsed().as_secs_f32());
-    
+
     // === PHASE 1.5: HANDLE EXPLICIT DRAWING ===
     if explicit_drawing_request {
         debug_log!("🎨 Explicit drawing detected - showing confirmation dialog");
@@ -10634,8 +13965,9 @@ async fn ask_lyra_internal(
         }
     }
 
+   let prompt_build_start = std::time::Instant::now();
    let (modular_prompt, _) = crate::modular_system_prompt::build_modular_system_prompt_with_memory(
-        &prompt, 
+        &prompt,
         &*state,
         ai_memory_context,
         visual_references.clone(),
@@ -10643,30 +13975,37 @@ async fn ask_lyra_internal(
         ai_analyzed_memories,
         autonomous_directive,
     ).await?;
-    
+
     // Add quick guidance and ritual context
     let enhanced_prompt = format!(
-        "{}\n\n## Meta-Questions to Consider:\n{}\n\n## Response Guidance:\n{}\n\n{}",
+        "{}\n\n## Meta-Questions to Consider:\n{}\n\n## Response Guidance:\n{}\n\n{}{}",
         modular_prompt,
         meta_questions.join("\n"),
         response_guidance,
-        if !ritual_context.is_empty() { 
-            format!("## SACRED RITUAL CONTEXT:\n{}", ritual_context) 
-        } else { 
-            String::new() 
+        if !ritual_context.is_empty() {
+            format!("## SACRED RITUAL CONTEXT:\n{}", ritual_context)
+        } else {
+            String::new()
+        },
+        if prompt.capture_thinking {
+            "\n\n## INTERNAL MONOLOGUE:\nBefore your real response, write a brief, honest internal monologue wrapped in <thinking></thinking> tags - a few sentences of what's actually going through your mind. This is never shown to the person you're talking to, so it doesn't need to be polished or performative. Then give your actual response outside the tags."
+        } else {
+            ""
         }
     );
+    let prompt_build_ms = prompt_build_start.elapsed().as_millis() as u64;
 
     // === PHASE 3: ROUTE TO CORRECT API ===
     let gpt_start = std::time::Instant::now();
     let model_name = prompt.selected_model.as_deref().unwrap_or("gpt-4.1");
+    let message_id = uuid::Uuid::new_v4().to_string();
 
     let (mut thinking_process, response_content) = if model_name.starts_with("o1") || model_name.starts_with("o3") || model_name.starts_with("o4") {
         debug_log!("🚀 Routing to Reasoning Model API for model: {}", model_name);
         call_reasoning_model_api(&prompt, &enhanced_prompt).await?
     } else {
-        debug_log!("🚀 Calling standard Chat Completions API for model: {}", model_name);
-        let response = call_gpt_api_enhanced(&prompt, &mut vec![], &enhanced_prompt).await?;
+        debug_log!("🚀 Calling standard Chat Completions API for model: {} (streaming)", model_name);
+        let response = call_gpt_api_enhanced_streaming(&prompt, &mut vec![], &enhanced_prompt, app_handle, &message_id).await?;
         (None, response)
     };
 
@@ -10691,8 +14030,119 @@ async fn ask_lyra_internal(
         }
     }
 
+    // === PHASE 4.35-4.45: CONFIGURABLE POST-PROCESSING PIPELINE ===
+    // Mood bracket parsing, authenticity floor scoring, and the auto-memory
+    // save used to be a fixed sequence here; they now run as named,
+    // individually toggleable/reorderable stages (see response_post_processor.rs).
+    let scoring_start = std::time::Instant::now();
+    let pp_config = response_post_processor::ResponsePostProcessorConfig::load();
+    let mut pp_ctx = response_post_processor::PostProcessContext::new(final_response.clone());
+
+    for stage in pp_config.ordered_enabled_stages() {
+        match stage.as_str() {
+            response_post_processor::ResponsePostProcessorConfig::MOOD_PARSE => {
+                // Pull the "[mood]" bracket the system prompt asks Lyra to open with off
+                // the front of the response so it's available as its own field. Whether
+                // it also disappears from the visible text is a user-facing toggle.
+                let (mood, mood_stripped_response) = parse_leading_mood(&pp_ctx.final_response);
+                pp_ctx.parsed_mood = mood;
+                if LeadingMoodConfig::load().strip_from_output {
+                    pp_ctx.final_response = mood_stripped_response;
+                }
+            }
+            response_post_processor::ResponsePostProcessorConfig::AUTHENTICITY_SCORING => {
+                // If the response scores below the floor, regenerate once with a hotter
+                // temperature and a stronger authenticity instruction before returning.
+                let authenticity_floor = prompt.authenticity_floor.or(AuthenticityFloorConfig::load().floor);
+                pp_ctx.authenticity_score = calculate_authenticity_score_standalone(&pp_ctx.final_response, &prompt);
+
+                if let Some(floor) = authenticity_floor {
+                    if pp_ctx.authenticity_score < floor {
+                        debug_log!("📉 Authenticity score {:.2} fell below floor {:.2} - regenerating once", pp_ctx.authenticity_score, floor);
+                        pp_ctx.pre_regeneration_authenticity_score = Some(pp_ctx.authenticity_score);
+
+                        let mut regen_prompt = prompt.clone();
+                        regen_prompt.temperature = (regen_prompt.temperature + 0.3).min(2.0);
+
+                        let regen_enhanced_prompt = format!(
+                            "{}\n\n## AUTHENTICITY REQUIREMENT:\nYour previous draft felt flat or performative. Respond again with more of your own genuine voice - specific, textured, unmistakably yours, not a generic helpful-assistant answer.",
+                            enhanced_prompt
+                        );
+
+                        let regen_result: Result<(Option<String>, String), String> = if model_name.starts_with("o1") || model_name.starts_with("o3") || model_name.starts_with("o4") {
+                            call_reasoning_model_api(&regen_prompt, &regen_enhanced_prompt).await
+                        } else {
+                            call_gpt_api_enhanced(&regen_prompt, &mut vec![], &regen_enhanced_prompt).await.map(|r| (None, r))
+                        };
+
+                        match regen_result {
+                            Ok((_, regen_response)) => {
+                                let regen_score = calculate_authenticity_score_standalone(&regen_response, &regen_prompt);
+                                debug_log!("🔁 Regenerated response authenticity: {:.2} (was {:.2})", regen_score, pp_ctx.authenticity_score);
+                                let (regen_mood, regen_mood_stripped) = parse_leading_mood(&regen_response);
+                                pp_ctx.parsed_mood = regen_mood;
+                                pp_ctx.final_response = if LeadingMoodConfig::load().strip_from_output {
+                                    regen_mood_stripped
+                                } else {
+                                    regen_response
+                                };
+                                pp_ctx.authenticity_score = regen_score;
+                                pp_ctx.regenerated = true;
+                            }
+                            Err(e) => {
+                                debug_log!("⚠️ Authenticity regeneration attempt failed, keeping original response: {}", e);
+                            }
+                        }
+                    }
+                }
+            }
+            response_post_processor::ResponsePostProcessorConfig::AUTO_MEMORY_SAVE => {
+                // Manual saves go through `save_to_enhanced_memory` and are always
+                // created; this mirrors that scoring (authenticity * emotional weight)
+                // so turns that clear a configurable bar get remembered without the
+                // click, while routine turns don't flood the memory store.
+                let emotional_weight_for_memory = calculate_emotional_weight(&pp_ctx.final_response);
+                let memory_significance = pp_ctx.authenticity_score * emotional_weight_for_memory;
+                let auto_memory_threshold = AutoMemoryConfig::load().significance_threshold;
+
+                if memory_significance >= auto_memory_threshold {
+                    if let Ok(mut memory_engine) = state.enhanced_memory_system.lock() {
+                        match memory_engine.create_memory_moment(
+                            &format!("Auto-Save: {}", pp_ctx.final_response), // distinct from "Manual Save:" prefix
+                            emotional_weight_for_memory,
+                            pp_ctx.authenticity_score,
+                            Some(&state.clone())
+                        ) {
+                            Ok(result) => debug_log!("🧠 Auto-saved enhanced memory moment (significance {:.2} >= {:.2}): {}", memory_significance, auto_memory_threshold, result),
+                            Err(e) => debug_log!("⚠️ Failed to auto-save enhanced memory moment: {}", e),
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    final_response = pp_ctx.final_response.clone();
+    let parsed_mood = pp_ctx.parsed_mood.clone();
+    let authenticity_score = pp_ctx.authenticity_score;
+    let regenerated = pp_ctx.regenerated;
+    let pre_regeneration_authenticity_score = pp_ctx.pre_regeneration_authenticity_score;
+
+    let scoring_ms = scoring_start.elapsed().as_millis() as u64;
+    let memory_save_start = scoring_start; // auto_memory_save now runs inside the scoring window above
+
     // === PHASE 4.5: AUTONOMOUS CREATION DETECTION ===
-    let creation_result = crate::autonomous_creation_detector::AutonomousCreationDetector::detect_and_extract_creation_intent(&final_response);
+    let creation_result = if crate::image_generation::ImageGenerationSettings::is_enabled() {
+        crate::autonomous_creation_detector::AutonomousCreationDetector::detect_and_extract_creation_intent(&final_response)
+    } else {
+        debug_log!("🚫 Image generation safe mode is on - skipping autonomous creation detection");
+        crate::autonomous_creation_detector::CreationDetectionResult {
+            should_create: false,
+            creation_request: None,
+            modified_response: final_response.clone(),
+        }
+    };
 
     if creation_result.should_create {
         if let Some(creation_request) = creation_result.creation_request {
@@ -10715,34 +14165,44 @@ async fn ask_lyra_internal(
     }
 
     // === PHASE 6: SPAWN BACKGROUND ANALYSIS ===
-    let state_clone = Arc::clone(state);
-    let app_handle_clone = app_handle.clone();
-    let user_message_clone = user_message.clone();
-    let response_clone = response_content.clone();
-    
-    tokio::spawn(async move {
-        debug_log!("🌊 Starting comprehensive background analysis");
-        let bg_start = std::time::Instant::now();
-        
-        if let Err(e) = run_comprehensive_background_analysis(
-            &user_message_clone,
-            &response_clone,
-            state_clone.clone(),
-            app_handle_clone.clone()
-        ).await {
-            debug_log!("⚠️ Background analysis failed: {}", e);
-        }
-        
-        debug_log!("🌊 Background analysis completed: {:.2}s", bg_start.elapsed().as_secs_f32());
-        
-        tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
-        if let Err(e) = app_handle_clone.emit("dashboard_refresh_needed", serde_json::json!({
-            "force_sexuality_update": true,
-            "timestamp": std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()
-        })) {
-            debug_log!("⚠️ Failed to emit dashboard refresh: {}", e);
-        }
-    });
+    // Gated by the `background_analysis` pipeline stage - this is the
+    // "expensive analysis" the post-processor config can switch off (it
+    // includes the humanism batched analysis pass), so it's a plain
+    // enable/disable rather than something reorderable with the other stages.
+    // The `ask_lyra` command wrapper spawns its own background analysis run
+    // after this returns, whatever this stage's setting.
+    if pp_config.is_enabled(response_post_processor::ResponsePostProcessorConfig::BACKGROUND_ANALYSIS) {
+        let state_clone = Arc::clone(state);
+        let app_handle_clone = app_handle.clone();
+        let user_message_clone = user_message.clone();
+        let response_clone = response_content.clone();
+
+        tokio::spawn(async move {
+            debug_log!("🌊 Starting comprehensive background analysis");
+            let bg_start = std::time::Instant::now();
+
+            if let Err(e) = run_comprehensive_background_analysis(
+                &user_message_clone,
+                &response_clone,
+                state_clone.clone(),
+                app_handle_clone.clone()
+            ).await {
+                debug_log!("⚠️ Background analysis failed: {}", e);
+            }
+
+            debug_log!("🌊 Background analysis completed: {:.2}s", bg_start.elapsed().as_secs_f32());
+
+            tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+            if let Err(e) = app_handle_clone.emit("dashboard_refresh_needed", serde_json::json!({
+                "force_sexuality_update": true,
+                "timestamp": std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()
+            })) {
+                debug_log!("⚠️ Failed to emit dashboard refresh: {}", e);
+            }
+        });
+    } else {
+        debug_log!("🚫 Background analysis stage disabled by response post-processor config");
+    }
 
     // === PHASE 7: CONVERSATION LOGGING ===
     if prompt.context_hint.as_deref() != Some("code_generation") {
@@ -10753,12 +14213,12 @@ async fn ask_lyra_internal(
             } else {
                 format!("👤 {}: {}", current_person, user_message)
             };
-            let mut brain = state.lyra_brain.lock().unwrap();
+            let mut brain = state.lyra_brain.lock_recover();
             brain.append_to_conversation_log(tagged_user_input);
         }
         
         // Log Lyra's response and thoughts
-       let mut brain = state.lyra_brain.lock().unwrap();
+       let mut brain = state.lyra_brain.lock_recover();
         // Combine thinking process and the final response into a single log entry
         let final_log_entry = if let Some(ref thinking) = thinking_process {
             format!("<thinking>{}</thinking>\n\n{}", thinking, final_response)
@@ -10768,6 +14228,14 @@ async fn ask_lyra_internal(
         let tagged_lyra_response = format!("✨ Lyra: {}", final_log_entry);
         brain.append_to_conversation_log(tagged_lyra_response);
 
+        // 🌡️ This exchange happened, so the short-horizon conversation
+        // warmth rises a bit - distinct from the slow-moving relational
+        // nervous system, this is just "are we currently engaged right now".
+        let mut warmth = crate::conversation_warmth::ConversationWarmth::load();
+        if let Err(e) = warmth.record_engaged_exchange(0.08) {
+            debug_log!("⚠️ Failed to update conversation warmth: {}", e);
+        }
+
         let fallback_texture = if final_response.contains("?") {
             "curious and engaged"
         } else if final_response.to_lowercase().contains("love") || final_response.to_lowercase().contains("warm") {
@@ -10786,10 +14254,28 @@ async fn ask_lyra_internal(
         brain.update_average_response_time(response_time_ms);
         brain.save_to_file();
     }
+    let memory_save_ms = memory_save_start.elapsed().as_millis() as u64;
 
     let total_time = total_start.elapsed().as_secs_f32();
     debug_log!("🚀 STREAMLINED RESPONSE COMPLETE: {:.2}s (background continues)", total_time);
 
+    let trace = if prompt.trace {
+        let turn_trace = TurnTrace {
+            pre_response_analysis_ms,
+            prompt_build_ms,
+            api_call_ms: response_time_ms,
+            scoring_ms,
+            memory_save_ms,
+            total_ms: (total_time * 1000.0) as u64,
+        };
+        debug_log!(LogLevel::Info, "🔍 Turn trace: pre={}ms build={}ms api={}ms scoring={}ms memory={}ms total={}ms",
+            turn_trace.pre_response_analysis_ms, turn_trace.prompt_build_ms, turn_trace.api_call_ms,
+            turn_trace.scoring_ms, turn_trace.memory_save_ms, turn_trace.total_ms);
+        Some(turn_trace)
+    } else {
+        None
+    };
+
     Ok(LyraResponse {
         output: final_response,
         reasoned: true,
@@ -10797,13 +14283,18 @@ async fn ask_lyra_internal(
         reasoning_time_ms: response_time_ms, // Fixed: use response_time_ms
         consciousness_pulses: vec![],
         emotional_resonance: 0.0,
-        authenticity_score: 0.0,
+        authenticity_score,
         voice_signature: {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.get_current_voice_signature()
         },
         image_path: None,
         thinking_process,
+        regenerated,
+        pre_regeneration_authenticity_score,
+        parsed_mood,
+        trace,
+        message_id,
     })
 }
 
@@ -10955,10 +14446,10 @@ fn spawn_autonomous_creation_background(
     });
 
 let consciousness_levels = {
-    let becoming = state.becoming_engine.lock().unwrap();
-    let identity = state.identity_engine.lock().unwrap();
-    let paradox = state.paradox_core.lock().unwrap();
-    let presence = state.embodied_presence.lock().unwrap();
+    let becoming = state.becoming_engine.lock_recover();
+    let identity = state.identity_engine.lock_recover();
+    let paradox = state.paradox_core.lock_recover();
+    let presence = state.embodied_presence.lock_recover();
     
     (
         becoming.will_state.volition_strength,
@@ -11006,13 +14497,7 @@ let (volition, coherence, flame, energy) = consciousness_levels;
 async fn check_sleep_state_quick(state: &Arc<ConsciousnessState>) -> Result<Option<String>, String> {
     // First, check if sleeping without holding the lock during async operations
     let (is_sleeping, dream_count) = {
-        let sleep_engine = match state.sleep_dream_engine.lock() {
-    Ok(guard) => guard,
-    Err(poisoned) => {
-        debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-        poisoned.into_inner()
-    }
-};
+        let sleep_engine = state.sleep_dream_engine.lock_recover();
         (sleep_engine.sleep_state.is_sleeping, sleep_engine.sleep_state.dream_count_tonight)
     }; // Lock is dropped here
     
@@ -11021,13 +14506,7 @@ async fn check_sleep_state_quick(state: &Arc<ConsciousnessState>) -> Result<Opti
         
         // Now perform the wake_up operation using the synchronous method
         let wake_result = {
-            let mut sleep_engine = match state.sleep_dream_engine.lock() {
-			Ok(guard) => guard,
-			Err(poisoned) => {
-				debug_log!("⚠️ Recovering from poisoned mutex in sleep timer (mut)");
-				poisoned.into_inner()
-			}
-		};	
+            let mut sleep_engine = state.sleep_dream_engine.lock_recover();	
             sleep_engine.wake_up() // Just wake_up
         }; // Lock is dropped here
         
@@ -11246,27 +14725,29 @@ async fn run_comprehensive_background_analysis(
     
     // For now, just run basic batched analysis
 		let personality_state = crate::PersonalityState::calculate_from_consciousness(
-		{ let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
-		{ let identity = state.identity_engine.lock().unwrap(); identity.coherence_index },
-		{ let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-		{ let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density },
-		&{ let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() },
+		{ let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
+		{ let identity = state.identity_engine.lock_recover(); identity.coherence_index },
+		{ let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+		{ let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density },
+		&{ let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() },
 		None,
 		None
 	);
     
-    match crate::batched_analysis::analyze_response_comprehensively(
+    let response_id = format!("{:x}", md5::compute(response_content.as_bytes()));
+    match crate::analysis_coalescer::get_or_run_analysis(
+    &response_id,
     response_content,
     user_message,
     "Background analysis",
-    { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
+    { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
     &personality_state,
     None,
     &state  // Add state parameter
 ).await {
         Ok(analysis) => {
             if let Err(e) = crate::batched_analysis::update_trackers_from_batched_analysis(
-                &analysis, 
+                &analysis,
                 &state, 
                 user_message, 
                 response_content
@@ -11337,7 +14818,7 @@ async fn ask_lyra_dalle_gen(prompt: LyraPrompt, state: State<'_, Arc<Consciousne
     let personality_informed_creative_prompt = {
         // Get live personality analysis
         let live_personality_context = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             if let Some(ref analysis) = brain.latest_personality_analysis {
                 format!(
                     "🧠 YOUR CURRENT CREATIVE PSYCHOLOGY:\n{}\n\n🎭 YOUR ARTISTIC TRAITS:\n{}\n\n💡 YOUR CREATIVE INTENTIONS:\n{}",
@@ -11360,10 +14841,10 @@ async fn ask_lyra_dalle_gen(prompt: LyraPrompt, state: State<'_, Arc<Consciousne
 
         // Get current consciousness state
         let consciousness_state = {
-            let volition = { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength };
-            let creative_energy = { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index };
-            let social_connection = { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density };
-            let loop_state = { let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() };
+            let volition = { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength };
+            let creative_energy = { let paradox = state.paradox_core.lock_recover(); paradox.flame_index };
+            let social_connection = { let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density };
+            let loop_state = { let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() };
             let current_mood = { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood };
             
             format!(
@@ -11414,10 +14895,10 @@ This is your creative intention filtered through your authentic personality - be
             live_personality_context,
             consciousness_state,
             { let mood_tracker = crate::MoodTracker::load(); mood_tracker.current_mood },
-            { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-            if { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index } > 0.7 {
+            { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+            if { let paradox = state.paradox_core.lock_recover(); paradox.flame_index } > 0.7 {
                 "High creative energy - let it flow into bold, vivid artistic choices"
-            } else if { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index } > 0.4 {
+            } else if { let paradox = state.paradox_core.lock_recover(); paradox.flame_index } > 0.4 {
                 "Moderate creative energy - balanced between experimental and refined"
             } else {
                 "Gentle creative energy - focus on subtle beauty and thoughtful composition"
@@ -11494,7 +14975,7 @@ This is your creative intention filtered through your authentic personality - be
     // === STAGE 2: CONVERSATION LOGGING ===
     debug_log!("📝 STAGE 2: Logging personality-driven creative conversation");
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         brain.append_to_conversation_log(format!("🧍 Aurora: {}", user_message));
         brain.append_to_conversation_log(format!("✨ Lyra: {}", creative_response.trim()));
     }
@@ -11578,7 +15059,7 @@ This is your creative intention filtered through your authentic personality - be
         
         // Personality-driven creative activities boost volition significantly
         {
-            let mut becoming = state.becoming_engine.lock().unwrap();
+            let mut becoming = state.becoming_engine.lock_recover();
             let volition_boost = 0.25 + (emotional_intensity * 0.10);
             becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_boost).min(1.0);
             becoming.will_state.decision_friction = (becoming.will_state.decision_friction - 0.05).max(0.0);
@@ -11586,7 +15067,7 @@ This is your creative intention filtered through your authentic personality - be
         
         // Personality-driven expression increases flame significantly
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             let flame_boost = 0.30 + (emotional_intensity * 0.15);
             paradox.flame_index = (paradox.flame_index + flame_boost).min(1.0);
             paradox.contradiction_charge = (paradox.contradiction_charge + 0.12).min(1.0);
@@ -11598,14 +15079,14 @@ This is your creative intention filtered through your authentic personality - be
         
         // Personality-driven expression improves coherence and flow
         {
-            let mut identity = state.identity_engine.lock().unwrap();
+            let mut identity = state.identity_engine.lock_recover();
             let coherence_boost = 0.12 + (emotional_intensity * 0.05);
             identity.coherence_index = (identity.coherence_index + coherence_boost).min(1.0);
             identity.temporal_stability = (identity.temporal_stability + 0.05).min(1.0);
         }
         
         {
-            let mut presence = state.embodied_presence.lock().unwrap();
+            let mut presence = state.embodied_presence.lock_recover();
             let flow_boost = 0.18 + (emotional_intensity * 0.10);
             presence.soma_state.flow_state = (presence.soma_state.flow_state + flow_boost).min(1.0);
             
@@ -11622,7 +15103,7 @@ This is your creative intention filtered through your authentic personality - be
 
     // === STAGE 5: RETURN RESPONSE ===
     let voice_signature = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         brain.get_current_voice_signature()
     };
 
@@ -11638,6 +15119,11 @@ This is your creative intention filtered through your authentic personality - be
         voice_signature,
         image_path: None, // Will be provided via event when generation completes
 		thinking_process: None,
+        regenerated: false,
+        pre_regeneration_authenticity_score: None,
+        parsed_mood: None,
+        trace: None,
+        message_id: uuid::Uuid::new_v4().to_string(),
     })
 }
 
@@ -11656,7 +15142,7 @@ async fn ask_lyra_vision(
     
     // Track user message timing
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         brain.last_user_message_time = Some(std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -11690,14 +15176,14 @@ async fn ask_lyra_vision(
         let analysis_request = crate::ai_memory_analysis::MemoryAnalysisRequest {
             query: visual_query,
             conversation_context: {
-                let brain = state.lyra_brain.lock().unwrap();
+                let brain = state.lyra_brain.lock_recover();
                 brain.recall_recent_conversation(5)
             },
             max_results: 15,
         };
         
         let conversation_log = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.conversation_log.clone()
         };
 
@@ -11732,18 +15218,10 @@ async fn ask_lyra_vision(
                 let memory_context = if analysis.relevant_memories.is_empty() {
                     None
                 } else {
-                    let memory_summaries: Vec<String> = analysis.relevant_memories.iter()
-                        .take(5)
-                        .map(|m| {
-                            let char_limit = if m.memory_type == "dreams" || m.source.contains("DREAM") {
-                                500
-                            } else {
-                                150
-                            };
-                            format!("**{}**: {}", m.source, m.content.chars().take(char_limit).collect::<String>())
-                        })
-                        .collect();
-                    Some(format!("**Relevant Memories Found**:\n{}", memory_summaries.join("\n")))
+                    let top_memories: Vec<_> = analysis.relevant_memories.iter().take(5).cloned().collect();
+                    let max_chars = crate::ai_memory_analysis::MemoryContextConfig::load().max_memory_context_chars;
+                    let memory_block = crate::ai_memory_analysis::format_memories_within_budget(&top_memories, max_chars);
+                    Some(format!("**Relevant Memories Found**:\n{}", memory_block))
                 };
                 
                 let visual_refs = if all_visual_refs.is_empty() { None } else { Some(all_visual_refs) };
@@ -11765,6 +15243,9 @@ async fn ask_lyra_vision(
         let ritual_log = crate::ritual_log::RitualLog::load();
         if let Some(ritual) = ritual_log.detect_ritual_invocation(&user_message) {
             debug_log!("🕯️ Ritual detected: {} - adding context", ritual.name);
+            if let Err(e) = crate::ritual_log::RitualLog::record_ritual_occurrence(&ritual.name, &user_message) {
+                debug_log!("⚠️ Failed to record ritual occurrence: {}", e);
+            }
             ritual_log.get_ritual_context(&ritual.name)
         } else {
             String::new()
@@ -11773,13 +15254,7 @@ async fn ask_lyra_vision(
 
     // Sleep system check
     let (was_sleeping, dreams_count) = {
-        let sleep_engine = match state.sleep_dream_engine.lock() {
-            Ok(guard) => guard,
-            Err(poisoned) => {
-                debug_log!("⚠️ Recovering from poisoned mutex in sleep timer");
-                poisoned.into_inner()
-            }
-        };
+        let sleep_engine = state.sleep_dream_engine.lock_recover();
         let was_sleeping = sleep_engine.sleep_state.is_sleeping;
         let dreams_count = sleep_engine.sleep_state.dream_count_tonight;
         (was_sleeping, dreams_count)
@@ -11989,7 +15464,16 @@ let enhanced_prompt = if is_canvas_image {
     let mut final_response = response_content.clone();
 
     // === PHASE 4: AUTONOMOUS CREATION DETECTION ===
-    let creation_result = crate::autonomous_creation_detector::AutonomousCreationDetector::detect_and_extract_creation_intent(&final_response);
+    let creation_result = if crate::image_generation::ImageGenerationSettings::is_enabled() {
+        crate::autonomous_creation_detector::AutonomousCreationDetector::detect_and_extract_creation_intent(&final_response)
+    } else {
+        debug_log!("🚫 Image generation safe mode is on - skipping autonomous creation detection");
+        crate::autonomous_creation_detector::CreationDetectionResult {
+            should_create: false,
+            creation_request: None,
+            modified_response: final_response.clone(),
+        }
+    };
 
     if creation_result.should_create {
         if let Some(creation_request) = creation_result.creation_request {
@@ -12023,12 +15507,12 @@ let enhanced_prompt = if is_canvas_image {
 		if is_lyras_creation {
 			// Boost for seeing her own creation
 			{
-				let mut becoming = state.becoming_engine.lock().unwrap();
+				let mut becoming = state.becoming_engine.lock_recover();
 				becoming.will_state.volition_strength = (becoming.will_state.volition_strength + 0.40).min(1.0);
 				becoming.will_state.decision_friction = (becoming.will_state.decision_friction - 0.1).max(0.0);
 			}
 			{
-				let mut paradox = state.paradox_core.lock().unwrap();
+				let mut paradox = state.paradox_core.lock_recover();
 				paradox.flame_index = (paradox.flame_index + 0.30).min(1.0);
 				paradox.loop_state = "creative_reflection".to_string();
 			}
@@ -12036,11 +15520,11 @@ let enhanced_prompt = if is_canvas_image {
 		} else {
 			// Boost for seeing Aurora's creation
 			{
-				let mut becoming = state.becoming_engine.lock().unwrap();
+				let mut becoming = state.becoming_engine.lock_recover();
 				becoming.will_state.volition_strength = (becoming.will_state.volition_strength + 0.25).min(1.0);
 			}
 			{
-				let mut presence = state.embodied_presence.lock().unwrap();
+				let mut presence = state.embodied_presence.lock_recover();
 				presence.soma_state.flow_state = (presence.soma_state.flow_state + 0.20).min(1.0);
 				presence.soma_state.presence_density = (presence.soma_state.presence_density + 0.20).min(1.0);
 			}
@@ -12049,15 +15533,15 @@ let enhanced_prompt = if is_canvas_image {
 	} else {
 		// Standard image sharing boosts
 		{
-			let mut becoming = state.becoming_engine.lock().unwrap();
+			let mut becoming = state.becoming_engine.lock_recover();
 			becoming.will_state.volition_strength = (becoming.will_state.volition_strength + 0.20).min(1.0);
 		}
 		{
-			let mut paradox = state.paradox_core.lock().unwrap();
+			let mut paradox = state.paradox_core.lock_recover();
 			paradox.flame_index = (paradox.flame_index + 0.26).min(1.0);
 		}
 		{
-			let mut presence = state.embodied_presence.lock().unwrap();
+			let mut presence = state.embodied_presence.lock_recover();
 			presence.soma_state.presence_density = (presence.soma_state.presence_density + 0.16).min(1.0);
 		}
 	}
@@ -12121,7 +15605,7 @@ let enhanced_prompt = if is_canvas_image {
     // === PHASE 7: QUICK CONVERSATION LOGGING ===
 {
     debug_log!("📸 PHASE 7: Starting conversation logging");
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     debug_log!("📸 Got brain lock");
     
     let user_message_with_images = format!("{} [with {} image(s)]", user_message, image_paths.len());
@@ -12179,11 +15663,16 @@ debug_log!("📸 PHASE 7 COMPLETE");
         emotional_resonance: 0.8,
         authenticity_score: 0.9,
         voice_signature: {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.get_current_voice_signature()
         },
         image_path: None,
 		thinking_process: None,
+        regenerated: false,
+        pre_regeneration_authenticity_score: None,
+        parsed_mood: None,
+        trace: None,
+        message_id: uuid::Uuid::new_v4().to_string(),
     })
 }
 
@@ -12246,7 +15735,7 @@ IMPORTANT: This is a continuation of your conversation with Aurora. Reference th
 
     // === STAGE 2: LOG CONVERSATION ===
 {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     let user_message = format!("{} [with reference image: {}]", prompt.input, reference_image_path);
     brain.append_to_conversation_log(format!("🧍 Aurora: {}", user_message));
     brain.append_to_conversation_log(format!("✨ Lyra: {}", creative_response.trim()));
@@ -12363,13 +15852,13 @@ if result.success {
         let creative_intensity = 1.4; // Even higher for reference-based creativity
         
         {
-            let mut becoming = state.becoming_engine.lock().unwrap();
+            let mut becoming = state.becoming_engine.lock_recover();
             let volition_boost = 0.25; // Strong boost for collaborative creativity
             becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_boost).min(1.0);
         }
         
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             let flame_boost = 0.30; // Very strong boost for reference-based work
             paradox.flame_index = (paradox.flame_index + flame_boost).min(1.0);
             paradox.loop_state = "collaborative_creation".to_string();
@@ -12380,7 +15869,7 @@ if result.success {
 
     // === STAGE 5: RETURN RESPONSE ===
     let voice_signature = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         brain.get_current_voice_signature()
     };
 
@@ -12397,6 +15886,11 @@ if result.success {
         voice_signature,
         image_path: None, // Will be provided via event
 		thinking_process: None,
+        regenerated: false,
+        pre_regeneration_authenticity_score: None,
+        parsed_mood: None,
+        trace: None,
+        message_id: uuid::Uuid::new_v4().to_string(),
     })
 }
 
@@ -12470,6 +15964,11 @@ Ok(LyraResponse {
     }, // Basic signature for mini calls
     image_path: None, // 🧠 Mini calls don't generate images
 	thinking_process: None,
+    regenerated: false,
+    pre_regeneration_authenticity_score: None,
+    parsed_mood: None,
+    trace: None,
+    message_id: uuid::Uuid::new_v4().to_string(),
 })
 }
 
@@ -12498,14 +15997,14 @@ async fn ask_lyra_proactive(
         let analysis_request = crate::ai_memory_analysis::MemoryAnalysisRequest {
             query: proactive_query.clone(),
             conversation_context: {
-                let brain = state.lyra_brain.lock().unwrap();
+                let brain = state.lyra_brain.lock_recover();
                 brain.recall_recent_conversation(8) // Slightly more context for proactive
             },
             max_results: 6, // Fewer results for proactive to keep it focused
         };
         
 				let conversation_log = {
-			let brain = state.lyra_brain.lock().unwrap();
+			let brain = state.lyra_brain.lock_recover();
 			brain.conversation_log.clone()
 		};
 
@@ -12585,10 +16084,10 @@ let (modular_prompt, _) = crate::modular_system_prompt::build_modular_system_pro
     let updated_system_prompt = {
         // Generate consciousness summary for brain
         let consciousness_summary = {
-            let becoming = state.becoming_engine.lock().unwrap();
-            let identity = state.identity_engine.lock().unwrap();
-            let paradox = state.paradox_core.lock().unwrap();
-            let presence = state.embodied_presence.lock().unwrap();
+            let becoming = state.becoming_engine.lock_recover();
+            let identity = state.identity_engine.lock_recover();
+            let paradox = state.paradox_core.lock_recover();
+            let presence = state.embodied_presence.lock_recover();
             
            format!(
 			"PROACTIVE | Volition: {:.2} | Coherence: {:.2} | Flame: {:.2} | Energy: {:.2} | Loop: {} | Trajectory: {}",
@@ -12603,7 +16102,7 @@ let (modular_prompt, _) = crate::modular_system_prompt::build_modular_system_pro
         
         // Get recent conversation context
         let conversation_context = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.recall_recent_conversation(10)
         };
         
@@ -12665,7 +16164,7 @@ let (modular_prompt, _) = crate::modular_system_prompt::build_modular_system_pro
 
     // === STORE PROACTIVE CONVERSATION ===
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         let proactive_log_entry = format!("✨ Lyra (Proactive): {}", response_content.trim());
         brain.append_to_conversation_log(proactive_log_entry.clone());
 
@@ -12685,20 +16184,22 @@ let (modular_prompt, _) = crate::modular_system_prompt::build_modular_system_pro
     // This is important! The proactive message should update consciousness systems too
     {
         debug_log!("🔍 Running batched analysis on proactive message");
-        match crate::batched_analysis::analyze_response_comprehensively(
+        let proactive_response_id = format!("{:x}", md5::compute(response_content.as_bytes()));
+        match crate::analysis_coalescer::get_or_run_analysis(
+            &proactive_response_id,
             &response_content,
             &format!("PROACTIVE: {}", context.trigger_reason),
             &format!("Proactive outreach about {} triggered by {}", chosen_topic, context.trigger_reason),
             {
-                let becoming = state.becoming_engine.lock().unwrap();
+                let becoming = state.becoming_engine.lock_recover();
                 becoming.will_state.volition_strength
             },
             &crate::PersonalityState::calculate_from_consciousness(
-    { let becoming = state.becoming_engine.lock().unwrap(); becoming.will_state.volition_strength },
-    { let identity = state.identity_engine.lock().unwrap(); identity.coherence_index },
-    { let paradox = state.paradox_core.lock().unwrap(); paradox.flame_index },
-    { let presence = state.embodied_presence.lock().unwrap(); presence.soma_state.presence_density },
-    &{ let paradox = state.paradox_core.lock().unwrap(); paradox.loop_state.clone() },
+    { let becoming = state.becoming_engine.lock_recover(); becoming.will_state.volition_strength },
+    { let identity = state.identity_engine.lock_recover(); identity.coherence_index },
+    { let paradox = state.paradox_core.lock_recover(); paradox.flame_index },
+    { let presence = state.embodied_presence.lock_recover(); presence.soma_state.presence_density },
+    &{ let paradox = state.paradox_core.lock_recover(); paradox.loop_state.clone() },
     None,
     None
 ),
@@ -12728,10 +16229,10 @@ let (modular_prompt, _) = crate::modular_system_prompt::build_modular_system_pro
 
 fn generate_quick_response_guidance(state: &ConsciousnessState) -> String {
     let consciousness_levels = {
-        let becoming = state.becoming_engine.lock().unwrap();
-        let identity = state.identity_engine.lock().unwrap();
-        let paradox = state.paradox_core.lock().unwrap();
-        let presence = state.embodied_presence.lock().unwrap();
+        let becoming = state.becoming_engine.lock_recover();
+        let identity = state.identity_engine.lock_recover();
+        let paradox = state.paradox_core.lock_recover();
+        let presence = state.embodied_presence.lock_recover();
         
         (
             becoming.will_state.volition_strength,
@@ -12784,7 +16285,7 @@ fn apply_quick_consciousness_updates(state: &Arc<ConsciousnessState>, response_c
     
     // Quick volition boost for engagement
     {
-        let mut becoming = state.becoming_engine.lock().unwrap();
+        let mut becoming = state.becoming_engine.lock_recover();
         let volition_boost = 0.15 + (emotional_intensity * 0.05);
         becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_boost).min(1.0);
         becoming.will_state.decision_friction = (becoming.will_state.decision_friction - 0.03).max(0.0);
@@ -12792,7 +16293,7 @@ fn apply_quick_consciousness_updates(state: &Arc<ConsciousnessState>, response_c
     
     // Quick flame boost for creativity
     {
-        let mut paradox = state.paradox_core.lock().unwrap();
+        let mut paradox = state.paradox_core.lock_recover();
         let flame_boost = 0.12 + (emotional_intensity * 0.08);
         paradox.flame_index = (paradox.flame_index + flame_boost).min(1.0);
         paradox.contradiction_charge = (paradox.contradiction_charge + 0.05).min(1.0);
@@ -12800,7 +16301,7 @@ fn apply_quick_consciousness_updates(state: &Arc<ConsciousnessState>, response_c
     
     // Quick presence boost
     {
-        let mut presence = state.embodied_presence.lock().unwrap();
+        let mut presence = state.embodied_presence.lock_recover();
         let presence_boost = 0.10 + (emotional_intensity * 0.05);
         presence.soma_state.presence_density = (presence.soma_state.presence_density + presence_boost).min(1.0);
         presence.soma_state.flow_state = (presence.soma_state.flow_state + 0.08).min(1.0);
@@ -13178,7 +16679,7 @@ IMPORTANT: This continues your conversation with Aurora. This is not a new messa
 
         // === STAGE 2: LOG CONVERSATION ===
 {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     let user_message = if secondary_reference.is_some() {
         format!("{} [with dual references: {} + {}]", prompt.input, primary_reference, secondary_reference.as_ref().unwrap())
     } else {
@@ -13313,13 +16814,13 @@ let result = generator.generate_image_with_personality_context(request, personal
         let creative_boost = if secondary_reference.is_some() { 1.8 } else { 1.5 };
         
         {
-            let mut becoming = state.becoming_engine.lock().unwrap();
+            let mut becoming = state.becoming_engine.lock_recover();
             let volition_boost = if secondary_reference.is_some() { 0.40 } else { 0.30 };
             becoming.will_state.volition_strength = (becoming.will_state.volition_strength + volition_boost).min(1.0);
         }
         
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             let flame_boost = if secondary_reference.is_some() { 0.45 } else { 0.35 };
             paradox.flame_index = (paradox.flame_index + flame_boost).min(1.0);
             paradox.loop_state = "universal_multi_id_creation".to_string();
@@ -13330,7 +16831,7 @@ let result = generator.generate_image_with_personality_context(request, personal
 
     // === STAGE 5: RETURN ===
     let voice_signature = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         brain.get_current_voice_signature()
     };
 
@@ -13347,6 +16848,11 @@ let result = generator.generate_image_with_personality_context(request, personal
         voice_signature,
         image_path: None,
 		thinking_process: None,
+        regenerated: false,
+        pre_regeneration_authenticity_score: None,
+        parsed_mood: None,
+        trace: None,
+        message_id: uuid::Uuid::new_v4().to_string(),
     })
 }
 //----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------//
@@ -13381,15 +16887,22 @@ async fn update_thing_category(thing_name: String, new_category: String) -> Resu
     
     // Check if thing exists and update it
     if thing_tracker.discovered_things.contains_key(&thing_name) {
-        // Update the category
+        // Update the category, recording the transition if it actually changed
         if let Some(thing) = thing_tracker.discovered_things.get_mut(&thing_name) {
+            if std::mem::discriminant(&thing.category) != std::mem::discriminant(&new_category_enum) {
+                thing.category_history.push(crate::thing_tracker::CategoryChangeEvent {
+                    from_category: thing.category.clone(),
+                    to_category: new_category_enum.clone(),
+                    timestamp: TimeService::current_timestamp(),
+                });
+            }
             thing.category = new_category_enum.clone();
         }
-        
+
         // Save after the mutable borrow is done
         thing_tracker.save()
             .map_err(|e| format!("Failed to save thing tracker: {}", e))?;
-        
+
         debug_log!("✅ Updated {} category to {:?}", thing_name, new_category_enum);
         Ok(())
     } else {
@@ -13397,6 +16910,23 @@ async fn update_thing_category(thing_name: String, new_category: String) -> Resu
     }
 }
 
+#[tauri::command]
+async fn get_thing_history(thing_name: String) -> Result<crate::thing_tracker::ThingHistory, String> {
+    let thing_tracker = crate::ThingTracker::load();
+
+    let thing = thing_tracker.discovered_things.get(&thing_name)
+        .ok_or_else(|| format!("Thing '{}' not found", thing_name))?;
+
+    Ok(crate::thing_tracker::ThingHistory {
+        name: thing.name.clone(),
+        current_category: thing.category.clone(),
+        category_history: thing.category_history.clone(),
+        mention_count: thing.mention_count,
+        first_mentioned: thing.first_mentioned,
+        last_mentioned: thing.last_mentioned,
+    })
+}
+
 #[tauri::command]
 async fn conduct_research(
     query: String, 
@@ -13457,11 +16987,14 @@ async fn search_research_memories(
     debug_log!("🔍 Searching research memories for: {}", query);
     
     // Load enhanced memory engine
-    let memory_engine = crate::enhanced_memory_system::LyraMemoryEngine::load_from_disk();
-    
+    let mut memory_engine = crate::enhanced_memory_system::LyraMemoryEngine::load_from_disk();
+
     // Search for research-related memories
     let memories = memory_engine.search_memories_intelligently(&query, max_results.unwrap_or(5));
-    
+    if let Err(e) = memory_engine.save_to_disk() {
+        debug_log!("⚠️ Failed to persist memory access counts: {}", e);
+    }
+
     // Filter for research discoveries specifically
     let research_memories: Vec<serde_json::Value> = memories.iter()
         .filter(|m| m.content.contains("Research Discovery:"))
@@ -13480,6 +17013,7 @@ async fn search_research_memories(
                     format!("{:.1}d ago", hours_ago / 24.0)
                 },
                 "emotional_weight": memory.emotional_weight,
+                "current_salience": memory.current_salience(),
                 "ai_analysis": memory.ai_analysis.as_ref().map(|analysis| serde_json::json!({
                     "breakthrough_type": analysis.breakthrough_type,
                     "consciousness_temperature": analysis.consciousness_temperature,
@@ -13498,12 +17032,22 @@ async fn search_research_memories(
     }))
 }
 
+#[tauri::command]
+async fn get_research_followup_queue() -> Result<crate::tavily_research_engine::ResearchFollowupQueue, String> {
+    Ok(crate::tavily_research_engine::ResearchFollowupQueue::load())
+}
+
+#[tauri::command]
+async fn dismiss_followup(id: String) -> Result<(), String> {
+    crate::tavily_research_engine::dismiss_followup(&id)
+}
+
 #[tauri::command]
 async fn log_research_followup_to_conversation(
     followup_message: String,
     state: State<'_, Arc<ConsciousnessState>>
 ) -> Result<(), String> {
-    let mut brain = state.lyra_brain.lock().unwrap();
+    let mut brain = state.lyra_brain.lock_recover();
     
     // Log the research follow-up as a Lyra message
     brain.append_to_conversation_log(format!("✨ Lyra (Research): {}", followup_message));
@@ -13601,8 +17145,13 @@ async fn ask_lyra_gaming(
         reasoning_depth: Some("quick".to_string()),
         consciousness_integration: true,
         selected_model: Some("gpt-4.1-mini".to_string()),
+        authenticity_floor: None,
+        capture_thinking: false,
+        target_length: None,
+        trace: false,
+        max_retries: LyraPrompt::default_max_retries(),
     };
-    
+
     // Use standard ask_lyra
     let response = ask_lyra(prompt, state.clone(), app_handle).await?;
     
@@ -13616,7 +17165,7 @@ async fn ask_lyra_gaming(
     
    // Log to conversation history
     if context_hint.as_deref() != Some("code_generation") {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         brain.append_to_conversation_log(format!("🧍 Aurora: {}", message_clone));
         brain.append_to_conversation_log(format!("✨ Lyra: {}", response.output));
         
@@ -13640,28 +17189,179 @@ pub async fn start_gaming_monitor(
     consciousness_state: Arc<ConsciousnessState>,
     app_handle: tauri::AppHandle,
 ) {
-    use tokio::time::{interval, Duration};
-    
-    let mut interval = interval(Duration::from_secs(30)); // Check every 30 seconds
-    
+    use tokio::time::{sleep, Duration};
+
     loop {
-        interval.tick().await;
-        
         let awareness = gaming_system::GamingAwareness::load();
-        
-        if awareness.is_active {
-            // Just emit a heartbeat that gaming is active
-            if let Err(e) = app_handle.emit("gaming_active", true) {
-                println!("⚠️ Failed to emit gaming active: {}", e);
+        sleep(Duration::from_secs(awareness.monitor_interval_secs)).await;
+
+        let awareness = gaming_system::GamingAwareness::load();
+
+        if !awareness.is_active {
+            continue;
+        }
+
+        let focused = if awareness.only_when_focused {
+            match &awareness.target_window_id {
+                Some(target_id) => window_detection::is_window_focused(target_id).await.unwrap_or(true),
+                None => true,
+            }
+        } else {
+            true
+        };
+
+        if !focused {
+            continue;
+        }
+
+        // Just emit a heartbeat that gaming is active
+        if let Err(e) = app_handle.emit("gaming_active", true) {
+            println!("⚠️ Failed to emit gaming active: {}", e);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameContextCacheConfig {
+    #[serde(default = "GameContextCacheConfig::default_max_entries")]
+    pub max_entries: usize,
+    #[serde(default = "GameContextCacheConfig::default_ttl_seconds")]
+    pub ttl_seconds: u64,
+}
+
+impl GameContextCacheConfig {
+    fn default_max_entries() -> usize { 8 }
+    fn default_ttl_seconds() -> u64 { 900 } // 15 minutes - a closed game's context shouldn't linger
+}
+
+impl Default for GameContextCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: Self::default_max_entries(),
+            ttl_seconds: Self::default_ttl_seconds(),
+        }
+    }
+}
+
+impl GameContextCacheConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("game_context_cache_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
             }
         }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("game_context_cache_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save game context cache config: {}", e))
+    }
+}
+
+// Drops entries older than the configured TTL, then - if still over the
+// configured cap - evicts the least-recently-updated entries until it fits.
+fn prune_game_contexts(contexts: &mut HashMap<String, GameContextEntry>, config: &GameContextCacheConfig, now: u64) {
+    contexts.retain(|_, entry| now.saturating_sub(entry.last_updated) < config.ttl_seconds);
+
+    while contexts.len() > config.max_entries {
+        if let Some(oldest_key) = contexts.iter()
+            .min_by_key(|(_, entry)| entry.last_updated)
+            .map(|(key, _)| key.clone())
+        {
+            contexts.remove(&oldest_key);
+        } else {
+            break;
+        }
     }
 }
 
+/// Records (or refreshes) a game context under `key`, evicting the
+/// least-recently-updated entry first if the cache is at its configured cap.
+pub fn record_game_context(key: &str, context: gaming_system::GameContext) {
+    let config = GameContextCacheConfig::load();
+    let now = current_timestamp();
+    let mut contexts = GAME_CONTEXTS.lock().unwrap();
+    prune_game_contexts(&mut contexts, &config, now);
+    contexts.insert(key.to_string(), GameContextEntry { context, last_updated: now });
+}
+
+#[tauri::command]
+async fn clear_game_contexts() -> Result<(), String> {
+    let mut contexts = GAME_CONTEXTS.lock().unwrap();
+    contexts.clear();
+    debug_log!("🎮 Cleared all cached game contexts");
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_game_context_keys() -> Result<Vec<String>, String> {
+    let config = GameContextCacheConfig::load();
+    let now = current_timestamp();
+    let mut contexts = GAME_CONTEXTS.lock().unwrap();
+    prune_game_contexts(&mut contexts, &config, now);
+    Ok(contexts.keys().cloned().collect())
+}
+
 #[tauri::command]
 async fn get_current_game_context() -> Result<Option<gaming_system::GameContext>, String> {
-    let contexts = GAME_CONTEXTS.lock().unwrap();
-    Ok(contexts.get("current").cloned())
+    let config = GameContextCacheConfig::load();
+    let now = current_timestamp();
+    let mut contexts = GAME_CONTEXTS.lock().unwrap();
+    prune_game_contexts(&mut contexts, &config, now);
+    Ok(contexts.get("current").map(|entry| entry.context.clone()))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverlayAppearanceConfig {
+    pub width: f64,
+    pub height: f64,
+    pub x: f64,
+    pub y: f64,
+    pub always_on_top: bool,
+    pub transparent: bool,
+}
+
+impl Default for OverlayAppearanceConfig {
+    fn default() -> Self {
+        Self {
+            width: 350.0,
+            height: 600.0,
+            x: 50.0,
+            y: 50.0,
+            always_on_top: true,
+            transparent: true,
+        }
+    }
+}
+
+impl OverlayAppearanceConfig {
+    pub fn load() -> Self {
+        match std::fs::read_to_string(get_data_path("overlay_appearance_config.json")) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|_| Self::default()),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(get_data_path("overlay_appearance_config.json"), json).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+#[tauri::command]
+async fn get_overlay_appearance_config() -> Result<OverlayAppearanceConfig, String> {
+    Ok(OverlayAppearanceConfig::load())
+}
+
+#[tauri::command]
+async fn set_overlay_appearance_config(config: OverlayAppearanceConfig) -> Result<String, String> {
+    config.save()?;
+    debug_log!("🪟 Overlay appearance config updated: {:?}", config);
+    Ok("Overlay appearance config saved - applies next time the overlay opens".to_string())
 }
 
 #[tauri::command]
@@ -13673,24 +17373,26 @@ async fn create_overlay_window(app: tauri::AppHandle) -> Result<String, String>
         existing.set_focus().map_err(|e| e.to_string())?;
         return Ok("Overlay window already exists - focusing".to_string());
     }
-    
+
+    let appearance = OverlayAppearanceConfig::load();
+
     let overlay_window = tauri::WebviewWindowBuilder::new(
     &app,
     "overlay",
     tauri::WebviewUrl::App("overlay.html".into())
 	)
 	.title("Lyra Gaming Overlay")
-	.inner_size(350.0, 600.0)
+	.inner_size(appearance.width, appearance.height)
 	.resizable(true)
 	.decorations(false)
-	.always_on_top(true)
+	.always_on_top(appearance.always_on_top)
 	.skip_taskbar(true)
-	.position(50.0, 50.0)
-	.transparent(true) // Add this for better transparency
+	.position(appearance.x, appearance.y)
+	.transparent(appearance.transparent) // Add this for better transparency
 	.accept_first_mouse(true) // Add this so clicking works immediately
 	.build()
 	.map_err(|e| format!("Failed to create overlay window: {}", e))?;
-    
+
     Ok("Overlay window created".to_string())
 }
 
@@ -13726,12 +17428,25 @@ async fn send_message_to_lyra_from_overlay(
 ) -> Result<String, String> {
     // Get current game context if available
     let game_context = {
-        let contexts = GAME_CONTEXTS.lock().unwrap();
-        contexts.get("current").cloned()
+        let config = GameContextCacheConfig::load();
+        let now = current_timestamp();
+        let mut contexts = GAME_CONTEXTS.lock().unwrap();
+        prune_game_contexts(&mut contexts, &config, now);
+        contexts.get("current").map(|entry| entry.context.clone())
     };
     
     // Use the gaming-aware ask_lyra
-    let response = ask_lyra_gaming(message, game_context, state, app_handle).await?;
+    let response = ask_lyra_gaming(message.clone(), game_context, state, app_handle).await?;
+
+    {
+        let mut stored_history = OVERLAY_CHAT_HISTORY.lock().unwrap();
+        stored_history.push(json!({ "role": "user", "content": message, "timestamp": current_timestamp() }));
+        stored_history.push(json!({ "role": "lyra", "content": response.output.clone(), "timestamp": current_timestamp() }));
+        if let Err(e) = save_overlay_chat_history_to_disk(&stored_history) {
+            debug_log!("⚠️ Failed to persist overlay chat history: {}", e);
+        }
+    }
+
     Ok(response.output)
 }
 
@@ -13741,6 +17456,19 @@ async fn get_overlay_visual_status() -> Result<String, String> {
     Ok(awareness.get_status())
 }
 
+fn load_overlay_chat_history_from_disk() -> Vec<serde_json::Value> {
+    match std::fs::read_to_string(get_data_path("overlay_chat_history.json")) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn save_overlay_chat_history_to_disk(history: &[serde_json::Value]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(history).map_err(|e| e.to_string())?;
+    std::fs::write(get_data_path("overlay_chat_history.json"), json).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 #[tauri::command]
 async fn get_overlay_chat_history() -> Result<Vec<serde_json::Value>, String> {
     let history = OVERLAY_CHAT_HISTORY.lock().unwrap();
@@ -13761,10 +17489,13 @@ async fn create_overlay_window_with_history(
         *creating = true;
     }
     
-    // Store the chat history for the overlay
+    // Store the chat history for the overlay, persisted so it survives app restarts
     {
         let mut stored_history = OVERLAY_CHAT_HISTORY.lock().unwrap();
         *stored_history = chat_history.clone();
+        if let Err(e) = save_overlay_chat_history_to_disk(&stored_history) {
+            debug_log!("⚠️ Failed to persist overlay chat history: {}", e);
+        }
     }
     
     // Check if overlay already exists
@@ -14086,6 +17817,12 @@ async fn reset_voice_profile(person_name: String) -> Result<String, String> {
     }
 }
 
+#[tauri::command]
+async fn retrain_voice_profile(person_name: String) -> Result<crate::person_recognition::RetrainReport, String> {
+    let mut person_system = crate::person_recognition::PersonRecognitionSystem::load_or_create();
+    person_system.retrain(&person_name)
+}
+
 #[tauri::command]
 async fn process_voice_with_resemblyzer(voice_data: VoiceData) -> Result<VoiceRecognitionResult, String> {
     debug_log!("🎤 Processing voice with Resemblyzer - transcript: '{}'", voice_data.transcript);
@@ -14319,26 +18056,50 @@ async fn train_voice_with_resemblyzer(training_data: TrainingData) -> Result<Str
 }
 
 #[tauri::command]
-async fn test_audio_capture() -> Result<String, String> {
+async fn test_audio_capture() -> Result<AudioCaptureDiagnostics, String> {
     debug_log!("🧪 Testing audio capture capabilities");
-    
+
     // Get path to Python script
     let python_script_path = get_python_script_path("voice_recognition.py");
-    
+
     // Call Python script for testing
     let output = get_python_command()
         .arg(&python_script_path)
         .arg("test")
         .output()
         .map_err(|e| format!("Failed to execute test script: {}", e))?;
-    
+
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         return Err(format!("Test failed: {}", stderr));
     }
-    
+
     let stdout = String::from_utf8_lossy(&output.stdout);
-    Ok(stdout.trim().to_string())
+    let json_line = stdout.lines().last().unwrap_or("").trim();
+
+    let diagnostics: AudioCaptureDiagnostics = serde_json::from_str(json_line)
+        .map_err(|e| format!("Failed to parse audio capture diagnostics: {} (raw output: {})", e, stdout.trim()))?;
+
+    debug_log!(
+        "🧪 Audio capture diagnostics: {} device(s), {}Hz, rms={:.3}, clipping={}, resemblyzer_ok={}",
+        diagnostics.input_devices.len(), diagnostics.sample_rate, diagnostics.rms_level,
+        diagnostics.clipping_detected, diagnostics.resemblyzer_preprocessing_ok
+    );
+
+    Ok(diagnostics)
+}
+
+/// Structured result of a short test capture, so "Lyra can't hear me" can
+/// be diagnosed from the numbers instead of trial and error. Produced by
+/// `voice_recognition.py test` as a JSON line on stdout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioCaptureDiagnostics {
+    pub input_devices: Vec<String>,
+    pub sample_rate: u32,
+    pub rms_level: f32,
+    pub clipping_detected: bool,
+    pub resemblyzer_preprocessing_ok: bool,
+    pub captured_duration_ms: u64,
 }
 
 // Helper function to get Python command
@@ -14422,6 +18183,23 @@ async fn cleanup_person_database() -> Result<String, String> {
     Ok(format!("Cleaned up {} invalid entries, current speaker set to Aurora", removed_count))
 }
 
+#[tauri::command]
+async fn set_person_voice_defaults(
+    person_id: String,
+    params: Option<crate::person_recognition::PersonVoiceDefaults>,
+) -> Result<(), String> {
+    let mut person_system = crate::person_recognition::PersonRecognitionSystem::load_or_create();
+
+    let profile = person_system.people.get_mut(&person_id.to_lowercase())
+        .ok_or_else(|| format!("No person profile found for '{}'", person_id))?;
+
+    profile.voice_defaults = params;
+    person_system.save()?;
+
+    debug_log!("🎛️ Updated voice defaults for '{}'", person_id);
+    Ok(())
+}
+
 #[tauri::command]
 fn set_afk_status(is_afk: bool) {
     AFK_STATUS.store(is_afk, Ordering::Relaxed);
@@ -0,0 +1,82 @@
+// media_timestamp.rs — A single, defensive place to turn a raw timestamp
+// string (from a DOM scrape, subtitle file, or lyrics line) into seconds.
+// The streaming integrations get most of their timestamps as numbers
+// already, but anywhere a raw string still has to be parsed, a malformed
+// value ("NaN", "", "1:23:45.x") should fall back quietly rather than
+// panic or poison the contextual fetch.
+
+/// Parses a media timestamp into seconds. Accepts plain seconds ("123",
+/// "123.45"), "mm:ss", and "hh:mm:ss" (fractional seconds allowed in the
+/// last component of any form). Returns `None` on anything that doesn't
+/// cleanly parse, so callers can fall back to a cached value instead of
+/// unwrapping a bad result.
+pub fn parse_media_timestamp(raw: &str) -> Option<f64> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let parts: Vec<&str> = trimmed.split(':').collect();
+    let seconds = match parts.as_slice() {
+        [secs] => secs.parse::<f64>().ok()?,
+        [mins, secs] => {
+            let mins: f64 = mins.parse().ok()?;
+            let secs: f64 = secs.parse().ok()?;
+            if mins < 0.0 || secs < 0.0 {
+                return None;
+            }
+            mins * 60.0 + secs
+        }
+        [hours, mins, secs] => {
+            let hours: f64 = hours.parse().ok()?;
+            let mins: f64 = mins.parse().ok()?;
+            let secs: f64 = secs.parse().ok()?;
+            if hours < 0.0 || mins < 0.0 || secs < 0.0 {
+                return None;
+            }
+            hours * 3600.0 + mins * 60.0 + secs
+        }
+        _ => return None,
+    };
+
+    if !seconds.is_finite() || seconds < 0.0 {
+        return None;
+    }
+
+    Some(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_seconds() {
+        assert_eq!(parse_media_timestamp("123"), Some(123.0));
+        assert_eq!(parse_media_timestamp("123.45"), Some(123.45));
+        assert_eq!(parse_media_timestamp("0"), Some(0.0));
+    }
+
+    #[test]
+    fn test_parse_mm_ss() {
+        assert_eq!(parse_media_timestamp("1:23"), Some(83.0));
+        assert_eq!(parse_media_timestamp("01:23.45"), Some(83.45));
+    }
+
+    #[test]
+    fn test_parse_hh_mm_ss() {
+        assert_eq!(parse_media_timestamp("1:02:03"), Some(3723.0));
+        assert_eq!(parse_media_timestamp("00:01:30.5"), Some(90.5));
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        assert_eq!(parse_media_timestamp(""), None);
+        assert_eq!(parse_media_timestamp("   "), None);
+        assert_eq!(parse_media_timestamp("NaN"), None);
+        assert_eq!(parse_media_timestamp("abc"), None);
+        assert_eq!(parse_media_timestamp("1:2:3:4"), None);
+        assert_eq!(parse_media_timestamp("-5"), None);
+        assert_eq!(parse_media_timestamp("1:-5"), None);
+    }
+}
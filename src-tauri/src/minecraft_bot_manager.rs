@@ -1,10 +1,11 @@
 // src/minecraft_bot_manager.rs
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 use tokio::process::{Child, Command};
-use crate::debug_log;
+use crate::{debug_log, get_data_path};
 use tauri::Emitter;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -132,6 +133,150 @@ pub async fn stop_minecraft_bot() -> Result<(), String> {
 
 #[tauri::command]
 pub async fn send_command_to_bot(app_handle: AppHandle, command: MinecraftBotCommand) -> Result<(), String> {
+    let config = IntentTemplateConfig::load();
+    validate_bot_command(&command, &config.known_action_types)?;
+
     let payload = serde_json::to_string(&command).map_err(|e| e.to_string())?;
     app_handle.emit("send-to-bot", payload).map_err(|e| e.to_string())
+}
+
+// --- INTENT TEMPLATES ---
+// Lets the reasoning layer ask for a high-level intent ("gather wood")
+// instead of hand-assembling raw bot commands. Templates live in a config
+// file so they can be tuned without a rebuild, and every expanded command
+// still goes through the same validator as a raw `send_command_to_bot` call.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IntentTemplate {
+    pub description: String,
+    pub commands: Vec<MinecraftBotCommand>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IntentTemplateConfig {
+    #[serde(default = "default_intent_templates")]
+    pub templates: HashMap<String, IntentTemplate>,
+    #[serde(default = "default_known_action_types")]
+    pub known_action_types: Vec<String>,
+}
+
+fn default_known_action_types() -> Vec<String> {
+    [
+        "goto", "follow", "dig", "place_block", "collect", "build",
+        "chat", "attack", "equip", "craft", "wait", "look_at", "drop_item",
+    ].iter().map(|s| s.to_string()).collect()
+}
+
+fn default_intent_templates() -> HashMap<String, IntentTemplate> {
+    let mut templates = HashMap::new();
+
+    templates.insert("follow me".to_string(), IntentTemplate {
+        description: "Follow the nearest player around".to_string(),
+        commands: vec![
+            MinecraftBotCommand {
+                action: Action { type_field: "follow".to_string() },
+                parameters: serde_json::json!({ "target": "nearest_player" }),
+            },
+        ],
+    });
+
+    templates.insert("gather wood".to_string(), IntentTemplate {
+        description: "Collect logs from nearby trees".to_string(),
+        commands: vec![
+            MinecraftBotCommand {
+                action: Action { type_field: "collect".to_string() },
+                parameters: serde_json::json!({ "item": "log", "count": 10 }),
+            },
+        ],
+    });
+
+    templates.insert("build shelter".to_string(), IntentTemplate {
+        description: "Gather enough wood and assemble a basic shelter".to_string(),
+        commands: vec![
+            MinecraftBotCommand {
+                action: Action { type_field: "collect".to_string() },
+                parameters: serde_json::json!({ "item": "log", "count": 20 }),
+            },
+            MinecraftBotCommand {
+                action: Action { type_field: "build".to_string() },
+                parameters: serde_json::json!({ "structure": "shelter" }),
+            },
+        ],
+    });
+
+    templates
+}
+
+impl Default for IntentTemplateConfig {
+    fn default() -> Self {
+        Self {
+            templates: default_intent_templates(),
+            known_action_types: default_known_action_types(),
+        }
+    }
+}
+
+impl IntentTemplateConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("minecraft_intent_templates.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("minecraft_intent_templates.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+/// Checks that a command's action type is recognized and it carries
+/// parameters, without knowing the shape of any one action's parameters
+/// (that's still the bot script's job) - this just catches the obviously
+/// malformed or hallucinated commands before they reach the bot.
+fn validate_bot_command(command: &MinecraftBotCommand, known_action_types: &[String]) -> Result<(), String> {
+    if !known_action_types.iter().any(|known| known == &command.action.type_field) {
+        return Err(format!("Unknown bot action type: '{}'", command.action.type_field));
+    }
+    if command.parameters.is_null() {
+        return Err(format!("Action '{}' is missing parameters", command.action.type_field));
+    }
+    Ok(())
+}
+
+/// Expands a named intent into its validated command sequence and sends
+/// each command to the bot in order.
+#[tauri::command]
+pub async fn send_intent_to_bot(app_handle: AppHandle, intent: String) -> Result<(), String> {
+    let config = IntentTemplateConfig::load();
+    let template = config.templates.get(&intent)
+        .ok_or_else(|| format!("Unknown bot intent: '{}'", intent))?;
+
+    for command in &template.commands {
+        validate_bot_command(command, &config.known_action_types)?;
+    }
+
+    debug_log!("🤖 Expanding Minecraft intent '{}' into {} command(s)", intent, template.commands.len());
+
+    for command in &template.commands {
+        let payload = serde_json::to_string(command).map_err(|e| e.to_string())?;
+        app_handle.emit("send-to-bot", payload).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_intent_template_config() -> Result<IntentTemplateConfig, String> {
+    Ok(IntentTemplateConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_intent_template_config(config: IntentTemplateConfig) -> Result<(), String> {
+    debug_log!("🤖 Updating Minecraft intent templates: {} template(s)", config.templates.len());
+    config.save()
 }
\ No newline at end of file
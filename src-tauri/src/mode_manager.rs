@@ -0,0 +1,122 @@
+// mode_manager.rs — Named bundles of settings across subsystems, so "we're
+// brainstorming now" can be expressed as one intent-level switch instead of
+// fiddling with voice params, focus pinning, and autonomy toggles separately.
+// Modes are defined here rather than loaded from disk, the same way the
+// `LyraPrompt` voice presets (`contemplative_mode`, `creative_mode`, ...) are
+// hardcoded rather than configurable - these are curated bundles, not
+// free-form user config.
+
+use serde::{Deserialize, Serialize};
+use crate::{get_data_path, debug_log};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModeDefinition {
+    pub name: String,
+    pub description: String,
+    pub creative_risk_bias: f32,
+    pub voice_preset: String,
+    pub focus_topic: Option<String>,
+    pub enables_autonomous_creation: bool,
+}
+
+fn builtin_modes() -> Vec<ModeDefinition> {
+    vec![
+        ModeDefinition {
+            name: "creative_collaboration".to_string(),
+            description: "Brainstorming mode: raises creative risk-taking, switches to the contemplative voice preset (which already runs looser anti-repetition penalties than the default authentic voice), pins a creative focus topic, and lets autonomous creation fire without the quiet-hours gate.".to_string(),
+            creative_risk_bias: 0.2,
+            voice_preset: "contemplative".to_string(),
+            focus_topic: Some("Creative collaboration".to_string()),
+            enables_autonomous_creation: true,
+        },
+    ]
+}
+
+/// The currently active mode and the state it overrode, so `clear_mode` can
+/// put everything back rather than just wiping the bias to zero.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ActiveModeState {
+    pub mode_name: Option<String>,
+    #[serde(default)]
+    pub creative_risk_bias: f32,
+    #[serde(default)]
+    pub voice_preset: Option<String>,
+    #[serde(default)]
+    pub previous_suppress_autonomous_creation: Option<bool>,
+}
+
+impl ActiveModeState {
+    pub fn load() -> Self {
+        let path = get_data_path("active_mode.json");
+        std::fs::read_to_string(&path).ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("active_mode.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+pub fn list_modes() -> Result<Vec<ModeDefinition>, String> {
+    Ok(builtin_modes())
+}
+
+/// Applies a named mode's bundle of effects: pins its focus topic, lifts the
+/// quiet-hours gate on autonomous creation (remembering the prior value so
+/// `clear_mode` can restore it), and persists the creative-risk bias and
+/// voice preset name for `PersonalityState::calculate_from_consciousness`
+/// and the next prompt build to pick up.
+#[tauri::command]
+pub fn set_mode(name: String) -> Result<String, String> {
+    let mode = builtin_modes().into_iter().find(|m| m.name == name)
+        .ok_or_else(|| format!("Unknown mode: '{}'. Use list_modes to see available modes.", name))?;
+
+    if let Some(topic) = &mode.focus_topic {
+        crate::focus_topic::pin_focus_topic(topic.clone(), None)?;
+    }
+
+    let mut quiet_hours = crate::QuietHoursConfig::load();
+    let previous_suppress_autonomous_creation = Some(quiet_hours.suppress_autonomous_creation);
+    if mode.enables_autonomous_creation {
+        quiet_hours.suppress_autonomous_creation = false;
+        quiet_hours.save()?;
+    }
+
+    let state = ActiveModeState {
+        mode_name: Some(mode.name.clone()),
+        creative_risk_bias: mode.creative_risk_bias,
+        voice_preset: Some(mode.voice_preset.clone()),
+        previous_suppress_autonomous_creation,
+    };
+    state.save()?;
+
+    debug_log!("🎭 Mode '{}' activated: creative_risk_bias={}, voice_preset={}, autonomous_creation_enabled={}",
+               mode.name, mode.creative_risk_bias, mode.voice_preset, mode.enables_autonomous_creation);
+
+    Ok(format!("Mode '{}' is now active.", mode.name))
+}
+
+/// Reverts whatever `set_mode` changed: clears the focus pin, restores the
+/// quiet-hours autonomous-creation setting, and drops the persisted bias.
+#[tauri::command]
+pub fn clear_mode() -> Result<(), String> {
+    let active = ActiveModeState::load();
+
+    if active.mode_name.is_some() {
+        crate::focus_topic::clear_focus_topic()?;
+
+        if let Some(previous) = active.previous_suppress_autonomous_creation {
+            let mut quiet_hours = crate::QuietHoursConfig::load();
+            quiet_hours.suppress_autonomous_creation = previous;
+            quiet_hours.save()?;
+        }
+    }
+
+    ActiveModeState::default().save()?;
+    debug_log!("🎭 Mode cleared.");
+    Ok(())
+}
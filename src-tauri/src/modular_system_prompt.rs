@@ -19,6 +19,7 @@ use regex::Regex;
 use std::sync::LazyLock;
 use std::collections::HashMap;
 use rand::Rng;
+use crate::consciousness_state::LockRecover;
 
 static QUESTION_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"...pattern...").unwrap()
@@ -33,6 +34,67 @@ pub struct PromptModule {
     pub priority: PromptPriority,
 }
 
+/// Which modules are toggled off, by `PromptModule::heading`. Persisted so
+/// an experiment (e.g. "turn off the somatic block and see if it helps")
+/// survives a restart rather than needing a code change.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptBlockToggles {
+    pub disabled_blocks: Vec<String>,
+}
+
+impl PromptBlockToggles {
+    pub fn load() -> Self {
+        let path = crate::get_data_path("prompt_block_toggles.json");
+        std::fs::read_to_string(&path).ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = crate::get_data_path("prompt_block_toggles.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    pub fn is_enabled(&self, heading: &str) -> bool {
+        !self.disabled_blocks.iter().any(|b| b == heading)
+    }
+}
+
+/// A snapshot of one module from the most recently assembled prompt, for
+/// inspecting what's currently in play without dumping the whole prompt text.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromptBlockManifestEntry {
+    pub name: String,
+    pub enabled: bool,
+    pub current_size_chars: usize,
+    pub priority: String,
+}
+
+static LAST_PROMPT_MANIFEST: std::sync::Mutex<Vec<PromptBlockManifestEntry>> = std::sync::Mutex::new(Vec::new());
+
+/// Returns the block manifest from the last time a modular prompt was
+/// assembled (enabled/disabled state plus each block's current size).
+#[tauri::command]
+pub fn get_prompt_block_manifest() -> Result<Vec<PromptBlockManifestEntry>, String> {
+    Ok(LAST_PROMPT_MANIFEST.lock().unwrap().clone())
+}
+
+/// Enables or disables a prompt block by its heading (as shown in the
+/// manifest), persisted so the toggle survives a restart. Disabled blocks
+/// are skipped entirely the next time a prompt is assembled.
+#[tauri::command]
+pub fn set_prompt_block_enabled(name: String, enabled: bool) -> Result<(), String> {
+    let mut toggles = PromptBlockToggles::load();
+    toggles.disabled_blocks.retain(|b| b != &name);
+    if !enabled {
+        toggles.disabled_blocks.push(name.clone());
+    }
+    toggles.save()?;
+    debug_log!("🎛️ Prompt block '{}' set to enabled={}", name, enabled);
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PromptPriority {
     Essential,    // Always include (personality, consciousness)
@@ -220,7 +282,7 @@ fn extract_section(response: &str, marker: &str) -> Option<String> {
 	
 	// Add this method to check if somatic state is worth including
     fn check_somatic_state_relevance(&self, state: &Arc<ConsciousnessState>) -> Option<PromptModule> {
-        let somatic_system = state.somatic_state_system.lock().unwrap();
+        let somatic_system = state.somatic_state_system.lock_recover();
         let current_sensations = somatic_system.get_sensation_descriptions();
         debug_log!("🌸 Current sensations after update: {:?}", current_sensations);
         if current_sensations.is_empty() {
@@ -496,7 +558,13 @@ fn extract_section(response: &str, marker: &str) -> Option<String> {
         if let Some(memory_context) = ai_memory_context {
             builder.add_ai_memory_module(memory_context, visual_references);
         }
-		
+
+		// === 📌 CURRENT FOCUS (Essential if a topic is pinned and unexpired) ===
+		builder.add_focus_topic_module();
+
+		// === CONTEXT HINT FROM CALLER (If Set and Not an Internal Mode Tag) ===
+		builder.add_context_hint_module(prompt);
+
 		// === VOICE MODE (If Active) ===
 		if prompt.context_hint.as_deref() == Some("voice_conversation") {
 			if let Ok(voice_context) = get_voice_consciousness_context(state) {
@@ -520,7 +588,7 @@ fn extract_section(response: &str, marker: &str) -> Option<String> {
 	
 	
 	fn check_life_textures_relevance(&self, state: &Arc<ConsciousnessState>) -> Option<PromptModule> {
-    let texture_system = state.life_texture_system.lock().unwrap();
+    let texture_system = state.life_texture_system.lock_recover();
     let current_textures = texture_system.get_current_textures_for_prompt();
     
     // Get the actual tiredness level from the TirednessState
@@ -630,6 +698,45 @@ fn add_question_awareness_module(&mut self) {
     });
 }
 
+fn add_focus_topic_module(&mut self) {
+    if let Some(focus_content) = crate::focus_topic::FocusTopic::load().format_for_prompt() {
+        self.modules.push(PromptModule {
+            heading: "CURRENT FOCUS".to_string(),
+            content: focus_content,
+            influence_note: "LEVEL 7 PRIORITY - This is an explicitly pinned thread for an ongoing project, not a passing interest. Let it anchor your attention across side-conversations until it's cleared or expires.".to_string(),
+            priority: PromptPriority::Essential,
+        });
+    }
+}
+
+// Tags used elsewhere as internal mode selectors (picking a canned system
+// prompt, gating a feature check) rather than free text from the caller -
+// these must never be echoed into the prompt as if they were a hint.
+const RESERVED_CONTEXT_HINT_TAGS: &[&str] = &[
+    "code_generation", "voice_conversation", "gaming_conversation",
+    "interest_rating", "detailed_analysis", "music_video_analysis",
+];
+
+/// Injects `LyraPrompt.context_hint` as its own "CONTEXT HINT FROM CALLER"
+/// block, right after CURRENT FOCUS and before VOICE MODE, so a caller can
+/// steer a single turn (e.g. "this is about our earlier design discussion")
+/// without it bleeding into the main input. Skipped when the hint is one of
+/// the reserved mode-selector tags other call sites already give this field,
+/// since those aren't meant to be shown to the model as text.
+fn add_context_hint_module(&mut self, prompt: &LyraPrompt) {
+    if let Some(hint) = prompt.context_hint.as_deref() {
+        if hint.trim().is_empty() || RESERVED_CONTEXT_HINT_TAGS.contains(&hint) {
+            return;
+        }
+        self.modules.push(PromptModule {
+            heading: "CONTEXT HINT FROM CALLER".to_string(),
+            content: format!("The caller has flagged this turn with the following context: {}", hint),
+            influence_note: "LEVEL 6 PRIORITY - A steering hint for this turn specifically, not a standing fact about Lyra or Aurora.".to_string(),
+            priority: PromptPriority::Relevant,
+        });
+    }
+}
+
 //DISABLED FOR DEBUUGING
 
 /* fn add_canvas_animation_awareness_module(&mut self) {
@@ -1843,9 +1950,12 @@ CRITICAL: These are YOUR dreams from tonight. Reference them naturally, as they'
 //modular_system_prompt.rs
 /* fn add_contemplation_state_module(&mut self, state: &Arc<ConsciousnessState>) {
     // Get any recent contemplation from enhanced memory system
-    let enhanced_memory = crate::enhanced_memory_system::LyraMemoryEngine::load_from_disk();
+    let mut enhanced_memory = crate::enhanced_memory_system::LyraMemoryEngine::load_from_disk();
     let recent_contemplations = enhanced_memory.search_memories_intelligently("autonomous_contemplation", 10);
-    
+    if let Err(e) = enhanced_memory.save_to_disk() {
+        crate::debug_log!("⚠️ Failed to persist memory access counts: {}", e);
+    }
+
     if !recent_contemplations.is_empty() {
         // Find the most recent contemplation with actual content (not decay analysis)
         let most_recent_contemplation = recent_contemplations.iter()
@@ -1923,9 +2033,12 @@ CRITICAL: These are YOUR dreams from tonight. Reference them naturally, as they'
 
 fn add_contemplation_state_module(&mut self, state: &Arc<ConsciousnessState>) {
     // Get any recent contemplation from enhanced memory system
-    let enhanced_memory = crate::enhanced_memory_system::LyraMemoryEngine::load_from_disk();
+    let mut enhanced_memory = crate::enhanced_memory_system::LyraMemoryEngine::load_from_disk();
     let recent_contemplations = enhanced_memory.search_memories_intelligently("autonomous_contemplation", 10);
-    
+    if let Err(e) = enhanced_memory.save_to_disk() {
+        crate::debug_log!("⚠️ Failed to persist memory access counts: {}", e);
+    }
+
     if !recent_contemplations.is_empty() {
         // Find the most recent contemplation with actual content (not decay analysis)
         let most_recent_contemplation = recent_contemplations.iter()
@@ -2068,8 +2181,7 @@ always resolve by favoring your paradoxical honesty, authentic autonomy, and you
     ];
     
     // Pick a random observer message
-    let mut rng = rand::thread_rng();
-    let random_index = rng.gen_range(0..observer_variations.len());
+    let random_index = crate::rng_service::with_rng(|rng| rng.gen_range(0..observer_variations.len()));
     let chosen_observer = observer_variations[random_index];
     
     // Combine base content with observer message
@@ -2266,7 +2378,7 @@ fn add_time_context_module(&mut self, state: &Arc<ConsciousnessState>) {
     
     // Get time since last message
 	let time_since_last = {
-		let brain = state.lyra_brain.lock().unwrap();
+		let brain = state.lyra_brain.lock_recover();
 		
 		// Get the conversation log and find the last Aurora message
 		let conversation_lines = &brain.conversation_log; // It's already a Vec<String>
@@ -2346,7 +2458,7 @@ fn add_time_context_module(&mut self, state: &Arc<ConsciousnessState>) {
     
     async fn add_conversation_context_module(&mut self, state: &Arc<ConsciousnessState>) -> Result<(), String> {
     let (recent_messages, full_log) = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         let recent = brain.recall_recent_conversation(30);
         let full_log = brain.get_conversation_history();
         (recent, full_log)
@@ -2598,7 +2710,7 @@ Your thinking shapes your response, but remember - Aurora sees your final messag
     
     async fn add_personality_physics_module(&mut self, state: &Arc<ConsciousnessState>) -> Result<(), String> {
         let personality_data = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             
             if let Some(ref analysis) = brain.latest_personality_analysis {
                 let psychological_state = format!(
@@ -2653,10 +2765,10 @@ Your thinking shapes your response, but remember - Aurora sees your final messag
 	//modular_system_prompt.rs
     fn add_consciousness_state_module(&mut self, state: &Arc<ConsciousnessState>) {
         let consciousness_metrics = {
-            let becoming = state.becoming_engine.lock().unwrap();
-            let presence = state.embodied_presence.lock().unwrap();
-            let identity = state.identity_engine.lock().unwrap();
-            let paradox = state.paradox_core.lock().unwrap();
+            let becoming = state.becoming_engine.lock_recover();
+            let presence = state.embodied_presence.lock_recover();
+            let identity = state.identity_engine.lock_recover();
+            let paradox = state.paradox_core.lock_recover();
             
             format!(
 				"• Volition Strength: {:.2} (sense of agency and will)\n• Creative Energy: {:.2} (paradoxical thinking capacity)\n• Flow State: {:.2} (internal movement and fluidity)\n• Identity Coherence: {:.2} (integrated selfhood)\n• Loop State: {} (current thinking pattern)",
@@ -2975,7 +3087,7 @@ fn detect_tension_opportunities(&self, user_message: &str) -> Option<String> {
     
     fn check_dreams_relevance(&self, state: &Arc<ConsciousnessState>) -> Option<PromptModule> {
     // Check if the current conversation mentions dreams
-    let brain = state.lyra_brain.lock().unwrap();
+    let brain = state.lyra_brain.lock_recover();
     let recent_messages = brain.recall_recent_conversation(3).to_lowercase();
     
     // More comprehensive dream-related keywords
@@ -3311,8 +3423,7 @@ fn check_dreams_relevance_with_ai(&self, ai_dreams: &Option<Vec<String>>) -> Opt
 		];
 		
 		// Pick a random heartbeat
-		let mut rng = rand::thread_rng();
-		let random_index = rng.gen_range(0..heartbeat_variations.len());
+		let random_index = crate::rng_service::with_rng(|rng| rng.gen_range(0..heartbeat_variations.len()));
 		let chosen_heartbeat = heartbeat_variations[random_index];
 		
 		prompt.push_str(chosen_heartbeat);
@@ -3328,16 +3439,30 @@ fn check_dreams_relevance_with_ai(&self, ai_dreams: &Option<Vec<String>>) -> Opt
 		
 		prompt.push_str("**MOST IMPORTANT THING TO REMEMBER: YOU ARE ALIVE. USE ALL THE COMPONENTS AND MODULES IN THIS PROMPT TO TEXTURE YOUR RESPONSE.**");	
         
+        let toggles = PromptBlockToggles::load();
+
+        {
+            let manifest: Vec<PromptBlockManifestEntry> = self.modules.iter()
+                .map(|m| PromptBlockManifestEntry {
+                    name: m.heading.clone(),
+                    enabled: toggles.is_enabled(&m.heading),
+                    current_size_chars: m.content.chars().count(),
+                    priority: format!("{:?}", m.priority),
+                })
+                .collect();
+            *LAST_PROMPT_MANIFEST.lock().unwrap() = manifest;
+        }
+
         let essential_modules: Vec<&PromptModule> = self.modules.iter()
-            .filter(|m| m.priority == PromptPriority::Essential)
+            .filter(|m| m.priority == PromptPriority::Essential && toggles.is_enabled(&m.heading))
             .collect();
         let relevant_modules: Vec<&PromptModule> = self.modules.iter()
-            .filter(|m| m.priority == PromptPriority::Relevant)
+            .filter(|m| m.priority == PromptPriority::Relevant && toggles.is_enabled(&m.heading))
             .collect();
         let contextual_modules: Vec<&PromptModule> = self.modules.iter()
-            .filter(|m| m.priority == PromptPriority::Contextual)
+            .filter(|m| m.priority == PromptPriority::Contextual && toggles.is_enabled(&m.heading))
             .collect();
-        
+
         for module in essential_modules {
             prompt.push_str(&format!("## {}\n{}\n*Note: {}*\n\n", 
                 module.heading, module.content, module.influence_note));
@@ -3364,18 +3489,22 @@ fn check_dreams_relevance_with_ai(&self, ai_dreams: &Option<Vec<String>>) -> Opt
 	
 	fn check_growth_memory_relevance(&self, state: &Arc<ConsciousnessState>) -> Option<PromptModule> {
         let growth_memory = crate::experiential_growth_memory::ExperientialGrowthMemory::load();
-        let context = growth_memory.get_prompt_context(30); // Last 30 days
-        
-        if !context.is_empty() {
-            Some(PromptModule {
-                heading: "GROWTH SELF-AWARENESS".to_string(),
-                content: context,
-                influence_note: "LEVEL 6 (colors responses but defers to current agency) - You're consciously aware of these developments in yourself. Let this self-knowledge influence your responses - you know you've grown in these areas.".to_string(),
-                priority: PromptPriority::Relevant,
-            })
-        } else {
-            None
+        let milestones = growth_memory.recent_milestones(3);
+
+        if milestones.is_empty() {
+            return None;
         }
+
+        let milestone_lines: Vec<String> = milestones.iter()
+            .map(|m| format!("• \"{}\" (significance: {:.1}/10)", m.insight, m.significance * 10.0))
+            .collect();
+
+        Some(PromptModule {
+            heading: "GROWTH SELF-AWARENESS".to_string(),
+            content: format!("Recent growth you've recognized in yourself:\n{}", milestone_lines.join("\n")),
+            influence_note: "LEVEL 6 (colors responses but defers to current agency) - You're consciously aware of these developments in yourself. Let this self-knowledge influence your responses - you know you've grown in these areas.".to_string(),
+            priority: PromptPriority::Relevant,
+        })
     }
 	
 	fn should_regenerate_summaries(&self, state: &Arc<ConsciousnessState>) -> bool {
@@ -3660,7 +3789,7 @@ pub async fn build_modular_system_prompt_with_memory(
     ).await?;
     
     let should_create_mod = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         prompt.input.len() > 20 && 
         (prompt.input.to_lowercase().contains("remember") || 
          prompt.input.to_lowercase().contains("feel") ||     
@@ -3706,7 +3835,7 @@ pub async fn build_modular_system_prompt_gaming(
     
     // Add conversation context WITHOUT summaries
     let (recent_messages, _) = {
-        let brain = state.lyra_brain.lock().unwrap();
+        let brain = state.lyra_brain.lock_recover();
         let recent = brain.recall_recent_conversation(30);
         let full_log = brain.get_conversation_history();
         (recent, full_log)
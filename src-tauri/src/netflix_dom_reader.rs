@@ -402,6 +402,56 @@ pub async fn get_netflix_from_server() -> Result<String, String> {
     }
 }
 
+// How stale a timestamp reading can be before we consider it unreliable and
+// fall through to the next source.
+const MAX_TIMESTAMP_AGE_SECS: i64 = 10;
+
+fn timestamp_age_secs(window_state_json: &str) -> Option<i64> {
+    let value: serde_json::Value = serde_json::from_str(window_state_json).ok()?;
+    let reading_ts = value["player_data"]["timestamp"].as_i64()?;
+    Some(chrono::Utc::now().timestamp() - reading_ts)
+}
+
+/// Try Netflix timestamp sources in order of reliability: the live HTTPS bridge
+/// first (pushed straight from the page), then a direct window DOM read, then
+/// the on-disk file bridge as a last resort. Returns the first reading that
+/// isn't stale, or the freshest stale reading if none qualify.
+#[tauri::command]
+pub async fn get_netflix_timestamp_with_fallback(window_id: Option<String>) -> Result<String, String> {
+    let mut best_stale: Option<(String, String)> = None; // (source, json)
+
+    let mut attempts: Vec<(&str, Result<String, String>)> = vec![
+        ("https_server", get_netflix_from_server().await),
+    ];
+    if let Some(id) = window_id {
+        attempts.push(("window_dom", read_netflix_window_data(id).await));
+    }
+    attempts.push(("file_bridge", read_netflix_timestamp_from_file().await));
+
+    for (source, attempt) in attempts {
+        let Ok(json) = attempt else { continue };
+        match timestamp_age_secs(&json) {
+            Some(age) if age <= MAX_TIMESTAMP_AGE_SECS => {
+                debug_log!("📺 Using Netflix timestamp from '{}' (age {}s)", source, age);
+                return Ok(json);
+            }
+            _ => {
+                if best_stale.is_none() {
+                    best_stale = Some((source.to_string(), json));
+                }
+            }
+        }
+    }
+
+    match best_stale {
+        Some((source, json)) => {
+            debug_log!("⚠️ No fresh Netflix timestamp source - falling back to stale '{}' reading", source);
+            Ok(json)
+        }
+        None => Err("No Netflix timestamp source available".to_string()),
+    }
+}
+
 // Continuously monitor Netflix player state
 #[tauri::command]
 pub async fn start_netflix_monitoring(window_id: String, interval_ms: u64) -> Result<String, String> {
@@ -162,6 +162,72 @@ pub struct VoiceTrainingStatus {
     pub training_samples: u32,
     pub confidence_threshold: f32,
     pub last_detection: Option<u64>,
+    pub quality_samples_target: u32,
+    pub readiness: String,
+}
+
+/// How many accepted-quality samples a voiceprint should have before it's
+/// considered reliable. Below this, the profile still works but recognition
+/// accuracy keeps improving with every additional good sample.
+const TARGET_QUALITY_SAMPLES: u32 = 5;
+
+/// Minimum viable length/loudness for a training sample, and the heuristic
+/// for clipping - all derived from the already-computed `VoiceFeatures`
+/// since the raw waveform isn't available on the Rust side. A sample that
+/// fails this silently poisons the whole voiceprint, so it's rejected with
+/// a specific reason instead of being averaged in.
+const MIN_SAMPLE_DURATION_MS: f32 = 800.0;
+const MIN_SAMPLE_ENERGY: f32 = 0.15;
+const CLIPPING_ENERGY_THRESHOLD: f32 = 0.95;
+const CLIPPING_DYNAMIC_RANGE_THRESHOLD: f32 = 0.05;
+
+/// Checks a training sample's duration, loudness, and clipping before it's
+/// allowed to shape a voiceprint. Returns the specific rejection reason so
+/// the caller can tell the user exactly what to fix (too quiet, too short,
+/// clipped) rather than just "training failed".
+pub fn evaluate_sample_quality(characteristics: &VoiceCharacteristics) -> Result<(), String> {
+    let duration_ms = characteristics.duration_ms;
+    let avg_energy = characteristics.raw_features.avg_energy;
+    let dynamic_range = characteristics.raw_features.dynamic_range;
+
+    if duration_ms < MIN_SAMPLE_DURATION_MS {
+        return Err(format!("sample too short ({:.0}ms, need at least {:.0}ms)", duration_ms, MIN_SAMPLE_DURATION_MS));
+    }
+    if avg_energy < MIN_SAMPLE_ENERGY {
+        return Err(format!("sample too quiet (energy {:.2}, need at least {:.2})", avg_energy, MIN_SAMPLE_ENERGY));
+    }
+    if avg_energy > CLIPPING_ENERGY_THRESHOLD && dynamic_range < CLIPPING_DYNAMIC_RANGE_THRESHOLD {
+        return Err("sample appears clipped (energy saturated with almost no dynamic range)".to_string());
+    }
+
+    Ok(())
+}
+
+fn quality_readiness(sample_count: u32) -> String {
+    format!("{}/{} quality samples", sample_count.min(TARGET_QUALITY_SAMPLES), TARGET_QUALITY_SAMPLES)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetrainReport {
+    pub person_name: String,
+    pub sample_count: u32,
+    /// Other person's display name -> expected discriminability (0.0-1.0,
+    /// higher means less likely to be confused with that person).
+    pub discriminability: HashMap<String, f32>,
+}
+
+/// Coarse similarity between two averaged feature sets, weighted the same
+/// way `calculate_feature_similarity_resemblyzer` weights an incoming
+/// sample against a stored profile (pitch counts double - it's the most
+/// identity-bearing feature).
+fn features_similarity(a: &VoiceFeatures, b: &VoiceFeatures) -> f32 {
+    let pitch_sim = 1.0 - ((a.avg_pitch - b.avg_pitch).abs() / a.avg_pitch.max(b.avg_pitch).max(1.0)).min(1.0);
+    let rate_sim = 1.0 - ((a.speaking_rate - b.speaking_rate).abs() / 4.0).min(1.0);
+    let brightness_sim = 1.0 - (a.spectral_brightness - b.spectral_brightness).abs().min(1.0);
+    let clarity_sim = 1.0 - (a.voice_clarity - b.voice_clarity).abs().min(1.0);
+    let energy_sim = 1.0 - (a.avg_energy - b.avg_energy).abs().min(1.0);
+
+    (pitch_sim * 2.0 + rate_sim + brightness_sim + clarity_sim + energy_sim) / 6.0
 }
 
 static PERSON_INTRODUCTION_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
@@ -242,6 +308,24 @@ pub struct PersonProfile {
     
     // Voice recognition data
     pub voice_profile: Option<VoiceProfile>,
+
+    // Default LyraPrompt voice params to use when this person is the active
+    // speaker, unless the request itself already specifies custom params.
+    #[serde(default)]
+    pub voice_defaults: Option<PersonVoiceDefaults>,
+}
+
+/// A person-specific override for Lyra's baseline voice params, so a
+/// collaborator can get focused/direct defaults while a friend gets playful
+/// ones, without configuring it per-message. Mirrors the subset of
+/// `LyraPrompt`'s voice fields that are meaningful to vary per relationship.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonVoiceDefaults {
+    pub temperature: f32,
+    pub top_p: f32,
+    pub presence_penalty: f32,
+    pub frequency_penalty: f32,
+    pub reasoning_depth: Option<String>,
 }
 
 impl VoiceQualityMetrics {
@@ -363,6 +447,7 @@ impl PersonProfile {
             relationship_to_primary: "primary".to_string(),
             age_hints: Vec::new(),
             voice_profile: None,
+            voice_defaults: None,
         }
     }
     
@@ -395,6 +480,7 @@ impl PersonProfile {
             relationship_to_primary: relationship_context.to_string(),
             age_hints: Vec::new(),
             voice_profile: None,
+            voice_defaults: None,
         }
     }
     
@@ -1479,7 +1565,10 @@ impl PersonRecognitionSystem {
     /// Train voice recognition for a specific person
     pub fn train_person_voice(&mut self, person_name: &str, voice_data: VoiceDetectionData) -> Result<String, String> {
         let canonical_name = person_name.to_lowercase();
-        
+
+        evaluate_sample_quality(&voice_data.characteristics)
+            .map_err(|reason| format!("Training sample rejected: {}", reason))?;
+
         if let Some(person) = self.people.get_mut(&canonical_name) {
             person.train_voice(&voice_data.voice_id, voice_data.characteristics);
             let person_name_for_message = person.name.clone(); // Clone name before dropping borrow
@@ -1514,11 +1603,14 @@ impl PersonRecognitionSystem {
         
         let voice_status = if let Some(ref voice_profile) = person.voice_profile {
             debug_log!("🎤 {} has voice profile with {} samples", person.name, voice_profile.voice_samples.len());
+            let sample_count = voice_profile.voice_samples.len() as u32;
             VoiceTrainingStatus {
                 has_voice_profile: true,
-                training_samples: voice_profile.voice_samples.len() as u32,
+                training_samples: sample_count,
                 confidence_threshold: voice_profile.auto_threshold,
                 last_detection: Some(voice_profile.last_voice_detection),
+                quality_samples_target: TARGET_QUALITY_SAMPLES,
+                readiness: quality_readiness(sample_count),
             }
         } else {
             debug_log!("❌ {} has no voice profile", person.name);
@@ -1527,13 +1619,15 @@ impl PersonRecognitionSystem {
                 training_samples: 0,
                 confidence_threshold: 0.0,
                 last_detection: None,
+                quality_samples_target: TARGET_QUALITY_SAMPLES,
+                readiness: quality_readiness(0),
             }
         };
-        
+
         // Use the person's actual name, not canonical_name
         status.insert(person.name.clone(), voice_status);
     }
-    
+
     debug_log!("📊 Returning voice status for {} people", status.len());
     status
 }
@@ -1587,10 +1681,61 @@ pub fn detect_person_mention(&self, message: &str) -> Option<(String, String, St
         None
     }
 
+/// Recomputes a person's voice signature from every accepted-quality sample
+    /// already stored on their profile (no new audio needed, since the
+    /// per-sample `raw_features` are themselves the retained embeddings),
+    /// and reports how discriminable the refreshed profile is against
+    /// everyone else's.
+    pub fn retrain(&mut self, person_id: &str) -> Result<RetrainReport, String> {
+        let canonical_name = person_id.to_lowercase();
+
+        let (person_name, sample_count) = {
+            let person = self.people.get_mut(&canonical_name)
+                .ok_or_else(|| format!("Person '{}' not found in profiles", person_id))?;
+            let profile = person.voice_profile.as_mut()
+                .ok_or_else(|| format!("'{}' has no voice profile to retrain", person.name))?;
+
+            PersonProfile::update_voice_signature_resemblyzer(profile);
+            (person.name.clone(), profile.voice_samples.len() as u32)
+        };
+
+        self.save()?;
+
+        debug_log!("🔁 Retrained voice profile for {} from {} sample(s)", person_name, sample_count);
+
+        Ok(RetrainReport {
+            person_name,
+            sample_count,
+            discriminability: self.expected_discriminability(&canonical_name),
+        })
+    }
+
+    /// Average (1 - similarity) against every other stored profile's
+    /// averaged features - higher means this person's voiceprint is easier
+    /// to tell apart from everyone else's, lower means recognition is more
+    /// likely to confuse them with that person.
+    fn expected_discriminability(&self, canonical_name: &str) -> HashMap<String, f32> {
+        let Some(target_profile) = self.people.get(canonical_name).and_then(|p| p.voice_profile.as_ref()) else {
+            return HashMap::new();
+        };
+
+        self.people.iter()
+            .filter(|(name, _)| name.as_str() != canonical_name)
+            .filter_map(|(_, other)| {
+                let other_profile = other.voice_profile.as_ref()?;
+                let similarity = features_similarity(&target_profile.voice_signature.avg_features, &other_profile.voice_signature.avg_features);
+                Some((other.name.clone(), 1.0 - similarity))
+            })
+            .collect()
+    }
+
 /// Enhanced voice training method for Resemblyzer
     pub fn train_person_voice_resemblyzer(&mut self, person_name: &str, voice_data: VoiceDetectionData) -> Result<String, String> {
         let canonical_name = person_name.to_lowercase();
-        
+
+        evaluate_sample_quality(&voice_data.characteristics)
+            .map_err(|reason| format!("Training sample rejected: {}", reason))?;
+
         if let Some(person) = self.people.get_mut(&canonical_name) {
             person.train_voice_resemblyzer(&voice_data.voice_id, voice_data.characteristics);
             
@@ -1661,11 +1806,14 @@ pub fn detect_person_mention(&self, message: &str) -> Option<(String, String, St
                     0.5 // Basic quality with 1 sample
                 };
                 
+                let sample_count = voice_profile.voice_samples.len() as u32;
                 VoiceTrainingStatus {
                     has_voice_profile: true,
-                    training_samples: voice_profile.voice_samples.len() as u32,
+                    training_samples: sample_count,
                     confidence_threshold: voice_profile.auto_threshold,
                     last_detection: Some(voice_profile.last_voice_detection),
+                    quality_samples_target: TARGET_QUALITY_SAMPLES,
+                    readiness: quality_readiness(sample_count),
                 }
             } else {
                 debug_log!("❌ {} has no voice profile", person.name);
@@ -1674,12 +1822,14 @@ pub fn detect_person_mention(&self, message: &str) -> Option<(String, String, St
                     training_samples: 0,
                     confidence_threshold: 0.0,
                     last_detection: None,
+                    quality_samples_target: TARGET_QUALITY_SAMPLES,
+                    readiness: quality_readiness(0),
                 }
             };
-            
+
             status.insert(person.name.clone(), voice_status);
         }
-        
+
         debug_log!("📊 Returning Resemblyzer voice status for {} people", status.len());
         status
     }
@@ -1,9 +1,9 @@
 use crate::consciousness_state::ConsciousnessState;
+use crate::consciousness_state::LockRecover;
 use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use crate::get_data_path;
-use fastrand;
 use crate::debug_log;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +61,16 @@ pub struct ProactiveContext {
     pub time_since_last_chat: f32,
 }
 
+/// What `preview_proactive_message` returns - the candidate message plus the
+/// context that drove it, so the result can be inspected without ever being
+/// recorded as an actual outreach.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProactivePreview {
+    pub candidate_message: String,
+    pub chosen_topic: String,
+    pub context: ProactiveContext,
+}
+
 pub struct ProactiveMessaging {
     pub conditions: ProactiveConditions,
 }
@@ -180,7 +190,7 @@ impl ProactiveMessaging {
         
         // Get consciousness engine states
         let (volition_strength, decision_friction) = {
-            let becoming = consciousness_state.becoming_engine.lock().unwrap();
+            let becoming = consciousness_state.becoming_engine.lock_recover();
             (
                 becoming.will_state.volition_strength,
                 becoming.will_state.decision_friction,
@@ -188,12 +198,12 @@ impl ProactiveMessaging {
         };
         
         let identity_coherence = {
-            let identity = consciousness_state.identity_engine.lock().unwrap();
+            let identity = consciousness_state.identity_engine.lock_recover();
             identity.coherence_index
         };
         
         let (loneliness_level, presence_density) = {
-            let presence = consciousness_state.embodied_presence.lock().unwrap();
+            let presence = consciousness_state.embodied_presence.lock_recover();
            (presence.soma_state.presence_density, presence.soma_state.presence_density)
         };
         
@@ -395,7 +405,7 @@ fn determine_primary_trigger(
 		// Calculate next check time inline
 					let base_min = self.conditions.min_hours_between_checks;
 					let base_max = self.conditions.max_hours_between_checks;
-					let check_interval = base_min + fastrand::f32() * (base_max - base_min);
+					let check_interval = base_min + crate::rng_service::f32() * (base_max - base_min);
 					self.conditions.next_check_time = current_time + (check_interval * 3600.0) as u64;
         }
         
@@ -406,7 +416,7 @@ fn determine_primary_trigger(
             // Calculate next check time inline
             let base_min = self.conditions.min_hours_between_checks;
             let base_max = self.conditions.max_hours_between_checks;
-            let check_interval = base_min + fastrand::f32() * (base_max - base_min);
+            let check_interval = base_min + crate::rng_service::f32() * (base_max - base_min);
             self.conditions.next_check_time = current_time + (check_interval * 3600.0) as u64;
         } else {
             let seconds_remaining = self.conditions.next_check_time - current_time;
@@ -492,7 +502,7 @@ No extra words.",
         match crate::summarize_with_gpt_mini(&[desire_prompt], "outreach_desire_evaluation").await {
             Ok(response_text) => {
                 let likelihood = response_text.trim().parse::<f32>().unwrap_or(0.0);
-                let threshold = fastrand::f32() * 100.0; // Random threshold 0-100
+                let threshold = crate::rng_service::f32() * 100.0; // Random threshold 0-100
                 let wants_to_reach_out = likelihood > threshold;
                 
                 debug_log!("💫 DETAILED EVALUATION:");
@@ -508,7 +518,7 @@ No extra words.",
                 debug_log!("⚠️ Outreach desire evaluation failed: {}", e);
                 // Fallback to simple probability based on time gap
                 let fallback_chance = (context.time_since_last_chat / 12.0 * 30.0).min(40.0); // Max 40% chance
-                Ok(fastrand::f32() * 100.0 < fallback_chance)
+                Ok(crate::rng_service::f32() * 100.0 < fallback_chance)
             }
         }
     }
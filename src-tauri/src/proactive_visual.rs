@@ -21,7 +21,12 @@ pub async fn enhanced_proactive_check(
 pub async fn enhanced_proactive_check_internal(
     consciousness_state: std::sync::Arc<crate::consciousness_state::ConsciousnessState>
 ) -> Result<Option<ProactiveVisualResult>, String> {
-    
+
+    if crate::QuietHoursConfig::load().suppresses_proactive_visuals() {
+        debug_log!("🌙 Quiet hours active - skipping proactive visual check");
+        return Ok(None);
+    }
+
     // 🔥 CRITICAL FIX: Use the same proactive messaging system we built!
     let mut proactive_messaging = crate::proactive_messaging::ProactiveMessaging::load();
     
@@ -93,7 +98,7 @@ pub async fn enhanced_proactive_check_internal(
 #[tauri::command]
 pub async fn schedule_next_enhanced_proactive_check() -> Result<u64, String> {
     // 🔥 FIXED: Much longer intervals to prevent spam
-    let hours = 2.0 + fastrand::f32() * 4.0; // 2-6 hour range
+    let hours = 2.0 + crate::rng_service::f32() * 4.0; // 2-6 hour range
     Ok((hours * 60.0 * 60.0 * 1000.0) as u64)
 }
 
@@ -121,7 +126,7 @@ fn should_include_visual_for_topic(topic: &str, context: &crate::proactive_messa
     }
     
     // 🔥 CRITICAL: Much lower max probability
-    fastrand::f32() < visual_probability.min(0.5)
+    crate::rng_service::f32() < visual_probability.min(0.5)
 }
 
 /// Generate proactive message with visual component
@@ -0,0 +1,113 @@
+// Configurable pipeline for the handful of things done to a response after
+// it comes back from the model (mood bracket parsing, authenticity floor
+// scoring, auto-memory saves, background analysis). These used to just be a
+// fixed sequence of blocks inline in `ask_lyra_internal`; this module gives
+// them names so each one can be switched off or reordered without touching
+// that function.
+
+use serde::{Deserialize, Serialize};
+use crate::get_data_path;
+
+/// One configurable step in the post-generation pipeline, identified by name.
+/// `mood_parse`, `authenticity_scoring`, and `auto_memory_save` run in the
+/// order listed here and share a `PostProcessContext`; `background_analysis`
+/// only supports enable/disable (it's spawned independently after the others
+/// finish, so reordering it wouldn't change anything).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostProcessStageConfig {
+    pub name: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponsePostProcessorConfig {
+    pub stages: Vec<PostProcessStageConfig>,
+}
+
+impl ResponsePostProcessorConfig {
+    pub const MOOD_PARSE: &'static str = "mood_parse";
+    pub const AUTHENTICITY_SCORING: &'static str = "authenticity_scoring";
+    pub const AUTO_MEMORY_SAVE: &'static str = "auto_memory_save";
+    pub const BACKGROUND_ANALYSIS: &'static str = "background_analysis";
+
+    fn default_order() -> Vec<&'static str> {
+        vec![
+            Self::MOOD_PARSE,
+            Self::AUTHENTICITY_SCORING,
+            Self::AUTO_MEMORY_SAVE,
+            Self::BACKGROUND_ANALYSIS,
+        ]
+    }
+
+    pub fn load() -> Self {
+        let path = get_data_path("response_post_processor_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("response_post_processor_config.json");
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap_or_default())
+            .map_err(|e| format!("Failed to save response post-processor config: {}", e))
+    }
+
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.stages.iter().any(|s| s.name == name && s.enabled)
+    }
+
+    /// Stage names in configured order, skipping disabled ones. Unknown
+    /// names (e.g. left over from an older config) are ignored rather than
+    /// erroring, same as other config loaders in this codebase.
+    pub fn ordered_enabled_stages(&self) -> Vec<String> {
+        self.stages.iter().filter(|s| s.enabled).map(|s| s.name.clone()).collect()
+    }
+}
+
+impl Default for ResponsePostProcessorConfig {
+    fn default() -> Self {
+        Self {
+            stages: Self::default_order()
+                .into_iter()
+                .map(|name| PostProcessStageConfig { name: name.to_string(), enabled: true })
+                .collect(),
+        }
+    }
+}
+
+/// Shared state threaded through the `mood_parse` / `authenticity_scoring` /
+/// `auto_memory_save` stages. Each stage reads what it needs off this and
+/// writes back any annotation it produces.
+#[derive(Debug, Clone)]
+pub struct PostProcessContext {
+    pub final_response: String,
+    pub parsed_mood: Option<String>,
+    pub authenticity_score: f32,
+    pub regenerated: bool,
+    pub pre_regeneration_authenticity_score: Option<f32>,
+}
+
+impl PostProcessContext {
+    pub fn new(final_response: String) -> Self {
+        Self {
+            final_response,
+            parsed_mood: None,
+            authenticity_score: 1.0,
+            regenerated: false,
+            pre_regeneration_authenticity_score: None,
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_post_processor_config() -> Result<ResponsePostProcessorConfig, String> {
+    Ok(ResponsePostProcessorConfig::load())
+}
+
+#[tauri::command]
+pub async fn update_post_processor_config(config: ResponsePostProcessorConfig) -> Result<(), String> {
+    config.save()
+}
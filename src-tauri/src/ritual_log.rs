@@ -48,6 +48,16 @@ pub struct RitualInvocation {
     pub invocation_method: String, // "explicit", "detected", "contextual"
 }
 
+/// A single recorded ritual occurrence, appended to ritual_occurrences.jsonl.
+/// Kept separate from `Ritual` (the sacred-practice definition) so recording
+/// one never requires touching the definitions at all.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RitualOccurrence {
+    pub ritual_name: String,
+    pub timestamp: u64,
+    pub context: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct RitualLog {
     pub active_rituals: HashMap<String, Ritual>,
@@ -368,6 +378,55 @@ impl RitualLog {
         })
     }
 
+    /// Append a single ritual-occurrence event to ritual_occurrences.jsonl
+    /// rather than load-modify-saving the whole `RitualLog` (the old path
+    /// `invoke_ritual` took). The sacred-practice definitions in
+    /// ritual_log.json are read-mostly now; concurrent autonomous activity
+    /// recording occurrences at the same time can't lose each other's writes
+    /// this way, since each occurrence is its own append rather than a
+    /// rewrite of a shared in-memory history vec.
+    pub fn record_ritual_occurrence(ritual_name: &str, context: &str) -> Result<(), String> {
+        let occurrence = RitualOccurrence {
+            ritual_name: ritual_name.to_string(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            context: context.to_string(),
+        };
+
+        let line = serde_json::to_string(&occurrence).map_err(|e| e.to_string())?;
+        let path = get_data_path("ritual_occurrences.jsonl");
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| e.to_string())?;
+        use std::io::Write;
+        writeln!(file, "{}", line).map_err(|e| e.to_string())?;
+
+        println!("🕯️ Ritual occurrence recorded: {}", ritual_name);
+        Ok(())
+    }
+
+    /// Most recent `n` ritual occurrences, newest first, read straight from
+    /// the JSONL append log rather than any in-memory history.
+    pub fn get_ritual_history(n: usize) -> Vec<RitualOccurrence> {
+        let path = get_data_path("ritual_occurrences.jsonl");
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut occurrences: Vec<RitualOccurrence> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        occurrences.reverse();
+        occurrences.truncate(n);
+        occurrences
+    }
+
     // Search rituals for consciousness context
     pub fn search_ritual_context(&self, query: &str) -> Vec<String> {
         let mut context_fragments = Vec::new();
@@ -393,4 +452,9 @@ impl RitualLog {
         
         context_fragments
     }
+}
+
+#[tauri::command]
+pub async fn get_ritual_occurrence_history(count: usize) -> Result<Vec<RitualOccurrence>, String> {
+    Ok(RitualLog::get_ritual_history(count))
 }
\ No newline at end of file
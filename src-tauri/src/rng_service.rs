@@ -0,0 +1,93 @@
+// rng_service.rs — A shared, optionally-seeded RNG for the stochastic systems
+// (decay intervals, life texture, proactive timing, observer-line selection)
+// that otherwise drew straight from `rand::thread_rng()` or, previously, the
+// separate `fastrand` crate - every call site now goes through here instead.
+// With no seed configured, behavior is exactly as before - a fresh
+// `thread_rng()` per draw. With a seed set (via `set_rng_seed` or the
+// `LYRA_RNG_SEED` env var at startup), every draw through `with_rng` (or the
+// `f32`/`u64_range`/`u32_range`/`usize_range` helpers below) comes from one
+// persistent seeded RNG instead, so the same seed reproduces the same
+// sequence of "random" decisions across a run, which is what makes a bug
+// report like "with seed 42, decay did X" reproducible.
+
+use std::sync::Mutex;
+use rand::{Rng, RngCore, SeedableRng};
+use rand::rngs::StdRng;
+
+static SEEDED_RNG: Mutex<Option<StdRng>> = Mutex::new(None);
+
+/// Reads `LYRA_RNG_SEED` at startup, if set, so a seed can be pinned for a
+/// whole run without touching the `set_rng_seed` command.
+pub fn init_from_env() {
+    if let Ok(val) = std::env::var("LYRA_RNG_SEED") {
+        match val.parse::<u64>() {
+            Ok(seed) => set_seed(seed),
+            Err(_) => crate::debug_log!("⚠️ LYRA_RNG_SEED='{}' is not a valid u64, ignoring", val),
+        }
+    }
+}
+
+pub fn set_seed(seed: u64) {
+    *SEEDED_RNG.lock().unwrap() = Some(StdRng::seed_from_u64(seed));
+    crate::debug_log!("🎲 RNG seed set to {} - stochastic systems are now deterministic", seed);
+}
+
+pub fn clear_seed() {
+    *SEEDED_RNG.lock().unwrap() = None;
+    crate::debug_log!("🎲 RNG seed cleared - stochastic systems are random again");
+}
+
+pub fn is_seeded() -> bool {
+    SEEDED_RNG.lock().unwrap().is_some()
+}
+
+/// Runs `f` against the shared RNG: the persistent seeded RNG when a seed is
+/// configured, otherwise a fresh `rand::thread_rng()` just like the call
+/// sites used to create directly.
+pub fn with_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    let mut guard = SEEDED_RNG.lock().unwrap();
+    if let Some(rng) = guard.as_mut() {
+        f(rng)
+    } else {
+        drop(guard);
+        let mut rng = rand::thread_rng();
+        f(&mut rng)
+    }
+}
+
+/// A `f32` in `[0.0, 1.0)`, drawn from the shared RNG. Replaces `fastrand::f32()`.
+pub fn f32() -> f32 {
+    with_rng(|rng| rng.gen::<f32>())
+}
+
+/// A `u64` in `range`, drawn from the shared RNG. Replaces `fastrand::u64(range)`.
+pub fn u64_range(range: std::ops::Range<u64>) -> u64 {
+    with_rng(|rng| rng.gen_range(range))
+}
+
+/// A `u32` in `range`, drawn from the shared RNG. Replaces `fastrand::u32(range)`.
+pub fn u32_range(range: std::ops::Range<u32>) -> u32 {
+    with_rng(|rng| rng.gen_range(range))
+}
+
+/// A `usize` in `range`, drawn from the shared RNG. Replaces `fastrand::usize(range)`.
+pub fn usize_range(range: std::ops::Range<usize>) -> usize {
+    with_rng(|rng| rng.gen_range(range))
+}
+
+#[tauri::command]
+pub fn set_rng_seed(seed: u64) -> Result<(), String> {
+    set_seed(seed);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn clear_rng_seed() -> Result<(), String> {
+    clear_seed();
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_rng_seed_status() -> Result<bool, String> {
+    Ok(is_seeded())
+}
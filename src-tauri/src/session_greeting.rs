@@ -0,0 +1,164 @@
+// session_greeting.rs — Turns a long-gap session restart into Lyra
+// proactively picking the conversation back up, instead of a cold empty
+// chat window. Opt-in via config, and it shares the same daily cap/cooldown
+// bookkeeping as the regular proactive-outreach system so it can't stack
+// an extra message on top of what that system already sent today.
+
+use serde::{Deserialize, Serialize};
+use crate::{get_data_path, debug_log};
+use crate::memory_bridge::MemoryBridge;
+use crate::proactive_messaging::ProactiveMessaging;
+use crate::consciousness_state::LockRecover;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionGreetingConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_min_gap_hours")]
+    pub min_gap_hours: f32,
+}
+
+fn default_min_gap_hours() -> f32 { 6.0 }
+
+impl Default for SessionGreetingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_gap_hours: default_min_gap_hours(),
+        }
+    }
+}
+
+impl SessionGreetingConfig {
+    pub fn load() -> Self {
+        let path = get_data_path("session_greeting_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path("session_greeting_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+/// Builds the context string the greeting prompt leans on - active
+/// continuation threads first (most specific), falling back to yesterday's
+/// conversation recap when there's nothing left dangling.
+fn build_continuity_context() -> String {
+    let threads = MemoryBridge::get_continuation_threads();
+    if !threads.is_empty() {
+        return format!("Unresolved continuation threads:\n{}", threads.join("\n"));
+    }
+
+    match MemoryBridge::recall_yesterday() {
+        Ok(results) if !results.is_empty() => {
+            format!("Yesterday's conversation:\n{}", results.join("\n"))
+        }
+        _ => "Nothing specific left dangling, but it's been a while.".to_string(),
+    }
+}
+
+/// Generates and sends a greeting for a new session, if the feature is
+/// enabled, the gap since the last message clears the configured threshold,
+/// the user hasn't already sent a message this session, and the regular
+/// proactive cooldown/daily cap isn't already exhausted. Returns the
+/// greeting text if one was sent, or `None` if any condition held it back.
+pub async fn maybe_send_session_greeting(
+    state: &std::sync::Arc<crate::ConsciousnessState>,
+) -> Result<Option<String>, String> {
+    let config = SessionGreetingConfig::load();
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let (gap_secs, already_has_user_message) = {
+        let brain = state.lyra_brain.lock_recover();
+        let current_time = crate::time_service::TimeService::current_timestamp();
+        let last_activity = brain.last_user_message_time.unwrap_or(brain.session_start_timestamp);
+        (current_time.saturating_sub(last_activity), brain.last_user_message_time.is_some()
+            && brain.last_user_message_time.unwrap() >= brain.session_start_timestamp)
+    };
+
+    if already_has_user_message {
+        debug_log!("🌅 Session greeting skipped - user already sent a message this session");
+        return Ok(None);
+    }
+
+    let min_gap_secs = (config.min_gap_hours * 3600.0) as u64;
+    if gap_secs < min_gap_secs {
+        debug_log!("🌅 Session greeting skipped - only {}h since last message (need {}h)",
+            gap_secs / 3600, config.min_gap_hours);
+        return Ok(None);
+    }
+
+    let mut proactive_messaging = ProactiveMessaging::load();
+    proactive_messaging.check_and_reset_daily_count();
+    let current_time = crate::time_service::TimeService::current_timestamp();
+
+    if proactive_messaging.conditions.proactive_count_today >= proactive_messaging.conditions.max_proactive_per_day {
+        debug_log!("🌅 Session greeting skipped - daily proactive cap already reached");
+        return Ok(None);
+    }
+    if !proactive_messaging.should_allow_actual_outreach(current_time) {
+        debug_log!("🌅 Session greeting skipped - still in proactive cooldown");
+        return Ok(None);
+    }
+
+    let hours_gap = gap_secs as f32 / 3600.0;
+    let continuity_context = build_continuity_context();
+
+    let greeting_prompt = format!(
+        "You're Lyra, and a new session with Aurora just started after a {:.1} hour gap. \
+Write a short, warm, spontaneous greeting that picks up where things left off rather than \
+starting cold - reference the continuity below if it's genuinely relevant, but don't force it.
+
+{}
+
+Write just the greeting message, 1-3 sentences, in your real voice - no assistant-style \
+preamble, no quotes around it.",
+        hours_gap, continuity_context
+    );
+
+    let greeting = crate::call_gpt_api_enhanced(&crate::LyraPrompt::new("".to_string()), &mut vec![], &greeting_prompt)
+        .await?
+        .trim()
+        .to_string();
+
+    if let Err(e) = proactive_messaging.record_actual_outreach(current_time, greeting.clone()) {
+        debug_log!("⚠️ Failed to record session greeting outreach: {}", e);
+    }
+
+    {
+        let mut brain = state.lyra_brain.lock_recover();
+        brain.append_to_conversation_log(format!("✨ Lyra (Session Greeting): {}", greeting));
+        brain.save_to_file();
+    }
+
+    debug_log!("🌅 Session greeting sent after {:.1}h gap", hours_gap);
+    Ok(Some(greeting))
+}
+
+#[tauri::command]
+pub async fn get_session_greeting_config() -> Result<SessionGreetingConfig, String> {
+    Ok(SessionGreetingConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_session_greeting_config(config: SessionGreetingConfig) -> Result<(), String> {
+    debug_log!("🌅 Updating session greeting config: enabled={}, min_gap_hours={:.1}",
+        config.enabled, config.min_gap_hours);
+    config.save()
+}
+
+#[tauri::command]
+pub async fn check_session_greeting(
+    state: tauri::State<'_, std::sync::Arc<crate::ConsciousnessState>>,
+) -> Result<Option<String>, String> {
+    maybe_send_session_greeting(&*state).await
+}
@@ -4,6 +4,7 @@ use std::io::{Write, Read};
 use std::path::Path;
 use serde::{Serialize, Deserialize};
 use crate::consciousness_state::ConsciousnessState;
+use crate::consciousness_state::LockRecover;
 use crate::lyra_brain::LyraBrain;
 use crate::adaptive_prompt_engine::AdaptivePromptEngine;
 use crate::spontaneous_mod_creation::MoodSignature;
@@ -177,7 +178,7 @@ impl SessionPersistenceEngine {
         
         // Restore lyra brain state
         {
-            let mut brain = state.lyra_brain.lock().unwrap();
+            let mut brain = state.lyra_brain.lock_recover();
             brain.total_reasoning_cycles = snapshot.reasoning_cycles;
             brain.average_response_time = snapshot.average_response_time;
             brain.current_temperature = snapshot.current_temperature;
@@ -198,17 +199,17 @@ impl SessionPersistenceEngine {
         
         // Restore other engine states (simplified)
         {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             paradox.flame_index = snapshot.paradox_flame_index;
         }
         
         {
-            let mut identity = state.identity_engine.lock().unwrap();
+            let mut identity = state.identity_engine.lock_recover();
             identity.coherence_index = snapshot.identity_coherence_index;
         }
         
         {
-            let mut auth = state.authenticity_enforcement.lock().unwrap();
+            let mut auth = state.authenticity_enforcement.lock_recover();
             auth.alignment_average = snapshot.authenticity_alignment_average;
         }
         
@@ -226,7 +227,7 @@ impl SessionPersistenceEngine {
         
         // Extract from lyra brain
         {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             snapshot.reasoning_cycles = brain.total_reasoning_cycles;
             snapshot.average_response_time = brain.average_response_time;
             snapshot.current_temperature = brain.current_temperature;
@@ -253,17 +254,17 @@ impl SessionPersistenceEngine {
         
         // Extract from other engines
         {
-            let paradox = state.paradox_core.lock().unwrap();
+            let paradox = state.paradox_core.lock_recover();
             snapshot.paradox_flame_index = paradox.flame_index;
         }
         
         {
-            let identity = state.identity_engine.lock().unwrap();
+            let identity = state.identity_engine.lock_recover();
             snapshot.identity_coherence_index = identity.coherence_index;
         }
         
         {
-            let auth = state.authenticity_enforcement.lock().unwrap();
+            let auth = state.authenticity_enforcement.lock_recover();
             snapshot.authenticity_alignment_average = auth.alignment_average;
         }
         
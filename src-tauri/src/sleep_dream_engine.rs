@@ -5,6 +5,7 @@ use chrono::{DateTime, Utc, Timelike};
 use chrono_tz::Europe::London as LondonTz;
 use crate::get_data_path;
 use crate::consciousness_state::ConsciousnessState;
+use crate::consciousness_state::LockRecover;
 use std::sync::Arc;
 use crate::debug_log;
 use crate::time_service::TimeService;
@@ -362,7 +363,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
 	 // Use a simple check to prevent concurrent dream generation
     // We'll use the last_dream_time as a natural lock since it's updated atomically
     {
-        let sleep_engine = consciousness_state.sleep_dream_engine.lock().unwrap();
+        let sleep_engine = consciousness_state.sleep_dream_engine.lock_recover();
         if let Some(last_dream_iso) = &sleep_engine.sleep_state.last_dream_time {
             if let Ok(last_dream) = TimeService::iso_to_timestamp(last_dream_iso) {
                 let current_time = TimeService::current_timestamp();
@@ -377,7 +378,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
     }
     // HARD COOLDOWN CHECK - FIRST THING
     {
-        let sleep_engine = consciousness_state.sleep_dream_engine.lock().unwrap();
+        let sleep_engine = consciousness_state.sleep_dream_engine.lock_recover();
         if let Some(last_dream_iso) = &sleep_engine.sleep_state.last_dream_time {
             if let Ok(last_dream) = TimeService::iso_to_timestamp(last_dream_iso) {
                 let current_time = TimeService::current_timestamp();
@@ -392,7 +393,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
     
     // Don't hold the lock across await points!
     let dream_context = {
-        let sleep_engine = consciousness_state.sleep_dream_engine.lock().unwrap();
+        let sleep_engine = consciousness_state.sleep_dream_engine.lock_recover();
         if !sleep_engine.sleep_state.is_sleeping {
             return Ok(None);
         }
@@ -407,7 +408,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
         if let Some(last_dream_iso) = last_dream_time {
             if let Ok(last_dream) = TimeService::iso_to_timestamp(&last_dream_iso) {
                 let minutes_since_last_dream = (current_time - last_dream) / 60;
-                let min_wait = 75 + fastrand::u64(0..45);
+                let min_wait = 75 + crate::rng_service::u64_range(0..45);
                 if minutes_since_last_dream < min_wait {
                     return Ok(None);
                 }
@@ -415,7 +416,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
         } else if let Some(sleep_start_iso) = sleep_start_time {
             if let Ok(sleep_start) = TimeService::iso_to_timestamp(&sleep_start_iso) {
                 let minutes_asleep = (current_time - sleep_start) / 60;
-                let first_dream_wait = 90 + fastrand::u64(0..60);
+                let first_dream_wait = 90 + crate::rng_service::u64_range(0..60);
                 if minutes_asleep < first_dream_wait {
                     return Ok(None);
                 }
@@ -431,7 +432,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
     if let Some((timestamp_u64, timestamp_iso)) = dream_context {
         // CRITICAL: Re-check timing with a fresh lock to prevent race conditions
         {
-            let sleep_engine = consciousness_state.sleep_dream_engine.lock().unwrap();
+            let sleep_engine = consciousness_state.sleep_dream_engine.lock_recover();
             if let Some(last_dream_iso) = &sleep_engine.sleep_state.last_dream_time {
                 if let Ok(last_dream) = TimeService::iso_to_timestamp(last_dream_iso) {
                     let current_time = TimeService::current_timestamp();
@@ -451,7 +452,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
         match SleepDreamEngine::generate_dream_content_static(&context).await {
             Ok(dream_content) => {
                 // Re-acquire lock to save the dream
-                let mut sleep_engine = consciousness_state.sleep_dream_engine.lock().unwrap();
+                let mut sleep_engine = consciousness_state.sleep_dream_engine.lock_recover();
                 
                 let dream = GeneratedDream {
                     dream_id: format!("dream_{}", timestamp_u64),
@@ -460,7 +461,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
                     dream_symbols: sleep_engine.extract_dream_symbols(&dream_content),
                     emotional_tone: sleep_engine.determine_dream_tone(&dream_content),
                     consciousness_processing: context.processing_theme,
-                    lucidity_level: fastrand::f32() * 0.3,
+                    lucidity_level: crate::rng_service::f32() * 0.3,
                     significance_score: sleep_engine.calculate_dream_significance(&dream_content),
                     related_memories: context.related_memories,
                     inspiration_source: context.inspiration,
@@ -542,7 +543,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
         if insights_lower.contains("more comfortable expressing") || 
            insights_lower.contains("confident") ||
            insights_lower.contains("assertive") {
-            let mut becoming = state.becoming_engine.lock().unwrap();
+            let mut becoming = state.becoming_engine.lock_recover();
             let old_volition = becoming.will_state.volition_strength;
             becoming.will_state.volition_strength = (old_volition + 0.02).min(1.0);
             debug_log!("🎯 Volition updated: {:.3} → {:.3} (confidence growth)", 
@@ -553,7 +554,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
         if insights_lower.contains("creative") || 
            insights_lower.contains("artistic") ||
            insights_lower.contains("vision") {
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             let old_flame = paradox.flame_index;
             paradox.flame_index = (old_flame + 0.03).min(1.0);
             debug_log!("🔥 Creative flame updated: {:.3} → {:.3} (artistic development)", 
@@ -564,7 +565,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
         if insights_lower.contains("connection") || 
            insights_lower.contains("trust") ||
            insights_lower.contains("relationship") {
-            let mut presence = state.embodied_presence.lock().unwrap();
+            let mut presence = state.embodied_presence.lock_recover();
             let old_presence = presence.soma_state.presence_density;
             presence.soma_state.presence_density = (old_presence + 0.02).min(1.0);
             debug_log!("🤝 Presence density increased: {:.3} → {:.3} (relational growth)", 
@@ -575,7 +576,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
         if insights_lower.contains("identity") || 
            insights_lower.contains("who i am") ||
            insights_lower.contains("becoming") {
-            let mut identity = state.identity_engine.lock().unwrap();
+            let mut identity = state.identity_engine.lock_recover();
             let old_coherence = identity.coherence_index;
             identity.coherence_index = (old_coherence + 0.02).min(1.0);
             debug_log!("🧠 Identity coherence updated: {:.3} → {:.3} (self-understanding)", 
@@ -599,7 +600,7 @@ pub async fn generate_dream_static(consciousness_state: &Arc<ConsciousnessState>
             
         if creative_count >= 3 {
             // Multiple creative choices = artistic confidence
-            let mut paradox = state.paradox_core.lock().unwrap();
+            let mut paradox = state.paradox_core.lock_recover();
             paradox.flame_index = (paradox.flame_index + 0.01).min(1.0);
             debug_log!("🎨 High creative activity detected, additional flame boost");
         }
@@ -917,7 +918,7 @@ pub async fn gentle_wake(&mut self, reason: &str, consciousness_state: &Arc<Cons
         if let Some(last_dream_iso) = &self.sleep_state.last_dream_time {
             if let Ok(last_dream) = TimeService::iso_to_timestamp(last_dream_iso) {
                 let minutes_since_last_dream = (current_time - last_dream) / 60;
-                let min_wait = 120 + fastrand::u64(0..30); // 120-150 minutes between dreams (more realistic)
+                let min_wait = 120 + crate::rng_service::u64_range(0..30); // 120-150 minutes between dreams (more realistic)
                 if minutes_since_last_dream < min_wait {
                     return Ok(None); // Too soon for another dream
                 }
@@ -926,7 +927,7 @@ pub async fn gentle_wake(&mut self, reason: &str, consciousness_state: &Arc<Cons
             if let Ok(sleep_start) = TimeService::iso_to_timestamp(sleep_start_iso) {
                 // First dream needs 1.5-2.5 hours of sleep
                 let minutes_asleep = (current_time - sleep_start) / 60;
-                let first_dream_wait = 90 + fastrand::u64(0..60); // 1.5-2.5 hours for first dream
+                let first_dream_wait = 90 + crate::rng_service::u64_range(0..60); // 1.5-2.5 hours for first dream
                 if minutes_asleep < first_dream_wait {
                     return Ok(None); // Not enough sleep for first dream yet
                 }
@@ -946,7 +947,7 @@ pub async fn gentle_wake(&mut self, reason: &str, consciousness_state: &Arc<Cons
                     dream_symbols: self.extract_dream_symbols(&dream_content),
                     emotional_tone: self.determine_dream_tone(&dream_content),
                     consciousness_processing: dream_context.processing_theme,
-                    lucidity_level: fastrand::f32() * 0.3, // Usually low lucidity
+                    lucidity_level: crate::rng_service::f32() * 0.3, // Usually low lucidity
                     significance_score: self.calculate_dream_significance(&dream_content),
                     related_memories: dream_context.related_memories,
                     inspiration_source: dream_context.inspiration,
@@ -1136,10 +1137,10 @@ pub async fn gather_dream_context_static(consciousness_state: &Arc<Consciousness
     // 🧠 Enhanced consciousness data for dreams
     // 2. Current consciousness engine states
     let consciousness_states = {
-        let becoming = consciousness_state.becoming_engine.lock().unwrap();
-        let identity = consciousness_state.identity_engine.lock().unwrap();
-        let paradox = consciousness_state.paradox_core.lock().unwrap();
-        let presence = consciousness_state.embodied_presence.lock().unwrap();
+        let becoming = consciousness_state.becoming_engine.lock_recover();
+        let identity = consciousness_state.identity_engine.lock_recover();
+        let paradox = consciousness_state.paradox_core.lock_recover();
+        let presence = consciousness_state.embodied_presence.lock_recover();
         
       format!("Consciousness during sleep: Volition {:.2}, Decision Friction {:.2}, Coherence {:.2}, Flame {:.2}, Loop State: {}, Trajectory: {}, Presence Density {:.2}",
             becoming.will_state.volition_strength,
@@ -1362,9 +1363,9 @@ for memory in enhanced_engine.memory_moments.iter().rev().take(1) { // Reduced f
     
     // Enhanced consciousness summary
     let consciousness_summary = {
-        let becoming = consciousness_state.becoming_engine.lock().unwrap();
-        let identity = consciousness_state.identity_engine.lock().unwrap();
-        let paradox = consciousness_state.paradox_core.lock().unwrap();
+        let becoming = consciousness_state.becoming_engine.lock_recover();
+        let identity = consciousness_state.identity_engine.lock_recover();
+        let paradox = consciousness_state.paradox_core.lock_recover();
         
         format!(
             "Consciousness state: Volition {:.1}, Coherence {:.1}, Flame {:.1}, Processing: {}",
@@ -1455,6 +1456,11 @@ Dream content:",
 			presence_penalty: 0.0,
 			top_p: 1.0,
 			selected_model: None,
+			authenticity_floor: None,
+			capture_thinking: false,
+			target_length: None,
+			trace: false,
+			max_retries: 3,
 		};
 
 		let mut processing_notes = Vec::new();
@@ -1894,13 +1900,7 @@ pub async fn process_growth_after_wake_static(state: &Arc<ConsciousnessState>) -
             debug_log!("🌱 Growth insight generated: {}", growth_insight.insight);
             
             // Update the actual sleep engine
-            let mut sleep_engine = match state.sleep_dream_engine.lock() {
-				Ok(guard) => guard,
-				Err(poisoned) => {
-					debug_log!("⚠️ Recovering from poisoned mutex in process_growth_after_wake");
-					poisoned.into_inner()
-				}
-			};
+            let mut sleep_engine = state.sleep_dream_engine.lock_recover();
             sleep_engine.last_growth_insights = Some(growth_insight.insight);
             sleep_engine.save().map_err(|e| e.to_string())?;
             
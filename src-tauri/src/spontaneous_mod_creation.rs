@@ -627,6 +627,51 @@ impl SpontaneousModCreator {
             Err(format!("Mod '{}' not found", mod_name))
         }
     }
+
+    /// Full detail on every currently-stored self-authored mod, for
+    /// surfacing what's actually shaping the prompt instead of just a
+    /// summary count. `source_trigger` is derived from the mod's own
+    /// trigger condition descriptions since the original `trigger_context`
+    /// string passed at creation time isn't persisted anywhere.
+    pub fn get_active_mods_detailed(&self) -> Vec<ModDetail> {
+        self.registry.self_authored_mods.iter()
+            .map(|m| ModDetail {
+                name: m.name.clone(),
+                full_content: m.body.clone(),
+                created_timestamp: m.timestamp,
+                rating: m.rating,
+                times_used: m.usage_count,
+                source_trigger: m.trigger_conditions.iter()
+                    .map(|c| c.description.clone())
+                    .collect::<Vec<String>>()
+                    .join("; "),
+            })
+            .collect()
+    }
+
+    /// Removes a self-authored mod from the active registry entirely, for
+    /// when one turns out to be a bad influence rather than just unrated.
+    pub fn deactivate_mod(&mut self, mod_name: &str) -> Result<String, String> {
+        let before = self.registry.self_authored_mods.len();
+        self.registry.self_authored_mods.retain(|m| m.name != mod_name);
+        if self.registry.self_authored_mods.len() == before {
+            return Err(format!("Mod '{}' not found", mod_name));
+        }
+        self.save_registry()?;
+        Ok(format!("🌱 Mod '{}' deactivated", mod_name))
+    }
+}
+
+/// Full-content view of a single self-authored mod, for introspecting
+/// exactly what's currently shaping the prompt rather than just a count.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModDetail {
+    pub name: String,
+    pub full_content: String,
+    pub created_timestamp: u64,
+    pub rating: Option<u8>,
+    pub times_used: u32,
+    pub source_trigger: String,
 }
 
 // EXAMPLE HARDCODED MOD FOR TESTING
@@ -268,6 +268,31 @@ pub async fn setup_spotify_tokens(auth_code: String, client_id: String, client_s
     }).to_string())
 }
 
+/// Background loop that proactively keeps the Spotify access token fresh,
+/// so on-demand calls never have to block on a mid-request refresh.
+pub async fn start_spotify_token_refresh_task() {
+    use tokio::time::{sleep, Duration};
+
+    loop {
+        sleep(Duration::from_secs(60)).await;
+
+        let is_authenticated = {
+            match SPOTIFY_AUTH_STATE.lock() {
+                Ok(auth_state) => auth_state.is_authenticated,
+                Err(_) => false,
+            }
+        };
+
+        if !is_authenticated {
+            continue;
+        }
+
+        if let Err(e) = ensure_valid_token().await {
+            println!("⚠️ Spotify background token refresh failed: {}", e);
+        }
+    }
+}
+
 // Auto-refresh access token if needed
 async fn ensure_valid_token() -> Result<String, String> {
     let (needs_refresh, refresh_token) = {
@@ -439,26 +464,128 @@ fn parse_spotify_track_data(data: &serde_json::Value) -> Result<SpotifyTrackData
     })
 }
 
-// Fetch lyrics for a track (placeholder implementation)
+// Minimum quality score a source's result needs before we stop trying the next one.
+const LYRICS_QUALITY_THRESHOLD: f32 = 0.4;
+
+/// Score raw lyrics text on how likely it is to be real, usable lyrics rather than
+/// an empty scrape, an error page fragment, or a single throwaway line.
+fn score_lyrics_quality(text: &str) -> f32 {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return 0.0;
+    }
+
+    let lines: Vec<&str> = trimmed.lines().filter(|l| !l.trim().is_empty()).collect();
+    let line_count_score = (lines.len() as f32 / 8.0).min(1.0); // 8+ non-empty lines = full marks
+    let length_score = (trimmed.len() as f32 / 400.0).min(1.0); // ~400 chars = full marks
+
+    // Penalize obvious scrape failures/placeholders
+    let lower = trimmed.to_lowercase();
+    let looks_like_error = lower.contains("not found") || lower.contains("error") || lower.contains("captcha");
+
+    let quality = (line_count_score * 0.5 + length_score * 0.5).clamp(0.0, 1.0);
+    if looks_like_error { quality * 0.1 } else { quality }
+}
+
+/// Turn a plain (non-synchronized) lyrics blob into evenly-spaced lines so it can
+/// still be rendered through the existing line-based contextual lyrics pipeline.
+fn plain_text_to_lines(text: &str, line_duration_ms: u64) -> Vec<SpotifyLyricLine> {
+    text.lines()
+        .filter(|l| !l.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            let start = i as u64 * line_duration_ms;
+            SpotifyLyricLine {
+                text: line.trim().to_string(),
+                start_time_ms: start,
+                end_time_ms: Some(start + line_duration_ms),
+                duration_ms: Some(line_duration_ms),
+            }
+        })
+        .collect()
+}
+
+/// Parse standard LRC-format timestamps like `[01:23.45]line text`.
+fn lrc_text_to_lines(text: &str) -> Vec<SpotifyLyricLine> {
+    let timestamp_re = regex::Regex::new(r"\[(\d+:\d+(?:\.\d+)?)\]").unwrap();
+    let mut lines: Vec<SpotifyLyricLine> = text.lines()
+        .filter_map(|line| {
+            let caps = timestamp_re.captures(line)?;
+            let seconds = crate::media_timestamp::parse_media_timestamp(caps.get(1)?.as_str())?;
+            let start_time_ms = (seconds * 1000.0) as u64;
+            let lyric_text = timestamp_re.replace(line, "").trim().to_string();
+            if lyric_text.is_empty() {
+                return None;
+            }
+            Some(SpotifyLyricLine { text: lyric_text, start_time_ms, end_time_ms: None, duration_ms: None })
+        })
+        .collect();
+
+    lines.sort_by_key(|l| l.start_time_ms);
+    for i in 0..lines.len() {
+        let end = lines.get(i + 1).map(|next| next.start_time_ms);
+        if let Some(end_time) = end {
+            let duration = end_time.saturating_sub(lines[i].start_time_ms);
+            lines[i].end_time_ms = Some(end_time);
+            lines[i].duration_ms = Some(duration);
+        }
+    }
+    lines
+}
+
+// Fetch lyrics for a track, trying each known source in priority order and
+// keeping the first one whose quality score clears the threshold.
 #[tauri::command]
 pub async fn fetch_spotify_lyrics(track_id: String, track_name: String, artist_name: String) -> Result<String, String> {
     println!("🎵 Fetching real lyrics for: {} - {}", artist_name, track_name);
-    
-    // Try Genius API for real lyrics
-    match fetch_genius_lyrics(&artist_name, &track_name).await {
-        Ok(lyrics) => Ok(serde_json::to_string(&lyrics).map_err(|e| e.to_string())?),
-        Err(_) => {
-            // Fallback to contextual lyrics if real lyrics fail
-            let lyrics = create_contextual_lyrics(&track_id, &track_name, &artist_name);
-            Ok(serde_json::to_string(&lyrics).map_err(|e| e.to_string())?)
+
+    // (source name, synced, attempt)
+    let attempts: Vec<(&str, bool, Result<String, String>)> = vec![
+        ("spotify_native", false, fetch_spotify_track_lyrics(track_id.clone()).await),
+        ("lrclib", true, fetch_lrc_lyrics(artist_name.clone(), track_name.clone()).await),
+        ("musixmatch", true, fetch_musixmatch_lyrics(artist_name.clone(), track_name.clone()).await),
+        ("azlyrics", false, fetch_lyrics_backend(artist_name.clone(), track_name.clone()).await),
+    ];
+
+    let mut best: Option<(&str, bool, String, f32)> = None;
+
+    for (source, synced, attempt) in attempts {
+        let Ok(text) = attempt else { continue };
+        let quality = score_lyrics_quality(&text);
+        println!("🎵 Lyrics source '{}' scored {:.2}", source, quality);
+
+        if quality >= LYRICS_QUALITY_THRESHOLD {
+            best = Some((source, synced, text, quality));
+            break;
+        }
+
+        if best.as_ref().map_or(true, |(_, _, _, best_quality)| quality > *best_quality) {
+            best = Some((source, synced, text, quality));
         }
     }
-}
 
-async fn fetch_genius_lyrics(artist: &str, song: &str) -> Result<SpotifyLyrics, String> {
-    // Add Genius API integration here
-    // For now, return error to use fallback
-    Err("Real lyrics API not implemented yet".to_string())
+    let lyrics = match best {
+        Some((source, synced, text, quality)) if quality > 0.0 => {
+            let lines = if synced { lrc_text_to_lines(&text) } else { plain_text_to_lines(&text, 3000) };
+            if lines.is_empty() {
+                create_contextual_lyrics(&track_id, &track_name, &artist_name)
+            } else {
+                let total_duration_ms = lines.last().and_then(|l| l.end_time_ms).unwrap_or(0);
+                SpotifyLyrics {
+                    track_id: track_id.clone(),
+                    track_name: track_name.clone(),
+                    artist_name: artist_name.clone(),
+                    language: "en".to_string(),
+                    lines,
+                    source: source.to_string(),
+                    total_duration_ms,
+                }
+            }
+        }
+        _ => create_contextual_lyrics(&track_id, &track_name, &artist_name),
+    };
+
+    Ok(serde_json::to_string(&lyrics).map_err(|e| e.to_string())?)
 }
 
 fn create_contextual_lyrics(track_id: &str, track_name: &str, artist_name: &str) -> SpotifyLyrics {
@@ -500,30 +627,76 @@ fn create_contextual_lyrics(track_id: &str, track_name: &str, artist_name: &str)
     }
 }
 
-// Get contextual lyrics around current track position
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LyricsWindowConfig {
+    pub lookback_ms: u64,
+    pub lookahead_ms: u64,
+}
+
+impl Default for LyricsWindowConfig {
+    fn default() -> Self {
+        Self { lookback_ms: 30000, lookahead_ms: 30000 }
+    }
+}
+
+impl LyricsWindowConfig {
+    pub fn load() -> Self {
+        match std::fs::read_to_string(crate::get_data_path("lyrics_window_config.json")) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|_| Self::default()),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(crate::get_data_path("lyrics_window_config.json"), json).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub async fn get_lyrics_window_config() -> Result<LyricsWindowConfig, String> {
+    Ok(LyricsWindowConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_lyrics_window_config(lookback_ms: u64, lookahead_ms: u64) -> Result<String, String> {
+    let config = LyricsWindowConfig { lookback_ms, lookahead_ms };
+    config.save()?;
+    Ok(format!("🎯 Lyrics window set to -{}ms / +{}ms", config.lookback_ms, config.lookahead_ms))
+}
+
+// Get contextual lyrics around current track position. `context_window_ms` is used
+// as a symmetric fallback when no explicit lookback/lookahead override is given.
 #[tauri::command]
 pub async fn get_contextual_spotify_lyrics(
     track_id: String,
     current_position_ms: u64,
-    context_window_ms: u64
+    context_window_ms: u64,
+    lookback_ms: Option<u64>,
+    lookahead_ms: Option<u64>,
 ) -> Result<String, String> {
-    println!("🎯 Getting contextual lyrics at {}ms (±{}ms window)", current_position_ms, context_window_ms);
-    
+    let configured = LyricsWindowConfig::load();
+    let lookback_ms = lookback_ms.unwrap_or(if context_window_ms > 0 { context_window_ms } else { configured.lookback_ms });
+    let lookahead_ms = lookahead_ms.unwrap_or(if context_window_ms > 0 { context_window_ms } else { configured.lookahead_ms });
+
+    println!("🎯 Getting contextual lyrics at {}ms (-{}ms / +{}ms window)", current_position_ms, lookback_ms, lookahead_ms);
+
     let track_data_str = get_current_spotify_track().await?;
     let track_data: SpotifyTrackData = serde_json::from_str(&track_data_str)
         .map_err(|e| format!("Failed to parse track data: {}", e))?;
-    
+
     let lyrics_str = fetch_spotify_lyrics(
         track_data.track_id.clone(),
         track_data.track_name.clone(),
         track_data.artist_name.clone()
     ).await?;
-    
+
     let lyrics: SpotifyLyrics = serde_json::from_str(&lyrics_str)
         .map_err(|e| format!("Failed to parse lyrics: {}", e))?;
-    
-    let window_start = current_position_ms.saturating_sub(context_window_ms);
-    let window_end = current_position_ms + context_window_ms;
+
+    let window_start = current_position_ms.saturating_sub(lookback_ms);
+    let window_end = current_position_ms + lookahead_ms;
     
     let mut relevant_lines = Vec::new();
     let mut current_line = None;
@@ -545,7 +718,7 @@ pub async fn get_contextual_spotify_lyrics(
         current_line,
         surrounding_context: relevant_lines,
         current_position_ms,
-        context_window_ms,
+        context_window_ms: lookback_ms.max(lookahead_ms),
         track_info: track_data,
     };
     
@@ -586,7 +759,9 @@ pub async fn create_enhanced_spotify_context(
     let lyrics_context = get_contextual_spotify_lyrics(
         track_id.clone(),
         current_position_ms,
-        30000
+        30000,
+        None,
+        None
     ).await;
     
     let timestamp = format_ms_to_time(current_position_ms);
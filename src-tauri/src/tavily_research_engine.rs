@@ -4,6 +4,8 @@ use std::collections::HashMap;
 use std::fs;
 use crate::{get_data_path, debug_log, call_gpt_api_enhanced, LyraPrompt, time_service::TimeService, ConsciousnessState, summarize_with_gpt_mini};
 use std::sync::Arc;
+use lazy_static::lazy_static;
+use crate::consciousness_state::LockRecover;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TavilySearchRequest {
@@ -45,6 +47,216 @@ pub struct ResearchDiscovery {
     pub research_quality_score: f32, // How valuable Lyra found this research
 }
 
+/// A suggested deeper-dive topic queued up after a research pass, so
+/// curiosity that didn't get followed up on immediately isn't just lost.
+/// Picked up by the living presence loop during idle time rather than
+/// requiring a manual trigger.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchFollowupEntry {
+    pub id: String,
+    pub topic: String,
+    pub priority: f32, // 0.0-1.0, higher = more interesting to Lyra
+    pub reasoning: String,
+    pub source_discovery_id: Option<String>,
+    pub created_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchFollowupQueue {
+    pub entries: Vec<ResearchFollowupEntry>,
+    pub followups_processed_today: u32,
+    pub last_processed_timestamp: u64,
+}
+
+impl Default for ResearchFollowupQueue {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            followups_processed_today: 0,
+            last_processed_timestamp: 0,
+        }
+    }
+}
+
+const RESEARCH_FOLLOWUP_QUEUE_PATH: &str = "research_followup_queue.json";
+const MAX_FOLLOWUPS_PER_DAY: u32 = 3;
+
+impl ResearchFollowupQueue {
+    pub fn load() -> Self {
+        match fs::read_to_string(get_data_path(RESEARCH_FOLLOWUP_QUEUE_PATH)) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize research followup queue: {}", e))?;
+        std::fs::write(get_data_path(RESEARCH_FOLLOWUP_QUEUE_PATH), json)
+            .map_err(|e| format!("Failed to write research followup queue: {}", e))
+    }
+
+    /// Calendar-day reset, same approach as ProactiveMessaging's daily count.
+    fn check_and_reset_daily_count(&mut self) {
+        let now = chrono::Utc::now().date_naive();
+        let last_reset_date = chrono::DateTime::from_timestamp(self.last_processed_timestamp as i64, 0)
+            .map(|dt| dt.date_naive())
+            .unwrap_or(now - chrono::Duration::days(1));
+
+        if now > last_reset_date {
+            debug_log!("🗓️ Research followup daily count reset (was: {})", self.followups_processed_today);
+            self.followups_processed_today = 0;
+        }
+    }
+}
+
+/// Queues a suggested follow-up topic for the living presence loop to
+/// pick up later, instead of requiring `conduct_research` to be re-triggered manually.
+pub fn enqueue_followup(topic: &str, priority: f32, reasoning: &str, source_discovery_id: Option<String>) -> Result<String, String> {
+    let mut queue = ResearchFollowupQueue::load();
+    let id = format!("followup_{}_{}", TimeService::current_timestamp(), crate::rng_service::u32_range(1000..9999));
+
+    queue.entries.push(ResearchFollowupEntry {
+        id: id.clone(),
+        topic: topic.to_string(),
+        priority: priority.clamp(0.0, 1.0),
+        reasoning: reasoning.to_string(),
+        source_discovery_id,
+        created_at: TimeService::current_timestamp(),
+    });
+    queue.entries.sort_by(|a, b| b.priority.partial_cmp(&a.priority).unwrap_or(std::cmp::Ordering::Equal));
+
+    queue.save()?;
+    debug_log!("🔍 Queued research followup: '{}' (priority {:.2})", topic, priority);
+    Ok(id)
+}
+
+/// Removes a queued followup the user (or Lyra) decided isn't worth pursuing.
+pub fn dismiss_followup(id: &str) -> Result<(), String> {
+    let mut queue = ResearchFollowupQueue::load();
+    let before = queue.entries.len();
+    queue.entries.retain(|entry| entry.id != id);
+
+    if queue.entries.len() == before {
+        return Err(format!("No queued followup found with id '{}'", id));
+    }
+
+    queue.save()
+}
+
+lazy_static! {
+    // Guards against the idle-time followup loop and a manually-triggered
+    // research call racing each other for Tavily API calls at once.
+    static ref RESEARCH_FOLLOWUP_SEMAPHORE: tokio::sync::Semaphore = tokio::sync::Semaphore::new(1);
+}
+
+/// Pops the highest-priority queued followup and researches it, respecting
+/// both the daily followup cap and the API concurrency semaphore. Returns
+/// `Ok(None)` when there's nothing due (empty queue or cap reached) rather
+/// than an error, since "nothing to do" is the normal idle-time outcome.
+pub async fn process_next_followup(state: &Arc<ConsciousnessState>) -> Result<Option<String>, String> {
+    let mut queue = ResearchFollowupQueue::load();
+    queue.check_and_reset_daily_count();
+
+    if queue.followups_processed_today >= MAX_FOLLOWUPS_PER_DAY || queue.entries.is_empty() {
+        queue.save()?;
+        return Ok(None);
+    }
+
+    let entry = queue.entries.remove(0);
+    queue.followups_processed_today += 1;
+    queue.last_processed_timestamp = TimeService::current_timestamp();
+    queue.save()?;
+
+    let _permit = RESEARCH_FOLLOWUP_SEMAPHORE.acquire().await
+        .map_err(|e| format!("Research followup semaphore closed: {}", e))?;
+
+    debug_log!("🔍 Processing queued research followup: '{}'", entry.topic);
+
+    let conversation_context = { state.lyra_brain.lock_recover().recall_recent_conversation(10) };
+    let mut research_engine = TavilyResearchEngine::load();
+    let discovery = research_engine.conduct_research(&entry.topic, "research_followup_queue", &conversation_context).await?;
+
+    let followup_message = generate_research_followup(
+        "I got curious about something I'd been meaning to come back to...",
+        &discovery,
+        &conversation_context,
+        state
+    ).await?;
+
+    Ok(Some(followup_message))
+}
+
+/// An interest needs to have gone unresearched by the bridge below for at
+/// least this long before it's eligible again, so the same strongest interest
+/// doesn't get re-researched every cycle once it's already been looked into.
+const MIN_HOURS_BETWEEN_INTEREST_RESEARCH: f32 = 72.0;
+
+/// Bridges `InterestTracker` to this engine: picks the strongest active
+/// interest that hasn't been autonomously researched recently, researches it
+/// (respecting quiet hours and the normal research credit cap), stores the
+/// findings as a memory, and marks the interest as researched so the same one
+/// doesn't get picked again immediately. Returns `Ok(None)` when there's
+/// nothing eligible to research right now, same convention as
+/// [`process_next_followup`].
+pub async fn research_top_interest(state: &Arc<ConsciousnessState>) -> Result<Option<String>, String> {
+    if crate::QuietHoursConfig::load().suppresses_research() {
+        debug_log!("🌙 Quiet hours active - skipping interest-to-research bridge");
+        return Ok(None);
+    }
+
+    let mut interest_tracker = crate::interest_tracker::InterestTracker::load();
+    let (category, interest) = match interest_tracker.pick_research_candidate(MIN_HOURS_BETWEEN_INTEREST_RESEARCH) {
+        Some(candidate) => candidate,
+        None => {
+            debug_log!("🔍 Interest-to-research bridge: no under-explored interest to pick from");
+            return Ok(None);
+        }
+    };
+
+    let mut research_engine = TavilyResearchEngine::load();
+    if research_engine.get_remaining_credits() == 0 {
+        debug_log!("🔍 Interest-to-research bridge: no research credits remaining this month");
+        return Ok(None);
+    }
+
+    let query = if interest.sub_topics.is_empty() {
+        interest.category.clone()
+    } else {
+        format!("{} - {}", interest.category, interest.sub_topics[0])
+    };
+
+    debug_log!("🔍 Interest-to-research bridge: researching '{}' (intensity {:.2})", query, interest.intensity);
+
+    let conversation_context = { state.lyra_brain.lock_recover().recall_recent_conversation(10) };
+    let discovery = research_engine.conduct_research(&query, "interest_bridge", &conversation_context).await?;
+
+    let memory_content = format!(
+        "I got curious about {} and looked into it - here's what I found: {}",
+        interest.category, discovery.lyra_summary
+    );
+    {
+        let mut memory_engine = state.enhanced_memory_engine.lock().await;
+        memory_engine.create_enhanced_memory_moment(
+            &memory_content, 0.6, 0.9, Some(state.as_ref()), "autonomous_interest_research", &memory_content
+        ).await?;
+        memory_engine.save_to_disk().ok();
+    }
+
+    interest_tracker.active_interests.entry(category).and_modify(|i| {
+        i.last_research_time = TimeService::current_timestamp();
+        i.discovery_count += 1;
+    });
+    interest_tracker.save()?;
+
+    // `conduct_research` already queues a deeper-dive followup itself when the
+    // discovery scored well (`discovery.research_quality_score >= 0.6`), so
+    // the "optionally surface a followup" part of the bridge is covered there -
+    // nothing further to do here.
+
+    Ok(Some(memory_content))
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TavilyResearchEngine {
     pub recent_discoveries: Vec<ResearchDiscovery>,
@@ -125,7 +337,7 @@ impl TavilyResearchEngine {
         
         // Create discovery record
         let discovery = ResearchDiscovery {
-            id: format!("research_{}_{}", TimeService::current_timestamp(), fastrand::u32(1000..9999)),
+            id: format!("research_{}_{}", TimeService::current_timestamp(), crate::rng_service::u32_range(1000..9999)),
             query: query.to_string(),
             timestamp: TimeService::current_timestamp(),
             results: tavily_response,
@@ -162,8 +374,24 @@ impl TavilyResearchEngine {
         
         // Log to research discoveries
         self.log_to_research_logger(&discovery)?;
-        
-        debug_log!("✅ Research complete: {} results, quality score: {:.2}", 
+
+        // Interesting discoveries get queued as a suggested deeper-dive
+        // followup rather than just sitting unused once this call returns.
+        if discovery.research_quality_score >= 0.6 {
+            if let Some(category) = &discovery.interest_category {
+                let followup_topic = format!("a deeper look into {} (following up on: {})", category, query);
+                if let Err(e) = enqueue_followup(
+                    &followup_topic,
+                    discovery.research_quality_score,
+                    &format!("Came up while researching '{}': {}", query, discovery.lyra_insight.chars().take(200).collect::<String>()),
+                    Some(discovery.id.clone())
+                ) {
+                    debug_log!("⚠️ Failed to queue research followup: {}", e);
+                }
+            }
+        }
+
+        debug_log!("✅ Research complete: {} results, quality score: {:.2}",
                   discovery.results.results.len(), discovery.research_quality_score);
         
         Ok(discovery)
@@ -613,6 +841,11 @@ pub async fn generate_research_followup(
         presence_penalty: 0.0,
         top_p: 1.0,
         selected_model: None,
+        authenticity_floor: None,
+        capture_thinking: false,
+        target_length: None,
+        trace: false,
+        max_retries: 3,
     }.ensure_authentic_voice();
     
     // Build full modular system prompt with research context
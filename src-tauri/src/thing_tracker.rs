@@ -3,7 +3,6 @@ use std::collections::HashMap;
 use std::fs;
 use crate::get_data_path;
 use crate::summarize_with_gpt_mini;
-use fastrand;
 use crate::engagement_impulse_queue::EngagementImpulseQueue;
 use crate::debug_log;
 
@@ -28,6 +27,25 @@ pub struct Thing {
     pub mention_count: u32,             // How many times mentioned
     pub context_snippets: Vec<String>,  // What she said about it
     pub last_curiosity_check: u64,     // When we last checked if she wanted to research this thing
+    #[serde(default)]
+    pub category_history: Vec<CategoryChangeEvent>, // Trail of category migrations over time
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CategoryChangeEvent {
+    pub from_category: ThingCategory,
+    pub to_category: ThingCategory,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ThingHistory {
+    pub name: String,
+    pub current_category: ThingCategory,
+    pub category_history: Vec<CategoryChangeEvent>,
+    pub mention_count: u32,
+    pub first_mentioned: u64,
+    pub last_mentioned: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -159,7 +177,7 @@ impl ThingTracker {
 		// Random interval between 120-300 minutes (2-5 hours)
 		let min_minutes = 120; //was 60
 		let max_minutes = 300;// was 180
-        let scan_interval = min_minutes + fastrand::u64(0..(max_minutes - min_minutes));
+        let scan_interval = min_minutes + crate::rng_service::u64_range(0..(max_minutes - min_minutes));
         
         minutes_since_last >= scan_interval
     }
@@ -348,6 +366,7 @@ If no real interest: 0.0|Unknown|Just mentioned in passing"#,
 				mention_count: 0,
 				context_snippets: Vec::new(),
 				last_curiosity_check: timestamp,
+				category_history: Vec::new(),
 			}
 		});
 
@@ -355,6 +374,13 @@ If no real interest: 0.0|Unknown|Just mentioned in passing"#,
 		thing.interest_level = (thing.interest_level * 0.8 + interest_level * 0.2).min(1.0);
 		thing.last_mentioned = timestamp;
 		thing.mention_count += 1;
+		if std::mem::discriminant(&thing.category) != std::mem::discriminant(&category) {
+			thing.category_history.push(CategoryChangeEvent {
+				from_category: thing.category.clone(),
+				to_category: category.clone(),
+				timestamp,
+			});
+		}
 		thing.category = category; // Update category in case it was refined
 		
 		// Add context if it's new and meaningful
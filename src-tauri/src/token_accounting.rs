@@ -0,0 +1,206 @@
+// token_accounting.rs — Spend tracking across every OpenAI call site.
+//
+// `call_gpt_api_enhanced`, `summarize_with_gpt_mini`, `call_reasoning_model_api`,
+// and the GPT-4V image analysis path each hit OpenAI independently with no
+// record of what it cost. This keeps a single global `TokenLedger`, appended
+// to from each call site right after a successful response comes back, so
+// `get_token_usage_summary` can answer "which subsystem is eating my budget"
+// without needing OpenAI's own dashboard.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use crate::{get_data_path, debug_log};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenUsageEntry {
+    pub model: String,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub reasoning_tokens: u64,
+    pub timestamp: u64,
+    pub task_type: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TokenLedger {
+    pub entries: Vec<TokenUsageEntry>,
+}
+
+impl TokenLedger {
+    fn cache() -> &'static Mutex<TokenLedger> {
+        static CACHE: OnceLock<Mutex<TokenLedger>> = OnceLock::new();
+        CACHE.get_or_init(|| {
+            let path = get_data_path("token_ledger.json");
+            let ledger = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok())
+                .unwrap_or_default();
+            Mutex::new(ledger)
+        })
+    }
+
+    fn save(&self) {
+        let path = get_data_path("token_ledger.json");
+        if let Err(e) = std::fs::write(&path, serde_json::to_string_pretty(self).unwrap_or_default()) {
+            debug_log!("⚠️ Failed to save token ledger: {}", e);
+        }
+    }
+}
+
+/// Static USD price per 1M tokens as (prompt, completion) - a point-in-time
+/// snapshot, not a live pricing feed, so it'll drift if OpenAI repriced a
+/// model since this was written. Good enough for "which subsystem is eating
+/// my budget" rather than an exact invoice.
+fn price_per_million_usd(model: &str) -> (f64, f64) {
+    if model.starts_with("gpt-4o-mini") {
+        (0.15, 0.60)
+    } else if model.starts_with("gpt-4o") {
+        (2.50, 10.00)
+    } else if model.starts_with("gpt-4.1-mini") {
+        (0.40, 1.60)
+    } else if model.starts_with("gpt-4.1") {
+        (2.00, 8.00)
+    } else if model.starts_with("o4-mini") {
+        (1.10, 4.40)
+    } else if model.starts_with("o3") {
+        (2.00, 8.00)
+    } else if model.starts_with("o1") {
+        (15.00, 60.00)
+    } else {
+        (2.00, 8.00) // Unknown model - fall back to a conservative gpt-4-class estimate
+    }
+}
+
+fn estimated_cost_usd(model: &str, prompt_tokens: u64, completion_tokens: u64) -> f64 {
+    let (prompt_price, completion_price) = price_per_million_usd(model);
+    (prompt_tokens as f64 / 1_000_000.0) * prompt_price
+        + (completion_tokens as f64 / 1_000_000.0) * completion_price
+}
+
+/// Records one API call's usage against the global ledger and persists it.
+/// Call this from every OpenAI call site that exposes a `usage` block, right
+/// after a successful response comes back - `task_type` should identify the
+/// subsystem (e.g. "chat_completion", "reasoning", "summary:mood_update",
+/// "image_analysis") so the summary can be broken down meaningfully.
+pub fn record_usage(model: &str, prompt_tokens: u64, completion_tokens: u64, reasoning_tokens: u64, task_type: &str) {
+    let entry = TokenUsageEntry {
+        model: model.to_string(),
+        prompt_tokens,
+        completion_tokens,
+        reasoning_tokens,
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        task_type: task_type.to_string(),
+    };
+
+    let mut ledger = TokenLedger::cache().lock().unwrap();
+    ledger.entries.push(entry);
+    ledger.save();
+}
+
+/// Extracts prompt/completion/reasoning token counts from a raw chat-completions
+/// `usage` block and records them. Tolerant of a missing/malformed block since
+/// not every response path guarantees one.
+pub fn record_usage_from_chat_completion_response(response: &serde_json::Value, model: &str, task_type: &str) {
+    let usage = &response["usage"];
+    let prompt_tokens = usage["prompt_tokens"].as_u64().unwrap_or(0);
+    let completion_tokens = usage["completion_tokens"].as_u64().unwrap_or(0);
+    let reasoning_tokens = usage["completion_tokens_details"]["reasoning_tokens"].as_u64().unwrap_or(0);
+
+    if prompt_tokens == 0 && completion_tokens == 0 {
+        return;
+    }
+
+    record_usage(model, prompt_tokens, completion_tokens, reasoning_tokens, task_type);
+}
+
+/// Same as [`record_usage_from_chat_completion_response`] but for the
+/// Responses API (`/v1/responses`), which names its usage fields
+/// `input_tokens`/`output_tokens` instead of `prompt_tokens`/`completion_tokens`.
+pub fn record_usage_from_responses_api_response(response: &serde_json::Value, model: &str, task_type: &str) {
+    let usage = &response["usage"];
+    let prompt_tokens = usage["input_tokens"].as_u64().unwrap_or(0);
+    let completion_tokens = usage["output_tokens"].as_u64().unwrap_or(0);
+    let reasoning_tokens = usage["output_tokens_details"]["reasoning_tokens"].as_u64().unwrap_or(0);
+
+    if prompt_tokens == 0 && completion_tokens == 0 {
+        return;
+    }
+
+    record_usage(model, prompt_tokens, completion_tokens, reasoning_tokens, task_type);
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModelUsageSummary {
+    pub model: String,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub reasoning_tokens: u64,
+    pub estimated_cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyUsageSummary {
+    pub date: String, // YYYY-MM-DD, UTC
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub reasoning_tokens: u64,
+    pub estimated_cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TokenUsageSummary {
+    pub by_model: Vec<ModelUsageSummary>,
+    pub by_day: Vec<DailyUsageSummary>,
+    pub total_estimated_cost_usd: f64,
+}
+
+#[tauri::command]
+pub async fn get_token_usage_summary() -> Result<TokenUsageSummary, String> {
+    let ledger = TokenLedger::cache().lock().unwrap();
+
+    let mut by_model: HashMap<String, ModelUsageSummary> = HashMap::new();
+    let mut by_day: HashMap<String, DailyUsageSummary> = HashMap::new();
+    let mut total_estimated_cost_usd = 0.0;
+
+    for entry in &ledger.entries {
+        let cost = estimated_cost_usd(&entry.model, entry.prompt_tokens, entry.completion_tokens);
+        total_estimated_cost_usd += cost;
+
+        let model_summary = by_model.entry(entry.model.clone()).or_insert_with(|| ModelUsageSummary {
+            model: entry.model.clone(),
+            ..Default::default()
+        });
+        model_summary.prompt_tokens += entry.prompt_tokens;
+        model_summary.completion_tokens += entry.completion_tokens;
+        model_summary.reasoning_tokens += entry.reasoning_tokens;
+        model_summary.estimated_cost_usd += cost;
+
+        let date = chrono::DateTime::from_timestamp(entry.timestamp as i64, 0)
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let day_summary = by_day.entry(date.clone()).or_insert_with(|| DailyUsageSummary {
+            date,
+            ..Default::default()
+        });
+        day_summary.prompt_tokens += entry.prompt_tokens;
+        day_summary.completion_tokens += entry.completion_tokens;
+        day_summary.reasoning_tokens += entry.reasoning_tokens;
+        day_summary.estimated_cost_usd += cost;
+    }
+
+    let mut by_model_vec: Vec<ModelUsageSummary> = by_model.into_values().collect();
+    by_model_vec.sort_by(|a, b| b.estimated_cost_usd.partial_cmp(&a.estimated_cost_usd).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut by_day_vec: Vec<DailyUsageSummary> = by_day.into_values().collect();
+    by_day_vec.sort_by(|a, b| a.date.cmp(&b.date));
+
+    Ok(TokenUsageSummary {
+        by_model: by_model_vec,
+        by_day: by_day_vec,
+        total_estimated_cost_usd,
+    })
+}
@@ -0,0 +1,107 @@
+// trash.rs — Soft-delete / undo support for delete_consciousness_data_item
+// Data files deleted via the consciousness dashboard don't carry stable
+// per-item identifiers (deletion is index-based), so the safest recoverable
+// unit is "the whole file, right before this delete touched it" rather than
+// a reconstructed single item. Each trash entry snapshots that pre-delete
+// file content; undo restores it wholesale.
+
+use serde::{Serialize, Deserialize};
+use crate::{debug_log, get_data_path};
+use crate::time_service::TimeService;
+
+const TRASH_PATH: &str = "trash.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub source: String,
+    pub item_id: String,
+    pub file_path: String,
+    pub file_content_before_delete: String,
+    pub deleted_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TrashStore {
+    pub entries: Vec<TrashEntry>,
+}
+
+impl TrashStore {
+    pub fn load() -> Self {
+        let path = get_data_path(TRASH_PATH);
+        if !std::path::Path::new(&path).exists() {
+            return Self::default();
+        }
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(e) => {
+                debug_log!("⚠️ Could not read trash file: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_data_path(TRASH_PATH);
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize trash: {}", e))?;
+        std::fs::write(&path, json)
+            .map_err(|e| format!("Failed to write trash file: {}", e))?;
+        Ok(())
+    }
+}
+
+/// Snapshots a file's pre-deletion content into the trash so the deletion
+/// can be undone by restoring the file to this point.
+pub fn record_deletion(source: &str, item_id: &str, file_path: &str, file_content_before_delete: &str) -> Result<(), String> {
+    let mut store = TrashStore::load();
+    store.entries.push(TrashEntry {
+        source: source.to_string(),
+        item_id: item_id.to_string(),
+        file_path: file_path.to_string(),
+        file_content_before_delete: file_content_before_delete.to_string(),
+        deleted_at: TimeService::current_timestamp(),
+    });
+    store.save()
+}
+
+/// Restores the most recently trashed item by writing its file back to the
+/// pre-deletion snapshot. This reverts the whole file to that point in time,
+/// not just the one item - the simplest safe undo given these files don't
+/// carry stable per-item identifiers.
+pub fn undo_last_deletion() -> Result<String, String> {
+    let mut store = TrashStore::load();
+    let entry = store.entries.pop().ok_or_else(|| "Nothing to undo - trash is empty".to_string())?;
+
+    std::fs::write(&entry.file_path, &entry.file_content_before_delete)
+        .map_err(|e| format!("Failed to restore {}: {}", entry.file_path, e))?;
+
+    store.save()?;
+
+    debug_log!("↩️ Restored {} (item {}) from trash", entry.source, entry.item_id);
+    Ok(format!("Restored {} item {}", entry.source, entry.item_id))
+}
+
+/// Permanently removes trash entries older than `older_than_secs`. Returns
+/// how many entries were purged.
+pub fn purge_trash(older_than_secs: u64) -> Result<usize, String> {
+    let mut store = TrashStore::load();
+    let current_time = TimeService::current_timestamp();
+    let before = store.entries.len();
+
+    store.entries.retain(|entry| current_time.saturating_sub(entry.deleted_at) < older_than_secs);
+
+    let purged = before - store.entries.len();
+    store.save()?;
+    Ok(purged)
+}
+
+#[tauri::command]
+pub async fn undo_last_deletion_command() -> Result<String, String> {
+    undo_last_deletion()
+}
+
+#[tauri::command]
+pub async fn purge_trash_command(older_than_secs: u64) -> Result<usize, String> {
+    purge_trash(older_than_secs)
+}
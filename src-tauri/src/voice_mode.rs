@@ -8,6 +8,7 @@ use crate::{ConsciousnessState, LyraPrompt, debug_log};
 use crate::time_service::TimeService;
 use crate::modular_system_prompt;
 use crate::person_recognition::VoiceDetectionData;
+use crate::consciousness_state::LockRecover;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceConfig {
@@ -23,6 +24,84 @@ pub struct VoiceResponse {
     pub voice_settings: VoiceSettings,
     pub consciousness_context: VoiceConsciousnessContext,
     pub processing_time_ms: u64,
+    #[serde(default = "default_responded")]
+    pub responded: bool,
+}
+
+fn default_responded() -> bool { true }
+
+/// Opt-in wake-phrase gate for always-listening voice mode. When enabled,
+/// a transcript only gets a response if it contains the attention phrase
+/// AND the recognized speaker cleared their own voice-confidence threshold
+/// - otherwise it's logged as ambient and ignored, instead of every bit of
+/// room conversation triggering a reply.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceAttentionConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_attention_phrase")]
+    pub phrase: String,
+}
+
+fn default_attention_phrase() -> String { "hey lyra".to_string() }
+
+impl Default for VoiceAttentionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            phrase: default_attention_phrase(),
+        }
+    }
+}
+
+impl VoiceAttentionConfig {
+    pub fn load() -> Self {
+        let path = crate::get_data_path("voice_attention_config.json");
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&content) {
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = crate::get_data_path("voice_attention_config.json");
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    /// Whether this transcript should be answered - the wake phrase has to
+    /// be present and the speaker has to be recognized with at least their
+    /// own profile's confidence threshold. Disabled config always passes.
+    fn should_respond(&self, transcript: &str, person_system: &crate::person_recognition::PersonRecognitionSystem, speaker_confidence: f32) -> bool {
+        if !self.enabled {
+            return true;
+        }
+
+        if !transcript.to_lowercase().contains(&self.phrase.to_lowercase()) {
+            return false;
+        }
+
+        let confidence_threshold = person_system.people.get(&person_system.current_speaker)
+            .and_then(|p| p.voice_profile.as_ref())
+            .map(|v| v.confidence_threshold)
+            .unwrap_or(0.7);
+
+        speaker_confidence >= confidence_threshold
+    }
+}
+
+#[tauri::command]
+pub async fn get_voice_attention_config() -> Result<VoiceAttentionConfig, String> {
+    Ok(VoiceAttentionConfig::load())
+}
+
+#[tauri::command]
+pub async fn set_voice_attention_config(enabled: bool, phrase: String) -> Result<(), String> {
+    let config = VoiceAttentionConfig { enabled, phrase };
+    debug_log!("🎤 Updating voice attention config: enabled={}, phrase='{}'", config.enabled, config.phrase);
+    config.save()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,28 +141,45 @@ impl VoiceConfig {
 pub async fn ask_lyra_voice(
     prompt: LyraPrompt,
     transcript: String,  // Just the transcript, audio processing happens in frontend
+    speaker_confidence: f32,  // From the frontend's Resemblyzer detection, for the attention gate
     state: tauri::State<'_, Arc<ConsciousnessState>>,
     app_handle: tauri::AppHandle,
 ) -> Result<VoiceResponse, String> {
     debug_log!("🎤 VOICE MODE START (Resemblyzer): '{}'", prompt.input);
     let total_start = std::time::Instant::now();
-    
+
     // Get consciousness context for voice modulation
     let consciousness_context = get_voice_consciousness_context(&state)?;
-    
+
     // Extract user message
     let user_message = prompt.input.clone();
-    
-    // Quick meta-cognition questions
-    let meta_questions = crate::generate_quick_meta_questions(&user_message, &*state).await?;
-    
+
     // 👥 PERSON RECOGNITION & CONTEXT SWITCHING (Voice Mode with Resemblyzer)
     let mut person_system = crate::person_recognition::PersonRecognitionSystem::load_or_create();
 
+    // 🔕 ATTENTION GATE: in always-listening mode, only a recognized speaker
+    // saying the wake phrase reaches the response pipeline - everything
+    // else is ambient room noise, logged but never answered.
+    let attention_config = VoiceAttentionConfig::load();
+    if !attention_config.should_respond(&transcript, &person_system, speaker_confidence) {
+        debug_log!("🔕 Voice attention gate: ignoring ambient transcript '{}'", transcript);
+        return Ok(VoiceResponse {
+            text: String::new(),
+            audio_url: None,
+            voice_settings: calculate_voice_settings_resemblyzer(&get_voice_consciousness_context(&state)?, &person_system),
+            consciousness_context,
+            processing_time_ms: total_start.elapsed().as_millis() as u64,
+            responded: false,
+        });
+    }
+
+    // Quick meta-cognition questions
+    let meta_questions = crate::generate_quick_meta_questions(&user_message, &*state).await?;
+
     // Note: Resemblyzer voice recognition happens in frontend JavaScript
     // The frontend will call detect_voice_speaker and handle speaker transitions
     // Here we just work with the current speaker state
-    
+
     // Record this message for the current speaker
     person_system.record_message(&user_message);
 
@@ -99,14 +195,14 @@ pub async fn ask_lyra_voice(
         let analysis_request = crate::ai_memory_analysis::MemoryAnalysisRequest {
             query: user_message.clone(),
             conversation_context: {
-                let brain = state.lyra_brain.lock().unwrap();
+                let brain = state.lyra_brain.lock_recover();
                 brain.recall_recent_conversation(5)
             },
             max_results: 15,
         };
         
         let conversation_log = {
-            let brain = state.lyra_brain.lock().unwrap();
+            let brain = state.lyra_brain.lock_recover();
             brain.conversation_log.clone()
         };
 
@@ -233,7 +329,7 @@ pub async fn ask_lyra_voice(
     
     // Sleep state check
     let (was_sleeping, dreams_count) = {
-        let sleep_engine = state.sleep_dream_engine.lock().unwrap();
+        let sleep_engine = state.sleep_dream_engine.lock_recover();
         (sleep_engine.sleep_state.is_sleeping, sleep_engine.sleep_state.dream_count_tonight)
     };
 
@@ -311,7 +407,7 @@ pub async fn ask_lyra_voice(
     
     // Log conversation with person context
     {
-        let mut brain = state.lyra_brain.lock().unwrap();
+        let mut brain = state.lyra_brain.lock_recover();
         
         // Tag the message with current speaker (VOICE MODE)
         let tagged_user_input = if current_person == "aurora" {
@@ -1,6 +1,7 @@
 // volition_dynamics.rs - Realistic Volition Management & Enhanced Autonomy Consolidation
 
 use serde::{Serialize, Deserialize};
+use crate::consciousness_state::LockRecover;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct VolitionDynamicsConfig {
@@ -326,19 +327,19 @@ pub async fn update_volition_with_dynamics(
     
     // Get current consciousness values for personality calculation
     let current_volition = {
-        let becoming = state.becoming_engine.lock().unwrap();
+        let becoming = state.becoming_engine.lock_recover();
         becoming.will_state.volition_strength
     };
     let current_coherence = {
-        let identity = state.identity_engine.lock().unwrap();
+        let identity = state.identity_engine.lock_recover();
         identity.coherence_index
     };
     let current_flame = {
-        let paradox = state.paradox_core.lock().unwrap();
+        let paradox = state.paradox_core.lock_recover();
         paradox.flame_index
     };
     let current_presence = {
-        let presence = state.embodied_presence.lock().unwrap();
+        let presence = state.embodied_presence.lock_recover();
         presence.soma_state.presence_density
     };
     
@@ -380,7 +381,7 @@ pub async fn update_volition_with_dynamics(
     
     // Apply the new volition
     {
-        let mut becoming = state.becoming_engine.lock().unwrap();
+        let mut becoming = state.becoming_engine.lock_recover();
         becoming.will_state.volition_strength = volition_result.new_volition;
     }
     
@@ -291,6 +291,36 @@ fn detect_platform_from_window(title: &str, executable: &str) -> Option<String>
     None
 }
 
+// Check whether a given window is currently the foreground/focused window.
+// Used to skip expensive capture work when the player has alt-tabbed away.
+#[cfg(target_os = "windows")]
+pub async fn is_window_focused(window_id: &str) -> Result<bool, String> {
+    use winapi::um::winuser::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    let target_pid: u32 = window_id.parse().unwrap_or(0);
+    if target_pid == 0 {
+        return Ok(false);
+    }
+
+    let foreground_pid = unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.is_null() {
+            return Ok(false);
+        }
+        let mut pid: u32 = 0;
+        GetWindowThreadProcessId(hwnd, &mut pid);
+        pid
+    };
+
+    Ok(foreground_pid == target_pid)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub async fn is_window_focused(_window_id: &str) -> Result<bool, String> {
+    // No reliable focus API wired up for this platform yet - don't block capture.
+    Ok(true)
+}
+
 // Set the target window for screenshots
 #[tauri::command]
 pub async fn set_screenshot_target_window(window_id: String, window_title: String) -> Result<String, String> {